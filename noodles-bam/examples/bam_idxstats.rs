@@ -18,16 +18,14 @@ fn main() -> io::Result<()> {
 
     let index = reader.index();
 
-    for ((reference_sequence_name, reference_sequence), index_reference_sequence) in header
+    for (
+        (reference_sequence_name, reference_sequence),
+        (mapped_record_count, unmapped_record_count),
+    ) in header
         .reference_sequences()
         .iter()
-        .zip(index.reference_sequences())
+        .zip(index.record_counts())
     {
-        let (mapped_record_count, unmapped_record_count) = index_reference_sequence
-            .metadata()
-            .map(|m| (m.mapped_record_count(), m.unmapped_record_count()))
-            .unwrap_or_default();
-
         println!(
             "{}\t{}\t{}\t{}",
             reference_sequence_name,
@@ -9,7 +9,7 @@
 use std::{env, path::PathBuf};
 
 use noodles_bam::{self as bam, bai};
-use noodles_csi::{BinningIndex, binning_index::ReferenceSequence};
+use noodles_csi::BinningIndex;
 use tokio::{fs::File, io};
 
 #[tokio::main]
@@ -21,16 +21,14 @@ async fn main() -> io::Result<()> {
 
     let index = bai::r#async::fs::read(src.with_extension("bam.bai")).await?;
 
-    for ((reference_sequence_name, reference_sequence), index_reference_sequence) in header
+    for (
+        (reference_sequence_name, reference_sequence),
+        (mapped_record_count, unmapped_record_count),
+    ) in header
         .reference_sequences()
         .iter()
-        .zip(index.reference_sequences())
+        .zip(index.record_counts())
     {
-        let (mapped_record_count, unmapped_record_count) = index_reference_sequence
-            .metadata()
-            .map(|m| (m.mapped_record_count(), m.unmapped_record_count()))
-            .unwrap_or_default();
-
         println!(
             "{}\t{}\t{}\t{}",
             reference_sequence_name,
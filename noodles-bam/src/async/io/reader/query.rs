@@ -2,12 +2,12 @@ use std::vec;
 
 use futures::{Stream, stream};
 use noodles_bgzf as bgzf;
-use noodles_core::region::Interval;
+use noodles_core::region::{Interval, QueryMode};
 use noodles_csi::binning_index::index::reference_sequence::bin::Chunk;
 use tokio::io::{self, AsyncRead, AsyncSeek};
 
 use super::Reader;
-use crate::{Record, io::reader::query::intersects};
+use crate::{Record, io::reader::query::matches};
 
 enum State {
     Seek,
@@ -65,7 +65,12 @@ where
                             ctx.state = State::Seek;
                         }
 
-                        if intersects(&record, ctx.reference_sequence_id, ctx.interval)? {
+                        if matches(
+                            &record,
+                            ctx.reference_sequence_id,
+                            ctx.interval,
+                            QueryMode::Overlap,
+                        )? {
                             return Ok(Some((record, ctx)));
                         }
                     }
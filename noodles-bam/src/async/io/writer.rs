@@ -11,6 +11,7 @@ use crate::Record;
 pub struct Writer<W> {
     inner: W,
     buf: Vec<u8>,
+    strict: bool,
 }
 
 impl<W> Writer<W>
@@ -59,6 +60,22 @@ where
         self.inner
     }
 
+    /// Sets whether to check record positions against the reference sequence lengths.
+    ///
+    /// See [`crate::io::Writer::set_strict`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// use tokio::io;
+    /// let mut writer = bam::r#async::io::Writer::new(io::sink());
+    /// writer.set_strict(true);
+    /// ```
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
     /// Shuts down the output stream.
     ///
     /// # Examples
@@ -149,7 +166,11 @@ where
         header: &sam::Header,
         record: &dyn sam::alignment::Record,
     ) -> io::Result<()> {
-        use crate::record::codec::encode;
+        use crate::record::codec::{encode, validate_positions};
+
+        if self.strict {
+            validate_positions(header, record)?;
+        }
 
         self.buf.clear();
         encode(&mut self.buf, header, record)?;
@@ -189,6 +210,57 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             buf: Vec::new(),
+            strict: false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::{
+            RecordBuf,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+            },
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_write_alignment_record_with_strict() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(const { NonZeroUsize::new(8).unwrap() }),
+            )
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(6)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .build();
+
+        let mut writer = Writer::new(io::sink());
+        writer.write_alignment_record(&header, &record).await?;
+
+        let mut writer = Writer::new(io::sink());
+        writer.set_strict(true);
+
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record).await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -2,17 +2,23 @@
 
 mod builder;
 
-use std::io::{self, Read};
+use std::{
+    borrow::Cow,
+    io::{self, Read},
+};
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{
+    Region,
+    region::{Interval, QueryMode},
+};
 use noodles_csi::BinningIndex;
 use noodles_sam::{self as sam, alignment::RecordBuf};
 
 pub use self::builder::Builder;
 use super::{
     Reader,
-    reader::{Query, RecordBufs, Records},
+    reader::{Query, QueryRegions, RecordBufs, Records},
 };
 use crate::Record;
 
@@ -20,6 +26,7 @@ use crate::Record;
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: Box<dyn BinningIndex>,
+    resolve_contig_aliases: bool,
 }
 
 impl<R> IndexedReader<R> {
@@ -91,6 +98,7 @@ where
         Self {
             inner: Reader::new(inner),
             index: Box::new(index),
+            resolve_contig_aliases: false,
         }
     }
 }
@@ -105,11 +113,105 @@ where
         header: &'h sam::Header,
         region: &Region,
     ) -> io::Result<Query<'r, R>> {
-        self.inner.query(header, &self.index, region)
+        let region = self.resolve_region(header, region);
+        self.inner.query(header, &self.index, &region)
+    }
+
+    /// Returns an iterator over records that match the given region using the given query mode.
+    pub fn query_with_mode<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+        region: &Region,
+        query_mode: QueryMode,
+    ) -> io::Result<Query<'r, R>> {
+        let region = self.resolve_region(header, region);
+        self.inner
+            .query_with_mode(header, &self.index, &region, query_mode)
+    }
+
+    /// Returns an iterator over records that intersect the given interval of the reference
+    /// sequence with the given ID.
+    ///
+    /// See [`Reader::query_by_reference_id`].
+    pub fn query_by_reference_id<'r>(
+        &'r mut self,
+        header: &sam::Header,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> io::Result<Query<'r, R>> {
+        self.inner
+            .query_by_reference_id(header, &self.index, reference_sequence_id, interval)
+    }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Each matching record is returned once, even if it intersects more than one region. See
+    /// [`Reader::query_regions`].
+    pub fn query_regions<'r>(
+        &'r mut self,
+        header: &sam::Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, R>> {
+        let regions: Vec<_> = regions
+            .iter()
+            .map(|region| self.resolve_region(header, region).into_owned())
+            .collect();
+
+        self.inner.query_regions(header, &self.index, &regions)
     }
 
     /// Returns an iterator of unmapped records after querying for the unmapped region.
     pub fn query_unmapped(&mut self) -> io::Result<impl Iterator<Item = io::Result<Record>>> {
         self.inner.query_unmapped(&self.index)
     }
+
+    fn resolve_region<'a>(&self, header: &sam::Header, region: &'a Region) -> Cow<'a, Region> {
+        if self.resolve_contig_aliases {
+            let reference_sequences = header.reference_sequences();
+            region.resolve_chr_alias(|name| reference_sequences.contains_key(name))
+        } else {
+            Cow::Borrowed(region)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::header::record::value::{Map, map::ReferenceSequence};
+
+    use super::*;
+    use crate::bai;
+
+    #[test]
+    fn test_resolve_region() -> Result<(), Box<dyn std::error::Error>> {
+        const LN: NonZeroUsize = match NonZeroUsize::new(8) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("chr1", Map::<ReferenceSequence>::new(LN))
+            .build();
+
+        let region = Region::new("1", Position::try_from(2)?..=Position::try_from(5)?);
+
+        let reader = Builder::default()
+            .set_index(bai::Index::default())
+            .build_from_reader(io::empty())?;
+        assert_eq!(reader.resolve_region(&header, &region).as_ref(), &region);
+
+        let reader = Builder::default()
+            .set_index(bai::Index::default())
+            .set_resolve_contig_aliases(true)
+            .build_from_reader(io::empty())?;
+        assert_eq!(
+            reader.resolve_region(&header, &region).name(),
+            b"chr1".as_slice()
+        );
+
+        Ok(())
+    }
 }
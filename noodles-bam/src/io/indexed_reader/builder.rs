@@ -9,12 +9,13 @@ use noodles_bgzf as bgzf;
 use noodles_csi::{self as csi, BinningIndex};
 
 use super::IndexedReader;
-use crate::bai;
+use crate::{bai, io::Reader};
 
 /// An indexed BAM reader builder.
 #[derive(Default)]
 pub struct Builder {
     index: Option<Box<dyn BinningIndex>>,
+    resolve_contig_aliases: bool,
 }
 
 impl Builder {
@@ -35,6 +36,23 @@ impl Builder {
         self
     }
 
+    /// Sets whether to resolve contig aliases when querying.
+    ///
+    /// When enabled, a query region whose reference sequence name is not in the header is retried
+    /// with a `chr` prefix added to or removed from the name, e.g., `1` resolves to `chr1`. By
+    /// default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam::io::indexed_reader::Builder;
+    /// let builder = Builder::default().set_resolve_contig_aliases(true);
+    /// ```
+    pub fn set_resolve_contig_aliases(mut self, resolve_contig_aliases: bool) -> Self {
+        self.resolve_contig_aliases = resolve_contig_aliases;
+        self
+    }
+
     /// Builds an indexed BAM reader from a path.
     ///
     /// If no index is set, this will attempt to read an associated index at `<src>.bai` or
@@ -60,7 +78,11 @@ impl Builder {
 
         let file = File::open(src)?;
 
-        Ok(IndexedReader::new(file, index))
+        Ok(IndexedReader {
+            inner: Reader::new(file),
+            index,
+            resolve_contig_aliases: self.resolve_contig_aliases,
+        })
     }
 
     /// Builds an indexed BAM reader from a reader.
@@ -82,7 +104,11 @@ impl Builder {
             .index
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
 
-        Ok(IndexedReader::new(reader, index))
+        Ok(IndexedReader {
+            inner: Reader::new(reader),
+            index,
+            resolve_contig_aliases: self.resolve_contig_aliases,
+        })
     }
 }
 
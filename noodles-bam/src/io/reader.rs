@@ -3,9 +3,11 @@
 mod builder;
 pub mod header;
 pub(crate) mod query;
+mod query_regions;
 mod record;
 mod record_buf;
 mod record_bufs;
+mod record_counter;
 mod records;
 
 use std::{
@@ -15,12 +17,21 @@ use std::{
 
 use bstr::BString;
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{
+    Region,
+    region::{Interval, QueryMode},
+};
 use noodles_csi::BinningIndex;
 use noodles_sam::{self as sam, alignment::RecordBuf, header::ReferenceSequences};
 
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs, records::Records};
-use self::{record::read_record, record_buf::read_record_buf};
+pub use self::{
+    builder::Builder,
+    query::Query,
+    query_regions::QueryRegions,
+    record_bufs::RecordBufs,
+    records::{Records, SampleFraction},
+};
+use self::{record::read_record, record_buf::read_record_buf, record_counter::RecordCounter};
 use crate::Record;
 
 /// A BAM reader.
@@ -81,6 +92,7 @@ use crate::Record;
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    record_counter: RecordCounter,
 }
 
 impl<R> Reader<R> {
@@ -125,6 +137,46 @@ impl<R> Reader<R> {
     pub fn into_inner(self) -> R {
         self.inner
     }
+
+    /// Returns the number of records read.
+    ///
+    /// This counts the records successfully read using [`Self::read_record`] or
+    /// [`Self::read_record_buf`], including those read using record iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// let reader = bam::io::Reader::from(io::empty());
+    /// assert_eq!(reader.records_read(), 0);
+    /// ```
+    pub fn records_read(&self) -> u64 {
+        self.record_counter.records_read()
+    }
+
+    /// Returns whether the last record read reached EOF.
+    ///
+    /// This is only set after a read returns a block size of 0, i.e., it is `false` after reading
+    /// the last record and before attempting to read another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = bam::io::Reader::from(io::empty());
+    /// assert!(!reader.is_eof());
+    ///
+    /// let mut record = bam::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// assert!(reader.is_eof());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn is_eof(&self) -> bool {
+        self.record_counter.is_eof()
+    }
 }
 
 impl<R> Reader<R>
@@ -223,7 +275,8 @@ where
         _header: &sam::Header,
         record: &mut RecordBuf,
     ) -> io::Result<usize> {
-        read_record_buf(&mut self.inner, &mut self.buf, record)
+        let n = read_record_buf(&mut self.inner, &mut self.buf, record)?;
+        Ok(self.record_counter.track(n))
     }
 
     /// Reads a record.
@@ -255,13 +308,13 @@ where
         let fields = record.fields_mut();
 
         let block_size = match read_record(&mut self.inner, &mut fields.buf)? {
-            0 => return Ok(0),
+            0 => return Ok(self.record_counter.track(0)),
             n => n,
         };
 
         fields.index()?;
 
-        Ok(block_size)
+        Ok(self.record_counter.track(block_size))
     }
 
     /// Returns an iterator over alignment record buffers starting from the current stream
@@ -312,6 +365,70 @@ where
     pub fn records(&mut self) -> Records<'_, R> {
         Records::new(self)
     }
+
+    /// Reads the remaining records and counts them by reference sequence.
+    ///
+    /// This returns the same counts as [`BinningIndex::record_counts`] and
+    /// [`BinningIndex::unplaced_unmapped_record_count`] but does not require an index, e.g., for
+    /// unindexed or streamed inputs. The first value is a list of mapped and unmapped record
+    /// counts for each reference sequence in the header, and the second is the number of records
+    /// without a reference sequence (`*`). Records are classified as unmapped by their flags.
+    ///
+    /// The stream is expected to be directly after the reference sequences or at the start of
+    /// another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let (record_counts, unplaced_unmapped_record_count) = reader.record_counts(&header)?;
+    ///
+    /// for ((name, _), (mapped_record_count, unmapped_record_count)) in
+    ///     header.reference_sequences().iter().zip(record_counts)
+    /// {
+    ///     println!("{name}\t{mapped_record_count}\t{unmapped_record_count}");
+    /// }
+    ///
+    /// println!("*\t0\t{unplaced_unmapped_record_count}");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn record_counts(&mut self, header: &sam::Header) -> io::Result<(Vec<(u64, u64)>, u64)> {
+        let reference_sequences = header.reference_sequences();
+
+        let mut record_counts = vec![(0, 0); reference_sequences.len()];
+        let mut unplaced_unmapped_record_count = 0;
+
+        let mut record = Record::default();
+
+        while self.read_record(&mut record)? != 0 {
+            let Some(reference_sequence_id) = record.reference_sequence_id().transpose()? else {
+                unplaced_unmapped_record_count += 1;
+                continue;
+            };
+
+            let (mapped_record_count, unmapped_record_count) = record_counts
+                .get_mut(reference_sequence_id)
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid reference sequence ID: {reference_sequence_id}"),
+                    )
+                })?;
+
+            if record.flags().is_unmapped() {
+                *unmapped_record_count += 1;
+            } else {
+                *mapped_record_count += 1;
+            }
+        }
+
+        Ok((record_counts, unplaced_unmapped_record_count))
+    }
 }
 
 impl<R> Reader<bgzf::io::Reader<R>>
@@ -378,6 +495,45 @@ where
         index: &I,
         region: &Region,
     ) -> io::Result<Query<'r, R>>
+    where
+        I: BinningIndex,
+    {
+        self.query_with_mode(header, index, region, QueryMode::default())
+    }
+
+    /// Returns an iterator over records that match the given region using the given query mode.
+    ///
+    /// With [`QueryMode::Overlap`], this is the same as [`Self::query`]. With
+    /// [`QueryMode::Contained`], only records whose alignment spans are fully contained in the
+    /// region are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    /// use noodles_core::region::QueryMode;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = bai::fs::read("sample.bam.bai")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query_with_mode(&header, &index, &region, QueryMode::Contained)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_with_mode<'r, I>(
+        &'r mut self,
+        header: &sam::Header,
+        index: &I,
+        region: &Region,
+        query_mode: QueryMode,
+    ) -> io::Result<Query<'r, R>>
     where
         I: BinningIndex,
     {
@@ -389,6 +545,114 @@ where
             chunks,
             reference_sequence_id,
             region.interval(),
+            query_mode,
+        ))
+    }
+
+    /// Returns an iterator over records that intersect the given interval of the reference
+    /// sequence with the given ID.
+    ///
+    /// This is the same as [`Self::query`] but skips resolving a region name. The reference
+    /// sequence ID is the index of the reference sequence in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    /// use noodles_core::Position;
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = bai::fs::read("sample.bam.bai")?;
+    /// let interval = (Position::try_from(8)?..=Position::try_from(13)?).into();
+    /// let query = reader.query_by_reference_id(&header, &index, 0, interval)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_by_reference_id<'r, I>(
+        &'r mut self,
+        header: &sam::Header,
+        index: &I,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> io::Result<Query<'r, R>>
+    where
+        I: BinningIndex,
+    {
+        validate_reference_sequence_id(header.reference_sequences(), reference_sequence_id)?;
+        let chunks = index.query(reference_sequence_id, interval)?;
+
+        Ok(Query::new(
+            self.get_mut(),
+            chunks,
+            reference_sequence_id,
+            interval,
+            QueryMode::default(),
+        ))
+    }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Overlapping and adjacent regions are merged, and the index chunks of all regions are
+    /// resolved together, so each part of the file is read at most once. Records are returned in
+    /// file order, and a record that intersects more than one region is only returned once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bam::{self as bam, bai};
+    ///
+    /// let mut reader = File::open("sample.bam").map(bam::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = bai::fs::read("sample.bam.bai")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, I>(
+        &'r mut self,
+        header: &sam::Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, R>>
+    where
+        I: BinningIndex,
+    {
+        use noodles_csi::binning_index::{merge_chunks, merge_intervals};
+
+        let intervals: Vec<_> = regions
+            .iter()
+            .map(|region| {
+                resolve_region(header.reference_sequences(), region)
+                    .map(|reference_sequence_id| (reference_sequence_id, region.interval()))
+            })
+            .collect::<io::Result<_>>()?;
+
+        let intervals = merge_intervals(&intervals);
+
+        let mut chunks = Vec::new();
+
+        for &(reference_sequence_id, interval) in &intervals {
+            chunks.extend(index.query(reference_sequence_id, interval)?);
+        }
+
+        Ok(QueryRegions::new(
+            self.get_mut(),
+            merge_chunks(&chunks),
+            intervals,
         ))
     }
 
@@ -439,6 +703,7 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            record_counter: RecordCounter::default(),
         }
     }
 }
@@ -484,3 +749,160 @@ pub(crate) fn resolve_region(
             )
         })
 }
+
+pub(crate) fn validate_reference_sequence_id(
+    reference_sequences: &ReferenceSequences,
+    reference_sequence_id: usize,
+) -> io::Result<()> {
+    let len = reference_sequences.len();
+
+    if reference_sequence_id < len {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("reference sequence ID out of range: {reference_sequence_id} (len = {len})"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::io::BytesRead;
+    use noodles_sam::alignment::io::Write as _;
+
+    use super::*;
+
+    #[test]
+    fn test_read_record_with_bytes_read() -> io::Result<()> {
+        let header = sam::Header::default();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for name in ["r0", "r1"] {
+            let record = RecordBuf::builder().set_name(name).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let src = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(BytesRead::new(&src[..]));
+        reader.read_header()?;
+
+        let mut record = Record::default();
+        let mut block_sizes = Vec::new();
+
+        loop {
+            match reader.read_record(&mut record)? {
+                0 => break,
+                n => block_sizes.push(n),
+            }
+        }
+
+        // 32 bytes of fixed-width fields + `b"r0\x00"`
+        assert_eq!(block_sizes, [35, 35]);
+        assert_eq!(reader.get_ref().get_ref().bytes_read(), src.len() as u64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_read_and_is_eof() -> io::Result<()> {
+        let header = sam::Header::default();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for name in ["r0", "r1", "r2"] {
+            let record = RecordBuf::builder().set_name(name).build();
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        let src = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(&src[..]);
+        reader.read_header()?;
+
+        let mut record = RecordBuf::default();
+        reader.read_record_buf(&header, &mut record)?;
+        assert_eq!(reader.records_read(), 1);
+
+        for result in reader.records() {
+            result?;
+        }
+
+        assert_eq!(reader.records_read(), 3);
+        assert!(reader.is_eof());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_counts() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZero;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::record::Flags,
+            header::record::value::{Map, map::ReferenceSequence},
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZero::<usize>::MIN))
+            .add_reference_sequence("sq1", Map::<ReferenceSequence>::new(NonZero::<usize>::MIN))
+            .build();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let records = [
+            (Some(0), Flags::empty()),
+            (Some(0), Flags::empty()),
+            (Some(0), Flags::UNMAPPED),
+            (Some(1), Flags::UNMAPPED),
+            (None, Flags::UNMAPPED),
+            (None, Flags::UNMAPPED),
+        ];
+
+        for (reference_sequence_id, flags) in records {
+            let mut builder = RecordBuf::builder().set_flags(flags);
+
+            if let Some(id) = reference_sequence_id {
+                builder = builder
+                    .set_reference_sequence_id(id)
+                    .set_alignment_start(Position::MIN);
+            }
+
+            writer.write_alignment_record(&header, &builder.build())?;
+        }
+
+        let src = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(&src[..]);
+        let header = reader.read_header()?;
+
+        let (record_counts, unplaced_unmapped_record_count) = reader.record_counts(&header)?;
+        assert_eq!(record_counts, [(2, 1), (0, 1)]);
+        assert_eq!(unplaced_unmapped_record_count, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_reference_sequence_id() {
+        use std::num::NonZero;
+
+        use sam::header::record::value::{Map, map::ReferenceSequence};
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZero::<usize>::MIN))
+            .build();
+
+        assert!(validate_reference_sequence_id(header.reference_sequences(), 0).is_ok());
+        assert!(matches!(
+            validate_reference_sequence_id(header.reference_sequences(), 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+}
@@ -9,6 +9,9 @@ use noodles_bgzf as bgzf;
 use super::Reader;
 
 /// A BAM reader builder.
+///
+/// The built reader checks for a BGZF EOF marker at the end of the stream. Use
+/// [`bgzf::io::Reader::set_check_eof`] on [`Reader::get_mut`] to disable this check.
 #[derive(Debug, Default)]
 pub struct Builder;
 
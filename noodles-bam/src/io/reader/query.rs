@@ -1,7 +1,7 @@
 use std::io;
 
 use noodles_bgzf as bgzf;
-use noodles_core::region::Interval;
+use noodles_core::region::{Interval, QueryMode};
 use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
 use noodles_sam::alignment::Record as _;
 
@@ -11,6 +11,7 @@ struct Reader<'r, R> {
     inner: super::Reader<csi::io::Query<'r, R>>,
     reference_sequence_id: usize,
     interval: Interval,
+    query_mode: QueryMode,
 }
 
 impl<'r, R> Reader<'r, R>
@@ -22,11 +23,13 @@ where
         chunks: Vec<Chunk>,
         reference_sequence_id: usize,
         interval: Interval,
+        query_mode: QueryMode,
     ) -> Self {
         Self {
             inner: super::Reader::from(csi::io::Query::new(reader, chunks)),
             reference_sequence_id,
             interval,
+            query_mode,
         }
     }
 
@@ -36,13 +39,14 @@ where
             record,
             self.reference_sequence_id,
             self.interval,
+            self.query_mode,
         )
     }
 }
 
 /// An iterator over records of a BAM reader that intersects a given region.
 ///
-/// This is created by calling [`Reader::query`].
+/// This is created by calling [`Reader::query`] or [`Reader::query_with_mode`].
 pub struct Query<'r, R> {
     reader: Reader<'r, R>,
     record: Record,
@@ -57,9 +61,10 @@ where
         chunks: Vec<Chunk>,
         reference_sequence_id: usize,
         interval: Interval,
+        query_mode: QueryMode,
     ) -> Self {
         Self {
-            reader: Reader::new(reader, chunks, reference_sequence_id, interval),
+            reader: Reader::new(reader, chunks, reference_sequence_id, interval, query_mode),
             record: Record::default(),
         }
     }
@@ -80,10 +85,11 @@ where
     }
 }
 
-pub(crate) fn intersects(
+pub(crate) fn matches(
     record: &Record,
     reference_sequence_id: usize,
     region_interval: Interval,
+    query_mode: QueryMode,
 ) -> io::Result<bool> {
     let Some(id) = record.reference_sequence_id().transpose()? else {
         return Ok(false);
@@ -102,7 +108,7 @@ pub(crate) fn intersects(
         ) {
             (Some(start), Some(end)) => {
                 let alignment_interval = (start..=end).into();
-                Ok(region_interval.intersects(alignment_interval))
+                Ok(query_mode.matches(region_interval, alignment_interval))
             }
             _ => Ok(false),
         }
@@ -118,6 +124,7 @@ fn next_record<R>(
     record: &mut Record,
     reference_sequence_id: usize,
     interval: Interval,
+    query_mode: QueryMode,
 ) -> io::Result<usize>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
@@ -126,7 +133,7 @@ where
         match reader.read_record(record)? {
             0 => return Ok(0),
             n => {
-                if intersects(record, reference_sequence_id, interval)? {
+                if matches(record, reference_sequence_id, interval, query_mode)? {
                     return Ok(n);
                 }
             }
@@ -254,4 +261,111 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_next_with_contained_query_mode() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_id(0)
+                .set_flags(Flags::default())
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_reference_sequence_id(0)
+                .set_flags(Flags::default())
+                .set_alignment_start(Position::try_from(8)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build(),
+        ];
+
+        let src = write(&header, &records)?;
+        let index = index(&src)?;
+
+        let mut reader = crate::io::Reader::new(Cursor::new(src));
+        let region = "sq0:1-10".parse()?;
+
+        let query = reader.query_with_mode(&header, &index, &region, QueryMode::Overlap)?;
+        assert_eq!(query.count(), 2);
+
+        let query = reader.query_with_mode(&header, &index, &region, QueryMode::Contained)?;
+        let actual: Vec<_> = query
+            .map(|result| {
+                result.and_then(|record| RecordBuf::try_from_alignment_record(&header, &record))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let expected = [records[0].clone()];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let build_record = |name: &str, reference_sequence_id, start| -> io::Result<RecordBuf> {
+            let alignment_start = Position::try_from(start)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            Ok(RecordBuf::builder()
+                .set_name(name)
+                .set_reference_sequence_id(reference_sequence_id)
+                .set_flags(Flags::default())
+                .set_alignment_start(alignment_start)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .build())
+        };
+
+        let records = [
+            build_record("r0", 0, 1)?,
+            build_record("r1", 0, 3)?,
+            build_record("r2", 0, 8)?,
+            build_record("r3", 1, 1)?,
+            build_record("r4", 1, 8)?,
+        ];
+
+        let src = write(&header, &records)?;
+        let index = index(&src)?;
+
+        let mut reader = crate::io::Reader::new(Cursor::new(src));
+
+        let regions = [
+            "sq1:1-2".parse()?,
+            "sq0:2-3".parse()?,
+            "sq0:4-5".parse()?,
+            "sq0:5-9".parse()?,
+            "sq0:4-4".parse()?,
+        ];
+
+        let actual: Vec<_> = reader
+            .query_regions(&header, &index, &regions)?
+            .map(|result| {
+                result.and_then(|record| RecordBuf::try_from_alignment_record(&header, &record))
+            })
+            .collect::<Result<_, _>>()?;
+
+        let expected = &records[..4];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,74 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::{Interval, QueryMode};
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+
+use super::query::matches;
+use crate::Record;
+
+/// An iterator over records of a BAM reader that intersect any of a set of regions.
+///
+/// This is created by calling [`super::Reader::query_regions`].
+///
+/// Each matching record is returned once, even if it intersects more than one region.
+pub struct QueryRegions<'r, R> {
+    reader: super::Reader<csi::io::Query<'r, R>>,
+    intervals: Vec<(usize, Interval)>,
+    record: Record,
+}
+
+impl<'r, R> QueryRegions<'r, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut R,
+        chunks: Vec<Chunk>,
+        intervals: Vec<(usize, Interval)>,
+    ) -> Self {
+        Self {
+            reader: super::Reader::from(csi::io::Query::new(reader, chunks)),
+            intervals,
+            record: Record::default(),
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<usize> {
+        loop {
+            match self.reader.read_record(&mut self.record)? {
+                0 => return Ok(0),
+                n => {
+                    if matches_any(&self.record, &self.intervals)? {
+                        return Ok(n);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<R> Iterator for QueryRegions<'_, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_record() {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(self.record.clone())),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn matches_any(record: &Record, intervals: &[(usize, Interval)]) -> io::Result<bool> {
+    for &(reference_sequence_id, interval) in intervals {
+        if matches(record, reference_sequence_id, interval, QueryMode::Overlap)? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
@@ -0,0 +1,58 @@
+/// A counter of records read by a reader.
+///
+/// A reader tracks each read by the number of bytes it returns: a nonzero count is a record, and 0
+/// is EOF.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct RecordCounter {
+    records_read: u64,
+    is_eof: bool,
+}
+
+impl RecordCounter {
+    /// Returns the number of records read.
+    pub(crate) fn records_read(&self) -> u64 {
+        self.records_read
+    }
+
+    /// Returns whether the last read reached EOF.
+    pub(crate) fn is_eof(&self) -> bool {
+        self.is_eof
+    }
+
+    /// Tracks a read that returned the given number of bytes.
+    ///
+    /// This returns the number of bytes, for convenience.
+    pub(crate) fn track(&mut self, n: usize) -> usize {
+        if n == 0 {
+            self.is_eof = true;
+        } else {
+            self.records_read += 1;
+            self.is_eof = false;
+        }
+
+        n
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_track() {
+        let mut counter = RecordCounter::default();
+
+        assert_eq!(counter.track(8), 8);
+        assert_eq!(counter.track(13), 13);
+        assert_eq!(counter.records_read(), 2);
+        assert!(!counter.is_eof());
+
+        assert_eq!(counter.track(0), 0);
+        assert_eq!(counter.records_read(), 2);
+        assert!(counter.is_eof());
+
+        counter.track(5);
+        assert_eq!(counter.records_read(), 3);
+        assert!(!counter.is_eof());
+    }
+}
@@ -1,8 +1,15 @@
 use std::io::{self, Read};
 
+use noodles_core::sample::{self, FractionSampler};
+
 use super::Reader;
 use crate::Record;
 
+/// An iterator over a deterministic fraction of lazily-evaluated records of a BAM reader.
+///
+/// This is created by calling [`Records::sample_fraction`].
+pub type SampleFraction<'a, R> = sample::SampleFraction<Records<'a, R>, fn(&Record) -> &[u8]>;
+
 /// An iterator over lazily-evaluated records of a BAM reader.
 ///
 /// This is created by calling [`Reader::records`].
@@ -21,6 +28,34 @@ where
             record: Record::default(),
         }
     }
+
+    /// Returns an iterator over a deterministic fraction of the records.
+    ///
+    /// Inclusion is decided by hashing the read name with the given seed, so records with the
+    /// same name, e.g., paired mates, are either all kept or all dropped. A trailing `/1` or `/2`
+    /// is ignored. On large inputs, about `fraction` of the records are kept. See
+    /// [`FractionSampler`].
+    ///
+    /// This returns an error if `fraction` is not in [0, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_bam as bam;
+    ///
+    /// let mut reader = bam::io::reader::Builder::default().build_from_path("sample.bam")?;
+    /// reader.read_header()?;
+    ///
+    /// for result in reader.records().sample_fraction(0.1, 0)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn sample_fraction(self, fraction: f64, seed: u64) -> io::Result<SampleFraction<'a, R>> {
+        let sampler = FractionSampler::new(fraction, seed)?;
+        Ok(SampleFraction::new(self, sampler, name))
+    }
 }
 
 impl<R> Iterator for Records<'_, R>
@@ -37,3 +72,8 @@ where
         }
     }
 }
+
+// Returns the read name, or an empty name if it is missing.
+fn name(record: &Record) -> &[u8] {
+    record.name().map(|name| name.as_ref()).unwrap_or_default()
+}
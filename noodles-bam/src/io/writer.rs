@@ -33,6 +33,7 @@ use crate::Record;
 pub struct Writer<W> {
     inner: W,
     buf: Vec<u8>,
+    strict: bool,
 }
 
 impl<W> Writer<W>
@@ -78,6 +79,26 @@ where
         self.inner
     }
 
+    /// Sets whether to check record positions against the reference sequence lengths.
+    ///
+    /// When enabled, writing a record errors if its alignment extends past the end of its
+    /// reference sequence or its mate alignment start is past the end of its mate reference
+    /// sequence. Reference sequence IDs are always checked against the header.
+    ///
+    /// By default, this is disabled, which allows writing provisional records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bam as bam;
+    /// let mut writer = bam::io::Writer::new(io::sink());
+    /// writer.set_strict(true);
+    /// ```
+    pub fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
     /// Writes a SAM header.
     ///
     /// This writes the BAM magic number, the raw SAM header, and a copy of the reference sequence
@@ -167,6 +188,7 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             buf: Vec::new(),
+            strict: false,
         }
     }
 }
@@ -184,7 +206,11 @@ where
         header: &sam::Header,
         record: &dyn sam::alignment::Record,
     ) -> io::Result<()> {
-        use crate::record::codec::encode;
+        use crate::record::codec::{encode, validate_positions};
+
+        if self.strict {
+            validate_positions(header, record)?;
+        }
 
         self.buf.clear();
         encode(&mut self.buf, header, record)?;
@@ -388,4 +414,81 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_alignment_record_with_out_of_bounds_positions()
+    -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::record::cigar::{Op, op::Kind},
+            header::record::value::{Map, map::ReferenceSequence},
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(const { NonZeroUsize::new(8).unwrap() }),
+            )
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::MIN)
+            .build();
+
+        let mut writer = Writer::new(io::sink());
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(6)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(Sequence::from(b"ACGT"))
+            .build();
+
+        let mut writer = Writer::new(io::sink());
+        writer.write_alignment_record(&header, &record)?;
+
+        let mut writer = Writer::new(io::sink());
+        writer.set_strict(true);
+
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(9)?)
+            .build();
+
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(5)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(Sequence::from(b"ACGT"))
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(8)?)
+            .build();
+
+        writer.write_alignment_record(&header, &record)?;
+
+        Ok(())
+    }
 }
@@ -192,6 +192,53 @@ impl Record {
     pub fn data(&self) -> Data<'_> {
         self.0.data()
     }
+
+    /// Encodes an alignment record as a BAM record.
+    ///
+    /// This validates the record as if it were being written, e.g., the read name must be at
+    /// most 254 characters, and the sequence length must match the read length described by the
+    /// CIGAR operations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::{
+    ///         record::cigar::{op::Kind, Op},
+    ///         RecordBuf,
+    ///     },
+    /// };
+    ///
+    /// let header = sam::Header::default();
+    ///
+    /// let record_buf = RecordBuf::builder()
+    ///     .set_name("r0")
+    ///     .set_cigar([Op::new(Kind::SoftClip, 4)].into_iter().collect())
+    ///     .set_sequence(b"ACGT".to_vec().into())
+    ///     .build();
+    ///
+    /// let record = bam::Record::try_from_alignment_record(&header, &record_buf)?;
+    /// assert_eq!(record.name(), Some(b"r0".into()));
+    /// assert_eq!(record.sequence().len(), 4);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn try_from_alignment_record<R>(header: &sam::Header, record: &R) -> io::Result<Self>
+    where
+        R: sam::alignment::Record + ?Sized,
+    {
+        let mut buf = Vec::new();
+        codec::encode(&mut buf, header, record)?;
+        Fields::try_from(buf).map(Self)
+    }
+}
+
+impl AsRef<[u8]> for Record {
+    /// Returns the encoded record, excluding the block size.
+    fn as_ref(&self) -> &[u8] {
+        &self.0.buf
+    }
 }
 
 impl fmt::Debug for Record {
@@ -342,4 +389,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_try_from_alignment_record() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::alignment::{
+            RecordBuf,
+            record::cigar::{Op, op::Kind},
+        };
+
+        let header = sam::Header::default();
+
+        let record_buf = RecordBuf::builder()
+            .set_name("r0")
+            .set_cigar([Op::new(Kind::SoftClip, 4)].into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_quality_scores(vec![45, 35, 43, 50].into())
+            .build();
+
+        let record = Record::try_from_alignment_record(&header, &record_buf)?;
+        let actual = RecordBuf::try_from_alignment_record(&header, &record)?;
+        assert_eq!(actual, record_buf);
+
+        let mut buf = Vec::new();
+        crate::record::codec::encode(&mut buf, &header, &record_buf)?;
+        assert_eq!(record.as_ref(), buf);
+
+        let record_buf = RecordBuf::builder().set_name("n".repeat(255)).build();
+        assert!(Record::try_from_alignment_record(&header, &record_buf).is_err());
+
+        let record_buf = RecordBuf::builder()
+            .set_cigar([Op::new(Kind::SoftClip, 3)].into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .build();
+        assert!(Record::try_from_alignment_record(&header, &record_buf).is_err());
+
+        Ok(())
+    }
 }
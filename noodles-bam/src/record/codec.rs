@@ -3,4 +3,7 @@
 pub mod decoder;
 pub mod encoder;
 
-pub(crate) use self::{decoder::decode, encoder::encode};
+pub(crate) use self::{
+    decoder::decode,
+    encoder::{encode, validate_positions},
+};
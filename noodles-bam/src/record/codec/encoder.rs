@@ -15,6 +15,7 @@ mod template_length;
 
 use std::{error, fmt, io};
 
+use noodles_core::Position;
 use noodles_sam::{self as sam, alignment::Record};
 
 use self::{
@@ -36,6 +37,20 @@ pub enum EncodeError {
     InvalidMateReferenceSequenceId(reference_sequence_id::EncodeError),
     /// The mate alignment start is invalid.
     InvalidMateAlignmentStart(position::EncodeError),
+    /// The alignment extends past the end of its reference sequence.
+    AlignmentOutOfBounds {
+        /// The last aligned position.
+        actual: Position,
+        /// The reference sequence length.
+        expected: usize,
+    },
+    /// The mate alignment start is past the end of its reference sequence.
+    MateAlignmentStartOutOfBounds {
+        /// The mate alignment start.
+        actual: Position,
+        /// The mate reference sequence length.
+        expected: usize,
+    },
 }
 
 impl error::Error for EncodeError {
@@ -45,6 +60,7 @@ impl error::Error for EncodeError {
             Self::InvalidAlignmentStart(e) => Some(e),
             Self::InvalidMateReferenceSequenceId(e) => Some(e),
             Self::InvalidMateAlignmentStart(e) => Some(e),
+            Self::AlignmentOutOfBounds { .. } | Self::MateAlignmentStartOutOfBounds { .. } => None,
         }
     }
 }
@@ -58,6 +74,16 @@ impl fmt::Display for EncodeError {
                 write!(f, "invalid mate reference sequence ID")
             }
             Self::InvalidMateAlignmentStart(_) => write!(f, "invalid mate alignment start"),
+            Self::AlignmentOutOfBounds { actual, expected } => {
+                write!(
+                    f,
+                    "alignment out of bounds: expected <= {expected}, got {actual}"
+                )
+            }
+            Self::MateAlignmentStartOutOfBounds { actual, expected } => write!(
+                f,
+                "mate alignment start out of bounds: expected <= {expected}, got {actual}"
+            ),
         }
     }
 }
@@ -141,11 +167,63 @@ where
     Ok(())
 }
 
+/// Checks that the alignment and mate alignment start are within the lengths of their reference
+/// sequences.
+///
+/// Reference sequence IDs missing from the header are not checked here; they fail to encode.
+pub(crate) fn validate_positions<R>(header: &sam::Header, record: &R) -> io::Result<()>
+where
+    R: Record + ?Sized,
+{
+    fn reference_sequence_length(header: &sam::Header, id: Option<usize>) -> Option<usize> {
+        id.and_then(|i| header.reference_sequences().get_index(i))
+            .map(|(_, reference_sequence)| reference_sequence.length().get())
+    }
+
+    let reference_sequence_id = record.reference_sequence_id(header).transpose()?;
+
+    if let (Some(length), Some(start)) = (
+        reference_sequence_length(header, reference_sequence_id),
+        record.alignment_start().transpose()?,
+    ) {
+        let end = record.alignment_end().transpose()?.unwrap_or(start);
+        let actual = start.max(end);
+
+        if usize::from(actual) > length {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                EncodeError::AlignmentOutOfBounds {
+                    actual,
+                    expected: length,
+                },
+            ));
+        }
+    }
+
+    let mate_reference_sequence_id = record.mate_reference_sequence_id(header).transpose()?;
+
+    if let (Some(length), Some(actual)) = (
+        reference_sequence_length(header, mate_reference_sequence_id),
+        record.mate_alignment_start().transpose()?,
+    ) {
+        if usize::from(actual) > length {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                EncodeError::MateAlignmentStartOutOfBounds {
+                    actual,
+                    expected: length,
+                },
+            ));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use std::num::NonZeroUsize;
 
-    use noodles_core::Position;
     use noodles_sam::{
         alignment::RecordBuf,
         header::record::value::{Map, map::ReferenceSequence},
@@ -202,4 +202,27 @@ pub(super) mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_array_with_out_of_range_value() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::record::data::field::Value;
+
+        let record =
+            sam::Record::try_from(&b"*\t4\t*\t0\t255\t*\t*\t0\t0\t*\t*\tZZ:B:c,127,200"[..])?;
+        let data = record.data();
+        let (_, value) = data.iter().next().ok_or("missing field")??;
+
+        let Value::Array(array) = value else {
+            return Err("invalid value".into());
+        };
+
+        let mut buf = Vec::new();
+
+        assert!(matches!(
+            write_array(&mut buf, &array),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -1,5 +1,6 @@
 use std::io;
 
+use noodles_core::phred;
 use noodles_sam as sam;
 
 /// BAM record quality scores.
@@ -25,6 +26,56 @@ impl<'a> QualityScores<'a> {
     pub fn iter(&self) -> impl Iterator<Item = u8> + use<'a> {
         self.0.iter().copied()
     }
+
+    /// Returns an iterator over the Phred quality scores.
+    ///
+    /// BAM stores quality scores without an offset, so this is equivalent to [`Self::iter`].
+    /// Use [`Self::checked_phred_scores`] to reject scores outside of 0..=93.
+    pub fn phred_scores(&self) -> impl Iterator<Item = u8> + use<'a> {
+        self.iter()
+    }
+
+    /// Returns an iterator over the Phred quality scores, checking each score.
+    ///
+    /// This yields an error for any score outside of 0..=93.
+    pub fn checked_phred_scores(&self) -> impl Iterator<Item = io::Result<u8>> + use<'a> {
+        self.iter().map(|score| {
+            if score <= phred::MAX_SCORE {
+                Ok(score)
+            } else {
+                Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid quality score: {score}"),
+                ))
+            }
+        })
+    }
+
+    /// Returns the mean score.
+    ///
+    /// This returns `None` if there are no scores and an error if any score is outside of 0..=93.
+    pub fn mean_quality(&self) -> io::Result<Option<f64>> {
+        if self.is_empty() {
+            return Ok(None);
+        }
+
+        let mut sum = 0;
+
+        for result in self.checked_phred_scores() {
+            sum += u64::from(result?);
+        }
+
+        Ok(Some(sum as f64 / self.len() as f64))
+    }
+
+    /// Returns the median score.
+    ///
+    /// When there is an even number of scores, the median is the mean of the two middle scores.
+    /// This returns `None` if there are no scores and an error if any score is outside of 0..=93.
+    pub fn median_quality(&self) -> io::Result<Option<f64>> {
+        let scores: Vec<_> = self.checked_phred_scores().collect::<io::Result<_>>()?;
+        Ok(phred::median(scores))
+    }
 }
 
 impl sam::alignment::record::QualityScores for QualityScores<'_> {
@@ -52,3 +103,61 @@ impl<'a> From<QualityScores<'a>> for sam::alignment::record_buf::QualityScores {
         Self::from(quality_scores.0.to_vec())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_phred_scores() -> io::Result<()> {
+        let quality_scores = QualityScores::new(&[0, 10, 40, 93]);
+        assert_eq!(
+            quality_scores
+                .checked_phred_scores()
+                .collect::<io::Result<Vec<_>>>()?,
+            [0, 10, 40, 93]
+        );
+
+        let quality_scores = QualityScores::new(&[0, 10, 94]);
+        assert!(matches!(
+            quality_scores.checked_phred_scores().collect::<io::Result<Vec<_>>>(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mean_quality() -> io::Result<()> {
+        assert_eq!(
+            QualityScores::new(&[0, 0, 10, 40]).mean_quality()?,
+            Some(12.5)
+        );
+        assert!(QualityScores::new(&[]).mean_quality()?.is_none());
+        assert!(matches!(
+            QualityScores::new(&[0, 0, 94]).mean_quality(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_median_quality() -> io::Result<()> {
+        assert_eq!(
+            QualityScores::new(&[40, 0, 20]).median_quality()?,
+            Some(20.0)
+        );
+        assert_eq!(
+            QualityScores::new(&[40, 0, 10, 20]).median_quality()?,
+            Some(15.0)
+        );
+        assert!(QualityScores::new(&[]).median_quality()?.is_none());
+        assert!(matches!(
+            QualityScores::new(&[40, 0, 94]).median_quality(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
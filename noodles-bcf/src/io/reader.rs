@@ -261,10 +261,53 @@ where
     }
 }
 
+impl<R> Reader<R>
+where
+    R: bgzf::io::Read,
+{
+    /// Returns the current virtual position of the underlying BGZF reader.
+    ///
+    /// When called directly after reading a record, this is the start of the next record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let reader = bcf::io::Reader::new(io::empty());
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::default());
+    /// ```
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
 impl<R> Reader<R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
 {
+    /// Seeks the underlying BGZF reader to the given virtual position.
+    ///
+    /// The position is expected to be the start of a record, e.g., one returned by
+    /// [`Self::virtual_position`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut reader = bcf::io::Reader::new(Cursor::new(Vec::new()));
+    /// let pos = reader.virtual_position();
+    /// reader.seek(pos)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
+        self.inner.seek_to_virtual_position(pos)
+    }
+
     /// Returns an iterator over records that intersects the given region.
     ///
     /// # Examples
@@ -353,3 +396,56 @@ pub(crate) fn resolve_region(
         )
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use noodles_core::Position;
+    use noodles_vcf::variant::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Default::default())
+            .build();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for start in [Position::MIN, Position::try_from(8)?] {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(start)
+                .set_reference_bases("N")
+                .build();
+
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        writer.try_finish()?;
+
+        let src = writer.into_inner().into_inner();
+        let mut reader = Reader::new(Cursor::new(src));
+        let header = reader.read_header()?;
+
+        let pos = reader.virtual_position();
+
+        let mut expected = RecordBuf::default();
+        reader.read_record_buf(&header, &mut expected)?;
+
+        let mut record = RecordBuf::default();
+        reader.read_record_buf(&header, &mut record)?;
+        assert_ne!(record, expected);
+
+        reader.seek(pos)?;
+
+        let mut actual = RecordBuf::default();
+        reader.read_record_buf(&header, &mut actual)?;
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+}
@@ -315,6 +315,62 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_with_fields_out_of_header_order() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use noodles_vcf::{self as vcf, variant::io::Write};
+
+        // The fields in `DATA` are stored in string map ID order, which differs from the order
+        // the INFO and FORMAT records are declared.
+        const RAW_HEADER: &str = r#"##fileformat=VCFv4.3
+##contig=<ID=sq0,IDX=0>
+##contig=<ID=sq1,IDX=1>
+##INFO=<ID=AA,Number=1,Type=String,Description="Ancestral allele",IDX=4>
+##INFO=<ID=AN,Number=1,Type=Integer,Description="Total number of alleles called genotypes",IDX=3>
+##INFO=<ID=HM3,Number=0,Type=Flag,Description="HM3 membership",IDX=1>
+##INFO=<ID=AC,Number=A,Type=Integer,Description="Allele count in genotypes, for each ALT allele, in the same order as listed",IDX=2>
+##FORMAT=<ID=PL,Number=G,Type=Integer,Description="Phred-scaled genotype likelihoods rounded to the closest integer",IDX=9>
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description="Conditional genotype quality",IDX=6>
+##FORMAT=<ID=GT,Number=1,Type=String,Description="Genotype",IDX=5>
+##FORMAT=<ID=AD,Number=R,Type=Integer,Description="Read depth for each allele",IDX=8>
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth",IDX=7>
+##FILTER=<ID=PASS,Description="All filters passed",IDX=0>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0	sample1	sample2
+"#;
+
+        let mut header: vcf::Header = RAW_HEADER.parse()?;
+        *header.string_maps_mut() = RAW_HEADER.parse()?;
+
+        let mut reader = &DATA[..];
+        let mut record = Record::default();
+        read_record(&mut reader, &mut record)?;
+
+        // The lazy record yields fields in the order they are stored.
+        let mut writer = vcf::io::Writer::new(Vec::new());
+        writer.write_variant_record(&header, &record)?;
+
+        let expected = b"sq1\t101\trs123\tA\tC\t30.1\tPASS\tHM3;AC=3;AN=6;AA=C\tGT:GQ:DP:AD:PL\t0/0:10:32:32,0:0,10,100\t0/1:10:48:32,16:10,0,100\t1/1:10:64:0,64:100,10,0\n";
+        assert_eq!(writer.get_ref(), expected);
+
+        // The record buffer yields fields in header order.
+        let expected = b"sq1\t101\trs123\tA\tC\t30.1\tPASS\tAA=C;AN=6;HM3;AC=3\tGT:PL:GQ:AD:DP\t0/0:0,10,100:10:32,0:32\t0/1:10,0,100:10:32,16:48\t1/1:100,10,0:10:0,64:64\n";
+
+        let mut reader = &DATA[..];
+        let mut record = vcf::variant::RecordBuf::default();
+        super::super::record_buf::read_record_buf(
+            &mut reader,
+            &header,
+            &mut Vec::new(),
+            &mut record,
+        )?;
+
+        let mut writer = vcf::io::Writer::new(Vec::new());
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_record_at_eof() -> io::Result<()> {
         let data = [];
@@ -226,4 +226,46 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_header_with_filters_before_infos() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::variant::{RecordBuf, io::Write};
+
+        use crate::io::Reader;
+
+        let src = b"##fileformat=VCFv4.5
+##contig=<ID=sq0>
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\t.\t.\tq10\tDP=13;NS=1\tGT\t0|0
+";
+
+        let mut vcf_reader = vcf::io::Reader::new(&src[..]);
+        let header = vcf_reader.read_header()?;
+        let expected: Vec<RecordBuf> =
+            vcf_reader.record_bufs(&header).collect::<io::Result<_>>()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for record in &expected {
+            writer.write_variant_record(&header, record)?;
+        }
+
+        writer.try_finish()?;
+
+        let dst = writer.into_inner().into_inner();
+        let mut reader = Reader::new(&dst[..]);
+        let actual_header = reader.read_header()?;
+        let actual: Vec<RecordBuf> = reader
+            .record_bufs(&actual_header)
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -15,12 +15,12 @@ use std::{fmt, io, str};
 use noodles_core::Position;
 use noodles_vcf::{self as vcf, header::StringMaps};
 
-use self::fields::Fields;
 pub(crate) use self::value::Value;
 pub use self::{
     alternate_bases::AlternateBases, filters::Filters, ids::Ids, info::Info,
     reference_bases::ReferenceBases, samples::Samples,
 };
+use self::{codec::decoder::get_contig, fields::Fields};
 
 /// A BCF record.
 #[derive(Clone, Default, PartialEq)]
@@ -74,12 +74,8 @@ impl Record {
     /// ```
     pub fn reference_sequence_name<'h>(&self, string_maps: &'h StringMaps) -> io::Result<&'h str> {
         self.reference_sequence_id().and_then(|i| {
-            string_maps.contigs().get_index(i).ok_or_else(|| {
-                io::Error::new(
-                    io::ErrorKind::InvalidData,
-                    "missing reference sequence name in contig string map",
-                )
-            })
+            get_contig(string_maps.contigs(), i)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
         })
     }
 
@@ -130,7 +126,7 @@ impl Record {
 
         let len = self.rlen()?;
 
-        start.checked_add(len - 1).ok_or_else(|| {
+        start.add_length(len).ok_or_else(|| {
             io::Error::new(
                 io::ErrorKind::InvalidData,
                 "calculation of the end position overflowed",
@@ -15,10 +15,14 @@ use std::io;
 use byteorder::{LittleEndian, ReadBytesExt};
 use noodles_vcf as vcf;
 
-use self::info::read_info;
 pub(crate) use self::{
-    bases::read_ref_alt, chromosome_id::read_chrom, filters::read_filter, ids::read_id,
-    position::read_pos, quality_score::read_qual, string_map::read_string_map_entry,
+    bases::read_ref_alt,
+    chromosome_id::{get_contig, read_chrom},
+    filters::read_filter,
+    ids::read_id,
+    info::{read_info, read_key as read_info_key},
+    position::read_pos,
+    quality_score::read_qual,
 };
 pub use self::{samples::read_samples, value::read_value};
 
@@ -29,12 +33,9 @@ pub fn read_site(
 ) -> io::Result<(usize, usize)> {
     let chrom = read_chrom(src)?;
 
-    *record.reference_sequence_name_mut() = header
-        .string_maps()
-        .contigs()
-        .get_index(chrom)
+    *record.reference_sequence_name_mut() = get_contig(header.string_maps().contigs(), chrom)
         .map(String::from)
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid chrom"))?;
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
     *record.variant_start_mut() = read_pos(src)?;
 
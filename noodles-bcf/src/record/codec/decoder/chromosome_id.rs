@@ -1,12 +1,37 @@
-use std::io;
+use std::{error, fmt, io};
 
 use byteorder::{LittleEndian, ReadBytesExt};
+use noodles_vcf::header::string_maps::ContigStringMap;
 
 pub(crate) fn read_chrom(src: &mut &[u8]) -> io::Result<usize> {
     src.read_i32::<LittleEndian>()
         .and_then(|n| usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
 }
 
+pub(crate) fn get_contig(
+    contig_string_map: &ContigStringMap,
+    chrom: usize,
+) -> Result<&str, DecodeError> {
+    contig_string_map
+        .get_index(chrom)
+        .ok_or(DecodeError::MissingStringMapEntry(chrom))
+}
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    MissingStringMapEntry(usize),
+}
+
+impl error::Error for DecodeError {}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingStringMapEntry(i) => write!(f, "contig id {i} not in string map"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -30,4 +55,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_contig() {
+        let mut contig_string_map = ContigStringMap::default();
+        contig_string_map.insert(String::from("sq0"));
+
+        assert_eq!(get_contig(&contig_string_map, 0), Ok("sq0"));
+
+        let result = get_contig(&contig_string_map, 2);
+        assert_eq!(result, Err(DecodeError::MissingStringMapEntry(2)));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "contig id 2 not in string map"
+        );
+    }
 }
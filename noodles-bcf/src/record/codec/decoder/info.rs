@@ -4,8 +4,12 @@ use std::{error, fmt};
 
 use noodles_vcf as vcf;
 
-pub(crate) use self::field::read_field;
+pub(crate) use self::field::{read_field, read_key};
 
+/// Reads INFO fields.
+///
+/// BCF stores fields in the order they were written, typically by string map ID. The decoded
+/// fields are reordered to follow the order of the INFO header records.
 pub fn read_info(
     src: &mut &[u8],
     header: &vcf::Header,
@@ -22,6 +26,11 @@ pub fn read_info(
         }
     }
 
+    let infos = header.infos();
+
+    info.as_mut()
+        .sort_by_cached_key(|key, _| infos.get_index_of(key).unwrap_or(usize::MAX));
+
     Ok(())
 }
 
@@ -11,8 +11,7 @@ pub(crate) fn read_field(
     src: &mut &[u8],
     header: &vcf::Header,
 ) -> Result<(String, Option<vcf::variant::record_buf::info::field::Value>), DecodeError> {
-    let raw_key = read_string_map_entry(src, header.string_maps().strings())
-        .map_err(DecodeError::InvalidStringMap)?;
+    let raw_key = read_key(src, header)?;
 
     let (key, info) = header
         .infos()
@@ -24,9 +23,20 @@ pub(crate) fn read_field(
     Ok((key.clone(), value))
 }
 
+pub(crate) fn read_key<'h>(
+    src: &mut &[u8],
+    header: &'h vcf::Header,
+) -> Result<&'h str, DecodeError> {
+    read_string_map_entry(src, header.string_maps().strings()).map_err(|e| match e {
+        string_map::DecodeError::MissingEntry(i) => DecodeError::MissingStringMapEntry(i),
+        _ => DecodeError::InvalidStringMap(e),
+    })
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum DecodeError {
     InvalidStringMap(string_map::DecodeError),
+    MissingStringMapEntry(usize),
     MissingInfoMapEntry,
     InvalidValue(value::DecodeError),
 }
@@ -35,6 +45,7 @@ impl error::Error for DecodeError {
     fn source(&self) -> Option<&(dyn error::Error + 'static)> {
         match self {
             Self::InvalidStringMap(e) => Some(e),
+            Self::MissingStringMapEntry(_) => None,
             Self::MissingInfoMapEntry => None,
             Self::InvalidValue(e) => Some(e),
         }
@@ -45,8 +56,48 @@ impl fmt::Display for DecodeError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::InvalidStringMap(_) => write!(f, "invalid string map"),
+            Self::MissingStringMapEntry(i) => write!(f, "INFO key id {i} not in string map"),
             Self::MissingInfoMapEntry => write!(f, "missing info map entry"),
             Self::InvalidValue(_) => write!(f, "invalid value"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::header::{
+        StringMaps,
+        record::value::{Map, map::Info},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_read_field() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::variant::{record::info::field::key, record_buf::info::field::Value as ValueBuf};
+
+        let mut header = vcf::Header::builder()
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(key::TOTAL_DEPTH))
+            .build();
+
+        *header.string_maps_mut() = StringMaps::try_from(&header)?;
+
+        // string map index = 1 (DP), value = Some(Int8(8))
+        let mut src = &[0x11, 0x01, 0x11, 0x08][..];
+        assert_eq!(
+            read_field(&mut src, &header),
+            Ok((String::from(key::TOTAL_DEPTH), Some(ValueBuf::from(8))))
+        );
+
+        // string map index = 5
+        let mut src = &[0x11, 0x05, 0x11, 0x08][..];
+        let result = read_field(&mut src, &header);
+        assert_eq!(result, Err(DecodeError::MissingStringMapEntry(5)));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "INFO key id 5 not in string map"
+        );
+
+        Ok(())
+    }
+}
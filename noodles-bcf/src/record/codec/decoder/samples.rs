@@ -10,6 +10,10 @@ use self::{
     values::{read_genotype_values, read_values},
 };
 
+/// Reads sample fields.
+///
+/// The decoded keys are reordered to follow the order of the FORMAT header records, with the
+/// genotype (`GT`) always first.
 pub fn read_samples(
     src: &mut &[u8],
     header: &vcf::Header,
@@ -43,9 +47,39 @@ pub fn read_samples(
         }
     }
 
+    sort_by_header_order(header, &mut keys, &mut samples);
+
     Ok(Samples::new(keys.into_iter().collect(), samples))
 }
 
+fn sort_by_header_order(
+    header: &vcf::Header,
+    keys: &mut Vec<String>,
+    samples: &mut [Vec<Option<vcf::variant::record_buf::samples::sample::Value>>],
+) {
+    use vcf::variant::record::samples::keys::key;
+
+    let formats = header.formats();
+
+    let mut indices: Vec<_> = (0..keys.len()).collect();
+
+    indices.sort_by_cached_key(|&i| {
+        let key = &keys[i];
+        let j = formats.get_index_of(key.as_str()).unwrap_or(usize::MAX);
+        (key != key::GENOTYPE, j)
+    });
+
+    if indices.iter().enumerate().all(|(i, &j)| i == j) {
+        return;
+    }
+
+    *keys = indices.iter().map(|&i| keys[i].clone()).collect();
+
+    for sample in samples {
+        *sample = indices.iter().map(|&i| sample[i].clone()).collect();
+    }
+}
+
 #[allow(clippy::enum_variant_names)]
 #[derive(Debug, Eq, PartialEq)]
 pub enum DecodeError {
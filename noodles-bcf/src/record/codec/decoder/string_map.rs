@@ -54,7 +54,7 @@ pub fn read_string_map_entry<'m>(
     string_map: &'m StringMap,
 ) -> Result<&'m str, DecodeError> {
     read_string_map_index(src)
-        .and_then(|j| string_map.get_index(j).ok_or(DecodeError::MissingEntry))
+        .and_then(|j| string_map.get_index(j).ok_or(DecodeError::MissingEntry(j)))
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -64,7 +64,7 @@ pub enum DecodeError {
     InvalidValue(super::value::DecodeError),
     InvalidIndex(num::TryFromIntError),
     InvalidIndexValue,
-    MissingEntry,
+    MissingEntry(usize),
 }
 
 impl error::Error for DecodeError {
@@ -84,7 +84,7 @@ impl fmt::Display for DecodeError {
             Self::InvalidValue(_) => write!(f, "invalid value"),
             Self::InvalidIndex(_) => write!(f, "invalid index"),
             Self::InvalidIndexValue => write!(f, "invalid index value"),
-            Self::MissingEntry => write!(f, "missing entry"),
+            Self::MissingEntry(i) => write!(f, "missing entry: {i}"),
         }
     }
 }
@@ -201,7 +201,7 @@ mod tests {
         let mut src = &[0x11, 0x08][..];
         assert_eq!(
             read_string_map_entry(&mut src, &string_map),
-            Err(DecodeError::MissingEntry)
+            Err(DecodeError::MissingEntry(8))
         );
     }
 }
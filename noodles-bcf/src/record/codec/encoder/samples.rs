@@ -22,12 +22,13 @@ where
 {
     use noodles_vcf::variant::record::samples::keys::key;
 
+    let rows: Vec<_> = samples.iter().collect();
+
     for (i, result) in samples.column_names(header).enumerate() {
         let key = result?;
 
         write_key(writer, string_maps.strings(), key)?;
 
-        let rows: Vec<_> = samples.iter().collect();
         let mut values = Vec::new();
 
         for sample in rows.iter() {
@@ -109,4 +110,57 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_samples_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::{
+            header::record::value::Map,
+            variant::{record::samples::keys::key, record_buf::samples::sample::Value},
+        };
+
+        use crate::record::codec::decoder::read_samples;
+
+        let mut header = vcf::Header::builder()
+            .add_format(key::READ_DEPTH, Map::from(key::READ_DEPTH))
+            .add_format(key::READ_DEPTHS, Map::from(key::READ_DEPTHS))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+        *header.string_maps_mut() = string_maps.clone();
+
+        // AD arrays are ragged and widen from Int8 to Int16 to fit 300.
+        let samples = vcf::variant::record_buf::Samples::new(
+            [
+                String::from(key::READ_DEPTH),
+                String::from(key::READ_DEPTHS),
+            ]
+            .into_iter()
+            .collect(),
+            vec![
+                vec![
+                    Some(Value::from(8)),
+                    Some(Value::from(vec![Some(5), Some(3)])),
+                ],
+                vec![None, Some(Value::from(vec![Some(300)]))],
+                vec![
+                    Some(Value::from(13)),
+                    Some(Value::from(vec![None, Some(2)])),
+                ],
+            ],
+        );
+
+        let mut buf = Vec::new();
+        write_samples(&mut buf, &header, &string_maps, &samples)?;
+
+        let mut src = &buf[..];
+        let actual = read_samples(&mut src, &header, 3, 2)?;
+
+        assert!(src.is_empty());
+        assert_eq!(actual, samples);
+
+        Ok(())
+    }
 }
@@ -23,3 +23,86 @@ where
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_vcf::{
+        header::record::value::{
+            Map,
+            map::{Info as InfoMap, info::Number, info::Type},
+        },
+        variant::{
+            record::info::field::key,
+            record_buf::{Info as InfoBuf, info::field::Value},
+        },
+    };
+
+    use super::*;
+    use crate::record::codec::decoder::read_info;
+
+    #[test]
+    fn test_write_info_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        const INT8_ARRAY: &str = "I8";
+        const INT16_ARRAY: &str = "I16";
+        const INT32_ARRAY: &str = "I32";
+
+        let mut header = vcf::Header::builder()
+            .add_info(key::TOTAL_DEPTH, Map::from(key::TOTAL_DEPTH))
+            .add_info(key::ALLELE_FREQUENCIES, Map::from(key::ALLELE_FREQUENCIES))
+            .add_info(
+                key::IS_SOMATIC_MUTATION,
+                Map::from(key::IS_SOMATIC_MUTATION),
+            )
+            .add_info(
+                INT8_ARRAY,
+                Map::<InfoMap>::new(Number::Unknown, Type::Integer, ""),
+            )
+            .add_info(
+                INT16_ARRAY,
+                Map::<InfoMap>::new(Number::Unknown, Type::Integer, ""),
+            )
+            .add_info(
+                INT32_ARRAY,
+                Map::<InfoMap>::new(Number::Unknown, Type::Integer, ""),
+            )
+            .build();
+
+        let string_maps = StringMaps::try_from(&header)?;
+        *header.string_maps_mut() = string_maps.clone();
+
+        let info: InfoBuf = [
+            (String::from(key::TOTAL_DEPTH), Some(Value::from(300))),
+            (
+                String::from(key::ALLELE_FREQUENCIES),
+                Some(Value::from(vec![Some(0.5), None])),
+            ),
+            (String::from(key::IS_SOMATIC_MUTATION), Some(Value::Flag)),
+            (
+                String::from(INT8_ARRAY),
+                Some(Value::from(vec![Some(-8), None, Some(13)])),
+            ),
+            (
+                String::from(INT16_ARRAY),
+                Some(Value::from(vec![Some(-8), Some(377)])),
+            ),
+            (
+                String::from(INT32_ARRAY),
+                Some(Value::from(vec![None, Some(75025)])),
+            ),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut buf = Vec::new();
+        write_info(&mut buf, &header, &string_maps, &info)?;
+
+        let mut src = &buf[..];
+        let mut actual = InfoBuf::default();
+        read_info(&mut src, &header, info.len(), &mut actual)?;
+
+        assert!(src.is_empty());
+        assert_eq!(actual, info);
+
+        Ok(())
+    }
+}
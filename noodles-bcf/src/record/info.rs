@@ -65,6 +65,11 @@ impl vcf::variant::record::Info for Info<'_> {
         self.get(header, key)
     }
 
+    /// Returns an iterator over fields.
+    ///
+    /// Fields are yielded in the order they are stored in the record, which is not necessarily
+    /// the order of the INFO header records. Use a record buffer (see
+    /// [`crate::io::Reader::read_record_buf`]) for fields in header order.
     fn iter<'a, 'h: 'a>(
         &'a self,
         header: &'h vcf::Header,
@@ -76,3 +81,59 @@ impl vcf::variant::record::Info for Info<'_> {
         }))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_with_flag() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::variant::io::Write;
+
+        use crate::io::{Reader, Writer};
+
+        let src = b"##fileformat=VCFv4.5
+##contig=<ID=sq0>
+##INFO=<ID=SOMATIC,Number=0,Type=Flag,Description=\"Somatic mutation\">
+##INFO=<ID=DB,Number=0,Type=Flag,Description=\"dbSNP membership\">
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\tSOMATIC;DP=13
+";
+
+        let mut vcf_reader = vcf::io::Reader::new(&src[..]);
+        let header = vcf_reader.read_header()?;
+
+        let mut vcf_record = vcf::Record::default();
+        vcf_reader.read_record(&mut vcf_record)?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_variant_record(&header, &vcf_record)?;
+        writer.try_finish()?;
+
+        let dst = writer.into_inner().into_inner();
+        let mut bcf_reader = Reader::new(&dst[..]);
+        let bcf_header = bcf_reader.read_header()?;
+
+        let mut bcf_record = crate::Record::default();
+        bcf_reader.read_record(&mut bcf_record)?;
+
+        let vcf_info = vcf_record.info();
+        let bcf_info = bcf_record.info();
+
+        assert!(matches!(
+            vcf_info.get(&header, "SOMATIC"),
+            Some(Ok(Some(Value::Flag)))
+        ));
+        assert!(matches!(
+            bcf_info.get(&bcf_header, "SOMATIC"),
+            Some(Ok(Some(Value::Flag)))
+        ));
+
+        assert!(vcf_info.get(&header, "DB").is_none());
+        assert!(bcf_info.get(&bcf_header, "DB").is_none());
+
+        Ok(())
+    }
+}
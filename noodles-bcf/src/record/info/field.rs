@@ -5,14 +5,14 @@ use std::io;
 use noodles_vcf::{self as vcf, variant::record::info::field::Value};
 
 use self::value::read_value;
-use crate::record::codec::decoder::read_string_map_entry;
+use crate::record::codec::decoder::read_info_key;
 
 pub(super) fn read_field<'a, 'h: 'a>(
     src: &mut &'a [u8],
     header: &'h vcf::Header,
 ) -> io::Result<(&'a str, Option<Value<'a>>)> {
-    let key = read_string_map_entry(src, header.string_maps().strings())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    let key =
+        read_info_key(src, header).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
     let (number, ty) = header
         .infos()
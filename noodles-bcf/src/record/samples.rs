@@ -113,6 +113,11 @@ impl vcf::variant::record::Samples for Samples<'_> {
         self.sample_count
     }
 
+    /// Returns an iterator over column names.
+    ///
+    /// Column names are yielded in the order the series are stored in the record, which is the
+    /// same order as [`Self::series`] but not necessarily the order of the FORMAT header records.
+    /// Use a record buffer (see [`crate::io::Reader::read_record_buf`]) for keys in header order.
     fn column_names<'a, 'h: 'a>(
         &'a self,
         header: &'h vcf::Header,
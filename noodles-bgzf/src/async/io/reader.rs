@@ -21,6 +21,9 @@ use crate::{VirtualPosition, gzi, io::Block};
 
 pin_project! {
     /// An async BGZF reader.
+    ///
+    /// Like [`crate::io::Reader`], by default, the reader verifies that a nonempty stream ends with
+    /// a BGZF EOF marker block. See [`Self::set_check_eof`] to disable this check.
     pub struct Reader<R>
     where
         R: AsyncRead,
@@ -30,6 +33,9 @@ pin_project! {
         block: Block,
         position: u64,
         worker_count: NonZeroUsize,
+        check_eof: bool,
+        // `None` if no frame was read since the start of the stream or the last seek.
+        last_frame_is_eof_marker: Option<bool>,
     }
 }
 
@@ -138,6 +144,22 @@ where
     pub fn virtual_position(&self) -> VirtualPosition {
         self.block.virtual_position()
     }
+
+    /// Sets whether to check for a BGZF EOF marker at the end of the stream.
+    ///
+    /// See [`crate::io::Reader::set_check_eof`]. By default, this is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use tokio::io;
+    /// let mut reader = bgzf::r#async::io::Reader::new(io::empty());
+    /// reader.set_check_eof(false);
+    /// ```
+    pub fn set_check_eof(&mut self, check_eof: bool) {
+        self.check_eof = check_eof;
+    }
 }
 
 impl<R> Reader<R>
@@ -167,7 +189,9 @@ where
         let mut stream = blocks.try_buffered(self.worker_count.get());
 
         self.block = match stream.try_next().await? {
-            Some(mut block) => {
+            Some((mut block, is_eof_marker)) => {
+                self.last_frame_is_eof_marker = Some(is_eof_marker);
+
                 let (cpos, upos) = pos.into();
 
                 self.position = cpos + block.size();
@@ -177,7 +201,10 @@ where
 
                 block
             }
-            None => Block::default(),
+            None => {
+                self.last_frame_is_eof_marker = None;
+                Block::default()
+            }
         };
 
         self.stream.replace(stream);
@@ -245,7 +272,9 @@ where
 
             loop {
                 match ready!(stream.as_mut().poll_next(cx)) {
-                    Some(Ok(mut block)) => {
+                    Some(Ok((mut block, is_eof_marker))) => {
+                        *this.last_frame_is_eof_marker = Some(is_eof_marker);
+
                         block.set_position(*this.position);
                         *this.position += block.size();
                         let data_len = block.data().len();
@@ -256,7 +285,16 @@ where
                         }
                     }
                     Some(Err(e)) => return Poll::Ready(Err(e)),
-                    None => return Poll::Ready(Ok(&[])),
+                    None => {
+                        if *this.check_eof && *this.last_frame_is_eof_marker == Some(false) {
+                            return Poll::Ready(Err(io::Error::new(
+                                io::ErrorKind::UnexpectedEof,
+                                "missing BGZF EOF marker",
+                            )));
+                        }
+
+                        return Poll::Ready(Ok(&[]));
+                    }
                 }
             }
         }
@@ -341,4 +379,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_with_missing_eof_marker() -> io::Result<()> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        assert!(matches!(
+            reader.read_to_end(&mut buf).await,
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut reader = Reader::new(&data[..]);
+        reader.set_check_eof(false);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+        assert_eq!(buf, b"noodles");
+
+        let mut reader = Reader::new(tokio::io::empty());
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf).await?;
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
 }
@@ -53,6 +53,8 @@ impl Builder {
             block: Block::default(),
             position: 0,
             worker_count,
+            check_eof: true,
+            last_frame_is_eof_marker: None,
         }
     }
 }
@@ -14,7 +14,7 @@ use crate::io::Block;
 pin_project! {
     pub struct Inflate {
         #[pin]
-        handle: JoinHandle<io::Result<Block>>,
+        handle: JoinHandle<io::Result<(Block, bool)>>,
     }
 }
 
@@ -27,17 +27,18 @@ impl Inflate {
 }
 
 impl Future for Inflate {
-    type Output = io::Result<Block>;
+    // The block and whether its frame is the BGZF EOF marker.
+    type Output = io::Result<(Block, bool)>;
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         self.project().handle.poll(cx)?
     }
 }
 
-fn inflate(src: Bytes) -> io::Result<Block> {
-    use crate::io::reader::frame::parse_block;
+fn inflate(src: Bytes) -> io::Result<(Block, bool)> {
+    use crate::io::{reader::frame::parse_block, writer::BGZF_EOF};
 
     let mut block = Block::default();
     parse_block(&src, &mut block)?;
-    Ok(block)
+    Ok((block, src[..] == BGZF_EOF))
 }
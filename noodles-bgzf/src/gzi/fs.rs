@@ -2,7 +2,7 @@
 
 use std::{
     fs::File,
-    io::{self, BufReader, BufWriter},
+    io::{self, BufReader, BufWriter, Read},
     path::Path,
 };
 
@@ -11,6 +11,53 @@ use super::{
     io::{Reader, Writer},
 };
 
+/// Builds a GZ index from a BGZF file.
+///
+/// The resulting index is equivalent to the one written by `bgzip --index`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use noodles_bgzf::gzi;
+/// let index = gzi::fs::index("in.gz")?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn index<P>(src: P) -> io::Result<Index>
+where
+    P: AsRef<Path>,
+{
+    let mut reader = File::open(src).map(BufReader::new)?;
+    index_inner(&mut reader)
+}
+
+fn index_inner<R>(reader: &mut R) -> io::Result<Index>
+where
+    R: Read,
+{
+    use crate::io::reader::frame::read_frame_into;
+
+    let mut buf = Vec::new();
+    let mut compressed_position = 0;
+    let mut uncompressed_position = 0;
+    let mut entries = Vec::new();
+
+    while read_frame_into(reader, &mut buf)?.is_some() {
+        // SAFETY: A frame is at least as large as the gzip trailer.
+        let (_, isize) = buf.split_last_chunk::<4>().unwrap();
+        let uncompressed_size = u64::from(u32::from_le_bytes(*isize));
+
+        compressed_position += buf.len() as u64;
+
+        // Like htslib, only blocks with data mark a boundary, which excludes the EOF block.
+        if uncompressed_size > 0 {
+            uncompressed_position += uncompressed_size;
+            entries.push((compressed_position, uncompressed_position));
+        }
+    }
+
+    Ok(Index::from(entries))
+}
+
 /// Reads the entire contents of a GZ index.
 ///
 /// This is a convenience function and is equivalent to opening the given path and reading the
@@ -51,3 +98,47 @@ where
     let mut writer = File::create(dst).map(BufWriter::new).map(Writer::new)?;
     writer.write_index(index)
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+    use crate::{self as bgzf, VirtualPosition};
+
+    #[test]
+    fn test_index_inner() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        writer.write_all(b"bgzf")?;
+        writer.flush()?;
+        writer.write_all(b"gzi")?;
+        let data = writer.finish()?;
+
+        let index = index_inner(&mut &data[..])?;
+        assert_eq!(index.as_ref().len(), 3);
+
+        let mut buf = Vec::new();
+        Writer::new(&mut buf).write_index(&index)?;
+        let actual = Reader::new(&buf[..]).read_index()?;
+        assert_eq!(actual, index);
+
+        let (second_block_position, _) = index.as_ref()[0];
+        assert_eq!(
+            index.query(9)?,
+            VirtualPosition::try_from((second_block_position, 2))?
+        );
+
+        let mut reader = bgzf::io::Reader::new(io::Cursor::new(data));
+
+        for (pos, expected) in [(0, b"noodles"), (3, b"dlesbgz"), (7, b"bgzfgzi")] {
+            reader.seek(index.query(pos)?)?;
+            let mut actual = [0; 7];
+            reader.read_exact(&mut actual)?;
+            assert_eq!(&actual, expected);
+        }
+
+        Ok(())
+    }
+}
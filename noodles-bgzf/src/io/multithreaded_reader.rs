@@ -7,7 +7,7 @@ use std::{
 
 use crossbeam_channel::{Receiver, Sender};
 
-use super::Block;
+use super::{Block, writer::BGZF_EOF};
 use crate::{VirtualPosition, gzi};
 
 type BufferedTx = Sender<io::Result<Buffer>>;
@@ -40,11 +40,17 @@ struct Buffer {
 ///
 /// This is a multithreaded BGZF reader that uses a thread pool to decompress block data. It places
 /// the inner reader on its own thread to read raw frames asynchronously.
+///
+/// Like [`super::Reader`], by default, the reader verifies that a nonempty stream ends with a BGZF
+/// EOF marker block. See [`Self::set_check_eof`] to disable this check.
 pub struct MultithreadedReader<R> {
     state: State<R>,
     worker_count: NonZeroUsize,
     position: u64,
     buffer: Buffer,
+    check_eof: bool,
+    // `None` if no frame was read since the start of the stream or the last seek.
+    last_frame_is_eof_marker: Option<bool>,
 }
 
 impl<R> MultithreadedReader<R> {
@@ -76,6 +82,22 @@ impl<R> MultithreadedReader<R> {
         self.buffer.block.virtual_position()
     }
 
+    /// Sets whether to check for a BGZF EOF marker at the end of the stream.
+    ///
+    /// See [`super::Reader::set_check_eof`]. By default, this is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::io::MultithreadedReader::new(io::empty());
+    /// reader.set_check_eof(false);
+    /// ```
+    pub fn set_check_eof(&mut self, check_eof: bool) {
+        self.check_eof = check_eof;
+    }
+
     /// Shuts down the reader.
     ///
     /// # Examples
@@ -144,6 +166,8 @@ where
             worker_count,
             position: 0,
             buffer: Buffer::default(),
+            check_eof: true,
+            last_frame_is_eof_marker: None,
         }
     }
 
@@ -243,6 +267,8 @@ where
         };
 
         while let Some(mut buffer) = recv_buffer(read_rx)? {
+            self.last_frame_is_eof_marker = Some(buffer.buf == BGZF_EOF);
+
             buffer.block.set_position(self.position);
             self.position += buffer.block.size();
 
@@ -250,10 +276,17 @@ where
             recycle_tx.send(prev_buffer).ok();
 
             if self.buffer.block.data().len() > 0 {
-                break;
+                return Ok(());
             }
         }
 
+        if self.check_eof && self.last_frame_is_eof_marker == Some(false) {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "missing BGZF EOF marker",
+            ));
+        }
+
         Ok(())
     }
 }
@@ -327,6 +360,7 @@ where
 
         self.get_mut().seek(SeekFrom::Start(cpos))?;
         self.position = cpos;
+        self.last_frame_is_eof_marker = None;
 
         self.read_block()?;
 
@@ -453,4 +487,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_with_missing_eof_marker() -> io::Result<()> {
+        #[rustfmt::skip]
+        static DATA: &[u8] = &[
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = MultithreadedReader::new(DATA);
+        let mut buf = Vec::new();
+        assert!(matches!(
+            reader.read_to_end(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut reader = MultithreadedReader::new(DATA);
+        reader.set_check_eof(false);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        let mut reader = MultithreadedReader::new(io::empty());
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
 }
@@ -232,3 +232,29 @@ fn compress(src: &[u8], compression_level: CompressionLevelImpl) -> io::Result<F
     let crc32 = deflate::encode(src, compression_level, &mut dst)?;
     Ok((dst, crc32, src.len()))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::Writer;
+
+    #[test]
+    fn test_write_matches_writer() -> io::Result<()> {
+        let data: Vec<u8> = (0..MAX_BUF_SIZE * 3 + 8)
+            .map(|i| b"ACGT"[(i * i + i / 7) % 4])
+            .collect();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let expected = writer.finish()?;
+
+        for worker_count in [NonZeroUsize::MIN, NonZeroUsize::try_from(4).unwrap()] {
+            let mut writer = MultithreadedWriter::with_worker_count(worker_count, Vec::new());
+            writer.write_all(&data)?;
+            let actual = writer.finish()?;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+}
@@ -33,7 +33,7 @@ impl Builder {
     ///
     /// ```
     /// use std::num::NonZeroUsize;
-    /// use noodles_bgzf as bgzf;;
+    /// use noodles_bgzf as bgzf;
     ///
     /// let builder = bgzf::io::multithreaded_writer::Builder::default()
     ///     .set_worker_count(NonZeroUsize::MIN);
@@ -7,7 +7,7 @@ pub use self::builder::Builder;
 
 use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
-use super::Block;
+use super::{Block, writer::BGZF_EOF};
 use crate::{BGZF_MAX_ISIZE, VirtualPosition, gzi};
 
 /// A BGZF reader.
@@ -17,6 +17,10 @@ use crate::{BGZF_MAX_ISIZE, VirtualPosition, gzi};
 /// correctly track (virtual) positions, the reader _cannot_ be double buffered (e.g., using
 /// [`std::io::BufReader`]).
 ///
+/// By default, the reader verifies that a nonempty stream ends with a BGZF EOF marker block and
+/// returns an [`io::ErrorKind::UnexpectedEof`] error if it is missing, e.g., when the stream is
+/// truncated. See [`Self::set_check_eof`] to disable this check.
+///
 /// # Examples
 ///
 /// ```no_run
@@ -32,6 +36,9 @@ pub struct Reader<R> {
     buf: Vec<u8>,
     position: u64,
     block: Block,
+    check_eof: bool,
+    // `None` if no frame was read since the start of the stream or the last seek.
+    last_frame_is_eof_marker: Option<bool>,
 }
 
 impl<R> Reader<R> {
@@ -76,6 +83,30 @@ impl<R> Reader<R> {
     pub fn into_inner(self) -> R {
         self.inner
     }
+
+    /// Sets whether to check for a BGZF EOF marker at the end of the stream.
+    ///
+    /// When enabled, reaching the end of a nonempty stream that does not end with a BGZF EOF
+    /// marker block returns an [`io::ErrorKind::UnexpectedEof`] error. This detects truncated
+    /// input, e.g., partial downloads. Disable this for streams where the trailer is legitimately
+    /// absent.
+    ///
+    /// Only the frames read since the start of the stream or the last seek are considered, i.e.,
+    /// seeking to the end of a stream does not trigger the check.
+    ///
+    /// By default, this is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::io::Reader::new(io::empty());
+    /// reader.set_check_eof(false);
+    /// ```
+    pub fn set_check_eof(&mut self, check_eof: bool) {
+        self.check_eof = check_eof;
+    }
 }
 
 impl<R> Reader<R>
@@ -130,16 +161,25 @@ where
         use self::frame::read_frame_into;
 
         while read_frame_into(&mut self.inner, &mut self.buf)?.is_some() {
+            self.last_frame_is_eof_marker = Some(self.buf == BGZF_EOF);
+
             f(&self.buf, &mut self.block)?;
 
             self.block.set_position(self.position);
             self.position += self.block.size();
 
             if self.block.data().len() > 0 {
-                break;
+                return Ok(self.block.data().len());
             }
         }
 
+        if self.check_eof && self.last_frame_is_eof_marker == Some(false) {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "missing BGZF EOF marker",
+            ));
+        }
+
         Ok(self.block.data().len())
     }
 
@@ -177,6 +217,7 @@ where
 
         self.inner.seek(SeekFrom::Start(cpos))?;
         self.position = cpos;
+        self.last_frame_is_eof_marker = None;
 
         self.read_block()?;
 
@@ -341,6 +382,50 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_with_missing_eof_marker() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Reader::new(&data[..]);
+        let mut buf = Vec::new();
+        assert!(matches!(
+            reader.read_to_end(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut reader = Reader::new(&data[..]);
+        reader.set_check_eof(false);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        let mut src = data.to_vec();
+        src.extend(BGZF_EOF);
+        let mut reader = Reader::new(&src[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        let mut reader = Reader::new(io::empty());
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert!(buf.is_empty());
+
+        let mut reader = Reader::new(io::Cursor::new(&data[..]));
+        reader.seek(VirtualPosition::try_from((data.len() as u64, 0))?)?;
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert!(buf.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
         #[rustfmt::skip]
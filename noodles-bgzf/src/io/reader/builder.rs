@@ -48,6 +48,8 @@ impl Builder {
             buf: Vec::new(),
             position: 0,
             block: Block::default(),
+            check_eof: true,
+            last_frame_is_eof_marker: None,
         }
     }
 }
@@ -265,7 +265,10 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::io::Read;
+
     use super::*;
+    use crate::io::Reader;
 
     #[test]
     fn test_virtual_position() -> Result<(), Box<dyn std::error::Error>> {
@@ -302,4 +305,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_with_compression_level() -> Result<(), Box<dyn std::error::Error>> {
+        fn write(data: &[u8], compression_level: CompressionLevel) -> io::Result<Vec<u8>> {
+            let mut writer = Builder::default()
+                .set_compression_level(compression_level)
+                .build_from_writer(Vec::new());
+
+            writer.write_all(data)?;
+            writer.finish()
+        }
+
+        fn read(src: &[u8]) -> io::Result<Vec<u8>> {
+            let mut reader = Reader::new(src);
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+            Ok(buf)
+        }
+
+        let data: Vec<u8> = b"noodles"
+            .iter()
+            .copied()
+            .cycle()
+            .take(MAX_BUF_SIZE * 2)
+            .collect();
+
+        let fast_dst = write(&data, CompressionLevel::FAST)?;
+        let best_dst = write(&data, CompressionLevel::try_from(9)?)?;
+        let none_dst = write(&data, CompressionLevel::NONE)?;
+
+        assert_eq!(read(&fast_dst)?, data);
+        assert_eq!(read(&best_dst)?, data);
+        assert_eq!(read(&none_dst)?, data);
+
+        assert!(none_dst.len() > data.len());
+        assert!(fast_dst.len() < none_dst.len());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,219 @@
+//! Nucleotide base composition.
+
+/// Counts of nucleotide bases in a sequence.
+///
+/// `N` is counted separately, and any other symbol, including IUPAC ambiguity codes, is counted as
+/// other.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct BaseComposition {
+    a: u64,
+    c: u64,
+    g: u64,
+    t: u64,
+    n: u64,
+    other: u64,
+}
+
+impl BaseComposition {
+    /// Counts the bases in the given sequence, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::BaseComposition;
+    ///
+    /// let composition = BaseComposition::new(b"ACgtN");
+    /// assert_eq!(composition.a(), 1);
+    /// assert_eq!(composition.g(), 1);
+    /// assert_eq!(composition.n(), 1);
+    /// ```
+    pub fn new(src: &[u8]) -> Self {
+        let mut composition = Self::default();
+        composition.add(src);
+        composition
+    }
+
+    /// Counts the bases in the given sequence, counting lowercase bases as other.
+    ///
+    /// This is useful when lowercase bases carry meaning, e.g., soft-masked regions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::BaseComposition;
+    ///
+    /// let composition = BaseComposition::new_case_sensitive(b"ACgtN");
+    /// assert_eq!(composition.a(), 1);
+    /// assert_eq!(composition.g(), 0);
+    /// assert_eq!(composition.other(), 2);
+    /// ```
+    pub fn new_case_sensitive(src: &[u8]) -> Self {
+        let mut composition = Self::default();
+        composition.add_case_sensitive(src);
+        composition
+    }
+
+    /// Adds the bases in the given sequence to the counts, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::BaseComposition;
+    ///
+    /// let mut composition = BaseComposition::default();
+    /// composition.add(b"ACGT");
+    /// composition.add(b"acgt");
+    ///
+    /// assert_eq!(composition.a(), 2);
+    /// assert_eq!(composition.other(), 0);
+    /// ```
+    pub fn add(&mut self, src: &[u8]) {
+        for &b in src {
+            self.add_base(b.to_ascii_uppercase());
+        }
+    }
+
+    /// Adds the bases in the given sequence to the counts, counting lowercase bases as other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::BaseComposition;
+    ///
+    /// let mut composition = BaseComposition::default();
+    /// composition.add_case_sensitive(b"ACGT");
+    /// composition.add_case_sensitive(b"acgt");
+    ///
+    /// assert_eq!(composition.a(), 1);
+    /// assert_eq!(composition.other(), 4);
+    /// ```
+    pub fn add_case_sensitive(&mut self, src: &[u8]) {
+        for &b in src {
+            self.add_base(b);
+        }
+    }
+
+    fn add_base(&mut self, b: u8) {
+        match b {
+            b'A' => self.a += 1,
+            b'C' => self.c += 1,
+            b'G' => self.g += 1,
+            b'T' => self.t += 1,
+            b'N' => self.n += 1,
+            _ => self.other += 1,
+        }
+    }
+
+    /// Returns the number of `A` bases.
+    pub fn a(&self) -> u64 {
+        self.a
+    }
+
+    /// Returns the number of `C` bases.
+    pub fn c(&self) -> u64 {
+        self.c
+    }
+
+    /// Returns the number of `G` bases.
+    pub fn g(&self) -> u64 {
+        self.g
+    }
+
+    /// Returns the number of `T` bases.
+    pub fn t(&self) -> u64 {
+        self.t
+    }
+
+    /// Returns the number of `N` bases.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the number of other symbols.
+    pub fn other(&self) -> u64 {
+        self.other
+    }
+
+    /// Returns the total number of symbols counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::BaseComposition;
+    /// assert_eq!(BaseComposition::new(b"ACGTNR").len(), 6);
+    /// ```
+    pub fn len(&self) -> u64 {
+        self.a + self.c + self.g + self.t + self.n + self.other
+    }
+
+    /// Returns whether no symbols were counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::BaseComposition;
+    /// assert!(BaseComposition::default().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the fraction of `G` and `C` bases.
+    ///
+    /// Only `A`, `C`, `G`, and `T` bases are included in the denominator; `N`, ambiguity codes,
+    /// and other symbols are excluded. This returns `None` if there are no such bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::BaseComposition;
+    ///
+    /// assert_eq!(BaseComposition::new(b"ACGGNNNN").gc_fraction(), Some(0.75));
+    /// assert_eq!(BaseComposition::new(b"NNNN").gc_fraction(), None);
+    /// ```
+    pub fn gc_fraction(&self) -> Option<f64> {
+        let gc = self.g + self.c;
+        let total = self.a + self.t + gc;
+
+        if total == 0 {
+            None
+        } else {
+            Some(gc as f64 / total as f64)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            BaseComposition::new(b"AACGTnr-"),
+            BaseComposition {
+                a: 2,
+                c: 1,
+                g: 1,
+                t: 1,
+                n: 1,
+                other: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn test_new_case_sensitive() {
+        assert_eq!(
+            BaseComposition::new_case_sensitive(b"AACGTnr-"),
+            BaseComposition {
+                a: 2,
+                c: 1,
+                g: 1,
+                t: 1,
+                n: 0,
+                other: 3,
+            }
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! I/O utilities.
+
+mod bytes_read;
+
+pub use self::bytes_read::BytesRead;
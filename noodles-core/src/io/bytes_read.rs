@@ -0,0 +1,154 @@
+use std::io::{self, BufRead, Read};
+
+/// A reader that counts the number of bytes consumed from the underlying reader.
+///
+/// This is useful for tracking progress through a stream, e.g., comparing the number of bytes
+/// read against the size of a file. When wrapping the source of a compressed stream, the count is
+/// the number of compressed bytes consumed.
+///
+/// Bytes are counted when they are read via [`Read::read`] or consumed via [`BufRead::consume`].
+///
+/// # Examples
+///
+/// ```
+/// use std::io::Read;
+/// use noodles_core::io::BytesRead;
+///
+/// let mut reader = BytesRead::new(&b"noodles"[..]);
+///
+/// let mut buf = [0; 3];
+/// reader.read_exact(&mut buf)?;
+///
+/// assert_eq!(reader.bytes_read(), 3);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct BytesRead<R> {
+    inner: R,
+    bytes_read: u64,
+}
+
+impl<R> BytesRead<R> {
+    /// Creates a reader that counts the number of bytes consumed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_core::io::BytesRead;
+    /// let reader = BytesRead::new(io::empty());
+    /// ```
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            bytes_read: 0,
+        }
+    }
+
+    /// Returns the number of bytes consumed from the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_core::io::BytesRead;
+    /// let reader = BytesRead::new(io::empty());
+    /// assert_eq!(reader.bytes_read(), 0);
+    /// ```
+    pub fn bytes_read(&self) -> u64 {
+        self.bytes_read
+    }
+
+    /// Returns a reference to the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_core::io::BytesRead;
+    /// let reader = BytesRead::new(io::empty());
+    /// let _inner = reader.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    ///
+    /// Reading directly from the underlying reader bypasses the byte count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_core::io::BytesRead;
+    /// let mut reader = BytesRead::new(io::empty());
+    /// let _inner = reader.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Returns the underlying reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_core::io::BytesRead;
+    /// let reader = BytesRead::new(io::empty());
+    /// let _inner = reader.into_inner();
+    /// ```
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R> Read for BytesRead<R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.bytes_read += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R> BufRead for BytesRead<R>
+where
+    R: BufRead,
+{
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.inner.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.inner.consume(amt);
+        self.bytes_read += amt as u64;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytes_read() -> io::Result<()> {
+        let mut reader = BytesRead::new(&b"noodles\ncore\n"[..]);
+
+        let mut buf = String::new();
+        reader.read_line(&mut buf)?;
+        assert_eq!(reader.bytes_read(), 8);
+
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(reader.bytes_read(), 10);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(reader.bytes_read(), 13);
+
+        Ok(())
+    }
+}
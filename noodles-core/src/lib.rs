@@ -1,6 +1,10 @@
 //! **noodles-core** contains shared structures and behavior among noodles libraries.
 
+pub mod base_composition;
+pub mod io;
+pub mod phred;
 pub mod position;
 pub mod region;
+pub mod sample;
 
-pub use self::{position::Position, region::Region};
+pub use self::{base_composition::BaseComposition, position::Position, region::Region};
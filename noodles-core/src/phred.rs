@@ -0,0 +1,131 @@
+//! Phred quality scores.
+
+use std::io;
+
+/// The offset of Phred+33 encoded quality scores.
+pub const OFFSET: u8 = b'!';
+
+/// The maximum Phred quality score that can be encoded as a printable Phred+33 character (`~`).
+pub const MAX_SCORE: u8 = b'~' - OFFSET;
+
+/// Decodes a Phred+33 encoded quality score.
+///
+/// This returns an error if the byte is not a printable Phred+33 value (`!`..=`~`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::phred;
+///
+/// assert_eq!(phred::decode(b'I')?, 40);
+/// assert!(phred::decode(b' ').is_err());
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn decode(b: u8) -> io::Result<u8> {
+    if (OFFSET..=OFFSET + MAX_SCORE).contains(&b) {
+        Ok(b - OFFSET)
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("invalid quality score: {b:#04x}"),
+        ))
+    }
+}
+
+/// Decodes a Phred+33 encoded quality score without checking it.
+///
+/// This is the unchecked counterpart of [`decode`] for input that is known to be valid. The
+/// result is meaningless for a byte that is not a printable Phred+33 value (`!`..=`~`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::phred;
+/// assert_eq!(phred::decode_unchecked(b'I'), 40);
+/// ```
+pub fn decode_unchecked(b: u8) -> u8 {
+    b.wrapping_sub(OFFSET)
+}
+
+/// Returns the median of the given quality scores.
+///
+/// When there is an even number of scores, the median is the mean of the two middle scores. This
+/// returns `None` if there are no scores.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::phred;
+///
+/// assert_eq!(phred::median([40, 0, 20]), Some(20.0));
+/// assert_eq!(phred::median([40, 0, 10, 20]), Some(15.0));
+/// assert!(phred::median([]).is_none());
+/// ```
+pub fn median<I>(scores: I) -> Option<f64>
+where
+    I: IntoIterator<Item = u8>,
+{
+    fn nth(counts: &[usize; 256], i: usize) -> u8 {
+        let mut cumulative_count = 0;
+
+        for (score, &count) in (0..=u8::MAX).zip(counts) {
+            cumulative_count += count;
+
+            if i < cumulative_count {
+                return score;
+            }
+        }
+
+        unreachable!()
+    }
+
+    let mut counts = [0; 256];
+    let mut n = 0;
+
+    for score in scores {
+        counts[usize::from(score)] += 1;
+        n += 1;
+    }
+
+    if n == 0 {
+        return None;
+    }
+
+    let mid = n / 2;
+
+    if n % 2 == 0 {
+        let (a, b) = (nth(&counts, mid - 1), nth(&counts, mid));
+        Some((f64::from(a) + f64::from(b)) / 2.0)
+    } else {
+        Some(f64::from(nth(&counts, mid)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() -> io::Result<()> {
+        assert_eq!(decode(b'!')?, 0);
+        assert_eq!(decode(b'+')?, 10);
+        assert_eq!(decode(b'~')?, 93);
+
+        for b in [0x00, b' ', 0x7f, 0xff] {
+            assert!(matches!(
+                decode(b),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median([40, 0, 20]), Some(20.0));
+        assert_eq!(median([40, 0, 10, 20]), Some(15.0));
+        assert_eq!(median([0, 0, 255]), Some(0.0));
+        assert!(median([]).is_none());
+    }
+}
@@ -69,6 +69,61 @@ impl Position {
             None
         }
     }
+
+    /// Returns the end position of an interval of the given length starting at this position.
+    ///
+    /// Both positions are 1-based and inclusive, i.e., this is `self + len - 1`. An interval with
+    /// a length of 0 ends at the position before this position.
+    ///
+    /// This returns `None` if the operation overflowed or, for an interval with a length of 0
+    /// starting at the minimum position, the end position would be 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    ///
+    /// let start = Position::try_from(8)?;
+    /// assert_eq!(start.add_length(6), Position::new(13));
+    /// assert_eq!(start.add_length(1), Some(start));
+    /// assert_eq!(start.add_length(0), Position::new(7));
+    ///
+    /// assert!(Position::MIN.add_length(0).is_none());
+    /// assert!(Position::MAX.add_length(2).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub const fn add_length(self, len: usize) -> Option<Self> {
+        match len.checked_sub(1) {
+            Some(n) => self.checked_add(n),
+            None => Self::new(self.0.get() - 1),
+        }
+    }
+
+    /// Returns the length of the interval from this position to the given end position.
+    ///
+    /// Both positions are 1-based and inclusive, i.e., this is `end - self + 1`. This is the
+    /// inverse of [`Self::add_length`]: an end position directly before this position is an
+    /// interval with a length of 0.
+    ///
+    /// This returns `None` if the end position is before the position directly before this
+    /// position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    ///
+    /// let start = Position::try_from(8)?;
+    ///
+    /// assert_eq!(start.span_to(Position::try_from(13)?), Some(6));
+    /// assert_eq!(start.span_to(start), Some(1));
+    /// assert_eq!(start.span_to(Position::try_from(7)?), Some(0));
+    /// assert!(start.span_to(Position::try_from(5)?).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub const fn span_to(self, end: Self) -> Option<usize> {
+        end.0.get().checked_sub(self.0.get() - 1)
+    }
 }
 
 impl fmt::Display for Position {
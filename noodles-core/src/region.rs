@@ -1,12 +1,14 @@
 //! Genomic region.
 
 pub mod interval;
+mod query_mode;
 
 use bstr::{BStr, BString};
 
-pub use self::interval::Interval;
+pub use self::{interval::Interval, query_mode::QueryMode};
 
 use std::{
+    borrow::Cow,
     error, fmt,
     ops::{Bound, RangeBounds},
     str::FromStr,
@@ -127,6 +129,86 @@ impl Region {
     pub fn interval(&self) -> Interval {
         self.interval
     }
+
+    /// Returns the region with a `chr` prefix added to or removed from the name.
+    ///
+    /// This is commonly used to match reference sequence names across naming conventions, e.g.,
+    /// `chr1` and `1`. The interval is unchanged. This returns `None` if the name is `chr`, i.e.,
+    /// removing the prefix would result in an empty name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let start = Position::try_from(5)?;
+    /// let end = Position::try_from(8)?;
+    ///
+    /// let region = Region::new("chr1", start..=end);
+    /// assert_eq!(region.chr_alias(), Some(Region::new("1", start..=end)));
+    ///
+    /// let region = Region::new("1", start..=end);
+    /// assert_eq!(region.chr_alias(), Some(Region::new("chr1", start..=end)));
+    ///
+    /// let region = Region::new("chr", start..=end);
+    /// assert!(region.chr_alias().is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn chr_alias(&self) -> Option<Self> {
+        const PREFIX: &[u8] = b"chr";
+
+        let name: BString = match self.name.strip_prefix(PREFIX) {
+            Some([]) => return None,
+            Some(suffix) => suffix.into(),
+            None => [PREFIX, self.name.as_slice()].concat().into(),
+        };
+
+        Some(Self {
+            name,
+            interval: self.interval,
+        })
+    }
+
+    /// Resolves the region to a known reference sequence name using its `chr` alias.
+    ///
+    /// If `contains` is false for the region name but true for its [`Self::chr_alias`], the alias
+    /// is returned. Otherwise, the region is returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{Position, Region};
+    ///
+    /// let names = [&b"chr1"[..], b"2"];
+    /// let contains = |name: &[u8]| names.contains(&name);
+    ///
+    /// let start = Position::try_from(5)?;
+    /// let end = Position::try_from(8)?;
+    ///
+    /// let region = Region::new("1", start..=end);
+    /// assert_eq!(
+    ///     region.resolve_chr_alias(contains).as_ref(),
+    ///     &Region::new("chr1", start..=end)
+    /// );
+    ///
+    /// let region = Region::new("3", start..=end);
+    /// assert_eq!(region.resolve_chr_alias(contains).as_ref(), &region);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn resolve_chr_alias<F>(&self, contains: F) -> Cow<'_, Self>
+    where
+        F: Fn(&[u8]) -> bool,
+    {
+        if !contains(self.name()) {
+            if let Some(alias) = self.chr_alias() {
+                if contains(alias.name()) {
+                    return Cow::Owned(alias);
+                }
+            }
+        }
+
+        Cow::Borrowed(self)
+    }
 }
 
 impl fmt::Display for Region {
@@ -224,4 +306,35 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_resolve_chr_alias() -> Result<(), crate::position::TryFromIntError> {
+        let names = [&b"chr1"[..], b"2"];
+        let contains = |name: &[u8]| names.contains(&name);
+
+        let start = Position::try_from(2)?;
+        let end = Position::try_from(5)?;
+
+        let region = Region::new("1", start..=end);
+        let actual = region.resolve_chr_alias(contains);
+        assert_eq!(actual.as_ref(), &Region::new("chr1", start..=end));
+
+        let region = Region::new("chr2", start..=end);
+        let actual = region.resolve_chr_alias(contains);
+        assert_eq!(actual.as_ref(), &Region::new("2", start..=end));
+
+        let region = Region::new("chr1", start..=end);
+        assert!(matches!(
+            region.resolve_chr_alias(contains),
+            Cow::Borrowed(r) if r == &region
+        ));
+
+        let region = Region::new("3", start..=end);
+        assert!(matches!(
+            region.resolve_chr_alias(contains),
+            Cow::Borrowed(r) if r == &region
+        ));
+
+        Ok(())
+    }
 }
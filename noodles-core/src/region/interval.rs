@@ -94,18 +94,40 @@ impl Interval {
     /// # Ok::<_, noodles_core::position::TryFromIntError>(())
     /// ```
     pub fn intersects(&self, other: Self) -> bool {
-        fn resolve(interval: Interval) -> (Position, Position) {
-            (
-                interval.start().unwrap_or(Position::MIN),
-                interval.end().unwrap_or(Position::MAX),
-            )
-        }
-
         let (a_start, a_end) = resolve(*self);
         let (b_start, b_end) = resolve(other);
 
         a_start <= b_end && b_start <= a_end
     }
+
+    /// Returns whether the given interval is fully contained in this interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::Interval, Position};
+    ///
+    /// let a = Interval::from(Position::try_from(5)?..=Position::try_from(13)?);
+    /// let b = Interval::from(Position::try_from(8)?..=Position::try_from(13)?);
+    /// assert!(a.contains_interval(b));
+    ///
+    /// let c = Interval::from(Position::try_from(8)?..=Position::try_from(21)?);
+    /// assert!(!a.contains_interval(c));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn contains_interval(&self, other: Self) -> bool {
+        let (a_start, a_end) = resolve(*self);
+        let (b_start, b_end) = resolve(other);
+
+        a_start <= b_start && b_end <= a_end
+    }
+}
+
+fn resolve(interval: Interval) -> (Position, Position) {
+    (
+        interval.start().unwrap_or(Position::MIN),
+        interval.end().unwrap_or(Position::MAX),
+    )
 }
 
 impl fmt::Display for Interval {
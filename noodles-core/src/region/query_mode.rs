@@ -0,0 +1,64 @@
+use super::Interval;
+
+/// A region query mode.
+///
+/// This describes how a record interval must relate to a query region interval to be included in
+/// the query results.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum QueryMode {
+    /// The record interval intersects the region interval.
+    #[default]
+    Overlap,
+    /// The record interval is fully contained in the region interval.
+    Contained,
+}
+
+impl QueryMode {
+    /// Returns whether a record interval matches the region interval in this mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::{region::{Interval, QueryMode}, Position};
+    ///
+    /// let region_interval = Interval::from(Position::try_from(5)?..=Position::try_from(13)?);
+    /// let record_interval = Interval::from(Position::try_from(8)?..=Position::try_from(21)?);
+    ///
+    /// assert!(QueryMode::Overlap.matches(region_interval, record_interval));
+    /// assert!(!QueryMode::Contained.matches(region_interval, record_interval));
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn matches(self, region_interval: Interval, record_interval: Interval) -> bool {
+        match self {
+            Self::Overlap => region_interval.intersects(record_interval),
+            Self::Contained => region_interval.contains_interval(record_interval),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Position;
+
+    #[test]
+    fn test_matches() -> Result<(), crate::position::TryFromIntError> {
+        let region_interval = Interval::from(Position::try_from(5)?..=Position::try_from(8)?);
+
+        let a = (Position::try_from(5)?..=Position::try_from(8)?).into();
+        assert!(QueryMode::Overlap.matches(region_interval, a));
+        assert!(QueryMode::Contained.matches(region_interval, a));
+
+        let b = (Position::try_from(4)?..=Position::try_from(6)?).into();
+        assert!(QueryMode::Overlap.matches(region_interval, b));
+        assert!(!QueryMode::Contained.matches(region_interval, b));
+
+        let c = (Position::try_from(1)?..=Position::try_from(2)?).into();
+        assert!(!QueryMode::Overlap.matches(region_interval, c));
+        assert!(!QueryMode::Contained.matches(region_interval, c));
+
+        assert!(QueryMode::Contained.matches(Interval::from(..), a));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,380 @@
+//! Record sampling.
+
+use std::io;
+
+/// Selects exactly `n` items uniformly at random from an iterator of results.
+///
+/// This uses reservoir sampling (Vitter's Algorithm R), so the input is read once and at most `n`
+/// items are held in memory. If the input has fewer than `n` items, all of them are returned. The
+/// selected items are returned in input order.
+///
+/// This works with any record iterator, e.g., FASTQ or BAM records. To keep paired records
+/// together, sample an iterator of pairs, e.g., records of two FASTQ readers zipped into tuples.
+///
+/// The selection is deterministic for a given `seed` and input. The first error from the input is
+/// returned, and sampling stops.
+///
+/// # Examples
+///
+/// ```
+/// use std::io;
+/// use noodles_core::sample::reservoir_sample;
+///
+/// let records = (0..100).map(io::Result::Ok);
+/// let sample = reservoir_sample(records, 10, 8)?;
+/// assert_eq!(sample.len(), 10);
+/// assert!(sample.is_sorted());
+///
+/// let records = (0..5).map(io::Result::Ok);
+/// let sample = reservoir_sample(records, 10, 8)?;
+/// assert_eq!(sample, [0, 1, 2, 3, 4]);
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn reservoir_sample<I, T>(records: I, n: usize, seed: u64) -> io::Result<Vec<T>>
+where
+    I: IntoIterator<Item = io::Result<T>>,
+{
+    let mut rng = SplitMix64::new(seed);
+    let mut reservoir: Vec<(u64, T)> = Vec::with_capacity(n);
+
+    for (i, result) in (0..).zip(records) {
+        let record = result?;
+
+        if reservoir.len() < n {
+            reservoir.push((i, record));
+        } else {
+            // `j` is uniform over the first `i + 1` items, so the record replaces a random item in
+            // the reservoir with probability `n / (i + 1)`.
+            let j = rng.next_below(i + 1);
+
+            if let Some(slot) = usize::try_from(j).ok().and_then(|j| reservoir.get_mut(j)) {
+                *slot = (i, record);
+            }
+        }
+    }
+
+    reservoir.sort_unstable_by_key(|(i, _)| *i);
+
+    Ok(reservoir.into_iter().map(|(_, record)| record).collect())
+}
+
+/// A deterministic sampler that keeps a fraction of records by name.
+///
+/// Inclusion is decided by hashing the record name with a seed, so records with the same name,
+/// e.g., paired mates, are either all kept or all dropped. A trailing `/1` or `/2` in the name is
+/// ignored. On large inputs, about `fraction` of the names are kept.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FractionSampler {
+    fraction: f64,
+    seed: u64,
+}
+
+impl FractionSampler {
+    /// Creates a fraction sampler.
+    ///
+    /// This returns an error if `fraction` is not in [0, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sample::FractionSampler;
+    ///
+    /// let sampler = FractionSampler::new(1.0, 8)?;
+    /// assert!(sampler.is_sampled(b"r0"));
+    ///
+    /// let sampler = FractionSampler::new(0.0, 8)?;
+    /// assert!(!sampler.is_sampled(b"r0"));
+    ///
+    /// assert!(FractionSampler::new(1.5, 8).is_err());
+    /// assert!(FractionSampler::new(f64::NAN, 8).is_err());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn new(fraction: f64, seed: u64) -> io::Result<Self> {
+        if (0.0..=1.0).contains(&fraction) {
+            Ok(Self { fraction, seed })
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid fraction: expected 0 <= fraction <= 1, got {fraction}"),
+            ))
+        }
+    }
+
+    /// Returns whether a record with the given name is kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::sample::FractionSampler;
+    /// let sampler = FractionSampler::new(0.5, 8)?;
+    /// assert_eq!(sampler.is_sampled(b"r0/1"), sampler.is_sampled(b"r0/2"));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn is_sampled(&self, name: &[u8]) -> bool {
+        const SCALE: f64 = (1u64 << 53) as f64;
+        let n = hash(strip_mate_suffix(name), self.seed) >> 11;
+        (n as f64) / SCALE < self.fraction
+    }
+}
+
+/// An iterator over a deterministic fraction of records.
+///
+/// This wraps an iterator of record results and keeps the records whose names are sampled by a
+/// [`FractionSampler`]. Errors from the inner iterator are passed through.
+pub struct SampleFraction<I, F> {
+    records: I,
+    sampler: FractionSampler,
+    name: F,
+}
+
+impl<I, F> SampleFraction<I, F> {
+    /// Creates a fraction sampling iterator.
+    ///
+    /// `name` returns the name of a record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    /// use noodles_core::sample::{FractionSampler, SampleFraction};
+    ///
+    /// let records = [b"r0/1", b"r0/2"].into_iter().map(io::Result::Ok);
+    /// let sampler = FractionSampler::new(1.0, 8)?;
+    ///
+    /// let sample: Vec<_> = SampleFraction::new(records, sampler, |name| &name[..])
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(sample, [b"r0/1", b"r0/2"]);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new<T>(records: I, sampler: FractionSampler, name: F) -> Self
+    where
+        I: Iterator<Item = io::Result<T>>,
+        F: Fn(&T) -> &[u8],
+    {
+        Self {
+            records,
+            sampler,
+            name,
+        }
+    }
+}
+
+impl<I, F, T> Iterator for SampleFraction<I, F>
+where
+    I: Iterator<Item = io::Result<T>>,
+    F: Fn(&T) -> &[u8],
+{
+    type Item = io::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next()? {
+                Ok(record) => {
+                    if self.sampler.is_sampled((self.name)(&record)) {
+                        return Some(Ok(record));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+// Mates are commonly named `<name>/1` and `<name>/2`.
+fn strip_mate_suffix(name: &[u8]) -> &[u8] {
+    match name {
+        [prefix @ .., b'/', b'1' | b'2'] => prefix,
+        _ => name,
+    }
+}
+
+// FNV-1a followed by a SplitMix64 finalizer.
+fn hash(buf: &[u8], seed: u64) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x100000001b3;
+
+    let mut h = OFFSET_BASIS ^ seed;
+
+    for &b in buf {
+        h ^= u64::from(b);
+        h = h.wrapping_mul(PRIME);
+    }
+
+    h ^= h >> 30;
+    h = h.wrapping_mul(0xbf58476d1ce4e5b9);
+    h ^= h >> 27;
+    h = h.wrapping_mul(0x94d049bb133111eb);
+    h ^ (h >> 31)
+}
+
+// SplitMix64 (Steele et al., 2014).
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    // Returns a value in [0, bound) using multiply-shift reduction.
+    fn next_below(&mut self, bound: u64) -> u64 {
+        ((u128::from(self.next_u64()) * u128::from(bound)) >> 64) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reservoir_sample() -> io::Result<()> {
+        let sample = reservoir_sample((0..1000).map(Ok), 10, 0)?;
+        assert_eq!(sample.len(), 10);
+        assert!(sample.is_sorted());
+        assert_eq!(reservoir_sample((0..1000).map(Ok), 10, 0)?, sample);
+
+        assert_eq!(reservoir_sample((0..3).map(Ok), 10, 0)?, [0, 1, 2]);
+        assert!(reservoir_sample((0..3).map(Ok), 0, 0)?.is_empty());
+
+        let pairs = (0..100).map(|i| Ok((i, i)));
+        let sample = reservoir_sample(pairs, 5, 0)?;
+        assert!(sample.iter().all(|(a, b)| a == b));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reservoir_sample_with_error() {
+        let records = (0..10).map(|i| {
+            if i == 5 {
+                Err(io::Error::from(io::ErrorKind::InvalidData))
+            } else {
+                Ok(i)
+            }
+        });
+
+        assert!(matches!(
+            reservoir_sample(records, 3, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+
+    #[test]
+    fn test_reservoir_sample_is_uniform() -> io::Result<()> {
+        const LEN: usize = 100;
+        const N: usize = 10;
+        const TRIALS: u64 = 4000;
+
+        let mut counts = [0u64; LEN];
+
+        for seed in 0..TRIALS {
+            for i in reservoir_sample((0..LEN).map(Ok), N, seed)? {
+                counts[i] += 1;
+            }
+        }
+
+        // Each item is expected to be selected TRIALS * N / LEN = 400 times, with a standard
+        // deviation of about 19.
+        for (i, &count) in counts.iter().enumerate() {
+            assert!((300..500).contains(&count), "counts[{i}] = {count}");
+        }
+
+        // The first and second halves are selected equally often.
+        let (a, b) = counts.split_at(LEN / 2);
+        let (a, b): (u64, u64) = (a.iter().sum(), b.iter().sum());
+        assert!(a.abs_diff(b) < 800, "a = {a}, b = {b}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fraction_sampler_new() {
+        assert!(FractionSampler::new(0.0, 0).is_ok());
+        assert!(FractionSampler::new(0.5, 0).is_ok());
+        assert!(FractionSampler::new(1.0, 0).is_ok());
+
+        for fraction in [-0.1, 1.1, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                FractionSampler::new(fraction, 0),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+    }
+
+    #[test]
+    fn test_fraction_sampler_is_sampled() -> io::Result<()> {
+        const N: usize = 10000;
+
+        let sampler = FractionSampler::new(0.25, 8)?;
+
+        let n = (0..N)
+            .filter(|i| {
+                let (a, b) = (format!("r{i}/1"), format!("r{i}/2"));
+                let is_sampled = sampler.is_sampled(a.as_bytes());
+                assert_eq!(sampler.is_sampled(b.as_bytes()), is_sampled);
+                assert_eq!(sampler.is_sampled(format!("r{i}").as_bytes()), is_sampled);
+                is_sampled
+            })
+            .count();
+
+        assert!((2250..2750).contains(&n), "n = {n}");
+
+        let sampler = FractionSampler::new(0.0, 8)?;
+        assert!((0..N).all(|i| !sampler.is_sampled(format!("r{i}").as_bytes())));
+
+        let sampler = FractionSampler::new(1.0, 8)?;
+        assert!((0..N).all(|i| sampler.is_sampled(format!("r{i}").as_bytes())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_fraction() -> io::Result<()> {
+        const N: usize = 1000;
+
+        let records = (0..N).flat_map(|i| [format!("r{i}/1"), format!("r{i}/2")].map(Ok));
+        let sampler = FractionSampler::new(0.25, 8)?;
+        let actual: Vec<_> = SampleFraction::new(records, sampler, |name: &String| name.as_bytes())
+            .collect::<io::Result<_>>()?;
+
+        let expected: Vec<_> = (0..N)
+            .filter(|i| sampler.is_sampled(format!("r{i}").as_bytes()))
+            .flat_map(|i| [format!("r{i}/1"), format!("r{i}/2")])
+            .collect();
+
+        assert_eq!(actual, expected);
+
+        let records = [
+            Ok(String::from("r0")),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+        ];
+        let sampler = FractionSampler::new(0.0, 8)?;
+        let mut iter = SampleFraction::new(records.into_iter(), sampler, |name: &String| {
+            name.as_bytes()
+        });
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_strip_mate_suffix() {
+        assert_eq!(strip_mate_suffix(b"r0/1"), b"r0");
+        assert_eq!(strip_mate_suffix(b"r0/2"), b"r0");
+        assert_eq!(strip_mate_suffix(b"r0/3"), b"r0/3");
+        assert_eq!(strip_mate_suffix(b"r0"), b"r0");
+    }
+}
@@ -348,3 +348,86 @@ where
         ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use noodles_core::Position;
+    use sam::alignment::{
+        RecordBuf,
+        io::Write,
+        record::{
+            Flags,
+            cigar::{Op, op::Kind},
+        },
+    };
+
+    use super::*;
+    use crate::test_utils;
+
+    #[tokio::test]
+    async fn test_records() -> Result<(), Box<dyn std::error::Error>> {
+        let (repository, header) = test_utils::sq0_repository_and_header();
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"CGTA".to_vec().into())
+                .set_quality_scores(vec![45; 4].into())
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence(b"TTGA".to_vec().into())
+                .set_quality_scores(vec![35; 4].into())
+                .build(),
+        ];
+
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(&writer.get_ref()[..]);
+
+        let actual_header = reader.read_header().await?;
+        assert!(
+            actual_header
+                .reference_sequences()
+                .keys()
+                .eq(header.reference_sequences().keys())
+        );
+
+        let actual: Vec<_> = reader.records(&actual_header).try_collect().await?;
+        assert_eq!(actual.len(), records.len());
+
+        for (actual_record, expected_record) in actual.iter().zip(&records) {
+            assert_eq!(actual_record.name(), expected_record.name());
+            assert_eq!(actual_record.flags(), expected_record.flags());
+            assert_eq!(
+                actual_record.alignment_start(),
+                expected_record.alignment_start()
+            );
+            assert_eq!(actual_record.sequence(), expected_record.sequence());
+            assert_eq!(
+                actual_record.quality_scores(),
+                expected_record.quality_scores()
+            );
+        }
+
+        Ok(())
+    }
+}
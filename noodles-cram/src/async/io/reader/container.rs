@@ -10,7 +10,10 @@ where
     R: AsyncRead + Unpin,
 {
     match read_header(reader, &mut container.header).await? {
-        0 => Ok(0),
+        0 => {
+            read_eof_container_body(reader).await?;
+            Ok(0)
+        }
         len => {
             container.src.resize(len, 0);
             reader.read_exact(&mut container.src).await?;
@@ -18,3 +21,14 @@ where
         }
     }
 }
+
+async fn read_eof_container_body<R>(reader: &mut R) -> io::Result<()>
+where
+    R: AsyncRead + Unpin,
+{
+    use crate::io::reader::container::{EOF_BODY_LEN, validate_eof_container_body};
+
+    let mut buf = [0; EOF_BODY_LEN];
+    reader.read_exact(&mut buf).await?;
+    validate_eof_container_body(&buf)
+}
@@ -66,6 +66,12 @@ where
         actual_crc32,
     ) {
         Ok(0)
+    } else if len == 0 {
+        // A length of 0 is reserved to signal the EOF container.
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid container length: 0",
+        ))
     } else {
         Ok(len)
     }
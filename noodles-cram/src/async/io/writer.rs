@@ -268,7 +268,7 @@ where
         header: &sam::Header,
         record: &dyn sam::alignment::Record,
     ) -> io::Result<()> {
-        let record = Record::try_from_alignment_record(header, record)?;
+        let record = Record::try_from_alignment_record(header, record, &self.options)?;
         self.add_record(header, record).await
     }
 
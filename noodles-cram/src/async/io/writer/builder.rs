@@ -1,6 +1,7 @@
 use std::path::Path;
 
 use noodles_fasta as fasta;
+use noodles_sam::alignment::record::data::field::Tag;
 use tokio::{
     fs::File,
     io::{self, AsyncWrite},
@@ -10,7 +11,7 @@ use super::Writer;
 use crate::{
     container::BlockContentEncoderMap,
     file_definition::Version,
-    io::writer::{Options, RECORDS_PER_CONTAINER},
+    io::writer::{Options, RECORDS_PER_CONTAINER, TagFilter},
 };
 
 /// An async CRAM writer builder.
@@ -50,6 +51,28 @@ impl Builder {
         self
     }
 
+    /// Sets whether to store the quality scores of mapped records as an array.
+    ///
+    /// If `false`, the quality scores of mapped records are stored with their read features.
+    ///
+    /// The default is `false`.
+    pub fn store_quality_scores_as_array(mut self, value: bool) -> Self {
+        self.options.store_quality_scores_as_array = value;
+        self
+    }
+
+    /// Sets a predicate that selects which data fields are written.
+    ///
+    /// Only data fields with tags for which the predicate returns `true` are written. By default,
+    /// all data fields are written.
+    pub fn set_tag_filter<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Tag) -> bool + Send + Sync + 'static,
+    {
+        self.options.tag_filter = Some(TagFilter::new(f));
+        self
+    }
+
     /// Sets the block content-encoder map.
     pub fn set_block_content_encoder_map(mut self, map: BlockContentEncoderMap) -> Self {
         self.options.block_content_encoder_map = map;
@@ -4,10 +4,11 @@ pub(crate) mod block;
 pub mod block_content_encoder_map;
 pub mod compression_header;
 mod header;
-mod reference_sequence_context;
+pub mod reference_sequence_context;
 pub(crate) mod slice;
 
+pub(crate) use self::header::Header;
 pub use self::{
-    block_content_encoder_map::BlockContentEncoderMap, compression_header::CompressionHeader,
+    block::CompressionMethod, block_content_encoder_map::BlockContentEncoderMap,
+    compression_header::CompressionHeader, reference_sequence_context::ReferenceSequenceContext,
 };
-pub(crate) use self::{header::Header, reference_sequence_context::ReferenceSequenceContext};
@@ -5,9 +5,9 @@ pub(crate) mod encoding;
 pub mod preservation_map;
 mod tag_encodings;
 
+pub use self::preservation_map::PreservationMap;
 pub(crate) use self::{
-    data_series_encodings::DataSeriesEncodings, encoding::Encoding,
-    preservation_map::PreservationMap, tag_encodings::TagEncodings,
+    data_series_encodings::DataSeriesEncodings, encoding::Encoding, tag_encodings::TagEncodings,
 };
 
 /// A CRAM container compression header.
@@ -34,7 +34,16 @@ impl CompressionHeader {
         }
     }
 
-    pub(crate) fn preservation_map(&self) -> &PreservationMap {
+    /// Returns the preservation map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::container::CompressionHeader;
+    /// let compression_header = CompressionHeader::default();
+    /// assert!(compression_header.preservation_map().records_have_names());
+    /// ```
+    pub fn preservation_map(&self) -> &PreservationMap {
         &self.preservation_map
     }
 
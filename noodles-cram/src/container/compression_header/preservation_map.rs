@@ -6,8 +6,12 @@ pub mod tag_sets;
 
 pub(crate) use {key::Key, substitution_matrix::SubstitutionMatrix, tag_sets::TagSets};
 
+/// A CRAM container compression header preservation map.
+///
+/// The preservation map describes how records in the container are stored, e.g., whether read
+/// names are preserved or whether alignment starts are stored as deltas.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct PreservationMap {
+pub struct PreservationMap {
     pub(crate) records_have_names: bool,
     pub(crate) alignment_starts_are_deltas: bool,
     pub(crate) external_reference_sequence_is_required: bool,
@@ -16,7 +20,7 @@ pub(crate) struct PreservationMap {
 }
 
 impl PreservationMap {
-    pub fn new(
+    pub(crate) fn new(
         records_have_names: bool,
         alignment_starts_are_deltas: bool,
         external_reference_sequence_is_required: bool,
@@ -32,22 +36,60 @@ impl PreservationMap {
         }
     }
 
+    /// Returns whether read names are preserved (`RN`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::container::compression_header::PreservationMap;
+    /// let preservation_map = PreservationMap::default();
+    /// assert!(preservation_map.records_have_names());
+    /// ```
     pub fn records_have_names(&self) -> bool {
         self.records_have_names
     }
 
+    /// Returns whether alignment starts are stored as deltas of the previous record (`AP`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::container::compression_header::PreservationMap;
+    /// let preservation_map = PreservationMap::default();
+    /// assert!(preservation_map.alignment_starts_are_deltas());
+    /// ```
     pub fn alignment_starts_are_deltas(&self) -> bool {
         self.alignment_starts_are_deltas
     }
 
+    /// Returns whether an external reference sequence is required to decode records (`RR`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::container::compression_header::PreservationMap;
+    /// let preservation_map = PreservationMap::default();
+    /// assert!(preservation_map.external_reference_sequence_is_required());
+    /// ```
     pub fn external_reference_sequence_is_required(&self) -> bool {
         self.external_reference_sequence_is_required
     }
 
-    pub fn substitution_matrix(&self) -> &SubstitutionMatrix {
+    pub(crate) fn substitution_matrix(&self) -> &SubstitutionMatrix {
         &self.substitution_matrix
     }
 
+    /// Returns the tag sets (`TD`).
+    ///
+    /// Each record references a tag set by its index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::container::compression_header::PreservationMap;
+    /// let preservation_map = PreservationMap::default();
+    /// assert!(preservation_map.tag_sets().is_empty());
+    /// ```
     pub fn tag_sets(&self) -> &TagSets {
         &self.tag_sets
     }
@@ -4,10 +4,11 @@
 pub mod r#async;
 
 pub mod fs;
+mod indexer;
 pub mod io;
 pub mod record;
 
-pub use self::record::Record;
+pub use self::{indexer::Indexer, record::Record};
 
 /// A CRAM index.
 pub type Index = Vec<Record>;
@@ -0,0 +1,45 @@
+use super::{Index, Record};
+
+/// A CRAM index indexer.
+///
+/// An indexer collects CRAM index records for each slice (or reference sequence in a
+/// multi-reference slice) as they are written.
+#[derive(Debug, Default)]
+pub struct Indexer {
+    index: Index,
+}
+
+impl Indexer {
+    /// Adds a record.
+    ///
+    /// Records are expected to be added in the order they appear in the CRAM file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_cram::crai;
+    ///
+    /// let mut indexer = crai::Indexer::default();
+    ///
+    /// let record = crai::Record::new(Some(0), Position::new(8), 13, 21, 34, 55);
+    /// indexer.add_record(record);
+    /// ```
+    pub fn add_record(&mut self, record: Record) {
+        self.index.push(record);
+    }
+
+    /// Builds a CRAM index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::crai;
+    /// let indexer = crai::Indexer::default();
+    /// let index = indexer.build();
+    /// assert!(index.is_empty());
+    /// ```
+    pub fn build(self) -> Index {
+        self.index
+    }
+}
@@ -4,9 +4,15 @@ mod builder;
 
 pub use self::builder::Builder;
 
-use std::io::{self, Read, Seek};
+use std::{
+    borrow::Cow,
+    io::{self, Read, Seek},
+};
 
-use noodles_core::Region;
+use noodles_core::{
+    Region,
+    region::{Interval, QueryMode},
+};
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
@@ -20,6 +26,7 @@ use crate::{FileDefinition, crai};
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: crai::Index,
+    resolve_contig_aliases: bool,
 }
 
 impl<R> IndexedReader<R>
@@ -31,6 +38,7 @@ where
         Self {
             inner: Reader::new(inner),
             index,
+            resolve_contig_aliases: false,
         }
     }
 
@@ -95,6 +103,82 @@ where
         header: &'h sam::Header,
         region: &Region,
     ) -> io::Result<Query<'r, 'h, 'r, R>> {
-        self.inner.query(header, &self.index, region)
+        let region = self.resolve_region(header, region);
+        self.inner.query(header, &self.index, &region)
+    }
+
+    /// Returns an iterator over records that match the given region using the given query mode.
+    pub fn query_with_mode<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+        region: &Region,
+        query_mode: QueryMode,
+    ) -> io::Result<Query<'r, 'h, 'r, R>> {
+        let region = self.resolve_region(header, region);
+        self.inner
+            .query_with_mode(header, &self.index, &region, query_mode)
+    }
+
+    /// Returns an iterator over records that intersect the given interval of the reference
+    /// sequence with the given ID.
+    ///
+    /// See [`Reader::query_by_reference_id`].
+    pub fn query_by_reference_id<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> io::Result<Query<'r, 'h, 'r, R>> {
+        self.inner
+            .query_by_reference_id(header, &self.index, reference_sequence_id, interval)
+    }
+
+    fn resolve_region<'a>(&self, header: &sam::Header, region: &'a Region) -> Cow<'a, Region> {
+        if self.resolve_contig_aliases {
+            let reference_sequences = header.reference_sequences();
+            region.resolve_chr_alias(|name| reference_sequences.contains_key(name))
+        } else {
+            Cow::Borrowed(region)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::header::record::value::{Map, map::ReferenceSequence};
+
+    use super::*;
+
+    #[test]
+    fn test_resolve_region() -> Result<(), Box<dyn std::error::Error>> {
+        const LN: NonZeroUsize = match NonZeroUsize::new(8) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("chr1", Map::<ReferenceSequence>::new(LN))
+            .build();
+
+        let region = Region::new("1", Position::try_from(2)?..=Position::try_from(5)?);
+
+        let reader = Builder::default()
+            .set_index(crai::Index::default())
+            .build_from_reader(io::empty())?;
+        assert_eq!(reader.resolve_region(&header, &region).as_ref(), &region);
+
+        let reader = Builder::default()
+            .set_index(crai::Index::default())
+            .set_resolve_contig_aliases(true)
+            .build_from_reader(io::empty())?;
+        assert_eq!(
+            reader.resolve_region(&header, &region).name(),
+            b"chr1".as_slice()
+        );
+
+        Ok(())
     }
 }
@@ -15,6 +15,7 @@ use crate::crai;
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
     index: Option<crai::Index>,
+    resolve_contig_aliases: bool,
 }
 
 impl Builder {
@@ -52,6 +53,23 @@ impl Builder {
         self
     }
 
+    /// Sets whether to resolve contig aliases when querying.
+    ///
+    /// When enabled, a query region whose reference sequence name is not in the header is retried
+    /// with a `chr` prefix added to or removed from the name, e.g., `1` resolves to `chr1`. By
+    /// default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::indexed_reader::Builder;
+    /// let builder = Builder::default().set_resolve_contig_aliases(true);
+    /// ```
+    pub fn set_resolve_contig_aliases(mut self, resolve_contig_aliases: bool) -> Self {
+        self.resolve_contig_aliases = resolve_contig_aliases;
+        self
+    }
+
     /// Builds an indexed CRAM reader from a path.
     ///
     /// If no index is set, this will attempt to read an associated index at `<src>.crai`.
@@ -104,7 +122,11 @@ impl Builder {
             .index
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
 
-        Ok(IndexedReader { inner, index })
+        Ok(IndexedReader {
+            inner,
+            index,
+            resolve_contig_aliases: self.resolve_contig_aliases,
+        })
     }
 }
 
@@ -3,19 +3,42 @@
 mod builder;
 pub(crate) mod collections;
 pub(crate) mod container;
+mod container_summaries;
 pub mod header;
+mod missing_quality_policy;
 pub(crate) mod num;
 mod query;
+mod query_unmapped;
 mod records;
 
-use std::io::{self, Read, Seek, SeekFrom};
+use std::{
+    collections::HashSet,
+    io::{self, Read, Seek, SeekFrom},
+};
 
-use noodles_core::Region;
+#[cfg(feature = "parallel")]
+use std::num::NonZeroUsize;
+
+use noodles_core::{
+    Region,
+    region::{Interval, QueryMode},
+};
 use noodles_fasta as fasta;
-use noodles_sam as sam;
+use noodles_sam::{self as sam, alignment::record::data::field::Tag};
 
-pub use self::{builder::Builder, container::Container, query::Query, records::Records};
-use self::{container::read_container, header::read_header};
+pub use self::{
+    builder::Builder,
+    container::Container,
+    container_summaries::{ContainerSummaries, ContainerSummary},
+    missing_quality_policy::MissingQualityPolicy,
+    query::Query,
+    query_unmapped::QueryUnmapped,
+    records::Records,
+};
+use self::{
+    container::{read_container, slice::records::Options},
+    header::read_header,
+};
 use crate::{FileDefinition, crai};
 
 /// A CRAM reader.
@@ -43,6 +66,10 @@ use crate::{FileDefinition, crai};
 pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
+    #[cfg(feature = "parallel")]
+    worker_count: NonZeroUsize,
+    missing_quality_policy: MissingQualityPolicy,
+    tag_filter: Option<HashSet<Tag>>,
 }
 
 impl<R> Reader<R> {
@@ -111,6 +138,13 @@ where
         &self.reference_sequence_repository
     }
 
+    fn records_options(&self) -> Options<'_> {
+        Options {
+            missing_quality_policy: self.missing_quality_policy,
+            tag_filter: self.tag_filter.as_ref(),
+        }
+    }
+
     /// Returns a CRAM header reader.
     ///
     /// # Examples
@@ -232,6 +266,9 @@ where
     ///
     /// The stream is expected to be at the start of a container.
     ///
+    /// The stream position is unknown after an error reading a container, so the iterator yields
+    /// no more records after such an error. Errors decoding a container do not stop iteration.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -251,6 +288,58 @@ where
     pub fn records<'r, 'h: 'r>(&'r mut self, header: &'h sam::Header) -> Records<'r, 'h, R> {
         Records::new(self, header)
     }
+
+    /// Returns an iterator over unplaced, unmapped records starting from the current stream
+    /// position.
+    ///
+    /// Containers and slices whose reference sequence context is a single reference sequence are
+    /// skipped without decoding their blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// for result in reader.query_unmapped(&header) {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn query_unmapped<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+    ) -> QueryUnmapped<'r, 'h, R> {
+        QueryUnmapped::new(self, header)
+    }
+
+    /// Returns an iterator over container summaries starting from the current stream position.
+    ///
+    /// Only container and block headers are read, so no records are decoded, and no reference
+    /// sequences are required. The stream is expected to be at the start of a container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// for result in reader.container_summaries() {
+    ///     let summary = result?;
+    ///     println!("{} records", summary.record_count());
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn container_summaries(&mut self) -> ContainerSummaries<'_, R> {
+        ContainerSummaries::new(self)
+    }
 }
 
 impl<R> Reader<R>
@@ -317,6 +406,42 @@ where
         header: &'h sam::Header,
         index: &'i crai::Index,
         region: &Region,
+    ) -> io::Result<Query<'r, 'h, 'i, R>> {
+        self.query_with_mode(header, index, region, QueryMode::default())
+    }
+
+    /// Returns an iterator over records that match the given region using the given query mode.
+    ///
+    /// With [`QueryMode::Overlap`], this is the same as [`Self::query`]. With
+    /// [`QueryMode::Contained`], only records whose alignment spans are fully contained in the
+    /// region are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_core::region::QueryMode;
+    /// use noodles_cram::{self as cram, crai};
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    /// let index = crai::fs::read("sample.cram.crai")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query_with_mode(&header, &index, &region, QueryMode::Contained)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_with_mode<'r, 'h: 'r, 'i: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+        index: &'i crai::Index,
+        region: &Region,
+        query_mode: QueryMode,
     ) -> io::Result<Query<'r, 'h, 'i, R>> {
         let reference_sequence_id = header
             .reference_sequences()
@@ -334,6 +459,61 @@ where
             index,
             reference_sequence_id,
             region.interval(),
+            query_mode,
+        ))
+    }
+
+    /// Returns an iterator over records that intersect the given interval of the reference
+    /// sequence with the given ID.
+    ///
+    /// This is the same as [`Self::query`] but skips resolving a region name. The reference
+    /// sequence ID is the index of the reference sequence in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_core::Position;
+    /// use noodles_cram::{self as cram, crai};
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    /// let index = crai::fs::read("sample.cram.crai")?;
+    /// let interval = (Position::try_from(8)?..=Position::try_from(13)?).into();
+    /// let query = reader.query_by_reference_id(&header, &index, 0, interval)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_by_reference_id<'r, 'h: 'r, 'i: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+        index: &'i crai::Index,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> io::Result<Query<'r, 'h, 'i, R>> {
+        let len = header.reference_sequences().len();
+
+        if reference_sequence_id >= len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "reference sequence ID out of range: {reference_sequence_id} (len = {len})"
+                ),
+            ));
+        }
+
+        Ok(Query::new(
+            self,
+            header,
+            index,
+            reference_sequence_id,
+            interval,
+            QueryMode::default(),
         ))
     }
 }
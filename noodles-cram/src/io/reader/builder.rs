@@ -1,22 +1,38 @@
 use std::{
+    collections::HashSet,
     fs::File,
     io::{self, Read},
     path::Path,
 };
 
+#[cfg(feature = "parallel")]
+use std::num::NonZeroUsize;
+
 use noodles_fasta as fasta;
+use noodles_sam::alignment::record::data::field::Tag;
 
-use super::Reader;
+use super::{MissingQualityPolicy, Reader};
 
 /// A CRAM reader builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
+    #[cfg(feature = "parallel")]
+    worker_count: WorkerCount,
+    missing_quality_policy: MissingQualityPolicy,
+    tag_filter: Option<HashSet<Tag>>,
 }
 
 impl Builder {
     /// Sets the reference sequence repository.
     ///
+    /// When querying the same reference from multiple threads, give each reader its own handle
+    /// created by [`fasta::Repository::with_local_cache_capacity`]. Sequences are then stored
+    /// once in the shared repository, and each reader mostly reads from its own small cache
+    /// rather than contending on the shared lock. A cache capacity of the length of the largest
+    /// reference sequence queried is typically sufficient, as regions are usually extracted
+    /// from one reference sequence at a time.
+    ///
     /// # Examples
     ///
     /// ```
@@ -36,6 +52,70 @@ impl Builder {
         self
     }
 
+    /// Sets the worker count.
+    ///
+    /// This is the number of threads that decode containers when iterating records using
+    /// [`Reader::records`]. Containers are read on the calling thread while the workers decode
+    /// the preceding ones, and records are yielded in their original order.
+    ///
+    /// By default, the worker count is set to 1, i.e., containers are decoded serially on the
+    /// calling thread.
+    ///
+    /// This requires the `parallel` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::reader::Builder;
+    /// let builder = Builder::default().set_worker_count(NonZeroUsize::MIN);
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn set_worker_count(mut self, worker_count: NonZeroUsize) -> Self {
+        self.worker_count = WorkerCount(worker_count);
+        self
+    }
+
+    /// Sets the missing quality policy.
+    ///
+    /// This determines how quality scores that are all `0xff` (missing) are read. By default,
+    /// this is [`MissingQualityPolicy::Collapse`], i.e., missing quality scores are read as empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::reader::{Builder, MissingQualityPolicy};
+    /// let builder = Builder::default().set_missing_quality_policy(MissingQualityPolicy::Preserve);
+    /// ```
+    pub fn set_missing_quality_policy(
+        mut self,
+        missing_quality_policy: MissingQualityPolicy,
+    ) -> Self {
+        self.missing_quality_policy = missing_quality_policy;
+        self
+    }
+
+    /// Sets the data field tags to decode.
+    ///
+    /// Only data fields with the given tags are decoded. The values of other data fields are
+    /// skipped. By default, all data fields are decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::reader::Builder;
+    /// use noodles_sam::alignment::record::data::field::Tag;
+    ///
+    /// let builder = Builder::default().set_tag_filter([Tag::EDIT_DISTANCE, Tag::ALIGNMENT_SCORE]);
+    /// ```
+    pub fn set_tag_filter<I>(mut self, tags: I) -> Self
+    where
+        I: IntoIterator<Item = Tag>,
+    {
+        self.tag_filter = Some(tags.into_iter().collect());
+        self
+    }
+
     /// Builds a CRAM reader from a path.
     ///
     /// # Examples
@@ -69,6 +149,22 @@ impl Builder {
         Reader {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
+            #[cfg(feature = "parallel")]
+            worker_count: self.worker_count.0,
+            missing_quality_policy: self.missing_quality_policy,
+            tag_filter: self.tag_filter,
         }
     }
 }
+
+// The worker count defaults to 1.
+#[cfg(feature = "parallel")]
+#[derive(Debug)]
+struct WorkerCount(NonZeroUsize);
+
+#[cfg(feature = "parallel")]
+impl Default for WorkerCount {
+    fn default() -> Self {
+        Self(NonZeroUsize::MIN)
+    }
+}
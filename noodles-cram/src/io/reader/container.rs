@@ -26,6 +26,27 @@ impl Container {
     }
 
     /// Returns the compression header.
+    ///
+    /// This can be used to inspect how records in the container are stored.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram::{self as cram, io::reader::Container};
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// let mut container = Container::default();
+    ///
+    /// while reader.read_container(&mut container)? != 0 {
+    ///     let compression_header = container.compression_header()?;
+    ///     let preservation_map = compression_header.preservation_map();
+    ///     println!("{}", preservation_map.records_have_names());
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
     pub fn compression_header(&self) -> io::Result<CompressionHeader> {
         let end = self
             .header
@@ -67,7 +88,10 @@ where
     R: Read,
 {
     match read_header(reader, &mut container.header)? {
-        0 => Ok(0),
+        0 => {
+            read_eof_container_body(reader)?;
+            Ok(0)
+        }
         len => {
             container.src.resize(len, 0);
             reader.read_exact(&mut container.src)?;
@@ -75,3 +99,118 @@ where
         }
     }
 }
+
+fn read_eof_container_body<R>(reader: &mut R) -> io::Result<()>
+where
+    R: Read,
+{
+    let mut buf = [0; EOF_BODY_LEN];
+    reader.read_exact(&mut buf)?;
+    validate_eof_container_body(&buf)
+}
+
+pub(crate) const EOF_BODY_LEN: usize = 15;
+
+pub(crate) fn validate_eof_container_body(buf: &[u8; EOF_BODY_LEN]) -> io::Result<()> {
+    use crate::io::writer::container::EOF;
+
+    if buf[..] == EOF[EOF.len() - EOF_BODY_LEN..] {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid EOF container",
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compression_header() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_sam::{
+            self as sam,
+            alignment::{
+                RecordBuf,
+                io::Write,
+                record::data::field::{Tag, Type},
+                record_buf::data::field::Value,
+            },
+        };
+
+        use crate::container::compression_header::preservation_map::tag_sets;
+
+        let header = sam::Header::default();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_data(
+                [(Tag::ALIGNMENT_HIT_COUNT, Value::from(1))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let mut reader = crate::io::Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let mut container = Container::default();
+        reader.read_container(&mut container)?;
+
+        let compression_header = container.compression_header()?;
+        let preservation_map = compression_header.preservation_map();
+
+        assert!(preservation_map.records_have_names());
+        assert!(preservation_map.alignment_starts_are_deltas());
+        assert!(preservation_map.external_reference_sequence_is_required());
+        assert_eq!(
+            *preservation_map.tag_sets(),
+            [vec![tag_sets::Key::new(
+                Tag::ALIGNMENT_HIT_COUNT,
+                Type::UInt8
+            )]]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_container_with_eof_container() -> io::Result<()> {
+        use crate::io::writer::container::EOF;
+
+        let mut container = Container::default();
+
+        let mut src = &EOF[..];
+        assert_eq!(read_container(&mut src, &mut container)?, 0);
+        assert!(src.is_empty());
+
+        let mut src = &EOF[..EOF.len() - 1];
+        assert!(matches!(
+            read_container(&mut src, &mut container),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut src = EOF.to_vec();
+        let i = src.len() - 1;
+        src[i] = 0x00;
+        assert!(matches!(
+            read_container(&mut &src[..], &mut container),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let mut src: &[u8] = &[];
+        assert!(matches!(
+            read_container(&mut src, &mut container),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        Ok(())
+    }
+}
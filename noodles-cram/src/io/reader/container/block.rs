@@ -54,7 +54,7 @@ impl Block<'_> {
     }
 }
 
-fn read_block<'c>(src: &mut &'c [u8]) -> io::Result<Block<'c>> {
+pub fn read_block<'c>(src: &mut &'c [u8]) -> io::Result<Block<'c>> {
     let original_src = *src;
 
     let mut compression_method = read_compression_method(src)?;
@@ -180,4 +180,51 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_decode_with_rans_nx16() -> io::Result<()> {
+        use crate::codecs::rans_nx16::{self, Flags};
+
+        // order-0 encoding of b"noodles" with 4-way interleaving
+        let src = [
+            0x00, // flags = {empty}
+            0x07, // uncompressed len = 7
+            0x64, 0x65, 0x00, 0x6c, 0x6e, 0x6f, 0x00, 0x73, 0x00, 0x01, 0x01, 0x01, 0x01, 0x03,
+            0x01, 0x00, 0x26, 0x20, 0x00, 0x00, 0xb8, 0x0a, 0x00, 0x00, 0xd8, 0x0a, 0x00, 0x00,
+            0x00, 0x04, 0x00,
+        ];
+
+        let block = Block {
+            compression_method: CompressionMethod::RansNx16,
+            content_type: ContentType::ExternalData,
+            content_id: ContentId::from(1),
+            uncompressed_size: 7,
+            src: &src,
+        };
+
+        assert_eq!(block.decode()?, b"noodles");
+
+        let data = b"ACGTACGTNNNNACGTTTTTGGGGCCCCAAAA".repeat(8);
+
+        for flags in [
+            Flags::N32,
+            Flags::N32 | Flags::ORDER,
+            Flags::N32 | Flags::RLE,
+            Flags::N32 | Flags::PACK,
+        ] {
+            let src = rans_nx16::encode(flags, &data)?;
+
+            let block = Block {
+                compression_method: CompressionMethod::RansNx16,
+                content_type: ContentType::ExternalData,
+                content_id: ContentId::from(1),
+                uncompressed_size: data.len(),
+                src: &src,
+            };
+
+            assert_eq!(block.decode()?, data, "flags = {flags:?}");
+        }
+
+        Ok(())
+    }
 }
@@ -67,6 +67,12 @@ where
         expected_crc32,
     ) {
         Ok(0)
+    } else if len == 0 {
+        // A length of 0 is reserved to signal the EOF container.
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid container length: 0",
+        ))
     } else {
         Ok(len)
     }
@@ -170,6 +176,29 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_header_with_zero_length() {
+        let src = [
+            0x00, 0x00, 0x00, 0x00, // length = 0 bytes
+            0x00, // reference sequence ID = 0
+            0x00, // starting position on the reference = 0
+            0x00, // alignment span = 0
+            0x00, // number of records = 0
+            0x00, // record counter = 0
+            0x00, // bases = 0
+            0x00, // number of blocks = 0
+            0x00, // landmark count = 0
+            0x82, 0x46, 0x74, 0x0f, // CRC32
+        ];
+
+        let mut header = Header::default();
+
+        assert!(matches!(
+            read_header(&mut &src[..], &mut header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData,
+        ));
+    }
+
     #[test]
     fn test_read_header_with_a_checksum_mismatch() {
         // EOF container header
@@ -5,15 +5,15 @@ use std::{borrow::Cow, io};
 
 use noodles_core::Position;
 use noodles_fasta as fasta;
-use noodles_sam::{self as sam, alignment::Record as _};
+use noodles_sam as sam;
 
 use self::{
     header::read_header,
-    records::{ExternalDataReaders, Records},
+    records::{ExternalDataReaders, Options, Records},
 };
 use super::read_block_as;
 use crate::{
-    Record, calculate_normalized_sequence_digest,
+    Record,
     container::{
         CompressionHeader, ReferenceSequenceContext,
         block::{self, ContentType},
@@ -57,6 +57,9 @@ impl<'c> Slice<'c> {
 
     /// Reads and returns a list of raw records in this slice.
     ///
+    /// Record IDs (see [`Record::id`]) start at the record counter in the slice header, so no
+    /// state needs to be carried between containers or slices to assign IDs in file order.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -98,7 +101,26 @@ impl<'c> Slice<'c> {
         compression_header: &'ch CompressionHeader,
         core_data_src: &'c [u8],
         external_data_srcs: &'c [(block::ContentId, Vec<u8>)],
-    ) -> io::Result<Vec<Record>> {
+    ) -> io::Result<Vec<Record<'c>>> {
+        self.records_with_options(
+            reference_sequence_repository,
+            header,
+            compression_header,
+            core_data_src,
+            external_data_srcs,
+            Options::default(),
+        )
+    }
+
+    pub(crate) fn records_with_options<'h: 'c, 'ch: 'c>(
+        &self,
+        reference_sequence_repository: fasta::Repository,
+        header: &'h sam::Header,
+        compression_header: &'ch CompressionHeader,
+        core_data_src: &'c [u8],
+        external_data_srcs: &'c [(block::ContentId, Vec<u8>)],
+        options: Options<'c>,
+    ) -> io::Result<Vec<Record<'c>>> {
         let core_data_reader = BitReader::new(core_data_src);
 
         let mut external_data_readers = ExternalDataReaders::new();
@@ -116,6 +138,7 @@ impl<'c> Slice<'c> {
             external_data_readers,
             reference_sequence_context,
             initial_id,
+            options,
         );
 
         let slice_reference_sequence = get_slice_reference_sequence(
@@ -277,8 +300,7 @@ fn calculate_template_length_chunk(
     ) -> Option<Position> {
         alignment_start.and_then(|start| {
             let span = calculate_alignment_span(read_length, features);
-            let end = usize::from(start) + span - 1;
-            Position::new(end)
+            start.add_length(span)
         })
     }
 
@@ -341,16 +363,12 @@ fn get_slice_reference_sequence(
         slice_header.embedded_reference_bases_block_content_id();
 
     if external_reference_sequence_is_required {
-        let reference_sequence_name = header
-            .reference_sequences()
-            .get_index(context.reference_sequence_id())
-            .map(|(name, _)| name)
-            .expect("invalid slice reference sequence ID");
-
-        let sequence = reference_sequence_repository
-            .get(reference_sequence_name)
-            .transpose()?
-            .expect("invalid slice reference sequence name");
+        let sequence = get_reference_sequence(
+            reference_sequence_repository,
+            header,
+            context.reference_sequence_id(),
+        )?
+        .expect("invalid slice reference sequence name");
 
         // § 8.5 "Slice header block" (2024-09-04): "MD5sums should not be validated if the stored
         // checksum is all-zero."
@@ -386,22 +404,49 @@ fn get_record_reference_sequence(
         return Ok(None);
     }
 
-    let reference_sequence_name = record
-        .reference_sequence(header)
-        .transpose()?
-        .map(|(name, _)| name)
+    let reference_sequence_id = record
+        .reference_sequence_id
         .expect("invalid reference sequence ID");
 
-    let sequence = reference_sequence_repository
-        .get(reference_sequence_name)
-        .transpose()?
-        .expect("invalid reference sequence name");
+    let sequence =
+        get_reference_sequence(reference_sequence_repository, header, reference_sequence_id)?
+            .expect("invalid reference sequence name");
 
     Ok(Some(ReferenceSequence::External { sequence }))
 }
 
+// Reference sequences are looked up by name and, failing that, by the MD5 checksum in the
+// reference sequence header record (`M5`), if present.
+fn get_reference_sequence(
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+    reference_sequence_id: usize,
+) -> io::Result<Option<fasta::record::Sequence>> {
+    use sam::header::record::value::map::reference_sequence::{Md5Checksum, tag};
+
+    let (name, reference_sequence) = header
+        .reference_sequences()
+        .get_index(reference_sequence_id)
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+        })?;
+
+    let Some(raw_md5) = reference_sequence.other_fields().get(&tag::MD5_CHECKSUM) else {
+        return reference_sequence_repository.get(name).transpose();
+    };
+
+    let md5: Md5Checksum = raw_md5
+        .to_string()
+        .parse()
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    reference_sequence_repository
+        .get_by_name_or_md5(name, &md5.into())
+        .transpose()
+}
+
 fn validate_sequence(sequence: &[u8], expected_checksum: &[u8; 16]) -> io::Result<()> {
-    let actual_checksum = calculate_normalized_sequence_digest(sequence);
+    let actual_checksum = fasta::record::sequence::normalized_md5(sequence);
 
     if &actual_checksum == expected_checksum {
         Ok(())
@@ -418,6 +463,7 @@ fn validate_sequence(sequence: &[u8], expected_checksum: &[u8; 16]) -> io::Resul
 #[cfg(test)]
 mod tests {
     use bstr::ByteSlice;
+    use noodles_sam::alignment::Record as _;
 
     use super::*;
     use crate::record::Flags;
@@ -568,4 +614,223 @@ mod tests {
         let record = Record::default();
         assert_eq!(calculate_template_length(&record, &record), 0);
     }
+
+    #[test]
+    fn test_records_ids_across_containers() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{RecordBuf, io::Write};
+
+        use crate::io::{Reader, reader::Container, writer::RECORDS_PER_CONTAINER};
+
+        let header = sam::Header::default();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let record_count = RECORDS_PER_CONTAINER + 2;
+
+        for i in 0..record_count {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}"))
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![20; 4].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let src = writer.into_inner();
+
+        let mut reader = Reader::new(&src[..]);
+        let header = reader.read_header()?;
+
+        let mut container = Container::default();
+        let mut ids = Vec::new();
+        let mut container_count = 0;
+
+        while reader.read_container(&mut container)? != 0 {
+            let compression_header = container.compression_header()?;
+
+            for result in container.slices() {
+                let slice = result?;
+                let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
+
+                let records = slice.records(
+                    fasta::Repository::default(),
+                    &header,
+                    &compression_header,
+                    &core_data_src,
+                    &external_data_srcs,
+                )?;
+
+                ids.extend(records.iter().map(|record| record.id()));
+            }
+
+            container_count += 1;
+        }
+
+        assert_eq!(container_count, 2);
+        assert!(ids.into_iter().eq(0..record_count as u64));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_features() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            RecordBuf,
+            io::Write,
+            record::cigar::{Op, op::Kind},
+        };
+
+        use crate::{
+            io::{Reader, reader::Container},
+            record::Feature,
+            test_utils,
+        };
+
+        let (repository, header) = test_utils::sq0_repository_and_header();
+
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        let record = RecordBuf::builder()
+            .set_flags(sam::alignment::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(
+                [
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Deletion, 1),
+                    Op::new(Kind::Match, 2),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(b"ACTA".to_vec().into())
+            .set_quality_scores(vec![20; 4].into())
+            .build();
+
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+        let src = writer.into_inner();
+
+        let mut reader = Reader::new(&src[..]);
+        let header = reader.read_header()?;
+
+        let mut container = Container::default();
+        reader.read_container(&mut container)?;
+        let compression_header = container.compression_header()?;
+
+        let slice = container
+            .slices()
+            .next()
+            .transpose()?
+            .expect("missing slice");
+        let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
+
+        let records = slice.records(
+            repository,
+            &header,
+            &compression_header,
+            &core_data_src,
+            &external_data_srcs,
+        )?;
+
+        assert_eq!(records.len(), 1);
+
+        let deletions: Vec<_> = records[0]
+            .features()
+            .iter()
+            .filter(|feature| matches!(feature, Feature::Deletion { .. }))
+            .collect();
+
+        assert_eq!(
+            deletions,
+            [&Feature::Deletion {
+                position: Position::try_from(3)?,
+                len: 1,
+            }]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_reference_sequence_resolved_by_md5()
+    -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            RecordBuf,
+            io::Write,
+            record::cigar::{Op, op::Kind},
+        };
+
+        use crate::{
+            io::{Reader, reader::Container},
+            test_utils::{self, SQ0_SEQUENCE},
+        };
+
+        let (repository, header) = test_utils::sq0_repository_and_header();
+
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        let record = RecordBuf::builder()
+            .set_flags(sam::alignment::record::Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(b"ACTT".to_vec().into())
+            .set_quality_scores(vec![20; 4].into())
+            .build();
+
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+        let src = writer.into_inner();
+
+        let mut reader = Reader::new(&src[..]);
+        let header = reader.read_header()?;
+
+        let mut container = Container::default();
+        reader.read_container(&mut container)?;
+        let compression_header = container.compression_header()?;
+
+        let slice = container
+            .slices()
+            .next()
+            .transpose()?
+            .expect("missing slice");
+        let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
+
+        // The sequence is only known as "chr0" and by its checksum.
+        let sequence = fasta::record::Sequence::from(SQ0_SEQUENCE.to_vec());
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("chr0", None),
+            sequence.clone(),
+        )]);
+
+        let records = slice.records(
+            repository,
+            &header,
+            &compression_header,
+            &core_data_src,
+            &external_data_srcs,
+        )?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(
+            records[0].reference_sequence,
+            Some(super::ReferenceSequence::External { sequence })
+        );
+        assert_eq!(records[0].sequence().iter().collect::<Vec<_>>(), b"ACTT");
+
+        Ok(())
+    }
 }
@@ -3,10 +3,10 @@ mod external_data_readers;
 
 pub use external_data_readers::ExternalDataReaders;
 
-use std::{borrow::Cow, error, fmt, io};
+use std::{borrow::Cow, collections::HashSet, error, fmt, io};
 
 use noodles_core::Position;
-use noodles_sam as sam;
+use noodles_sam::{self as sam, alignment::record::data::field::Tag};
 
 use crate::{
     Record,
@@ -14,7 +14,7 @@ use crate::{
         CompressionHeader, ReferenceSequenceContext, block,
         compression_header::{data_series_encodings::DataSeries, preservation_map::tag_sets},
     },
-    io::BitReader,
+    io::{BitReader, reader::MissingQualityPolicy},
     record::{Feature, Flags, MateFlags, feature},
 };
 
@@ -38,6 +38,14 @@ impl fmt::Display for ReadRecordError {
     }
 }
 
+/// Options that control how slice records are decoded.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Options<'a> {
+    pub missing_quality_policy: MissingQualityPolicy,
+    /// The data field tags to decode. If `None`, all data fields are decoded.
+    pub tag_filter: Option<&'a HashSet<Tag>>,
+}
+
 pub struct Records<'c, 'ch: 'c> {
     compression_header: &'ch CompressionHeader,
     core_data_reader: BitReader<'c>,
@@ -45,6 +53,7 @@ pub struct Records<'c, 'ch: 'c> {
     reference_sequence_context: ReferenceSequenceContext,
     id: u64,
     prev_alignment_start: Option<Position>,
+    options: Options<'c>,
 }
 
 impl<'c, 'ch: 'c> Records<'c, 'ch> {
@@ -54,6 +63,7 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
         external_data_readers: ExternalDataReaders<'c>,
         reference_sequence_context: ReferenceSequenceContext,
         initial_id: u64,
+        options: Options<'c>,
     ) -> Self {
         let initial_alignment_start = match reference_sequence_context {
             ReferenceSequenceContext::Some(context) => Some(context.alignment_start()),
@@ -67,6 +77,7 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
             reference_sequence_context,
             id: initial_id,
             prev_alignment_start: initial_alignment_start,
+            options,
         }
     }
 
@@ -338,7 +349,8 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
         for &key in tag_set {
             let id = block::ContentId::from(key);
 
-            let value = self
+            // The value is always decoded to advance the data readers, even if it is skipped.
+            let src = self
                 .compression_header
                 .tag_encodings()
                 .get(&id)
@@ -348,9 +360,15 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
                         ReadRecordError::MissingTagEncoding(key),
                     )
                 })?
-                .decode(&mut self.core_data_reader, &mut self.external_data_readers)
-                .and_then(|src| self::data::read_value(src, key.ty()))?;
+                .decode(&mut self.core_data_reader, &mut self.external_data_readers)?;
+
+            if let Some(tags) = self.options.tag_filter {
+                if !tags.contains(&key.tag()) {
+                    continue;
+                }
+            }
 
+            let value = self::data::read_value(src, key.ty())?;
             record.data.push((key.tag(), value));
         }
 
@@ -652,7 +670,9 @@ impl<'c, 'ch: 'c> Records<'c, 'ch> {
             read_length,
         )?;
 
-        if src.iter().all(|&n| n == MISSING) {
+        if self.options.missing_quality_policy == MissingQualityPolicy::Collapse
+            && src.iter().all(|&n| n == MISSING)
+        {
             Ok(&[])
         } else {
             Ok(src)
@@ -0,0 +1,212 @@
+use std::io::{self, Read};
+
+use super::{Container, Reader, container::block::read_block};
+use crate::container::{CompressionMethod, ReferenceSequenceContext};
+
+/// A summary of a CRAM container.
+///
+/// This is created from the container header and block headers. No block data is decoded.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContainerSummary {
+    reference_sequence_context: ReferenceSequenceContext,
+    record_count: usize,
+    base_count: u64,
+    slice_count: usize,
+    block_count: usize,
+    compressed_size: usize,
+    uncompressed_size: usize,
+    compression_methods: Vec<CompressionMethod>,
+}
+
+impl ContainerSummary {
+    /// Returns the reference sequence context.
+    pub fn reference_sequence_context(&self) -> ReferenceSequenceContext {
+        self.reference_sequence_context
+    }
+
+    /// Returns the number of records.
+    pub fn record_count(&self) -> usize {
+        self.record_count
+    }
+
+    /// Returns the number of bases.
+    pub fn base_count(&self) -> u64 {
+        self.base_count
+    }
+
+    /// Returns the number of slices.
+    pub fn slice_count(&self) -> usize {
+        self.slice_count
+    }
+
+    /// Returns the number of blocks, including the compression header block.
+    pub fn block_count(&self) -> usize {
+        self.block_count
+    }
+
+    /// Returns the total compressed size of the block data.
+    pub fn compressed_size(&self) -> usize {
+        self.compressed_size
+    }
+
+    /// Returns the total uncompressed size of the block data.
+    pub fn uncompressed_size(&self) -> usize {
+        self.uncompressed_size
+    }
+
+    /// Returns the compression methods used by the blocks.
+    ///
+    /// Each compression method is listed once, in the order of first use.
+    pub fn compression_methods(&self) -> &[CompressionMethod] {
+        &self.compression_methods
+    }
+}
+
+impl TryFrom<&Container> for ContainerSummary {
+    type Error = io::Error;
+
+    fn try_from(container: &Container) -> Result<Self, Self::Error> {
+        let header = container.header();
+
+        let mut block_count = 0;
+        let mut compressed_size = 0;
+        let mut uncompressed_size = 0;
+        let mut compression_methods = Vec::new();
+
+        let mut src = &container.src[..];
+
+        while !src.is_empty() {
+            let block = read_block(&mut src)?;
+
+            block_count += 1;
+            compressed_size += block.src.len();
+            uncompressed_size += block.uncompressed_size;
+
+            if !compression_methods.contains(&block.compression_method) {
+                compression_methods.push(block.compression_method);
+            }
+        }
+
+        Ok(Self {
+            reference_sequence_context: header.reference_sequence_context(),
+            record_count: header.record_count(),
+            base_count: header.base_count(),
+            slice_count: header.landmarks().len(),
+            block_count,
+            compressed_size,
+            uncompressed_size,
+            compression_methods,
+        })
+    }
+}
+
+/// An iterator over container summaries of a CRAM reader.
+///
+/// This is created by calling [`Reader::container_summaries`].
+pub struct ContainerSummaries<'r, R> {
+    reader: &'r mut Reader<R>,
+    container: Container,
+}
+
+impl<'r, R> ContainerSummaries<'r, R>
+where
+    R: Read,
+{
+    pub(super) fn new(reader: &'r mut Reader<R>) -> Self {
+        Self {
+            reader,
+            container: Container::default(),
+        }
+    }
+}
+
+impl<R> Iterator for ContainerSummaries<'_, R>
+where
+    R: Read,
+{
+    type Item = io::Result<ContainerSummary>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.read_container(&mut self.container) {
+            Ok(0) => None,
+            Ok(_) => Some(ContainerSummary::try_from(&self.container)),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::alignment::{
+        RecordBuf,
+        io::Write,
+        record::{
+            Flags,
+            cigar::{Op, op::Kind},
+        },
+    };
+
+    use super::*;
+    use crate::{io::writer, test_utils};
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let (repository, header) = test_utils::sq0_repository_and_header();
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for i in 0..3 {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}"))
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(i + 1)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![20; 4].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let src = writer.into_inner();
+
+        let expected_block_count = {
+            let mut reader = Reader::new(&src[..]);
+            reader.read_header()?;
+
+            let mut container = Container::default();
+            reader.read_container(&mut container)?;
+
+            container.header().block_count()
+        };
+
+        let mut reader = Reader::new(&src[..]);
+        reader.read_header()?;
+
+        let summaries: Vec<_> = reader.container_summaries().collect::<io::Result<_>>()?;
+        assert_eq!(summaries.len(), 1);
+
+        let summary = &summaries[0];
+
+        assert_eq!(
+            summary.reference_sequence_context(),
+            ReferenceSequenceContext::some(0, Position::MIN, Position::try_from(6)?)
+        );
+        assert_eq!(summary.record_count(), 3);
+        assert_eq!(summary.base_count(), 12);
+        assert_eq!(summary.slice_count(), 1);
+        assert_eq!(summary.block_count(), expected_block_count);
+        assert!(summary.compressed_size() > 0);
+        assert!(summary.uncompressed_size() > 0);
+        assert!(!summary.compression_methods().is_empty());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+/// A policy for handling missing quality scores.
+///
+/// In CRAM, missing quality scores are stored as a list of `0xff` values, one per base.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MissingQualityPolicy {
+    /// Quality scores that are all `0xff` are read as empty, i.e., no quality scores.
+    #[default]
+    Collapse,
+    /// Quality scores that are all `0xff` are kept as is.
+    ///
+    /// This allows distinguishing records with no quality scores (empty) from records with
+    /// missing quality scores (all `0xff`).
+    Preserve,
+}
@@ -3,7 +3,7 @@ use std::{
     slice, vec,
 };
 
-use noodles_core::region::Interval;
+use noodles_core::region::{Interval, QueryMode};
 use noodles_sam as sam;
 
 use super::{Container, Reader};
@@ -11,7 +11,7 @@ use crate::crai;
 
 /// An iterator over records that intersect a given region.
 ///
-/// This is created by calling [`Reader::query`].
+/// This is created by calling [`Reader::query`] or [`Reader::query_with_mode`].
 pub struct Query<'r, 'h: 'r, 'i: 'r, R>
 where
     R: Read + Seek,
@@ -24,6 +24,7 @@ where
 
     reference_sequence_id: usize,
     interval: Interval,
+    query_mode: QueryMode,
 
     records: vec::IntoIter<sam::alignment::RecordBuf>,
 }
@@ -38,6 +39,7 @@ where
         index: &'i crai::Index,
         reference_sequence_id: usize,
         interval: Interval,
+        query_mode: QueryMode,
     ) -> Self {
         Self {
             reader,
@@ -48,6 +50,7 @@ where
 
             reference_sequence_id,
             interval,
+            query_mode,
 
             records: Vec::new().into_iter(),
         }
@@ -85,12 +88,13 @@ where
                 let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
 
                 slice
-                    .records(
+                    .records_with_options(
                         self.reader.reference_sequence_repository.clone(),
                         self.header,
                         &compression_header,
                         &core_data_src,
                         &external_data_srcs,
+                        self.reader.records_options(),
                     )
                     .and_then(|records| {
                         records
@@ -131,7 +135,7 @@ where
         loop {
             match self.records.next() {
                 Some(record) => {
-                    if intersects(&record, self.interval) {
+                    if matches(&record, self.interval, self.query_mode) {
                         return Some(Ok(record));
                     }
                 }
@@ -145,12 +149,99 @@ where
     }
 }
 
-fn intersects(record: &sam::alignment::RecordBuf, region_interval: Interval) -> bool {
+fn matches(
+    record: &sam::alignment::RecordBuf,
+    region_interval: Interval,
+    query_mode: QueryMode,
+) -> bool {
     match (record.alignment_start(), record.alignment_end()) {
         (Some(start), Some(end)) => {
             let alignment_interval = (start..=end).into();
-            region_interval.intersects(alignment_interval)
+            query_mode.matches(region_interval, alignment_interval)
         }
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use noodles_core::Position;
+    use noodles_fasta as fasta;
+    use noodles_sam::{
+        alignment::{
+            RecordBuf,
+            io::Write,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+            },
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+    use crate::io::{reader::Builder, writer};
+
+    #[test]
+    fn test_next_with_contained_query_mode() -> Result<(), Box<dyn std::error::Error>> {
+        const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(16) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGTACGTACGT".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_index(true)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for (i, start) in [1, 8].into_iter().enumerate() {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}"))
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![20; 4].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let index = writer.take_index().ok_or("missing index")?;
+        let src = writer.into_inner();
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(Cursor::new(src));
+
+        reader.read_header()?;
+
+        let region = "sq0:1-10".parse()?;
+
+        let query = reader.query_with_mode(&header, &index, &region, QueryMode::Overlap)?;
+        assert_eq!(query.count(), 2);
+
+        let query = reader.query_with_mode(&header, &index, &region, QueryMode::Contained)?;
+        let names = query
+            .map(|result| result.map(|record| record.name().map(|name| name.to_vec())))
+            .collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(names, [Some(b"r0".to_vec())]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,207 @@
+use std::{
+    io::{self, Read},
+    vec,
+};
+
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::{Container, Reader};
+use crate::container::ReferenceSequenceContext;
+
+/// An iterator over unplaced, unmapped records.
+///
+/// This is created by calling [`Reader::query_unmapped`].
+///
+/// Containers and slices with a single reference sequence context only hold placed records and are
+/// skipped without being decoded.
+pub struct QueryUnmapped<'r, 'h: 'r, R>
+where
+    R: Read,
+{
+    reader: &'r mut Reader<R>,
+    header: &'h sam::Header,
+    records: vec::IntoIter<sam::alignment::RecordBuf>,
+}
+
+impl<'r, 'h: 'r, R> QueryUnmapped<'r, 'h, R>
+where
+    R: Read,
+{
+    pub(super) fn new(reader: &'r mut Reader<R>, header: &'h sam::Header) -> Self {
+        Self {
+            reader,
+            header,
+            records: Vec::new().into_iter(),
+        }
+    }
+
+    fn read_next_container(&mut self) -> Option<io::Result<()>> {
+        let mut container = Container::default();
+
+        match self.reader.read_container(&mut container) {
+            Ok(0) => return None,
+            Ok(_) => {}
+            Err(e) => return Some(Err(e)),
+        }
+
+        if is_placed(container.header().reference_sequence_context()) {
+            self.records = Vec::new().into_iter();
+            return Some(Ok(()));
+        }
+
+        let compression_header = match container.compression_header() {
+            Ok(compression_header) => compression_header,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let mut records = Vec::new();
+
+        for result in container.slices() {
+            let slice = match result {
+                Ok(slice) => slice,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if is_placed(slice.header().reference_sequence_context()) {
+                continue;
+            }
+
+            let (core_data_src, external_data_srcs) = match slice.decode_blocks() {
+                Ok(srcs) => srcs,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let slice_records = match slice.records_with_options(
+                self.reader.reference_sequence_repository.clone(),
+                self.header,
+                &compression_header,
+                &core_data_src,
+                &external_data_srcs,
+                self.reader.records_options(),
+            ) {
+                Ok(slice_records) => slice_records,
+                Err(e) => return Some(Err(e)),
+            };
+
+            for record in slice_records {
+                match record.reference_sequence_id(self.header).transpose() {
+                    Ok(Some(_)) => {}
+                    Ok(None) => {
+                        match sam::alignment::RecordBuf::try_from_alignment_record(
+                            self.header,
+                            &record,
+                        ) {
+                            Ok(record) => records.push(record),
+                            Err(e) => return Some(Err(e)),
+                        }
+                    }
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+        }
+
+        self.records = records.into_iter();
+
+        Some(Ok(()))
+    }
+}
+
+impl<R> Iterator for QueryUnmapped<'_, '_, R>
+where
+    R: Read,
+{
+    type Item = io::Result<sam::alignment::RecordBuf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.records.next() {
+                Some(record) => return Some(Ok(record)),
+                None => match self.read_next_container() {
+                    Some(Ok(())) => {}
+                    Some(Err(e)) => return Some(Err(e)),
+                    None => return None,
+                },
+            }
+        }
+    }
+}
+
+fn is_placed(reference_sequence_context: ReferenceSequenceContext) -> bool {
+    matches!(
+        reference_sequence_context,
+        ReferenceSequenceContext::Some(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_sam::alignment::{
+        RecordBuf,
+        io::Write,
+        record::{
+            Flags,
+            cigar::{Op, op::Kind},
+        },
+    };
+
+    use super::*;
+    use crate::{
+        io::{reader::Builder, writer},
+        test_utils,
+    };
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let (repository, header) = test_utils::sq0_repository_and_header();
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for i in 0..3 {
+            let record = RecordBuf::builder()
+                .set_name(format!("m{i}"))
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(i + 1)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![20; 4].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        for i in 0..2 {
+            let record = RecordBuf::builder()
+                .set_name(format!("u{i}"))
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![20; 4].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let src = writer.into_inner();
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(&src[..]);
+
+        let header = reader.read_header()?;
+
+        let names: Vec<_> = reader
+            .query_unmapped(&header)
+            .map(|result| result.map(|record| record.name().map(|name| name.to_vec())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(names, [Some(b"u0".to_vec()), Some(b"u1".to_vec())]);
+
+        Ok(())
+    }
+}
@@ -1,15 +1,28 @@
+#[cfg(feature = "parallel")]
+mod pool;
+
 use std::{
     io::{self, Read},
     vec,
 };
 
+use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use super::{Container, Reader};
+#[cfg(feature = "parallel")]
+use self::pool::Pool;
+use super::{Container, Reader, container::slice::records::Options};
 
 /// An iterator over records of a CRAM reader.
 ///
 /// This is created by calling [`Reader::records`].
+///
+/// With the `parallel` feature, if the reader was built with a worker count greater than 1,
+/// containers are decoded by a pool of worker threads while the next containers are read on the
+/// calling thread. Records are always yielded in their original order.
+///
+/// After an error reading a container, the iterator yields no more records, with or without
+/// workers. Errors decoding a container do not stop iteration.
 pub struct Records<'r, 'h: 'r, R>
 where
     R: Read,
@@ -18,6 +31,11 @@ where
     header: &'h sam::Header,
     container: Container,
     records: vec::IntoIter<sam::alignment::RecordBuf>,
+    #[cfg(feature = "parallel")]
+    pool: Option<Pool>,
+    #[cfg(feature = "parallel")]
+    read_error: Option<io::Error>,
+    is_eof: bool,
 }
 
 impl<'r, 'h: 'r, R> Records<'r, 'h, R>
@@ -30,52 +48,86 @@ where
             header,
             container: Container::default(),
             records: Vec::new().into_iter(),
+            #[cfg(feature = "parallel")]
+            pool: None,
+            #[cfg(feature = "parallel")]
+            read_error: None,
+            is_eof: false,
         }
     }
 
     fn read_container_records(&mut self) -> io::Result<bool> {
-        if self.reader.read_container(&mut self.container)? == 0 {
+        #[cfg(feature = "parallel")]
+        if self.reader.worker_count.get() > 1 {
+            return self.read_container_records_with_pool();
+        }
+
+        if self.is_eof {
             return Ok(true);
         }
 
-        let compression_header = self.container.compression_header()?;
+        // The stream position is unknown after a read error, so the iterator is fused.
+        match self.reader.read_container(&mut self.container) {
+            Ok(0) => {
+                self.is_eof = true;
+                return Ok(true);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                self.is_eof = true;
+                return Err(e);
+            }
+        }
 
-        self.records = self
-            .container
-            .slices()
-            .map(|result| {
-                let slice = result?;
-
-                let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
-
-                slice
-                    .records(
-                        self.reader.reference_sequence_repository.clone(),
-                        self.header,
-                        &compression_header,
-                        &core_data_src,
-                        &external_data_srcs,
-                    )
-                    .and_then(|records| {
-                        records
-                            .into_iter()
-                            .map(|record| {
-                                sam::alignment::RecordBuf::try_from_alignment_record(
-                                    self.header,
-                                    &record,
-                                )
-                            })
-                            .collect::<io::Result<Vec<_>>>()
-                    })
-            })
-            .collect::<Result<Vec<_>, _>>()?
-            .into_iter()
-            .flatten()
-            .collect::<Vec<_>>()
-            .into_iter();
+        self.records = decode_container(
+            &self.reader.reference_sequence_repository,
+            self.header,
+            &self.container,
+            self.reader.records_options(),
+        )?
+        .into_iter();
 
         Ok(false)
     }
+
+    #[cfg(feature = "parallel")]
+    fn read_container_records_with_pool(&mut self) -> io::Result<bool> {
+        let pool = self.pool.get_or_insert_with(|| {
+            Pool::new(
+                self.reader.worker_count,
+                self.reader.reference_sequence_repository.clone(),
+                self.header.clone(),
+                self.reader.missing_quality_policy,
+                self.reader.tag_filter.clone(),
+            )
+        });
+
+        // Containers are read while the workers decode the preceding ones.
+        while !self.is_eof && !pool.is_full() {
+            let mut container = Container::default();
+
+            match self.reader.read_container(&mut container) {
+                Ok(0) => self.is_eof = true,
+                Ok(_) => pool.send(container)?,
+                Err(e) => {
+                    // Records of the containers already sent are yielded before the error.
+                    self.read_error = Some(e);
+                    self.is_eof = true;
+                }
+            }
+        }
+
+        match pool.recv() {
+            Some(result) => {
+                self.records = result?.into_iter();
+                Ok(false)
+            }
+            None => match self.read_error.take() {
+                Some(e) => Err(e),
+                None => Ok(true),
+            },
+        }
+    }
 }
 
 impl<R> Iterator for Records<'_, '_, R>
@@ -97,3 +149,319 @@ where
         }
     }
 }
+
+fn decode_container(
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+    container: &Container,
+    options: Options<'_>,
+) -> io::Result<Vec<sam::alignment::RecordBuf>> {
+    let compression_header = container.compression_header()?;
+
+    container
+        .slices()
+        .map(|result| {
+            let slice = result?;
+
+            let (core_data_src, external_data_srcs) = slice.decode_blocks()?;
+
+            slice
+                .records_with_options(
+                    reference_sequence_repository.clone(),
+                    header,
+                    &compression_header,
+                    &core_data_src,
+                    &external_data_srcs,
+                    options,
+                )
+                .and_then(|records| {
+                    records
+                        .into_iter()
+                        .map(|record| {
+                            sam::alignment::RecordBuf::try_from_alignment_record(header, &record)
+                        })
+                        .collect::<io::Result<Vec<_>>>()
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|records| records.into_iter().flatten().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use noodles_sam::{
+        alignment::{
+            RecordBuf,
+            io::Write,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+                data::field::Tag,
+            },
+            record_buf::data::field::Value,
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+    use crate::{
+        io::reader::{Builder, MissingQualityPolicy},
+        test_utils,
+    };
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_next_with_multiple_workers() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::io::writer::RECORDS_PER_CONTAINER;
+
+        const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(100) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(vec![b'A'; 100]),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        let record_count = 2 * RECORDS_PER_CONTAINER + 5;
+
+        for i in 0..record_count {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}"))
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(i % 4 + 1)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![20; 4].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let src = writer.into_inner();
+
+        let read_records = |src: &[u8], worker_count| -> io::Result<Vec<_>> {
+            let mut reader = Builder::default()
+                .set_reference_sequence_repository(repository.clone())
+                .set_worker_count(worker_count)
+                .build_from_reader(src);
+
+            let header = reader.read_header()?;
+            Ok(reader.records(&header).collect())
+        };
+
+        let expected: Vec<_> = read_records(&src, NonZeroUsize::MIN)?
+            .into_iter()
+            .collect::<io::Result<_>>()?;
+        assert_eq!(expected.len(), record_count);
+
+        for worker_count in [2, 4] {
+            let worker_count = NonZeroUsize::try_from(worker_count)?;
+            let actual: Vec<_> = read_records(&src, worker_count)?
+                .into_iter()
+                .collect::<io::Result<_>>()?;
+            assert_eq!(actual, expected);
+        }
+
+        // The last data container is truncated. The records of the preceding containers are
+        // yielded before the read error.
+        let src = &src[..src.len() - 48];
+
+        for worker_count in [1, 4] {
+            let worker_count = NonZeroUsize::try_from(worker_count)?;
+            let mut results = read_records(src, worker_count)?;
+
+            assert!(matches!(
+                results.pop(),
+                Some(Err(e)) if e.kind() == io::ErrorKind::UnexpectedEof
+            ));
+
+            let actual: Vec<_> = results.into_iter().collect::<io::Result<_>>()?;
+            assert_eq!(actual, expected[..2 * RECORDS_PER_CONTAINER]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_substitutions() -> Result<(), Box<dyn std::error::Error>> {
+        const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(16) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGTacgtNCGT".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        // Each read mismatches the reference at every position, covering all substitution codes.
+        let sequences: [&[u8]; 4] = [b"CGTACGTA", b"GTACGTAC", b"TACGTACG", b"NNNNNNNN"];
+
+        let expected: Vec<_> = sequences
+            .iter()
+            .enumerate()
+            .map(|(i, sequence)| {
+                Ok(RecordBuf::builder()
+                    .set_name(format!("r{i}"))
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(2 * i + 1)?)
+                    .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+                    .set_sequence(sequence.to_vec().into())
+                    .set_quality_scores(vec![20; 8].into())
+                    .build())
+            })
+            .collect::<Result<_, Box<dyn std::error::Error>>>()?;
+
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for record in &expected {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let src = writer.into_inner();
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(&src[..]);
+
+        let header = reader.read_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&header)
+            .map(|result| {
+                result.and_then(|record| RecordBuf::try_from_alignment_record(&header, &record))
+            })
+            .collect::<io::Result<_>>()?;
+
+        let actual_sequences: Vec<_> = actual.iter().map(|record| record.sequence()).collect();
+        let expected_sequences: Vec<_> = expected.iter().map(|record| record.sequence()).collect();
+        assert_eq!(actual_sequences, expected_sequences);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_missing_quality_policy() -> Result<(), Box<dyn std::error::Error>> {
+        let (repository, header) = test_utils::sq0_repository_and_header();
+
+        let mut writer = crate::io::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        // Missing quality scores are written as `0xff` for each base.
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::UNMAPPED)
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_quality_scores(vec![0xff; 4].into())
+            .build();
+
+        writer.write_alignment_record(&header, &record)?;
+
+        writer.try_finish(&header)?;
+        let src = writer.into_inner();
+
+        let read_quality_scores = |missing_quality_policy| -> io::Result<Vec<u8>> {
+            let mut reader = Builder::default()
+                .set_reference_sequence_repository(repository.clone())
+                .set_missing_quality_policy(missing_quality_policy)
+                .build_from_reader(&src[..]);
+
+            let header = reader.read_header()?;
+
+            let record = reader
+                .records(&header)
+                .next()
+                .transpose()?
+                .ok_or_else(|| io::Error::from(io::ErrorKind::UnexpectedEof))?;
+
+            Ok(record.quality_scores().as_ref().to_vec())
+        };
+
+        assert!(read_quality_scores(MissingQualityPolicy::Collapse)?.is_empty());
+        assert_eq!(
+            read_quality_scores(MissingQualityPolicy::Preserve)?,
+            [0xff; 4]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_tag_filter() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::default();
+
+        let mut writer = crate::io::writer::Builder::default().build_from_writer(Vec::new());
+        writer.write_header(&header)?;
+
+        for (i, sequence) in [&b"ACGT"[..], b"TTGCA"].into_iter().enumerate() {
+            let record = RecordBuf::builder()
+                .set_name(format!("r{i}"))
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence(sequence.to_vec().into())
+                .set_quality_scores(vec![30; sequence.len()].into())
+                .set_data(
+                    [
+                        (Tag::EDIT_DISTANCE, Value::from(i as u8)),
+                        (Tag::COMMENT, Value::from(format!("noodles{i}"))),
+                        (Tag::ALIGNMENT_SCORE, Value::from(8 + i as u8)),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+        let src = writer.into_inner();
+
+        let mut reader = Builder::default()
+            .set_tag_filter([Tag::ALIGNMENT_SCORE])
+            .build_from_reader(&src[..]);
+
+        let header = reader.read_header()?;
+
+        let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 2);
+
+        for (i, (record, sequence)) in records.iter().zip([&b"ACGT"[..], b"TTGCA"]).enumerate() {
+            let data: Vec<_> = record.data().iter().collect();
+            assert_eq!(data, [(Tag::ALIGNMENT_SCORE, &Value::from(8 + i as u8))]);
+            assert_eq!(record.sequence().as_ref(), sequence);
+            assert_eq!(record.quality_scores().as_ref(), vec![30; sequence.len()]);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    io,
+    num::NonZeroUsize,
+    sync::Arc,
+    thread::{self, JoinHandle},
+};
+
+use crossbeam_channel::{Receiver, Sender};
+use noodles_fasta as fasta;
+use noodles_sam::{self as sam, alignment::record::data::field::Tag};
+
+use super::decode_container;
+use crate::io::reader::{Container, MissingQualityPolicy, container::slice::records::Options};
+
+type DecodedTx = Sender<io::Result<Vec<sam::alignment::RecordBuf>>>;
+type DecodedRx = Receiver<io::Result<Vec<sam::alignment::RecordBuf>>>;
+type DecodeTx = Sender<(Container, DecodedTx)>;
+type DecodeRx = Receiver<(Container, DecodedTx)>;
+
+struct Context {
+    reference_sequence_repository: fasta::Repository,
+    header: sam::Header,
+    missing_quality_policy: MissingQualityPolicy,
+    tag_filter: Option<HashSet<Tag>>,
+}
+
+impl Context {
+    fn options(&self) -> Options<'_> {
+        Options {
+            missing_quality_policy: self.missing_quality_policy,
+            tag_filter: self.tag_filter.as_ref(),
+        }
+    }
+}
+
+/// A pool of container decoders.
+///
+/// Containers are decoded in the order they are sent, and results are received in the same order.
+pub(super) struct Pool {
+    decode_tx: Option<DecodeTx>,
+    handles: Vec<JoinHandle<()>>,
+    queue: VecDeque<DecodedRx>,
+    capacity: usize,
+}
+
+impl Pool {
+    pub fn new(
+        worker_count: NonZeroUsize,
+        reference_sequence_repository: fasta::Repository,
+        header: sam::Header,
+        missing_quality_policy: MissingQualityPolicy,
+        tag_filter: Option<HashSet<Tag>>,
+    ) -> Self {
+        let context = Arc::new(Context {
+            reference_sequence_repository,
+            header,
+            missing_quality_policy,
+            tag_filter,
+        });
+
+        let (decode_tx, decode_rx) = crossbeam_channel::bounded(worker_count.get());
+        let handles = spawn_decoders(worker_count, decode_rx, context);
+
+        Self {
+            decode_tx: Some(decode_tx),
+            handles,
+            queue: VecDeque::new(),
+            capacity: worker_count.get(),
+        }
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.queue.len() >= self.capacity
+    }
+
+    pub fn send(&mut self, container: Container) -> io::Result<()> {
+        let (decoded_tx, decoded_rx) = crossbeam_channel::bounded(1);
+
+        self.decode_tx
+            .as_ref()
+            .ok_or_else(|| io::Error::other("container decoder pool is closed"))?
+            .send((container, decoded_tx))
+            .map_err(|_| io::Error::other("container decoders stopped"))?;
+
+        self.queue.push_back(decoded_rx);
+
+        Ok(())
+    }
+
+    pub fn recv(&mut self) -> Option<io::Result<Vec<sam::alignment::RecordBuf>>> {
+        let decoded_rx = self.queue.pop_front()?;
+
+        // The sender is dropped without a result only if the decoder panicked.
+        let result = decoded_rx
+            .recv()
+            .map_err(|_| io::Error::other("container decoder panicked"))
+            .and_then(|result| result);
+
+        Some(result)
+    }
+}
+
+impl Drop for Pool {
+    fn drop(&mut self) {
+        self.decode_tx.take();
+        self.queue.clear();
+
+        for handle in self.handles.drain(..) {
+            // A decoder panic was already returned as an error by `Self::recv`.
+            let _ = handle.join();
+        }
+    }
+}
+
+fn spawn_decoders(
+    worker_count: NonZeroUsize,
+    decode_rx: DecodeRx,
+    context: Arc<Context>,
+) -> Vec<JoinHandle<()>> {
+    (0..worker_count.get())
+        .map(|_| {
+            let decode_rx = decode_rx.clone();
+            let context = context.clone();
+
+            thread::spawn(move || {
+                while let Ok((container, decoded_tx)) = decode_rx.recv() {
+                    let result = decode_container(
+                        &context.reference_sequence_repository,
+                        &context.header,
+                        &container,
+                        context.options(),
+                    );
+
+                    // The receiver is dropped if the records iterator is dropped first.
+                    decoded_tx.send(result).ok();
+                }
+            })
+        })
+        .collect()
+}
@@ -15,11 +15,14 @@ use noodles_sam::{self as sam, alignment::io::Write as _};
 
 pub use self::builder::Builder;
 use self::{
-    container::write_container,
+    container::{push_index_records, write_container},
     header::{write_file_definition, write_file_header, write_header},
 };
-pub(crate) use self::{options::Options, record::Record};
-use crate::FileDefinition;
+pub(crate) use self::{
+    options::{Options, TagFilter},
+    record::Record,
+};
+use crate::{FileDefinition, crai};
 
 const DEFAULT_SLICES_PER_CONTAINER: usize = 1;
 const DEFAULT_RECORDS_PER_SLICE: usize = 10240;
@@ -55,6 +58,8 @@ pub struct Writer<W> {
     options: Options,
     records: Vec<Record>,
     record_counter: u64,
+    position: u64,
+    indexer: Option<crai::Indexer>,
 }
 
 impl<W> Writer<W> {
@@ -99,6 +104,35 @@ impl<W> Writer<W> {
     pub fn into_inner(self) -> W {
         self.inner
     }
+
+    /// Takes the CRAM index built while writing.
+    ///
+    /// This returns `None` if indexing was not enabled (see [`Builder::build_index`]) or the
+    /// index was already taken. It should only be called after [`Self::try_finish`], as records
+    /// are indexed when their container is written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = cram::io::writer::Builder::default()
+    ///     .build_index(true)
+    ///     .build_from_writer(io::sink());
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header)?;
+    /// writer.try_finish(&header)?;
+    ///
+    /// let index = writer.take_index();
+    /// assert_eq!(index, Some(Vec::new()));
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn take_index(&mut self) -> Option<crai::Index> {
+        self.indexer.take().map(|indexer| indexer.build())
+    }
 }
 
 impl<W> Writer<W>
@@ -157,7 +191,10 @@ where
     /// ```
     pub fn write_file_definition(&mut self) -> io::Result<()> {
         let file_definition = FileDefinition::new(self.options.version, Default::default());
-        write_file_definition(&mut self.inner, &file_definition)
+
+        let mut buf = Vec::new();
+        write_file_definition(&mut buf, &file_definition)?;
+        self.write_buf(&buf)
     }
 
     /// Writes a CRAM file header container.
@@ -184,7 +221,9 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_file_header(&mut self, header: &sam::Header) -> io::Result<()> {
-        write_file_header(&mut self.inner, &self.reference_sequence_repository, header)
+        let mut buf = Vec::new();
+        write_file_header(&mut buf, &self.reference_sequence_repository, header)?;
+        self.write_buf(&buf)
     }
 
     /// Writes a SAM header.
@@ -206,12 +245,16 @@ where
     pub fn write_header(&mut self, header: &sam::Header) -> io::Result<()> {
         let file_definition = FileDefinition::new(self.options.version, Default::default());
 
+        let mut buf = Vec::new();
+
         write_header(
-            &mut self.inner,
+            &mut buf,
             &self.reference_sequence_repository,
             &file_definition,
             header,
-        )
+        )?;
+
+        self.write_buf(&buf)
     }
 
     /// Writes a CRAM record.
@@ -253,8 +296,10 @@ where
     }
 
     fn flush(&mut self, header: &sam::Header) -> io::Result<()> {
-        write_container(
-            &mut self.inner,
+        let mut buf = Vec::new();
+
+        let container = write_container(
+            &mut buf,
             &self.reference_sequence_repository,
             &self.options,
             header,
@@ -262,6 +307,20 @@ where
             &mut self.records,
         )?;
 
+        if let (Some(indexer), Some((container_header, container_size))) =
+            (self.indexer.as_mut(), container)
+        {
+            push_index_records(
+                indexer,
+                self.position,
+                &container_header,
+                container_size,
+                &self.records,
+            )?;
+        }
+
+        self.write_buf(&buf)?;
+
         let record_count = u64::try_from(self.records.len())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
         self.record_counter += record_count;
@@ -270,6 +329,16 @@ where
 
         Ok(())
     }
+
+    fn write_buf(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.inner.write_all(buf)?;
+
+        let len =
+            u64::try_from(buf.len()).map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        self.position += len;
+
+        Ok(())
+    }
 }
 
 impl<W> sam::alignment::io::Write for Writer<W>
@@ -285,7 +354,7 @@ where
         header: &sam::Header,
         record: &dyn sam::alignment::Record,
     ) -> io::Result<()> {
-        let record = Record::try_from_alignment_record(header, record)?;
+        let record = Record::try_from_alignment_record(header, record, &self.options)?;
         self.add_record(header, record)
     }
 
@@ -293,3 +362,374 @@ where
         self.try_finish(header)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::{
+            RecordBuf,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+            },
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+    use crate::{io::reader::Container, test_utils};
+
+    #[test]
+    fn test_take_index() -> Result<(), Box<dyn std::error::Error>> {
+        let (repository, header) = test_utils::sq0_repository_and_header();
+
+        let mut writer = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_index(true)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for start in [2, 3] {
+            let record = RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![45; 4].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let index = writer.take_index().ok_or("missing index")?;
+        assert!(writer.take_index().is_none());
+
+        let mut reader = crate::io::Reader::new(io::Cursor::new(writer.into_inner()));
+        reader.read_header()?;
+        let container_position = reader.position()?;
+
+        let mut container = Container::default();
+        let container_len = reader.read_container(&mut container)?;
+        let landmark = container.header().landmarks()[0];
+
+        let expected = [crai::Record::new(
+            Some(0),
+            Position::new(2),
+            5,
+            container_position,
+            landmark as u64,
+            (container_len - landmark) as u64,
+        )];
+
+        assert_eq!(index, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_without_read_names() -> Result<(), Box<dyn std::error::Error>> {
+        let (repository, header) = test_utils::sq0_repository_and_header();
+
+        let build_record = |name: &str,
+                            flags,
+                            start,
+                            mate_start|
+         -> Result<RecordBuf, Box<dyn std::error::Error>> {
+            let mut builder = RecordBuf::builder()
+                .set_name(name)
+                .set_flags(flags)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(b"ACGT".to_vec().into())
+                .set_quality_scores(vec![45; 4].into());
+
+            if let Some(mate_start) = mate_start {
+                builder = builder
+                    .set_mate_reference_sequence_id(0)
+                    .set_mate_alignment_start(Position::try_from(mate_start)?);
+            }
+
+            Ok(builder.build())
+        };
+
+        let records = [
+            build_record(
+                "r0",
+                Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::FIRST_SEGMENT,
+                1,
+                Some(5),
+            )?,
+            build_record("s0", Flags::empty(), 3, None)?,
+            build_record(
+                "r0",
+                Flags::SEGMENTED | Flags::PROPERLY_SEGMENTED | Flags::LAST_SEGMENT,
+                5,
+                Some(1),
+            )?,
+        ];
+
+        let mut writer = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .preserve_read_names(false)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = crate::io::reader::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(io::Cursor::new(writer.get_ref()));
+
+        let header = reader.read_header()?;
+        let container_position = reader.position()?;
+
+        let mut container = Container::default();
+        reader.read_container(&mut container)?;
+        let compression_header = container.compression_header()?;
+        assert!(!compression_header.preservation_map().records_have_names());
+
+        reader.seek(io::SeekFrom::Start(container_position))?;
+
+        let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(actual.len(), 3);
+
+        // Mates are attached, so their names are dropped and regenerated on read.
+        let name = actual[0].name().ok_or("missing name")?;
+        assert_ne!(name, b"r0".as_slice());
+        assert_eq!(actual[2].name(), Some(name));
+        assert_eq!(actual[0].mate_alignment_start(), Position::new(5));
+        assert_eq!(actual[2].mate_alignment_start(), Position::new(1));
+
+        // Detached records keep their names.
+        assert_eq!(actual[1].name(), Some(b"s0".as_ref().into()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_substitutions() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::container::compression_header::preservation_map::{
+            SubstitutionMatrix, substitution_matrix::Base,
+        };
+
+        let (repository, header) = test_utils::sq0_repository_and_header();
+
+        // A>G, A>T; A>G, C>T
+        let sequences = [b"GCGTTCGT", b"GTGTACGT"];
+
+        let mut writer = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for sequence in sequences {
+            let record = RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 8)].into_iter().collect())
+                .set_sequence(sequence.to_vec().into())
+                .set_quality_scores(vec![45; 8].into())
+                .build();
+
+            writer.write_alignment_record(&header, &record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = crate::io::reader::Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(io::Cursor::new(writer.get_ref()));
+
+        let header = reader.read_header()?;
+        let container_position = reader.position()?;
+
+        let mut container = Container::default();
+        reader.read_container(&mut container)?;
+        let compression_header = container.compression_header()?;
+
+        let expected = SubstitutionMatrix([
+            [Base::G, Base::T, Base::C, Base::N], // A
+            [Base::T, Base::A, Base::G, Base::N], // C
+            [Base::A, Base::C, Base::T, Base::N], // G
+            [Base::A, Base::C, Base::G, Base::N], // T
+            [Base::A, Base::C, Base::G, Base::T], // N
+        ]);
+        assert_eq!(
+            compression_header.preservation_map().substitution_matrix(),
+            &expected
+        );
+
+        reader.seek(io::SeekFrom::Start(container_position))?;
+
+        let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(actual.len(), sequences.len());
+
+        for (record, sequence) in actual.iter().zip(sequences) {
+            assert_eq!(record.sequence().as_ref(), sequence);
+            assert_eq!(record.quality_scores().as_ref(), [45; 8]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_quality_scores_stored_as_array()
+    -> Result<(), Box<dyn std::error::Error>> {
+        const READ_LENGTH: usize = 100;
+        const RECORD_COUNT: usize = 1000;
+
+        let reference_sequence: Vec<u8> = b"ACGT".iter().copied().cycle().take(1024).collect();
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(reference_sequence.clone()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(reference_sequence.len())?),
+            )
+            .build();
+
+        // Quality scores that degrade along the read with some noise, as in short reads.
+        let mut state = 0x2545f491u32;
+        let mut records = Vec::with_capacity(RECORD_COUNT);
+
+        for i in 0..RECORD_COUNT {
+            let start = (i * 7) % (reference_sequence.len() - READ_LENGTH) + 1;
+            let sequence = reference_sequence[start - 1..start - 1 + READ_LENGTH].to_vec();
+
+            let quality_scores: Vec<_> = (0..READ_LENGTH)
+                .map(|j| {
+                    state ^= state << 13;
+                    state ^= state >> 17;
+                    state ^= state << 5;
+                    let noise = (state % 5) as u8;
+                    38 - (j * 20 / READ_LENGTH) as u8 - noise
+                })
+                .collect();
+
+            let record = RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(start)?)
+                .set_cigar([Op::new(Kind::Match, READ_LENGTH)].into_iter().collect())
+                .set_sequence(sequence.into())
+                .set_quality_scores(quality_scores.into())
+                .build();
+
+            records.push(record);
+        }
+
+        let write = |store_quality_scores_as_array| -> io::Result<Vec<u8>> {
+            let mut writer = Builder::default()
+                .set_reference_sequence_repository(repository.clone())
+                .store_quality_scores_as_array(store_quality_scores_as_array)
+                .build_from_writer(Vec::new());
+
+            writer.write_header(&header)?;
+
+            for record in &records {
+                writer.write_alignment_record(&header, record)?;
+            }
+
+            writer.try_finish(&header)?;
+
+            Ok(writer.into_inner())
+        };
+
+        let features_dst = write(false)?;
+        let array_dst = write(true)?;
+        assert!(array_dst.len() < features_dst.len());
+
+        for dst in [features_dst, array_dst] {
+            let mut reader = crate::io::reader::Builder::default()
+                .set_reference_sequence_repository(repository.clone())
+                .build_from_reader(&dst[..]);
+
+            let header = reader.read_header()?;
+
+            let actual: Vec<_> = reader
+                .records(&header)
+                .map(|result| {
+                    result.and_then(|record| RecordBuf::try_from_alignment_record(&header, &record))
+                })
+                .collect::<io::Result<_>>()?;
+
+            assert_eq!(actual.len(), records.len());
+
+            for (actual, expected) in actual.iter().zip(&records) {
+                assert_eq!(actual.sequence(), expected.sequence());
+                assert_eq!(actual.quality_scores(), expected.quality_scores());
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_tag_filter() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            record::data::field::Tag,
+            record_buf::{QualityScores, Sequence, data::field::Value},
+        };
+
+        let header = sam::Header::default();
+
+        let record = RecordBuf::builder()
+            .set_sequence(Sequence::from(b"ACGT"))
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .set_data(
+                [
+                    (Tag::EDIT_DISTANCE, Value::from(1)),
+                    (Tag::ORIGINAL_QUALITY_SCORES, Value::from("NDLS")),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut writer = Builder::default()
+            .set_tag_filter(|tag| tag != Tag::ORIGINAL_QUALITY_SCORES)
+            .build_from_writer(Vec::new());
+
+        writer.write_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let dst = writer.into_inner();
+        let mut reader = crate::io::Reader::new(&dst[..]);
+        reader.read_header()?;
+
+        let actual: Vec<_> = reader
+            .records(&header)
+            .map(|result| {
+                result.and_then(|record| RecordBuf::try_from_alignment_record(&header, &record))
+            })
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual.len(), 1);
+
+        let data = actual[0].data();
+        assert_eq!(data.get(&Tag::EDIT_DISTANCE), Some(&Value::from(1)));
+        assert!(data.get(&Tag::ORIGINAL_QUALITY_SCORES).is_none());
+
+        Ok(())
+    }
+}
@@ -5,15 +5,17 @@ use std::{
 };
 
 use noodles_fasta as fasta;
+use noodles_sam::alignment::record::data::field::Tag;
 
-use super::{Options, RECORDS_PER_CONTAINER, Writer};
-use crate::{codecs::Encoder, container::BlockContentEncoderMap, file_definition::Version};
+use super::{Options, RECORDS_PER_CONTAINER, TagFilter, Writer};
+use crate::{codecs::Encoder, container::BlockContentEncoderMap, crai, file_definition::Version};
 
 /// A CRAM writer builder.
 #[derive(Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
     options: Options,
+    build_index: bool,
 }
 
 impl Builder {
@@ -72,6 +74,53 @@ impl Builder {
         self
     }
 
+    /// Sets whether to store the quality scores of mapped records as an array.
+    ///
+    /// If `false`, the quality scores of mapped records are stored with their read features,
+    /// i.e., as stretches of quality scores (`q`) and single quality scores (`Q`). If `true`, they
+    /// are stored as a single array (`QS`), which avoids the overhead of the extra features and
+    /// typically compresses better. The quality scores of unmapped records are always stored as an
+    /// array.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().store_quality_scores_as_array(true);
+    /// ```
+    pub fn store_quality_scores_as_array(mut self, value: bool) -> Self {
+        self.options.store_quality_scores_as_array = value;
+        self
+    }
+
+    /// Sets a predicate that selects which data fields are written.
+    ///
+    /// Only data fields with tags for which the predicate returns `true` are written. Other data
+    /// fields are dropped before the records are encoded and do not appear in the tag sets. By
+    /// default, all data fields are written.
+    ///
+    /// This is typically used to drop large data fields that are not needed downstream, e.g.,
+    /// original quality scores (`OQ`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// use noodles_sam::alignment::record::data::field::Tag;
+    ///
+    /// let builder =
+    ///     Builder::default().set_tag_filter(|tag| tag != Tag::ORIGINAL_QUALITY_SCORES);
+    /// ```
+    pub fn set_tag_filter<F>(mut self, f: F) -> Self
+    where
+        F: Fn(Tag) -> bool + Send + Sync + 'static,
+    {
+        self.options.tag_filter = Some(TagFilter::new(f));
+        self
+    }
+
     /// Sets the block content-encoder map.
     ///
     /// # Examples
@@ -88,6 +137,24 @@ impl Builder {
         self
     }
 
+    /// Sets whether to build a CRAM index (CRAI) while writing.
+    ///
+    /// When enabled, an index record is added for each written slice, and the resulting index can
+    /// be retrieved using [`Writer::take_index`].
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().build_index(true);
+    /// ```
+    pub fn build_index(mut self, value: bool) -> Self {
+        self.build_index = value;
+        self
+    }
+
     /// Builds a CRAM writer from a path.
     ///
     /// # Examples
@@ -126,6 +193,8 @@ impl Builder {
             options: self.options,
             records: Vec::with_capacity(RECORDS_PER_CONTAINER),
             record_counter: 0,
+            position: 0,
+            indexer: self.build_index.then(crai::Indexer::default),
         }
     }
 }
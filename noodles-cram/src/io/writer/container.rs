@@ -2,12 +2,15 @@ mod block;
 pub(crate) mod compression_header;
 mod header;
 pub(crate) mod slice;
+mod substitutions;
 
 use std::{
     cmp,
+    collections::BTreeMap,
     io::{self, Write},
 };
 
+use noodles_core::Position;
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
@@ -18,10 +21,18 @@ pub use self::{
 use self::{
     compression_header::{build_compression_header, write_compression_header},
     slice::{Slice, build_slice},
+    substitutions::encode_substitutions,
 };
 use super::{DEFAULT_RECORDS_PER_SLICE, Options, Record};
-use crate::container::{Header, ReferenceSequenceContext, block::ContentType};
+use crate::{
+    container::{Header, ReferenceSequenceContext, block::ContentType},
+    crai,
+};
 
+/// Writes a data container.
+///
+/// On success, this returns the container header and size, i.e., the length of the container
+/// after the header. Nothing is written if there are no records.
 pub fn write_container<W>(
     writer: &mut W,
     reference_sequence_repository: &fasta::Repository,
@@ -29,12 +40,12 @@ pub fn write_container<W>(
     header: &sam::Header,
     record_counter: u64,
     records: &mut [Record],
-) -> io::Result<()>
+) -> io::Result<Option<(Header, usize)>>
 where
     W: Write,
 {
     if records.is_empty() {
-        return Ok(());
+        return Ok(None);
     }
 
     let (header, container_size, blocks) = build_container(
@@ -51,9 +62,80 @@ where
         write_block(writer, &block)?;
     }
 
+    Ok(Some((header, container_size)))
+}
+
+/// Adds CRAM index records for each slice in a written container.
+///
+/// Slices are expected to be built from consecutive chunks of `records`, as done by
+/// [`write_container`].
+pub fn push_index_records(
+    indexer: &mut crai::Indexer,
+    container_position: u64,
+    container_header: &Header,
+    container_size: usize,
+    records: &[Record],
+) -> io::Result<()> {
+    let landmarks = container_header.landmarks();
+
+    for (i, chunk) in records.chunks(DEFAULT_RECORDS_PER_SLICE).enumerate() {
+        let landmark = landmarks[i];
+        let slice_end = landmarks.get(i + 1).copied().unwrap_or(container_size);
+        let slice_length = slice_end - landmark;
+
+        push_slice_index_records(
+            indexer,
+            container_position,
+            landmark as u64,
+            slice_length as u64,
+            chunk,
+        );
+    }
+
     Ok(())
 }
 
+fn push_slice_index_records(
+    indexer: &mut crai::Indexer,
+    container_position: u64,
+    landmark: u64,
+    slice_length: u64,
+    records: &[Record],
+) {
+    let mut ranges: BTreeMap<Option<usize>, Option<(Position, Position)>> = BTreeMap::new();
+
+    for record in records {
+        let range = ranges.entry(record.reference_sequence_id).or_default();
+
+        if record.reference_sequence_id.is_none() {
+            continue;
+        }
+
+        if let (Some(start), Some(end)) = (record.alignment_start, record.alignment_end()) {
+            *range = match *range {
+                Some((s, e)) => Some((cmp::min(s, start), cmp::max(e, end))),
+                None => Some((start, end)),
+            };
+        }
+    }
+
+    for (reference_sequence_id, range) in ranges {
+        let (alignment_start, alignment_span) = match range {
+            Some((start, end)) => (Some(start), usize::from(end) - usize::from(start) + 1),
+            None => (None, 0),
+        };
+
+        indexer.add_record(crai::Record::new(
+            reference_sequence_id,
+            alignment_start,
+            alignment_span,
+            container_position,
+            landmark,
+            slice_length,
+        ));
+    }
+}
+
 fn build_container(
     reference_sequence_repository: &fasta::Repository,
     options: &Options,
@@ -64,6 +146,8 @@ fn build_container(
     let mut slices = Vec::new();
     let mut slice_record_counter = record_counter;
 
+    encode_substitutions(reference_sequence_repository, header, records)?;
+
     let compression_header = build_compression_header(options, records);
 
     for chunk in records.chunks_mut(DEFAULT_RECORDS_PER_SLICE) {
@@ -114,9 +198,7 @@ fn build_container(
 
         blocks.extend(slice.external_data_blocks);
 
-        let last_landmark = landmarks.last().copied().unwrap_or(0);
-        let landmark = last_landmark + slice_size;
-        landmarks.push(landmark);
+        landmarks.push(container_size);
 
         container_size += slice_size;
     }
@@ -10,7 +10,6 @@ use noodles_sam as sam;
 pub use self::header::write_header;
 use self::records::ExternalDataWriters;
 use crate::{
-    calculate_normalized_sequence_digest,
     codecs::Encoder,
     container::{
         BlockContentEncoderMap, CompressionHeader, ReferenceSequenceContext,
@@ -284,5 +283,61 @@ fn calculate_reference_sequence_md5(
     let interval = context.alignment_start()..=context.alignment_end();
     let sequence = &reference_sequence[interval];
 
-    Ok(Some(calculate_normalized_sequence_digest(sequence)))
+    Ok(Some(fasta::record::sequence::normalized_md5(sequence)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_mates() {
+        use sam::alignment::record::Flags as BamFlags;
+
+        let mut records = [
+            Record {
+                bam_flags: BamFlags::SEGMENTED | BamFlags::FIRST_SEGMENT,
+                name: Some("r0".into()),
+                ..Default::default()
+            },
+            Record {
+                bam_flags: BamFlags::empty(),
+                name: Some("s0".into()),
+                ..Default::default()
+            },
+            Record {
+                bam_flags: BamFlags::SEGMENTED | BamFlags::FIRST_SEGMENT,
+                name: Some("r1".into()),
+                ..Default::default()
+            },
+            Record {
+                bam_flags: BamFlags::SEGMENTED | BamFlags::LAST_SEGMENT,
+                name: Some("r0".into()),
+                ..Default::default()
+            },
+            Record {
+                bam_flags: BamFlags::SEGMENTED | BamFlags::SECONDARY,
+                name: Some("r1".into()),
+                ..Default::default()
+            },
+        ];
+
+        set_mates(&mut records);
+
+        let actual: Vec<_> = records
+            .iter()
+            .map(|record| (record.cram_flags, record.mate_distance))
+            .collect();
+
+        // The read side resolves the mate of record `i` to record `i + mate_distance + 1`.
+        let expected = [
+            (Flags::MATE_IS_DOWNSTREAM, Some(2)),
+            (Flags::IS_DETACHED, None),
+            (Flags::IS_DETACHED, None),
+            (Flags::empty(), None),
+            (Flags::IS_DETACHED, None),
+        ];
+
+        assert_eq!(actual, expected);
+    }
 }
@@ -0,0 +1,326 @@
+use std::{io, mem};
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+
+use crate::{
+    container::compression_header::preservation_map::substitution_matrix::Base,
+    io::writer::{Record, record::Feature},
+};
+
+/// Encodes read bases that differ from the reference sequence as substitutions.
+///
+/// Read bases in alignment matches are initially stored verbatim (`b` and `B` features). If the
+/// reference sequence of a record is available, bases equal to the reference are dropped, and
+/// single base mismatches between two of ACGTN are converted to substitutions (`X`). All other
+/// bases remain verbatim.
+pub(super) fn encode_substitutions(
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+    records: &mut [Record],
+) -> io::Result<()> {
+    let mut reference_sequence: Option<(usize, fasta::record::Sequence)> = None;
+
+    for record in records {
+        if record.bam_flags.is_unmapped() {
+            continue;
+        }
+
+        let (Some(reference_sequence_id), Some(alignment_start)) =
+            (record.reference_sequence_id, record.alignment_start)
+        else {
+            continue;
+        };
+
+        if reference_sequence
+            .as_ref()
+            .is_none_or(|(id, _)| *id != reference_sequence_id)
+        {
+            reference_sequence = get_reference_sequence(
+                reference_sequence_repository,
+                header,
+                reference_sequence_id,
+            )?
+            .map(|sequence| (reference_sequence_id, sequence));
+        }
+
+        if let Some((_, sequence)) = &reference_sequence {
+            encode_record_substitutions(sequence, alignment_start, record);
+        }
+    }
+
+    Ok(())
+}
+
+fn get_reference_sequence(
+    reference_sequence_repository: &fasta::Repository,
+    header: &sam::Header,
+    reference_sequence_id: usize,
+) -> io::Result<Option<fasta::record::Sequence>> {
+    let Some((name, _)) = header
+        .reference_sequences()
+        .get_index(reference_sequence_id)
+    else {
+        return Ok(None);
+    };
+
+    reference_sequence_repository.get(name).transpose()
+}
+
+fn encode_record_substitutions(
+    reference_sequence: &fasta::record::Sequence,
+    alignment_start: Position,
+    record: &mut Record,
+) {
+    let quality_scores_are_stored_as_array = record.cram_flags.quality_scores_are_stored_as_array();
+
+    let features = mem::take(&mut record.features);
+    let mut encoded_features = Vec::with_capacity(features.len());
+
+    let mut next_read_position = Position::MIN;
+    let mut next_reference_position = alignment_start;
+
+    for feature in features {
+        let position = feature.position();
+
+        let (read_len, reference_len) = match &feature {
+            Feature::Bases { bases, .. } => (bases.len(), bases.len()),
+            Feature::ReadBase { .. } | Feature::Substitution { .. } => (1, 1),
+            Feature::Insertion { bases, .. } | Feature::SoftClip { bases, .. } => (bases.len(), 0),
+            Feature::InsertBase { .. } => (1, 0),
+            Feature::Deletion { len, .. } | Feature::ReferenceSkip { len, .. } => (0, *len),
+            Feature::Scores { .. }
+            | Feature::QualityScore { .. }
+            | Feature::Padding { .. }
+            | Feature::HardClip { .. } => {
+                encoded_features.push(feature);
+                continue;
+            }
+        };
+
+        let reference_position = usize::from(next_reference_position)
+            + (usize::from(position) - usize::from(next_read_position));
+
+        next_read_position = position
+            .checked_add(read_len)
+            .expect("attempt to add with overflow");
+        next_reference_position =
+            Position::new(reference_position + reference_len).expect("invalid reference position");
+
+        match feature {
+            Feature::Bases { position, bases } => encode_bases(
+                &mut encoded_features,
+                reference_sequence,
+                position,
+                reference_position,
+                &bases,
+            ),
+            Feature::ReadBase {
+                position,
+                base,
+                quality_score,
+            } => match resolve_base(reference_sequence, reference_position, base) {
+                Resolution::Match => {
+                    if !quality_scores_are_stored_as_array {
+                        encoded_features.push(Feature::QualityScore {
+                            position,
+                            quality_score,
+                        });
+                    }
+                }
+                Resolution::Substitution(reference_base, read_base) => {
+                    encoded_features.push(Feature::Substitution {
+                        position,
+                        reference_base,
+                        read_base,
+                    });
+
+                    if !quality_scores_are_stored_as_array {
+                        encoded_features.push(Feature::QualityScore {
+                            position,
+                            quality_score,
+                        });
+                    }
+                }
+                Resolution::Verbatim => encoded_features.push(Feature::ReadBase {
+                    position,
+                    base,
+                    quality_score,
+                }),
+            },
+            _ => encoded_features.push(feature),
+        }
+    }
+
+    // Replaced features may now precede features at the same or earlier read positions, e.g.,
+    // the quality scores of a stretch of bases.
+    encoded_features.sort_by_key(|feature| feature.position());
+
+    record.features = encoded_features;
+}
+
+fn encode_bases(
+    features: &mut Vec<Feature>,
+    reference_sequence: &fasta::record::Sequence,
+    position: Position,
+    reference_position: usize,
+    bases: &[u8],
+) {
+    let mut verbatim_bases: Option<(Position, Vec<u8>)> = None;
+
+    for (i, &base) in bases.iter().enumerate() {
+        let read_position = position
+            .checked_add(i)
+            .expect("attempt to add with overflow");
+
+        let resolution = resolve_base(reference_sequence, reference_position + i, base);
+
+        if !matches!(resolution, Resolution::Verbatim) {
+            if let Some((position, bases)) = verbatim_bases.take() {
+                features.push(Feature::Bases { position, bases });
+            }
+        }
+
+        match resolution {
+            Resolution::Match => {}
+            Resolution::Substitution(reference_base, read_base) => {
+                features.push(Feature::Substitution {
+                    position: read_position,
+                    reference_base,
+                    read_base,
+                });
+            }
+            Resolution::Verbatim => verbatim_bases
+                .get_or_insert_with(|| (read_position, Vec::new()))
+                .1
+                .push(base),
+        }
+    }
+
+    if let Some((position, bases)) = verbatim_bases {
+        features.push(Feature::Bases { position, bases });
+    }
+}
+
+enum Resolution {
+    Match,
+    Substitution(Base, Base),
+    Verbatim,
+}
+
+fn resolve_base(
+    reference_sequence: &fasta::record::Sequence,
+    reference_position: usize,
+    read_base: u8,
+) -> Resolution {
+    let Some(raw_reference_base) = Position::new(reference_position)
+        .and_then(|position| reference_sequence.get(position))
+        .copied()
+    else {
+        return Resolution::Verbatim;
+    };
+
+    if read_base == raw_reference_base {
+        return Resolution::Match;
+    }
+
+    // The reader restores the case of a substituted base from the reference base.
+    if read_base.is_ascii_lowercase() != raw_reference_base.is_ascii_lowercase() {
+        return Resolution::Verbatim;
+    }
+
+    match (
+        Base::try_from(raw_reference_base),
+        Base::try_from(read_base),
+    ) {
+        (Ok(reference_base), Ok(read_base)) if reference_base != read_base => {
+            Resolution::Substitution(reference_base, read_base)
+        }
+        _ => Resolution::Verbatim,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_record_substitutions() -> Result<(), noodles_core::position::TryFromIntError> {
+        let reference_sequence = fasta::record::Sequence::from(b"ACGTACGTacgt".to_vec());
+
+        // 2S5M1I3M: ACGTTCGRTRg
+        let mut record = Record {
+            read_length: 11,
+            alignment_start: Some(Position::try_from(3)?),
+            features: vec![
+                Feature::SoftClip {
+                    position: Position::try_from(1)?,
+                    bases: b"AC".to_vec(),
+                },
+                Feature::Bases {
+                    position: Position::try_from(3)?,
+                    bases: b"GTTCG".to_vec(),
+                },
+                Feature::Scores {
+                    position: Position::try_from(3)?,
+                    quality_scores: vec![45; 5],
+                },
+                Feature::InsertBase {
+                    position: Position::try_from(8)?,
+                    base: b'R',
+                },
+                Feature::Bases {
+                    position: Position::try_from(9)?,
+                    bases: b"TR".to_vec(),
+                },
+                Feature::ReadBase {
+                    position: Position::try_from(11)?,
+                    base: b'g',
+                    quality_score: 8,
+                },
+            ],
+            ..Default::default()
+        };
+
+        encode_record_substitutions(&reference_sequence, Position::try_from(3)?, &mut record);
+
+        assert_eq!(
+            record.features,
+            [
+                Feature::SoftClip {
+                    position: Position::try_from(1)?,
+                    bases: b"AC".to_vec(),
+                },
+                Feature::Scores {
+                    position: Position::try_from(3)?,
+                    quality_scores: vec![45; 5],
+                },
+                Feature::Substitution {
+                    position: Position::try_from(5)?,
+                    reference_base: Base::A,
+                    read_base: Base::T,
+                },
+                Feature::InsertBase {
+                    position: Position::try_from(8)?,
+                    base: b'R',
+                },
+                Feature::Bases {
+                    position: Position::try_from(10)?,
+                    bases: b"R".to_vec(),
+                },
+                Feature::Substitution {
+                    position: Position::try_from(11)?,
+                    reference_base: Base::C,
+                    read_base: Base::G,
+                },
+                Feature::QualityScore {
+                    position: Position::try_from(11)?,
+                    quality_score: 8,
+                },
+            ]
+        );
+
+        Ok(())
+    }
+}
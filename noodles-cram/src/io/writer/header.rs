@@ -12,7 +12,7 @@ use self::{
     container::write_container, file_id::write_file_id, format_version::write_format_version,
     magic_number::write_magic_number,
 };
-use crate::{FileDefinition, calculate_normalized_sequence_digest};
+use crate::FileDefinition;
 
 pub fn write_header<W>(
     writer: &mut W,
@@ -73,7 +73,7 @@ pub(crate) fn add_missing_reference_sequence_checksums(
                 .transpose()?
                 .expect("missing reference sequence");
 
-            let checksum = calculate_normalized_sequence_digest(&sequence[..]);
+            let checksum = fasta::record::sequence::normalized_md5(&sequence[..]);
 
             entry.insert(Md5Checksum::from(checksum).to_string().into());
         }
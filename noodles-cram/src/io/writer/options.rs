@@ -1,9 +1,15 @@
+use std::{fmt, sync::Arc};
+
+use noodles_sam::alignment::record::data::field::Tag;
+
 use crate::{container::BlockContentEncoderMap, file_definition::Version};
 
 #[derive(Clone, Debug)]
 pub struct Options {
     pub preserve_read_names: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
+    pub store_quality_scores_as_array: bool,
+    pub tag_filter: Option<TagFilter>,
     pub version: Version,
     pub block_content_encoder_map: BlockContentEncoderMap,
 }
@@ -13,8 +19,33 @@ impl Default for Options {
         Self {
             preserve_read_names: true,
             encode_alignment_start_positions_as_deltas: true,
+            store_quality_scores_as_array: false,
+            tag_filter: None,
             version: Version::default(),
             block_content_encoder_map: BlockContentEncoderMap::default(),
         }
     }
 }
+
+/// A predicate that selects which data fields are written.
+#[derive(Clone)]
+pub struct TagFilter(Arc<dyn Fn(Tag) -> bool + Send + Sync>);
+
+impl TagFilter {
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(Tag) -> bool + Send + Sync + 'static,
+    {
+        Self(Arc::new(f))
+    }
+
+    pub fn matches(&self, tag: Tag) -> bool {
+        (self.0)(tag)
+    }
+}
+
+impl fmt::Debug for TagFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TagFilter").finish_non_exhaustive()
+    }
+}
@@ -41,10 +41,8 @@ impl Record {
     }
 
     pub fn alignment_end(&self) -> Option<Position> {
-        self.alignment_start.and_then(|start| {
-            let end = usize::from(start) + self.alignment_span() - 1;
-            Position::new(end)
-        })
+        self.alignment_start
+            .and_then(|start| start.add_length(self.alignment_span()))
     }
 }
 
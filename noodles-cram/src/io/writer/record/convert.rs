@@ -1,22 +1,25 @@
 use std::io;
 
-use bstr::BStr;
 use noodles_core::Position;
 use noodles_sam::{
     self as sam,
     alignment::{
-        record::data::field::{Tag, Value},
+        record::data::field::Tag,
         record_buf::{QualityScores, Sequence, data::field::Value as ValueBuf},
     },
 };
 
 use super::{Feature, Record};
-use crate::record::{Flags, MateFlags};
+use crate::{
+    io::writer::{Options, TagFilter},
+    record::{Flags, MateFlags},
+};
 
 impl Record {
     pub fn try_from_alignment_record(
         header: &sam::Header,
         record: &dyn sam::alignment::Record,
+        options: &Options,
     ) -> io::Result<Self> {
         let bam_flags = record.flags()?;
         let mut cram_flags = Flags::default();
@@ -26,7 +29,7 @@ impl Record {
         let quality_scores = if record.quality_scores().is_empty() {
             QualityScores::default()
         } else {
-            if bam_flags.is_unmapped() {
+            if bam_flags.is_unmapped() || options.store_quality_scores_as_array {
                 cram_flags.insert(Flags::QUALITY_SCORES_ARE_STORED_AS_ARRAY);
             }
 
@@ -46,11 +49,17 @@ impl Record {
         )?;
 
         let data = record.data();
-        let (data_buf, read_group_name) = get_filtered_data(data.as_ref())?;
-
-        let read_group_id = read_group_name
-            .map(|name| get_read_group_id(header, name))
-            .transpose()?;
+        let data_buf = get_filtered_data(data.as_ref(), options.tag_filter.as_ref())?;
+
+        let read_group_id = if options
+            .tag_filter
+            .as_ref()
+            .is_none_or(|tag_filter| tag_filter.matches(Tag::READ_GROUP))
+        {
+            header.read_group_index_of_record(record).transpose()?
+        } else {
+            None
+        };
 
         Ok(Self {
             bam_flags,
@@ -74,13 +83,6 @@ impl Record {
     }
 }
 
-fn get_read_group_id(header: &sam::Header, read_group_name: &BStr) -> io::Result<usize> {
-    header
-        .read_groups()
-        .get_index_of(read_group_name)
-        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "invalid read group name"))
-}
-
 fn cigar_to_features(
     cigar: &dyn sam::alignment::record::Cigar,
     flags: Flags,
@@ -216,28 +218,23 @@ fn cigar_to_features(
 #[allow(clippy::type_complexity)]
 fn get_filtered_data(
     data: &dyn sam::alignment::record::Data,
-) -> io::Result<(Vec<(Tag, ValueBuf)>, Option<&BStr>)> {
+    tag_filter: Option<&TagFilter>,
+) -> io::Result<Vec<(Tag, ValueBuf)>> {
     let mut data_buf = Vec::new();
-    let mut read_group_name = None;
 
     for result in data.iter() {
         let (tag, value) = result?;
 
-        if tag == Tag::READ_GROUP {
-            let Value::String(s) = value else {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    "invalid read group field value",
-                ));
-            };
-
-            read_group_name = Some(s);
+        if let Some(tag_filter) = tag_filter {
+            if !tag_filter.matches(tag) {
+                continue;
+            }
         }
 
         data_buf.push((tag, value.try_into()?));
     }
 
-    Ok((data_buf, read_group_name))
+    Ok(data_buf)
 }
 
 #[cfg(test)]
@@ -17,7 +17,6 @@ pub enum Feature {
         base: u8,
         quality_score: u8,
     },
-    #[allow(dead_code)]
     Substitution {
         position: Position,
         reference_base: Base,
@@ -2,7 +2,7 @@
 
 mod cigar;
 pub(crate) mod data;
-pub(crate) mod feature;
+pub mod feature;
 mod flags;
 mod mate_flags;
 mod quality_scores;
@@ -18,13 +18,14 @@ use noodles_sam::{
     alignment::record::{MappingQuality, data::field::Tag},
 };
 
+pub use self::feature::Feature;
 use self::{
     cigar::Cigar,
     data::{Data, field::Value},
     quality_scores::QualityScores,
     sequence::Sequence,
 };
-pub(crate) use self::{feature::Feature, flags::Flags, mate_flags::MateFlags};
+pub(crate) use self::{flags::Flags, mate_flags::MateFlags};
 use crate::{
     container::compression_header::preservation_map::SubstitutionMatrix,
     io::reader::container::slice::ReferenceSequence,
@@ -57,15 +58,37 @@ pub struct Record<'c> {
 }
 
 impl Record<'_> {
+    /// Returns the record ID.
+    ///
+    /// This is the 0-based index of the record in the file, i.e., the record counter of its slice
+    /// plus the position of the record in the slice. Because the record counter is read from the
+    /// slice header, IDs are global and stable across containers, regardless of which containers
+    /// are read.
+    ///
+    /// Unnamed records are given their ID as a name.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the read features.
+    ///
+    /// Features describe how a mapped read differs from the reference sequence and are read
+    /// directly from the slice data. Unlike the sequence and quality scores, which are
+    /// reconstructed from the features and reference sequence on access, this requires no
+    /// additional decoding.
+    ///
+    /// Unmapped records have no features.
+    pub fn features(&self) -> &[Feature<'_>] {
+        &self.features
+    }
+
     fn alignment_span(&self) -> usize {
         calculate_alignment_span(self.read_length, &self.features)
     }
 
     pub(crate) fn alignment_end(&self) -> Option<Position> {
-        self.alignment_start.and_then(|alignment_start| {
-            let end = usize::from(alignment_start) + self.alignment_span() - 1;
-            Position::new(end)
-        })
+        self.alignment_start
+            .and_then(|alignment_start| alignment_start.add_length(self.alignment_span()))
     }
 }
 
@@ -1,63 +1,110 @@
-pub mod code;
+//! CRAM record feature.
 
-pub use self::code::Code;
+pub(crate) mod code;
+
+pub(crate) use self::code::Code;
 
 use noodles_core::Position;
 
+/// A CRAM record feature.
+///
+/// A feature describes how a mapped read differs from the reference sequence. Feature positions
+/// are 1-based and relative to the start of the read.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum Feature<'c> {
+    /// A stretch of read bases (`b`).
     Bases {
+        /// The read position.
         position: Position,
+        /// The bases.
         bases: &'c [u8],
     },
+    /// A stretch of quality scores (`q`).
     Scores {
+        /// The read position.
         position: Position,
+        /// The quality scores.
         quality_scores: &'c [u8],
     },
+    /// A read base and its quality score (`B`).
     ReadBase {
+        /// The read position.
         position: Position,
+        /// The base.
         base: u8,
+        /// The quality score.
         quality_score: u8,
     },
+    /// A base substitution (`X`).
     Substitution {
+        /// The read position.
         position: Position,
+        /// The substitution code.
+        ///
+        /// The substituted base is resolved using the substitution matrix of the compression
+        /// header and the reference base at this position.
         code: u8,
     },
+    /// An insertion (`I`).
     Insertion {
+        /// The read position.
         position: Position,
+        /// The inserted bases.
         bases: &'c [u8],
     },
+    /// A deletion (`D`).
     Deletion {
+        /// The read position.
         position: Position,
+        /// The number of deleted reference bases.
         len: usize,
     },
+    /// A single-base insertion (`i`).
     InsertBase {
+        /// The read position.
         position: Position,
+        /// The inserted base.
         base: u8,
     },
+    /// A single quality score (`Q`).
     QualityScore {
+        /// The read position.
         position: Position,
+        /// The quality score.
         quality_score: u8,
     },
+    /// A reference skip (`N`).
     ReferenceSkip {
+        /// The read position.
         position: Position,
+        /// The number of skipped reference bases.
         len: usize,
     },
+    /// A soft clip (`S`).
     SoftClip {
+        /// The read position.
         position: Position,
+        /// The clipped bases.
         bases: &'c [u8],
     },
+    /// Padding (`P`).
     Padding {
+        /// The read position.
         position: Position,
+        /// The padding length.
         len: usize,
     },
+    /// A hard clip (`H`).
     HardClip {
+        /// The read position.
         position: Position,
+        /// The number of clipped bases.
         len: usize,
     },
 }
 
 impl Feature<'_> {
+    /// Returns the read position of the feature.
     pub fn position(&self) -> Position {
         match self {
             Self::Bases { position, .. } => *position,
@@ -0,0 +1,29 @@
+use std::num::NonZeroUsize;
+
+use noodles_fasta as fasta;
+use noodles_sam::{
+    self as sam,
+    header::record::value::{Map, map::ReferenceSequence},
+};
+
+pub(crate) const SQ0_SEQUENCE: &[u8] = b"ACGTACGT";
+
+const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(SQ0_SEQUENCE.len()) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+/// Returns a reference sequence repository with a single sequence (`sq0`) and a header that
+/// declares it.
+pub(crate) fn sq0_repository_and_header() -> (fasta::Repository, sam::Header) {
+    let repository = fasta::Repository::new(vec![fasta::Record::new(
+        fasta::record::Definition::new("sq0", None),
+        fasta::record::Sequence::from(SQ0_SEQUENCE.to_vec()),
+    )]);
+
+    let header = sam::Header::builder()
+        .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+        .build();
+
+    (repository, header)
+}
@@ -1,5 +1,6 @@
 //! Binning index.
 
+mod bins;
 pub mod index;
 mod indexer;
 mod reference_sequence;
@@ -7,10 +8,18 @@ mod reference_sequence;
 use std::io;
 
 use noodles_bgzf as bgzf;
-use noodles_core::region::Interval;
-
-use self::index::{Header, reference_sequence::bin::Chunk};
-pub use self::{index::Index, indexer::Indexer, reference_sequence::ReferenceSequence};
+use noodles_core::{Position, region::Interval};
+
+use self::index::{
+    Header,
+    reference_sequence::{Metadata, bin::Chunk},
+};
+pub use self::{
+    bins::{reg2bin, reg2bins},
+    index::Index,
+    indexer::Indexer,
+    reference_sequence::ReferenceSequence,
+};
 
 /// A binning index.
 pub trait BinningIndex {
@@ -29,6 +38,96 @@ pub trait BinningIndex {
     /// Returns the number of unplaced, unmapped records in the associated file.
     fn unplaced_unmapped_record_count(&self) -> Option<u64>;
 
+    /// Returns the metadata of the reference sequence at the given index.
+    ///
+    /// This returns `None` if the reference sequence does not exist or has no metadata.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     binning_index::{
+    ///         BinningIndex,
+    ///         index::{reference_sequence::Metadata, ReferenceSequence},
+    ///     },
+    /// };
+    ///
+    /// let metadata = Metadata::new(
+    ///     bgzf::VirtualPosition::from(610),
+    ///     bgzf::VirtualPosition::from(1597),
+    ///     55,
+    ///     0,
+    /// );
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_reference_sequences(vec![ReferenceSequence::new(
+    ///         Default::default(),
+    ///         Default::default(),
+    ///         Some(metadata.clone()),
+    ///     )])
+    ///     .build();
+    ///
+    /// assert_eq!(index.reference_sequence_metadata(0), Some(&metadata));
+    /// assert!(index.reference_sequence_metadata(1).is_none());
+    /// ```
+    fn reference_sequence_metadata(&self, i: usize) -> Option<&Metadata> {
+        self.reference_sequences()
+            .nth(i)
+            .and_then(|reference_sequence| reference_sequence.metadata())
+    }
+
+    /// Returns the mapped and unmapped record counts of each reference sequence.
+    ///
+    /// Each pair is `(mapped, unmapped)`. Reference sequences without metadata have counts of 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi::{
+    ///     self as csi,
+    ///     binning_index::{
+    ///         BinningIndex,
+    ///         index::{reference_sequence::Metadata, ReferenceSequence},
+    ///     },
+    /// };
+    ///
+    /// let index = csi::Index::builder()
+    ///     .set_reference_sequences(vec![
+    ///         ReferenceSequence::new(
+    ///             Default::default(),
+    ///             Default::default(),
+    ///             Some(Metadata::new(
+    ///                 bgzf::VirtualPosition::from(610),
+    ///                 bgzf::VirtualPosition::from(1597),
+    ///                 55,
+    ///                 8,
+    ///             )),
+    ///         ),
+    ///         ReferenceSequence::new(Default::default(), Default::default(), None),
+    ///     ])
+    ///     .build();
+    ///
+    /// assert_eq!(index.record_counts(), [(55, 8), (0, 0)]);
+    /// ```
+    fn record_counts(&self) -> Vec<(u64, u64)> {
+        self.reference_sequences()
+            .map(|reference_sequence| {
+                reference_sequence
+                    .metadata()
+                    .map(|metadata| {
+                        (
+                            metadata.mapped_record_count(),
+                            metadata.unmapped_record_count(),
+                        )
+                    })
+                    .unwrap_or_default()
+            })
+            .collect()
+    }
+
     /// Returns the chunks that overlap with the given region.
     fn query(&self, reference_sequence_id: usize, interval: Interval) -> io::Result<Vec<Chunk>>;
 
@@ -164,6 +263,81 @@ pub fn optimize_chunks(chunks: &[Chunk], min_offset: bgzf::VirtualPosition) -> V
     merged_chunks
 }
 
+/// Merges a list of intervals into a list of non-overlapping intervals.
+///
+/// Intervals are grouped by reference sequence ID. Overlapping or adjacent intervals on the same
+/// reference sequence are merged. The result is sorted by reference sequence ID and start
+/// position.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_csi::binning_index::merge_intervals;
+///
+/// let intervals = [
+///     (1, "5-8".parse()?),
+///     (0, "8-13".parse()?),
+///     (0, "1-5".parse()?),
+///     (0, "6-7".parse()?),
+///     (0, "21-34".parse()?),
+/// ];
+///
+/// let actual = merge_intervals(&intervals);
+///
+/// let expected = [
+///     (0, "1-13".parse()?),
+///     (0, "21-34".parse()?),
+///     (1, "5-8".parse()?),
+/// ];
+///
+/// assert_eq!(actual, expected);
+/// # Ok::<_, noodles_core::region::interval::ParseError>(())
+/// ```
+pub fn merge_intervals(intervals: &[(usize, Interval)]) -> Vec<(usize, Interval)> {
+    let mut intervals = intervals.to_vec();
+    intervals.sort_by_key(|(id, interval)| (*id, interval.start().unwrap_or(Position::MIN)));
+
+    let mut merged: Vec<(usize, Interval)> = Vec::with_capacity(intervals.len());
+
+    for (id, interval) in intervals {
+        if let Some((last_id, last_interval)) = merged.last_mut() {
+            if *last_id == id && is_mergeable(*last_interval, interval) {
+                *last_interval = union(*last_interval, interval);
+                continue;
+            }
+        }
+
+        merged.push((id, interval));
+    }
+
+    merged
+}
+
+fn is_mergeable(a: Interval, b: Interval) -> bool {
+    match a.end() {
+        Some(end) => {
+            let start = b.start().unwrap_or(Position::MIN);
+            usize::from(start) <= usize::from(end).saturating_add(1)
+        }
+        None => true,
+    }
+}
+
+// `a` is assumed to start at or before `b`.
+fn union(a: Interval, b: Interval) -> Interval {
+    let end = match (a.end(), b.end()) {
+        (Some(a_end), Some(b_end)) => Some(a_end.max(b_end)),
+        _ => None,
+    };
+
+    match (a.start(), end) {
+        (Some(start), Some(end)) => (start..=end).into(),
+        (Some(start), None) => (start..).into(),
+        (None, Some(end)) => (..=end).into(),
+        (None, None) => (..).into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,6 +401,33 @@ mod tests {
         assert!(merged_chunks.is_empty());
     }
 
+    #[test]
+    fn test_merge_intervals() -> Result<(), Box<dyn std::error::Error>> {
+        let intervals = [
+            (1, "5-8".parse()?),
+            (0, "8-13".parse()?),
+            (0, "1-5".parse()?),
+            (0, "6-7".parse()?),
+            (0, "21-34".parse()?),
+            (1, "1-5".parse()?),
+            (2, Interval::from(Position::try_from(3)?..)),
+            (2, "55-89".parse()?),
+        ];
+
+        let actual = merge_intervals(&intervals);
+
+        let expected = [
+            (0, "1-13".parse()?),
+            (0, "21-34".parse()?),
+            (1, "1-8".parse()?),
+            (2, Interval::from(Position::try_from(3)?..)),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_optimize_chunks() {
         let chunks = build_chunks();
@@ -0,0 +1,203 @@
+use noodles_core::Position;
+
+/// Calculates the ID of the smallest bin that contains the given region.
+///
+/// The region is given as a 1-based, inclusive interval [`start`, `end`]. This is `reg2bin`
+/// from the CSI specification, generalized to any `min_shift` and `depth`, e.g., BAI uses a
+/// `min_shift` of 14 and `depth` of 5.
+///
+/// The region is expected to be within the maximum position of the binning scheme, i.e.,
+/// 2^(`min_shift` + 3 × `depth`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning_index::reg2bin;
+///
+/// let start = Position::try_from(8)?;
+/// let end = Position::try_from(13)?;
+/// assert_eq!(reg2bin(start, end, 14, 5), 4681);
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+// `CSIv1.pdf` (2020-07-21)
+pub fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
+    // [beg, end), 0-based
+    let beg = usize::from(start) - 1;
+    let end = usize::from(end);
+
+    let end = end - 1;
+    let mut l = depth;
+    let mut s = min_shift;
+    let mut t = ((1 << (depth * 3)) - 1) / 7;
+
+    while l > 0 {
+        if beg >> s == end >> s {
+            return t + (beg >> s);
+        }
+
+        l -= 1;
+        s += 3;
+        t -= 1 << (l * 3);
+    }
+
+    0
+}
+
+/// Calculates the IDs of all bins that overlap the given region.
+///
+/// The region is given as a 1-based, inclusive interval [`start`, `end`]. This is `reg2bins`
+/// from the CSI specification, generalized to any `min_shift` and `depth`. The bin IDs are
+/// returned in ascending order.
+///
+/// The region is expected to be within the maximum position of the binning scheme, i.e.,
+/// 2^(`min_shift` + 3 × `depth`).
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_csi::binning_index::reg2bins;
+///
+/// let start = Position::try_from(8)?;
+/// let end = Position::try_from(13)?;
+/// assert_eq!(reg2bins(start, end, 14, 5), [0, 1, 9, 73, 585, 4681]);
+/// # Ok::<_, noodles_core::position::TryFromIntError>(())
+/// ```
+// `CSIv1.pdf` (2020-07-21)
+#[allow(clippy::many_single_char_names)]
+pub fn reg2bins(start: Position, end: Position, min_shift: u8, depth: u8) -> Vec<usize> {
+    // [beg, end), 0-based
+    let beg = usize::from(start) - 1;
+    let end = usize::from(end);
+
+    let end = end - 1;
+    let mut l = 0;
+    let mut t = 0;
+    let mut s = i32::from(min_shift) + i32::from(depth) * 3;
+
+    let mut bins = Vec::new();
+
+    while l <= depth {
+        let b = t + (beg >> s);
+        let e = t + (end >> s);
+
+        bins.extend(b..=e);
+
+        s -= 3;
+        t += 1 << (l * 3);
+        l += 1;
+    }
+
+    bins
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reg2bin() -> Result<(), noodles_core::position::TryFromIntError> {
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        let start = Position::try_from(8)?;
+        let end = start;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 9);
+
+        let end = Position::try_from(13)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 9);
+
+        let end = Position::try_from(16)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 9);
+
+        let end = Position::try_from(17)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 1);
+
+        let end = Position::try_from(143)?;
+        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg2bin_with_bai_binning_scheme() {
+        // `SAMv1.pdf` § 5.3: bins 0, 1–8, 9–72, 73–584, 585–4680, and 4681–37448 span 512 Mbp,
+        // 64 Mbp, 8 Mbp, 1 Mbp, 128 Kbp, and 16 Kbp, respectively.
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 5;
+
+        fn t(start: usize, end: usize, expected: usize) {
+            let start = Position::try_from(start).unwrap();
+            let end = Position::try_from(end).unwrap();
+            assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), expected);
+        }
+
+        t(1, 1, 4681);
+        t(1, 1 << 14, 4681);
+        t((1 << 14) + 1, 1 << 15, 4682);
+        t(1 << 14, (1 << 14) + 1, 585);
+        t((1 << 29) - 1, 1 << 29, 37448);
+        t(1, 1 << 17, 585);
+        t(1, 1 << 20, 73);
+        t(1, 1 << 23, 9);
+        t(1, 1 << 26, 1);
+        t(1, (1 << 26) + 1, 0);
+        t(1, 1 << 29, 0);
+    }
+
+    #[test]
+    fn test_reg2bins() -> Result<(), noodles_core::position::TryFromIntError> {
+        // +------------------------------------------------------------------------------------...
+        // | 0                                                                                  ...
+        // | 0-1023                                                                             ...
+        // +-------------------------------------------------------------------------+----------...
+        // | 1                                                                       | 2        ...
+        // | 0-127                                                                   | 128-255  ...
+        // +--------+--------+--------+--------+--------+--------+---------+---------+---------+...
+        // | 9      | 10     | 11     | 12     | 13     | 14     | 15      | 16      | 17      |...
+        // | 0-15   | 16-31  | 32-47  | 48-63  | 64-79  | 80-95  | 96-111  | 112-127 | 128-143 |...
+        // +--------+--------+--------+--------+--------+--------+---------+---------+---------+...
+
+        const MIN_SHIFT: u8 = 4;
+        const DEPTH: u8 = 2;
+
+        fn t(start: Position, end: Position, expected: &[usize]) {
+            assert_eq!(reg2bins(start, end, MIN_SHIFT, DEPTH), expected);
+        }
+
+        t(Position::try_from(1)?, Position::try_from(16)?, &[0, 1, 9]);
+        t(Position::try_from(9)?, Position::try_from(13)?, &[0, 1, 9]);
+
+        t(
+            Position::try_from(36)?,
+            Position::try_from(67)?,
+            &[0, 1, 11, 12, 13],
+        );
+
+        t(
+            Position::try_from(49)?,
+            Position::try_from(143)?,
+            &[0, 1, 2, 12, 13, 14, 15, 16, 17],
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reg2bins_with_bai_binning_scheme() -> Result<(), noodles_core::position::TryFromIntError>
+    {
+        const MIN_SHIFT: u8 = 14;
+        const DEPTH: u8 = 5;
+
+        let start = Position::try_from(1 << 14)?;
+        let end = Position::try_from((1 << 14) + 1)?;
+
+        assert_eq!(
+            reg2bins(start, end, MIN_SHIFT, DEPTH),
+            [0, 1, 9, 73, 585, 4681, 4682]
+        );
+
+        Ok(())
+    }
+}
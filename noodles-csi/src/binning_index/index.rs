@@ -51,6 +51,35 @@ where
     pub fn reference_sequences(&self) -> &[ReferenceSequence<I>] {
         &self.reference_sequences
     }
+
+    /// Returns the reference sequence names from the header, if present.
+    ///
+    /// This is typically only set for tabix indices and CSI indices built for tabix-like formats.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bstr::BString;
+    /// use noodles_csi::{self as csi, binning_index::index::Header};
+    ///
+    /// let index = csi::Index::default();
+    /// assert!(index.reference_sequence_names().is_none());
+    ///
+    /// let reference_sequence_names = [BString::from("sq0")].into_iter().collect();
+    /// let header = Header::builder()
+    ///     .set_reference_sequence_names(reference_sequence_names)
+    ///     .build();
+    /// let index = csi::Index::builder().set_header(header).build();
+    ///
+    /// assert!(index
+    ///     .reference_sequence_names()
+    ///     .is_some_and(|names| names.contains(&b"sq0"[..])));
+    /// ```
+    pub fn reference_sequence_names(&self) -> Option<&header::ReferenceSequenceNames> {
+        self.header
+            .as_ref()
+            .map(|header| header.reference_sequence_names())
+    }
 }
 
 impl<I> Default for Index<I>
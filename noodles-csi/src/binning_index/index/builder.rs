@@ -1,5 +1,7 @@
 //! Binning index builder.
 
+use std::io;
+
 use super::{Header, Index, ReferenceSequence, reference_sequence};
 
 /// A binning index builder.
@@ -116,6 +118,61 @@ where
     }
 }
 
+impl<I> Builder<I>
+where
+    I: reference_sequence::Index,
+{
+    /// Builds a binning index, validating that it can address the given reference sequences.
+    ///
+    /// The maximum position addressable by a binning index is 2^(`min_shift` + 3 * `depth`). This
+    /// returns an error if any reference sequence length exceeds this position.
+    ///
+    /// The reference sequence lengths are typically taken from the header of the indexed file,
+    /// e.g., the SAM header reference sequences or VCF header contigs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    ///
+    /// let index = csi::Index::builder().try_build([8, 13])?;
+    ///
+    /// // 2^(14 + 3 * 5) = 2^29
+    /// assert!(csi::Index::builder().try_build([1 << 30]).is_err());
+    /// assert!(csi::Index::builder().set_depth(6).try_build([1 << 30]).is_ok());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn try_build<L>(self, reference_sequence_lengths: L) -> io::Result<Index<I>>
+    where
+        L: IntoIterator<Item = usize>,
+    {
+        let max_position = max_position(self.min_shift, self.depth).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid min shift ({}) and depth ({})",
+                    self.min_shift, self.depth
+                ),
+            )
+        })?;
+
+        for (i, length) in reference_sequence_lengths.into_iter().enumerate() {
+            if length > max_position {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "reference sequence {i} length ({length}) exceeds max position ({max_position}) \
+                        for min shift ({}) and depth ({})",
+                        self.min_shift, self.depth
+                    ),
+                ));
+            }
+        }
+
+        Ok(self.build())
+    }
+}
+
 impl<I> Default for Builder<I> {
     fn default() -> Self {
         Self {
@@ -128,6 +185,15 @@ impl<I> Default for Builder<I> {
     }
 }
 
+// 2^(min_shift + 3 * depth)
+fn max_position(min_shift: u8, depth: u8) -> Option<usize> {
+    let exp = u32::from(depth)
+        .checked_mul(3)
+        .and_then(|n| n.checked_add(u32::from(min_shift)))?;
+
+    1usize.checked_shl(exp)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,4 +209,39 @@ mod tests {
         assert!(builder.reference_sequences.is_empty());
         assert!(builder.unplaced_unmapped_record_count.is_none());
     }
+
+    #[test]
+    fn test_try_build() {
+        const MAX_POSITION: usize = 1 << 29;
+
+        assert!(
+            Builder::<BinnedIndex>::default()
+                .try_build([8, MAX_POSITION])
+                .is_ok()
+        );
+
+        assert!(matches!(
+            Builder::<BinnedIndex>::default().try_build([8, MAX_POSITION + 1]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(
+            Builder::<BinnedIndex>::default()
+                .set_min_shift(15)
+                .try_build([MAX_POSITION + 1])
+                .is_ok()
+        );
+
+        assert!(matches!(
+            Builder::<BinnedIndex>::default().set_depth(u8::MAX).try_build([8]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
+
+    #[test]
+    fn test_max_position() {
+        assert_eq!(max_position(14, 5), Some(1 << 29));
+        assert_eq!(max_position(14, 6), Some(1 << 32));
+        assert_eq!(max_position(14, 255), None);
+    }
 }
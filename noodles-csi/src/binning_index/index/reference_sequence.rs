@@ -15,7 +15,7 @@ use noodles_core::{Position, region::Interval};
 
 use self::bin::Chunk;
 use super::resolve_interval;
-use crate::binning_index;
+use crate::binning_index::{self, reg2bin, reg2bins};
 
 /// A binning index reference sequence.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -95,7 +95,9 @@ where
         let max_bin_id = Bin::max_id(depth);
         let mut region_bins = BitVec::from_elem(max_bin_id, false);
 
-        reg2bins(start, end, min_shift, depth, &mut region_bins);
+        for id in reg2bins(start, end, min_shift, depth) {
+            region_bins.set(id, true);
+        }
 
         let query_bins = self
             .bins()
@@ -249,56 +251,6 @@ pub(crate) fn parent_id(id: usize) -> Option<usize> {
     if id > 0 { Some((id - 1) / M) } else { None }
 }
 
-// `CSIv1.pdf` (2020-07-21)
-fn reg2bin(start: Position, end: Position, min_shift: u8, depth: u8) -> usize {
-    // [beg, end), 0-based
-    let beg = usize::from(start) - 1;
-    let end = usize::from(end);
-
-    let end = end - 1;
-    let mut l = depth;
-    let mut s = min_shift;
-    let mut t = ((1 << (depth * 3)) - 1) / 7;
-
-    while l > 0 {
-        if beg >> s == end >> s {
-            return t + (beg >> s);
-        }
-
-        l -= 1;
-        s += 3;
-        t -= 1 << (l * 3);
-    }
-
-    0
-}
-
-// `CSIv1.pdf` (2020-07-21)
-#[allow(clippy::many_single_char_names)]
-fn reg2bins(start: Position, end: Position, min_shift: u8, depth: u8, bins: &mut BitVec) {
-    // [beg, end), 0-based
-    let beg = usize::from(start) - 1;
-    let end = usize::from(end);
-
-    let end = end - 1;
-    let mut l = 0;
-    let mut t = 0;
-    let mut s = i32::from(min_shift) + i32::from(depth) * 3;
-
-    while l <= depth {
-        let b = t + (beg >> s);
-        let e = t + (end >> s);
-
-        for i in b..=e {
-            bins.set(i, true);
-        }
-
-        s -= 3;
-        t += 1 << (l * 3);
-        l += 1;
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -319,77 +271,4 @@ mod tests {
 
         Ok(())
     }
-
-    #[test]
-    fn test_reg2bin() -> Result<(), noodles_core::position::TryFromIntError> {
-        const MIN_SHIFT: u8 = 4;
-        const DEPTH: u8 = 2;
-
-        let start = Position::try_from(8)?;
-        let end = start;
-        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 9);
-
-        let end = Position::try_from(13)?;
-        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 9);
-
-        let end = Position::try_from(16)?;
-        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 9);
-
-        let end = Position::try_from(17)?;
-        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 1);
-
-        let end = Position::try_from(143)?;
-        assert_eq!(reg2bin(start, end, MIN_SHIFT, DEPTH), 0);
-
-        Ok(())
-    }
-
-    #[test]
-    fn test_reg2bins() -> Result<(), noodles_core::position::TryFromIntError> {
-        // +------------------------------------------------------------------------------------...
-        // | 0                                                                                  ...
-        // | 0-1023                                                                             ...
-        // +-------------------------------------------------------------------------+----------...
-        // | 1                                                                       | 2        ...
-        // | 0-127                                                                   | 128-255  ...
-        // +--------+--------+--------+--------+--------+--------+---------+---------+---------+...
-        // | 9      | 10     | 11     | 12     | 13     | 14     | 15      | 16      | 17      |...
-        // | 0-15   | 16-31  | 32-47  | 48-63  | 64-79  | 80-95  | 96-111  | 112-127 | 128-143 |...
-        // +--------+--------+--------+--------+--------+--------+---------+---------+---------+...
-
-        const MIN_SHIFT: u8 = 4;
-        const DEPTH: u8 = 2;
-
-        fn t(start: Position, end: Position, expected_bin_ids: &[usize]) {
-            let max_bin_id = Bin::max_id(DEPTH);
-
-            let mut actual = BitVec::from_elem(max_bin_id, false);
-            reg2bins(start, end, MIN_SHIFT, DEPTH, &mut actual);
-
-            let mut expected = BitVec::from_elem(max_bin_id, false);
-
-            for &bin_id in expected_bin_ids {
-                expected.set(bin_id, true);
-            }
-
-            assert_eq!(actual, expected);
-        }
-
-        t(Position::try_from(1)?, Position::try_from(16)?, &[0, 1, 9]);
-        t(Position::try_from(9)?, Position::try_from(13)?, &[0, 1, 9]);
-
-        t(
-            Position::try_from(36)?,
-            Position::try_from(67)?,
-            &[0, 1, 11, 12, 13],
-        );
-
-        t(
-            Position::try_from(49)?,
-            Position::try_from(143)?,
-            &[0, 1, 2, 12, 13, 14, 15, 16, 17],
-        );
-
-        Ok(())
-    }
 }
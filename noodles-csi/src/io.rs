@@ -1,6 +1,7 @@
 //! CSI I/O.
 
 mod filter_by_region;
+mod indexed_lines;
 mod indexed_reader;
 mod indexed_record;
 pub mod indexed_records;
@@ -9,8 +10,9 @@ pub mod reader;
 pub(crate) mod writer;
 
 pub use self::{
-    filter_by_region::FilterByRegion, indexed_reader::IndexedReader, indexed_record::IndexedRecord,
-    indexed_records::IndexedRecords, query::Query, reader::Reader, writer::Writer,
+    filter_by_region::FilterByRegion, indexed_lines::IndexedLines, indexed_reader::IndexedReader,
+    indexed_record::IndexedRecord, indexed_records::IndexedRecords, query::Query, reader::Reader,
+    writer::Writer,
 };
 
 pub(crate) const MAGIC_NUMBER: [u8; 4] = *b"CSI\x01";
@@ -0,0 +1,162 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+
+/// A reader over raw lines of a BGZF stream and their virtual positions.
+///
+/// This is useful for formats that are indexed but not otherwise modeled, e.g., a custom
+/// tab-delimited format. Lines are yielded without their trailing newline (`\n` or `\r\n`).
+pub struct IndexedLines<R> {
+    reader: R,
+    buf: Vec<u8>,
+}
+
+impl<R> IndexedLines<R>
+where
+    R: bgzf::io::BufRead,
+{
+    /// Creates an indexed lines reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi as csi;
+    /// let reader = bgzf::io::Reader::new(&[][..]);
+    /// let lines = csi::io::IndexedLines::new(reader);
+    /// ```
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            buf: Vec::new(),
+        }
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Reads the next line and the virtual position of its start.
+    ///
+    /// This returns `None` when the stream reaches EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Write};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi as csi;
+    ///
+    /// let mut writer = bgzf::io::Writer::new(Vec::new());
+    /// writer.write_all(b"sq0\t8\t13\nsq0\t21\t34\n")?;
+    /// let data = writer.finish()?;
+    ///
+    /// let reader = bgzf::io::Reader::new(&data[..]);
+    /// let mut lines = csi::io::IndexedLines::new(reader);
+    ///
+    /// assert_eq!(
+    ///     lines.next_line()?,
+    ///     Some((bgzf::VirtualPosition::from(0), &b"sq0\t8\t13"[..]))
+    /// );
+    ///
+    /// assert_eq!(
+    ///     lines.next_line()?,
+    ///     Some((bgzf::VirtualPosition::from(9), &b"sq0\t21\t34"[..]))
+    /// );
+    ///
+    /// assert!(lines.next_line()?.is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn next_line(&mut self) -> io::Result<Option<(bgzf::VirtualPosition, &[u8])>> {
+        const LINE_FEED: u8 = b'\n';
+        const CARRIAGE_RETURN: u8 = b'\r';
+
+        // The stream is filled first to move the position to the start of the next block or
+        // chunk, if necessary.
+        if self.reader.fill_buf()?.is_empty() {
+            return Ok(None);
+        }
+
+        let position = self.reader.virtual_position();
+
+        self.buf.clear();
+        self.reader.read_until(LINE_FEED, &mut self.buf)?;
+
+        if self.buf.ends_with(&[LINE_FEED]) {
+            self.buf.pop();
+
+            if self.buf.ends_with(&[CARRIAGE_RETURN]) {
+                self.buf.pop();
+            }
+        }
+
+        Ok(Some((position, &self.buf)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+    use crate::{binning_index::index::reference_sequence::bin::Chunk, io::Query};
+
+    #[test]
+    fn test_next_line() -> io::Result<()> {
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(b"sq0\t8\t13\r\n")?;
+        writer.flush()?;
+        writer.write_all(b"sq0\t21\t34\nsq1\t55\t89")?;
+        let data = writer.finish()?;
+
+        let mut lines = IndexedLines::new(bgzf::io::Reader::new(&data[..]));
+
+        let (position, line) = lines.next_line()?.expect("missing line");
+        assert_eq!(position, bgzf::VirtualPosition::from(0));
+        assert_eq!(line, b"sq0\t8\t13");
+
+        let second_block_position = {
+            let (position, line) = lines.next_line()?.expect("missing line");
+            assert_eq!(position.uncompressed(), 0);
+            assert!(position.compressed() > 0);
+            assert_eq!(line, b"sq0\t21\t34");
+            position
+        };
+
+        let (position, line) = lines.next_line()?.expect("missing line");
+        assert_eq!(
+            position,
+            bgzf::VirtualPosition::new(second_block_position.compressed(), 10)
+                .expect("invalid virtual position")
+        );
+        assert_eq!(line, b"sq1\t55\t89");
+
+        assert!(lines.next_line()?.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_line_with_query() -> io::Result<()> {
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(b"sq0\t8\t13\nsq0\t21\t34\nsq0\t55\t89\n")?;
+        let data = writer.finish()?;
+
+        let mut reader = bgzf::io::Reader::new(Cursor::new(data));
+        let chunks = vec![Chunk::new(
+            bgzf::VirtualPosition::from(9),
+            bgzf::VirtualPosition::from(19),
+        )];
+        let query = Query::new(&mut reader, chunks);
+        let mut lines = query.indexed_lines();
+
+        assert_eq!(
+            lines.next_line()?,
+            Some((bgzf::VirtualPosition::from(9), &b"sq0\t21\t34"[..]))
+        );
+        assert!(lines.next_line()?.is_none());
+
+        Ok(())
+    }
+}
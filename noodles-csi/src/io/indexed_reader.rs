@@ -1,7 +1,7 @@
 use std::io::{self, Read, Seek};
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{Region, region::Interval};
 
 use super::{Query, indexed_records::Record};
 use crate::BinningIndex;
@@ -25,6 +25,22 @@ where
         }
     }
 
+    /// Creates an indexed reader from an existing BGZF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_csi as csi;
+    ///
+    /// let reader = bgzf::io::Reader::new(io::empty());
+    /// let indexed_reader = csi::io::IndexedReader::from_bgzf_reader(reader, csi::Index::default());
+    /// ```
+    pub fn from_bgzf_reader(inner: bgzf::io::Reader<R>, index: I) -> Self {
+        Self { inner, index }
+    }
+
     /// Returns a reference to the underlying reader.
     pub fn get_ref(&self) -> &bgzf::io::Reader<R> {
         &self.inner
@@ -77,4 +93,87 @@ where
             .indexed_records(header)
             .filter_by_region(region))
     }
+
+    /// Returns a reader over the uncompressed data of the chunks that intersect the given
+    /// reference sequence interval.
+    ///
+    /// This does not require an index header and does not parse or filter records. Data is read
+    /// at chunk granularity, so records outside the interval may be included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor, Read};
+    /// use noodles_core::Position;
+    /// use noodles_csi as csi;
+    ///
+    /// let mut reader = csi::io::IndexedReader::new(Cursor::new(Vec::new()), csi::Index::default());
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// let result = reader.query_reference_sequence(0, (start..=end).into());
+    /// assert!(result.is_err()); // the index has no reference sequences
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_reference_sequence(
+        &mut self,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> io::Result<Query<'_, bgzf::io::Reader<R>>> {
+        let chunks = self.index.query(reference_sequence_id, interval)?;
+        Ok(Query::new(&mut self.inner, chunks))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read, Write};
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::binning_index::{
+        Indexer,
+        index::reference_sequence::{bin::Chunk, index::BinnedIndex},
+    };
+
+    #[test]
+    fn test_query_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        const LINES: [(usize, usize, &str); 3] = [
+            (0, 8, "sq0\t8\tndls\n"),
+            (1, 5, "sq1\t5\tnoodles\n"),
+            (1, 13, "sq1\t13\tcsi\n"),
+        ];
+
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        let mut indexer = Indexer::<BinnedIndex>::new(14, 5);
+
+        for (reference_sequence_id, position, line) in LINES {
+            let chunk_start = writer.virtual_position();
+            writer.write_all(line.as_bytes())?;
+            writer.flush()?;
+            let chunk_end = writer.virtual_position();
+
+            let position = Position::try_from(position)?;
+            indexer.add_record(
+                Some((reference_sequence_id, position, position, true)),
+                Chunk::new(chunk_start, chunk_end),
+            )?;
+        }
+
+        let data = writer.finish()?;
+        let index = indexer.build(2);
+
+        let mut reader = IndexedReader::new(Cursor::new(data), index);
+
+        let start = Position::try_from(1)?;
+        let end = Position::try_from(21)?;
+        let mut query = reader.query_reference_sequence(1, (start..=end).into())?;
+
+        let mut buf = String::new();
+        query.read_to_string(&mut buf)?;
+        assert_eq!(buf, "sq1\t5\tnoodles\nsq1\t13\tcsi\n");
+
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::IndexedRecords;
+use super::{IndexedLines, IndexedRecords};
 use crate::binning_index::index::{Header, reference_sequence::bin::Chunk};
 
 enum State {
@@ -42,6 +42,11 @@ where
     pub fn indexed_records(self, header: &Header) -> IndexedRecords<Self> {
         IndexedRecords::new(self, header)
     }
+
+    /// Creates an iterator over raw lines and their virtual positions.
+    pub fn indexed_lines(self) -> IndexedLines<Self> {
+        IndexedLines::new(self)
+    }
 }
 
 impl<R> Read for Query<'_, R>
@@ -88,3 +93,14 @@ where
         self.reader.consume(amt);
     }
 }
+
+impl<R> bgzf::io::Read for Query<'_, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.reader.virtual_position()
+    }
+}
+
+impl<R> bgzf::io::BufRead for Query<'_, R> where R: bgzf::io::BufRead + bgzf::io::Seek {}
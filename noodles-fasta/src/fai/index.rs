@@ -1,19 +1,43 @@
-use std::io;
+use std::{collections::HashMap, io};
 
+use bstr::BString;
 use noodles_core::Region;
 
 use super::Record;
 
 /// A FASTA index.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
-pub struct Index(Vec<Record>);
+pub struct Index {
+    records: Vec<Record>,
+    names: HashMap<BString, usize>,
+}
 
 impl Index {
+    /// Returns the record with the given name.
+    ///
+    /// This is an O(1) lookup using a map of names built when the index is created. If there are
+    /// duplicate names, the first record with the name is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::fai;
+    ///
+    /// let index = fai::Index::from(vec![
+    ///     fai::Record::new("sq0", 8, 5, 4, 5),
+    ///     fai::Record::new("sq1", 13, 20, 80, 81),
+    /// ]);
+    ///
+    /// assert_eq!(index.get(b"sq1"), Some(&fai::Record::new("sq1", 13, 20, 80, 81)));
+    /// assert!(index.get(b"sq2").is_none());
+    /// ```
+    pub fn get(&self, name: &[u8]) -> Option<&Record> {
+        self.names.get(name).map(|&i| &self.records[i])
+    }
+
     /// Returns start position of the given region.
     pub fn query(&self, region: &Region) -> io::Result<u64> {
-        self.as_ref()
-            .iter()
-            .find(|record| record.name() == region.name())
+        self.get(region.name())
             .ok_or_else(|| {
                 io::Error::new(
                     io::ErrorKind::InvalidInput,
@@ -26,18 +50,42 @@ impl Index {
 
 impl AsRef<[Record]> for Index {
     fn as_ref(&self) -> &[Record] {
-        &self.0
+        &self.records
     }
 }
 
 impl From<Vec<Record>> for Index {
     fn from(records: Vec<Record>) -> Self {
-        Self(records)
+        let mut names = HashMap::with_capacity(records.len());
+
+        for (i, record) in records.iter().enumerate() {
+            names.entry(record.name().into()).or_insert(i);
+        }
+
+        Self { records, names }
     }
 }
 
 impl From<Index> for Vec<Record> {
     fn from(index: Index) -> Self {
-        index.0
+        index.records
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let index = Index::from(vec![
+            Record::new("sq0", 8, 5, 4, 5),
+            Record::new("sq1", 13, 20, 80, 81),
+            Record::new("sq0", 21, 40, 80, 81),
+        ]);
+
+        assert_eq!(index.get(b"sq0"), Some(&Record::new("sq0", 8, 5, 4, 5)));
+        assert_eq!(index.get(b"sq1"), Some(&Record::new("sq1", 13, 20, 80, 81)));
+        assert!(index.get(b"sq2").is_none());
     }
 }
@@ -1,5 +1,6 @@
 //! FASTA filesystem operations.
 
 mod index;
+mod index_parallel;
 
-pub use self::index::index;
+pub use self::{index::index, index_parallel::index_parallel};
@@ -0,0 +1,224 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Read, Seek, SeekFrom},
+    num::NonZeroUsize,
+    panic,
+    path::Path,
+    thread,
+};
+
+use memchr::memchr;
+
+use crate::{fai, io::Indexer};
+
+/// Indexes a FASTA file using multiple threads.
+///
+/// The file is split into up to `worker_count` chunks at the starts of definition lines, and each
+/// chunk is indexed on its own thread. The resulting index is the same as [`super::index`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::num::NonZeroUsize;
+/// use noodles_fasta as fasta;
+/// let worker_count = NonZeroUsize::try_from(8)?;
+/// let index = fasta::fs::index_parallel("reference.fa", worker_count)?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn index_parallel<P>(src: P, worker_count: NonZeroUsize) -> io::Result<fai::Index>
+where
+    P: AsRef<Path>,
+{
+    let src = src.as_ref();
+    let len = File::open(src)?.metadata()?.len();
+    index_parallel_inner(|| File::open(src), len, worker_count)
+}
+
+fn index_parallel_inner<F, R>(
+    open: F,
+    len: u64,
+    worker_count: NonZeroUsize,
+) -> io::Result<fai::Index>
+where
+    F: Fn() -> io::Result<R> + Sync,
+    R: Read + Seek,
+{
+    let boundaries = find_chunk_boundaries(open()?, len, worker_count)?;
+
+    let chunks = thread::scope(|scope| {
+        let handles: Vec<_> = boundaries
+            .windows(2)
+            .map(|bounds| {
+                let (start, end) = (bounds[0], bounds[1]);
+                let open = &open;
+                scope.spawn(move || index_chunk(open()?, start, end))
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap_or_else(|e| panic::resume_unwind(e)))
+            .collect::<io::Result<Vec<_>>>()
+    })?;
+
+    let records: Vec<_> = chunks.into_iter().flatten().collect();
+
+    Ok(fai::Index::from(records))
+}
+
+// Returns the sorted and deduplicated chunk boundaries, including the start (0) and end (`len`) of
+// the source. Each boundary, excluding the end, is the start of a definition line.
+fn find_chunk_boundaries<R>(reader: R, len: u64, worker_count: NonZeroUsize) -> io::Result<Vec<u64>>
+where
+    R: Read + Seek,
+{
+    let worker_count = worker_count.get() as u64;
+
+    let mut reader = BufReader::new(reader);
+    let mut boundaries = vec![0];
+
+    for i in 1..worker_count {
+        let target = len * i / worker_count;
+
+        if target <= boundaries.last().copied().unwrap_or_default() {
+            continue;
+        }
+
+        let boundary = find_definition_start(&mut reader, target)?.unwrap_or(len);
+
+        if boundary >= len {
+            break;
+        }
+
+        boundaries.push(boundary);
+    }
+
+    boundaries.push(len);
+    boundaries.dedup();
+
+    Ok(boundaries)
+}
+
+// Returns the position of the first definition line that starts at or after the given position.
+fn find_definition_start<R>(reader: &mut R, pos: u64) -> io::Result<Option<u64>>
+where
+    R: BufRead + Seek,
+{
+    const LINE_FEED: u8 = b'\n';
+    const DEFINITION_PREFIX: u8 = b'>';
+
+    // Starting at the previous byte finds a definition line that starts exactly at `pos`.
+    let mut pos = reader.seek(SeekFrom::Start(pos - 1))?;
+
+    loop {
+        let src = reader.fill_buf()?;
+
+        if src.is_empty() {
+            return Ok(None);
+        }
+
+        let (n, is_eol) = match memchr(LINE_FEED, src) {
+            Some(i) => (i + 1, true),
+            None => (src.len(), false),
+        };
+
+        reader.consume(n);
+        pos += n as u64;
+
+        if is_eol {
+            match reader.fill_buf()?.first() {
+                Some(&DEFINITION_PREFIX) => return Ok(Some(pos)),
+                Some(_) => {}
+                None => return Ok(None),
+            }
+        }
+    }
+}
+
+fn index_chunk<R>(mut reader: R, start: u64, end: u64) -> io::Result<Vec<fai::Record>>
+where
+    R: Read + Seek,
+{
+    reader.seek(SeekFrom::Start(start))?;
+
+    let reader = BufReader::new(reader.take(end - start));
+    let mut indexer = Indexer::with_offset(reader, start);
+    let mut records = Vec::new();
+
+    while let Some(record) = indexer.index_record()? {
+        records.push(record);
+    }
+
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    const SRC: &[u8] = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n>sq2 LN:4\nacgt\n>sq3\nA\n";
+
+    #[test]
+    fn test_index_parallel_inner() -> Result<(), Box<dyn std::error::Error>> {
+        let expected = {
+            let mut indexer = Indexer::new(SRC);
+            let mut records = Vec::new();
+
+            while let Some(record) = indexer.index_record()? {
+                records.push(record);
+            }
+
+            fai::Index::from(records)
+        };
+
+        let len = SRC.len() as u64;
+
+        for worker_count in 1..=8 {
+            let worker_count = NonZeroUsize::try_from(worker_count)?;
+            let actual = index_parallel_inner(|| Ok(Cursor::new(SRC)), len, worker_count)?;
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_chunk_boundaries() -> Result<(), Box<dyn std::error::Error>> {
+        let len = SRC.len() as u64;
+
+        let worker_count = NonZeroUsize::try_from(1)?;
+        assert_eq!(
+            find_chunk_boundaries(Cursor::new(SRC), len, worker_count)?,
+            [0, len]
+        );
+
+        let worker_count = NonZeroUsize::try_from(2)?;
+        assert_eq!(
+            find_chunk_boundaries(Cursor::new(SRC), len, worker_count)?,
+            [0, 28, len]
+        );
+
+        let worker_count = NonZeroUsize::try_from(64)?;
+        assert_eq!(
+            find_chunk_boundaries(Cursor::new(SRC), len, worker_count)?,
+            [0, 10, 28, 43, len]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index_parallel_inner_with_invalid_record() -> Result<(), Box<dyn std::error::Error>> {
+        let src = b">sq0\nACGT\n>sq1\n";
+        let worker_count = NonZeroUsize::try_from(2)?;
+
+        assert!(matches!(
+            index_parallel_inner(|| Ok(Cursor::new(&src[..])), src.len() as u64, worker_count),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
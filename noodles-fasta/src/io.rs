@@ -3,13 +3,16 @@
 pub mod indexed_reader;
 mod indexer;
 pub mod reader;
+mod rewrap;
 pub mod writer;
 
 use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
 use noodles_bgzf as bgzf;
 
-pub use self::{indexed_reader::IndexedReader, indexer::Indexer, reader::Reader, writer::Writer};
+pub use self::{
+    indexed_reader::IndexedReader, indexer::Indexer, reader::Reader, rewrap::rewrap, writer::Writer,
+};
 
 /// A buffered FASTA reader.
 pub enum BufReader<R> {
@@ -68,4 +68,11 @@ where
     pub fn query(&mut self, region: &Region) -> io::Result<Record> {
         self.inner.query(&self.index, region)
     }
+
+    /// Returns a record of the given region, checking that it is within the reference sequence.
+    ///
+    /// See [`Reader::query_checked`](super::Reader::query_checked).
+    pub fn query_checked(&mut self, region: &Region) -> io::Result<Record> {
+        self.inner.query_checked(&self.index, region)
+    }
 }
@@ -34,7 +34,12 @@ where
     /// let mut indexer = fasta::io::Indexer::new(io::empty());
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner, offset: 0 }
+        Self::with_offset(inner, 0)
+    }
+
+    // Creates a FASTA indexer for a stream that starts at the given offset of the source.
+    pub(crate) fn with_offset(inner: R, offset: u64) -> Self {
+        Self { inner, offset }
     }
 
     /// Consumes a single sequence line.
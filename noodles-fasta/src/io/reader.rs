@@ -7,7 +7,10 @@ pub mod sequence;
 
 pub use self::{builder::Builder, records::Records};
 
-use std::io::{self, BufRead, Seek, SeekFrom};
+use std::{
+    io::{self, BufRead, Seek, SeekFrom},
+    mem,
+};
 
 use noodles_core::{Position, Region};
 
@@ -19,6 +22,7 @@ pub(crate) const DEFINITION_PREFIX: u8 = b'>';
 /// A FASTA reader.
 pub struct Reader<R> {
     inner: R,
+    line_buf: String,
 }
 
 impl<R> Reader<R> {
@@ -79,7 +83,10 @@ where
     /// let mut reader = fasta::io::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            line_buf: String::new(),
+        }
     }
 
     /// Reads a raw definition line.
@@ -144,6 +151,61 @@ where
         read_sequence(&mut self.inner, buf)
     }
 
+    /// Reads a record.
+    ///
+    /// This reads a definition and its sequence into the given record. The record's buffers are
+    /// reused when possible, i.e., the sequence buffer is cleared and reused if it is not shared
+    /// with another record (e.g., a clone).
+    ///
+    /// The position of the stream is expected to be at the start or at the start of another
+    /// definition.
+    ///
+    /// If successful, this returns the number of bytes read for the definition. If the number of
+    /// bytes read is 0, the stream reached EOF.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let data = b">sq0\nACGT\n>sq1\nNNNN\nNNNN\nNN\n";
+    /// let mut reader = fasta::io::Reader::new(&data[..]);
+    ///
+    /// let mut record = fasta::Record::default();
+    ///
+    /// reader.read_record(&mut record)?;
+    /// assert_eq!(record.name(), b"sq0");
+    /// assert_eq!(record.sequence().as_ref(), b"ACGT");
+    ///
+    /// reader.read_record(&mut record)?;
+    /// assert_eq!(record.name(), b"sq1");
+    /// assert_eq!(record.sequence().as_ref(), b"NNNNNNNNNN");
+    ///
+    /// assert_eq!(reader.read_record(&mut record)?, 0);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        use crate::record::{Sequence, definition::parse_definition};
+
+        self.line_buf.clear();
+
+        let n = read_definition(&mut self.inner, &mut self.line_buf)?;
+
+        if n == 0 {
+            return Ok(0);
+        }
+
+        parse_definition(&self.line_buf, record.definition_mut())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let mut sequence_buf = mem::take(record.sequence_mut()).into_reusable_buf();
+        self.read_sequence(&mut sequence_buf)?;
+        *record.sequence_mut() = Sequence::from(sequence_buf);
+
+        Ok(n)
+    }
+
     /// Returns a sequence reader.
     ///
     /// A [`sequence::Reader`] can be used for lower-level reading of the raw sequence.
@@ -262,6 +324,61 @@ where
 
         Ok(Record::new(definition, sequence))
     }
+
+    /// Returns a record of the given region, checking that it is within the reference sequence.
+    ///
+    /// Unlike [`Self::query`], which returns a truncated sequence for a region that extends past
+    /// the end of the reference sequence, this returns an error if a bounded start or end of the
+    /// region is greater than the length of the reference sequence. Unbounded ends are resolved
+    /// to the end of the reference sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_fasta::{self as fasta, fai};
+    ///
+    /// let data = b">sq0\nACGT\n";
+    /// let index = fai::Index::from(vec![fai::Record::new("sq0", 4, 5, 4, 5)]);
+    ///
+    /// let mut reader = fasta::io::Reader::new(Cursor::new(data));
+    ///
+    /// let region = "sq0:2-4".parse()?;
+    /// let record = reader.query_checked(&index, &region)?;
+    /// assert_eq!(record.sequence().as_ref(), b"CGT");
+    ///
+    /// let region = "sq0:2-1000".parse()?;
+    /// assert!(matches!(
+    ///     reader.query_checked(&index, &region),
+    ///     Err(e) if e.kind() == io::ErrorKind::InvalidInput
+    /// ));
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_checked(&mut self, index: &fai::Index, region: &Region) -> io::Result<Record> {
+        let record = index.get(region.name()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid reference sequence name: {}", region.name()),
+            )
+        })?;
+
+        let interval = region.interval();
+        let length = record.length();
+
+        let is_out_of_bounds = [interval.start(), interval.end()]
+            .into_iter()
+            .flatten()
+            .any(|position| !u64::try_from(usize::from(position)).is_ok_and(|n| n <= length));
+
+        if is_out_of_bounds {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("region out of bounds: {region} (reference sequence length: {length})"),
+            ));
+        }
+
+        self.query(index, region)
+    }
 }
 
 // Reads all bytes until a line feed ('\n') or EOF is reached.
@@ -308,6 +425,74 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_record() -> io::Result<()> {
+        use bstr::BStr;
+
+        let data = b">sq0 LN:4\nACGT\n>sq1\nNN\nN\n";
+        let mut reader = Reader::new(&data[..]);
+        let mut record = Record::default();
+
+        assert_eq!(reader.read_record(&mut record)?, 10);
+        assert_eq!(record.name(), b"sq0");
+        assert_eq!(record.description(), Some(BStr::new("LN:4")));
+        assert_eq!(record.sequence().as_ref(), b"ACGT");
+        let ptr = record.sequence().as_ref().as_ptr();
+
+        assert_eq!(reader.read_record(&mut record)?, 5);
+        assert_eq!(record.name(), b"sq1");
+        assert!(record.description().is_none());
+        assert_eq!(record.sequence().as_ref(), b"NNN");
+        assert_eq!(record.sequence().as_ref().as_ptr(), ptr);
+
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        let data = b"sq0\nACGT\n";
+        let mut reader = Reader::new(&data[..]);
+        assert!(matches!(
+            reader.read_record(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_checked() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Cursor;
+
+        let data = b">sq0\nNNNN\n>sq1\nACGT\nAC\n";
+        let index = fai::Index::from(vec![
+            fai::Record::new("sq0", 4, 5, 4, 5),
+            fai::Record::new("sq1", 6, 15, 4, 5),
+        ]);
+
+        let mut reader = Reader::new(Cursor::new(data));
+
+        let region = "sq1".parse()?;
+        let record = reader.query_checked(&index, &region)?;
+        assert_eq!(record.sequence().as_ref(), b"ACGTAC");
+
+        let region = "sq1:5-6".parse()?;
+        let record = reader.query_checked(&index, &region)?;
+        assert_eq!(record.sequence().as_ref(), b"AC");
+
+        let region = "sq1:6".parse()?;
+        let record = reader.query_checked(&index, &region)?;
+        assert_eq!(record.sequence().as_ref(), b"C");
+
+        for raw_region in ["sq0:2-5", "sq0:5-8", "sq0:5", "sq2"] {
+            let region = raw_region.parse()?;
+
+            assert!(matches!(
+                reader.query_checked(&index, &region),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
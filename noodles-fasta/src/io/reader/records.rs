@@ -1,15 +1,18 @@
 use std::io::{self, BufRead};
 
-use crate::{Record, record::Sequence};
+use crate::Record;
 
 use super::Reader;
 
 /// An iterator over records of a FASTA reader.
 ///
 /// This is created by calling [`Reader::records`].
+///
+/// Records are read into a buffer that is reused between iterations. The sequence buffer is only
+/// reused if the previously returned record was dropped before the next call.
 pub struct Records<'a, R> {
     inner: &'a mut Reader<R>,
-    line_buf: String,
+    record: Record,
 }
 
 impl<'a, R> Records<'a, R>
@@ -19,7 +22,7 @@ where
     pub(crate) fn new(inner: &'a mut Reader<R>) -> Self {
         Self {
             inner,
-            line_buf: String::new(),
+            record: Record::default(),
         }
     }
 }
@@ -31,26 +34,9 @@ where
     type Item = io::Result<Record>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.line_buf.clear();
-
-        match self.inner.read_definition(&mut self.line_buf) {
-            Ok(0) => return None,
-            Ok(_) => {}
-            Err(e) => return Some(Err(e)),
-        }
-
-        let definition = match self.line_buf.parse() {
-            Ok(d) => d,
-            Err(e) => return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e))),
-        };
-
-        let mut sequence_buf = Vec::new();
-
-        match self.inner.read_sequence(&mut sequence_buf) {
-            Ok(_) => {
-                let record = Record::new(definition, Sequence::from(sequence_buf));
-                Some(Ok(record))
-            }
+        match self.inner.read_record(&mut self.record) {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(self.record.clone())),
             Err(e) => Some(Err(e)),
         }
     }
@@ -0,0 +1,132 @@
+use std::io::{self, BufRead, Write};
+
+use super::{Reader, reader::DEFINITION_PREFIX};
+
+const LINE_FEED: &[u8] = b"\n";
+
+/// Rewraps the sequences of FASTA records to a fixed line width.
+///
+/// Records are streamed from the reader to the writer. Definitions are written verbatim, and each
+/// sequence is hard wrapped at `line_base_count` bases per line, with the last line of a sequence
+/// holding the remaining bases. Sequences are never fully loaded into memory.
+///
+/// # Errors
+///
+/// This returns an error if `line_base_count` is 0 or a definition is invalid.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_fasta as fasta;
+///
+/// let src = b">sq0 LN:10\nACG\nTAC\nGTA\nC\n>sq1\nNN\n";
+/// let mut dst = Vec::new();
+/// fasta::io::rewrap(&src[..], &mut dst, 4)?;
+///
+/// assert_eq!(dst, b">sq0 LN:10\nACGT\nACGT\nAC\n>sq1\nNN\n");
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn rewrap<R, W>(reader: R, mut writer: W, line_base_count: usize) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    if line_base_count == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "invalid line base count",
+        ));
+    }
+
+    let mut reader = Reader::new(reader);
+    let mut definition = String::new();
+
+    loop {
+        definition.clear();
+
+        if reader.read_definition(&mut definition)? == 0 {
+            break;
+        }
+
+        if !definition.as_bytes().starts_with(&[DEFINITION_PREFIX]) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid definition",
+            ));
+        }
+
+        writer.write_all(definition.as_bytes())?;
+        writer.write_all(LINE_FEED)?;
+
+        rewrap_sequence(&mut reader.sequence_reader(), &mut writer, line_base_count)?;
+    }
+
+    Ok(())
+}
+
+fn rewrap_sequence<R, W>(reader: &mut R, writer: &mut W, line_base_count: usize) -> io::Result<()>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut column = 0;
+
+    loop {
+        let src = reader.fill_buf()?;
+
+        if src.is_empty() {
+            break;
+        }
+
+        let len = src.len().min(line_base_count - column);
+        writer.write_all(&src[..len])?;
+        reader.consume(len);
+
+        column += len;
+
+        if column == line_base_count {
+            writer.write_all(LINE_FEED)?;
+            column = 0;
+        }
+    }
+
+    if column > 0 {
+        writer.write_all(LINE_FEED)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rewrap() -> io::Result<()> {
+        let src = b">sq0 LN:8\r\nACGT\r\nACGT\r\n\n>sq1\n>sq2\nAC\nGTA\nCGTA\nC\n";
+        let mut dst = Vec::new();
+        rewrap(&src[..], &mut dst, 3)?;
+
+        let expected = b">sq0 LN:8\nACG\nTAC\nGT\n>sq1\n>sq2\nACG\nTAC\nGTA\nC\n";
+        assert_eq!(dst, expected);
+
+        let src = b">sq0\nACG\nTAC\n";
+        let mut dst = Vec::new();
+        rewrap(&src[..], &mut dst, 80)?;
+        assert_eq!(dst, b">sq0\nACGTAC\n");
+
+        assert!(matches!(
+            rewrap(&src[..], Vec::new(), 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let src = b"ACGT\n";
+        assert!(matches!(
+            rewrap(&src[..], Vec::new(), 80),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ use bstr::BStr;
 pub use self::{definition::Definition, sequence::Sequence};
 
 /// A FASTA record.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Record {
     definition: Definition,
     sequence: Sequence,
@@ -49,6 +49,22 @@ impl Record {
         &self.definition
     }
 
+    /// Returns a mutable reference to the record definition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    ///
+    /// let mut record = fasta::Record::new(Definition::new("sq0", None), Sequence::default());
+    /// *record.definition_mut() = Definition::new("sq1", None);
+    ///
+    /// assert_eq!(record.name(), b"sq1");
+    /// ```
+    pub fn definition_mut(&mut self) -> &mut Definition {
+        &mut self.definition
+    }
+
     /// Returns the record name.
     ///
     /// # Examples
@@ -100,4 +116,22 @@ impl Record {
     pub fn sequence(&self) -> &Sequence {
         &self.sequence
     }
+
+    /// Returns a mutable reference to the sequence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    ///
+    /// let mut record = fasta::Record::new(Definition::new("sq0", None), Sequence::default());
+    ///
+    /// let sequence = Sequence::from(b"ACGT".to_vec());
+    /// *record.sequence_mut() = sequence.clone();
+    ///
+    /// assert_eq!(record.sequence(), &sequence);
+    /// ```
+    pub fn sequence_mut(&mut self) -> &mut Sequence {
+        &mut self.sequence
+    }
 }
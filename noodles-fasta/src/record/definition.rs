@@ -13,7 +13,7 @@ const PREFIX: char = '>';
 ///
 /// A definition represents a definition line, i.e, a reference sequence name and, optionally, a
 /// description.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Definition {
     name: BString,
     description: Option<BString>,
@@ -109,24 +109,41 @@ impl FromStr for Definition {
     type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Err(ParseError::Empty);
-        } else if !s.starts_with(PREFIX) {
-            return Err(ParseError::MissingPrefix);
-        }
+        let mut definition = Self::default();
+        parse_definition(s, &mut definition)?;
+        Ok(definition)
+    }
+}
 
-        let line = &s[1..];
-        let mut components = line.splitn(2, |c: char| c.is_ascii_whitespace());
+// Parses a raw definition into an existing definition, reusing its buffers.
+pub(crate) fn parse_definition(s: &str, definition: &mut Definition) -> Result<(), ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    } else if !s.starts_with(PREFIX) {
+        return Err(ParseError::MissingPrefix);
+    }
 
-        let name = components
-            .next()
-            .and_then(|s| if s.is_empty() { None } else { Some(s.into()) })
-            .ok_or(ParseError::MissingName)?;
+    let line = &s[1..];
+    let mut components = line.splitn(2, |c: char| c.is_ascii_whitespace());
 
-        let description = components.next().map(|s| s.trim().into());
+    let name = components
+        .next()
+        .filter(|t| !t.is_empty())
+        .ok_or(ParseError::MissingName)?;
 
-        Ok(Self { name, description })
+    definition.name.clear();
+    definition.name.extend_from_slice(name.as_bytes());
+
+    match components.next().map(|t| t.trim()) {
+        Some(raw_description) => {
+            let description = definition.description.get_or_insert_with(BString::default);
+            description.clear();
+            description.extend_from_slice(raw_description.as_bytes());
+        }
+        None => definition.description = None,
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
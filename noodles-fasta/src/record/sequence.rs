@@ -1,13 +1,19 @@
 //! FASTA record sequence.
 
+mod checksum;
 pub mod complement;
+pub mod translate;
 
-pub use self::complement::Complement;
+pub use self::{
+    checksum::normalized_md5,
+    complement::Complement,
+    translate::{GeneticCode, TranslateError},
+};
 
 use std::ops::Index;
 
 use bytes::Bytes;
-use noodles_core::{position::SequenceIndex, region::Interval};
+use noodles_core::{BaseComposition, position::SequenceIndex, region::Interval};
 
 /// A FASTA record sequence.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
@@ -40,6 +46,19 @@ impl Sequence {
         self.0.is_empty()
     }
 
+    /// Returns the base composition of the sequence, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::Sequence;
+    /// let sequence = Sequence::from(b"ACgtNN".to_vec());
+    /// assert_eq!(sequence.base_composition().gc_fraction(), Some(0.5));
+    /// ```
+    pub fn base_composition(&self) -> BaseComposition {
+        BaseComposition::new(&self.0)
+    }
+
     /// Returns a reference to a base at or slice of bases between the given index.
     ///
     /// # Examples
@@ -136,6 +155,47 @@ impl Sequence {
     pub fn complement(&self) -> Complement<'_> {
         Complement::new(self.0.iter())
     }
+
+    /// Translates the sequence to amino acids in the given forward reading frame.
+    ///
+    /// The frame is the 0-based offset of the first codon and must be 0, 1, or 2. Bases are case
+    /// insensitive, and `U` is read as `T`.
+    ///
+    /// Stop codons translate to `*`. A codon with ambiguous bases (IUPAC codes) translates to an
+    /// amino acid if all its possible codons translate to the same amino acid; otherwise, it
+    /// translates to `X`. An incomplete trailing codon is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::{Sequence, sequence::GeneticCode};
+    ///
+    /// let sequence = Sequence::from(b"ATGGCNTGAAGNTA".to_vec());
+    ///
+    /// assert_eq!(sequence.translate(0, GeneticCode::Standard)?, b"MA*X");
+    /// assert_eq!(sequence.translate(0, GeneticCode::VertebrateMitochondrial)?, b"MAWX");
+    /// assert_eq!(sequence.translate(1, GeneticCode::Standard)?, b"WXEX");
+    /// # Ok::<_, noodles_fasta::record::sequence::TranslateError>(())
+    /// ```
+    pub fn translate(
+        &self,
+        frame: u8,
+        genetic_code: GeneticCode,
+    ) -> Result<Vec<u8>, TranslateError> {
+        translate::translate(&self.0, frame, genetic_code)
+    }
+
+    // Returns the underlying buffer, cleared, if it is not shared. Otherwise, a new buffer is
+    // returned.
+    pub(crate) fn into_reusable_buf(self) -> Vec<u8> {
+        if self.0.is_unique() {
+            let mut buf = Vec::from(self.0);
+            buf.clear();
+            buf
+        } else {
+            Vec::new()
+        }
+    }
 }
 
 impl AsRef<[u8]> for Sequence {
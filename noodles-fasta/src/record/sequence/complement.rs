@@ -46,7 +46,20 @@ impl ExactSizeIterator for Complement<'_> {}
 
 impl FusedIterator for Complement<'_> {}
 
-fn complement(b: u8) -> Result<u8, ComplementError> {
+/// Returns the complement of a base.
+///
+/// Bases are IUPAC nucleotide codes and are case-sensitive, i.e., the complement of a lowercase
+/// base is lowercase. `U` is complemented to `A`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fasta::record::sequence::complement::complement;
+/// assert_eq!(complement(b'A'), Ok(b'T'));
+/// assert_eq!(complement(b'r'), Ok(b'y'));
+/// assert!(complement(b'X').is_err());
+/// ```
+pub fn complement(b: u8) -> Result<u8, ComplementError> {
     match b {
         b'A' => Ok(b'T'),
         b'C' => Ok(b'G'),
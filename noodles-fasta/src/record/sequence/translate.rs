@@ -0,0 +1,177 @@
+//! FASTA record sequence translation.
+
+mod genetic_code;
+
+use std::{error, fmt};
+
+use bstr::ByteSlice;
+
+pub use self::genetic_code::{CodonTable, GeneticCode, TableError};
+
+const CODON_LEN: usize = 3;
+
+/// An error returned when a sequence fails to translate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TranslateError {
+    /// The reading frame is invalid.
+    InvalidFrame(u8),
+    /// A base is invalid.
+    InvalidBase(u8),
+}
+
+impl error::Error for TranslateError {}
+
+impl fmt::Display for TranslateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidFrame(frame) => {
+                write!(f, "invalid frame: expected 0, 1, or 2, got {frame}")
+            }
+            Self::InvalidBase(b) => {
+                let bytes = [*b];
+                write!(f, "invalid base: {:?}", bytes.as_bstr())
+            }
+        }
+    }
+}
+
+pub(super) fn translate(
+    src: &[u8],
+    frame: u8,
+    genetic_code: GeneticCode,
+) -> Result<Vec<u8>, TranslateError> {
+    if frame > 2 {
+        return Err(TranslateError::InvalidFrame(frame));
+    }
+
+    let src = src.get(usize::from(frame)..).unwrap_or_default();
+    let amino_acids = genetic_code.amino_acids();
+
+    src.chunks_exact(CODON_LEN)
+        .map(|codon| translate_codon(amino_acids, codon))
+        .collect()
+}
+
+fn translate_codon(amino_acids: &[u8; 64], codon: &[u8]) -> Result<u8, TranslateError> {
+    const AMBIGUOUS: u8 = b'X';
+
+    let codon = <&[u8; CODON_LEN]>::try_from(codon).expect("invalid codon length");
+
+    let mut amino_acid = None;
+
+    for i in codon_indices(codon)? {
+        let candidate = amino_acids[i];
+
+        match amino_acid {
+            None => amino_acid = Some(candidate),
+            Some(a) if a == candidate => {}
+            Some(_) => return Ok(AMBIGUOUS),
+        }
+    }
+
+    Ok(amino_acid.unwrap_or(AMBIGUOUS))
+}
+
+// Returns the indices of all codons a codon with ambiguous bases represents.
+fn codon_indices(codon: &[u8; CODON_LEN]) -> Result<impl Iterator<Item = usize>, TranslateError> {
+    let masks = [
+        base_mask(codon[0])?,
+        base_mask(codon[1])?,
+        base_mask(codon[2])?,
+    ];
+
+    Ok(indices(masks[0]).flat_map(move |i| {
+        indices(masks[1]).flat_map(move |j| indices(masks[2]).map(move |k| 16 * i + 4 * j + k))
+    }))
+}
+
+// Returns the set of nucleotides a base represents, where bits 0 to 3 are T, C, A, and G,
+// respectively.
+fn base_mask(b: u8) -> Result<u8, TranslateError> {
+    const T: u8 = 0b0001;
+    const C: u8 = 0b0010;
+    const A: u8 = 0b0100;
+    const G: u8 = 0b1000;
+
+    match b.to_ascii_uppercase() {
+        b'T' | b'U' => Ok(T),
+        b'C' => Ok(C),
+        b'A' => Ok(A),
+        b'G' => Ok(G),
+        b'W' => Ok(A | T),
+        b'S' => Ok(C | G),
+        b'M' => Ok(A | C),
+        b'K' => Ok(G | T),
+        b'R' => Ok(A | G),
+        b'Y' => Ok(C | T),
+        b'B' => Ok(C | G | T),
+        b'D' => Ok(A | G | T),
+        b'H' => Ok(A | C | T),
+        b'V' => Ok(A | C | G),
+        b'N' => Ok(A | C | G | T),
+        _ => Err(TranslateError::InvalidBase(b)),
+    }
+}
+
+fn indices(mask: u8) -> impl Iterator<Item = usize> {
+    (0..4).filter(move |i| mask & (1 << i) != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_translate() -> Result<(), TranslateError> {
+        let src = b"ATGGCCTGAAGATAA";
+
+        assert_eq!(translate(src, 0, GeneticCode::Standard)?, b"MA*R*");
+        assert_eq!(translate(src, 1, GeneticCode::Standard)?, b"WPED");
+        assert_eq!(translate(src, 2, GeneticCode::Standard)?, b"GLKI");
+
+        assert_eq!(
+            translate(src, 0, GeneticCode::VertebrateMitochondrial)?,
+            b"MAW**"
+        );
+
+        // incomplete trailing codon
+        assert_eq!(translate(b"ATGGC", 0, GeneticCode::Standard)?, b"M");
+        assert!(translate(b"AT", 0, GeneticCode::Standard)?.is_empty());
+        assert!(translate(b"A", 2, GeneticCode::Standard)?.is_empty());
+
+        assert_eq!(
+            translate(src, 3, GeneticCode::Standard),
+            Err(TranslateError::InvalidFrame(3))
+        );
+
+        assert_eq!(
+            translate(b"ATG-CC", 0, GeneticCode::Standard),
+            Err(TranslateError::InvalidBase(b'-'))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_translate_codon() -> Result<(), TranslateError> {
+        let amino_acids = GeneticCode::Standard.amino_acids();
+
+        assert_eq!(translate_codon(amino_acids, b"aug")?, b'M');
+        assert_eq!(translate_codon(amino_acids, b"TAA")?, b'*');
+
+        // ambiguous bases that resolve to a single amino acid
+        assert_eq!(translate_codon(amino_acids, b"GCN")?, b'A');
+        assert_eq!(translate_codon(amino_acids, b"TAR")?, b'*');
+        assert_eq!(translate_codon(amino_acids, b"YTG")?, b'L');
+
+        // ambiguous bases that resolve to multiple amino acids
+        assert_eq!(translate_codon(amino_acids, b"NNN")?, b'X');
+        assert_eq!(translate_codon(amino_acids, b"ATN")?, b'X');
+
+        let amino_acids = GeneticCode::VertebrateMitochondrial.amino_acids();
+        assert_eq!(translate_codon(amino_acids, b"ATR")?, b'M');
+        assert_eq!(translate_codon(amino_acids, b"AGR")?, b'*');
+
+        Ok(())
+    }
+}
@@ -0,0 +1,565 @@
+use std::{error, fmt};
+
+use bstr::ByteSlice;
+
+use super::{base_mask, codon_indices};
+
+const CODON_COUNT: usize = 64;
+
+const STOP: u8 = b'*';
+
+/// A genetic code, i.e., a codon table.
+///
+/// The named variants correspond to the NCBI genetic code tables. Other NCBI tables (see
+/// [`Self::from_ncbi`]) and user-defined tables (see [`Self::from_table`]) are represented by a
+/// [`CodonTable`]. A codon table that is the same as a named variant is always represented by
+/// that variant, so genetic codes compare equal regardless of how they were created.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum GeneticCode {
+    /// The standard code (table 1).
+    #[default]
+    Standard,
+    /// The vertebrate mitochondrial code (table 2).
+    VertebrateMitochondrial,
+    /// A codon table.
+    Table(CodonTable),
+}
+
+impl GeneticCode {
+    /// Returns the genetic code of the given NCBI translation table ID.
+    ///
+    /// This returns `None` if the ID is not a known table. Tables 27, 28, and 31, whose stop
+    /// codons are context-dependent, are not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::sequence::GeneticCode;
+    ///
+    /// assert_eq!(GeneticCode::from_ncbi(1), Some(GeneticCode::Standard));
+    ///
+    /// let bacterial = GeneticCode::from_ncbi(11).expect("missing table");
+    /// assert!(bacterial.is_start_codon(b"GTG"));
+    ///
+    /// assert!(GeneticCode::from_ncbi(7).is_none());
+    /// ```
+    pub fn from_ncbi(id: u8) -> Option<Self> {
+        match id {
+            1 => Some(Self::Standard),
+            2 => Some(Self::VertebrateMitochondrial),
+            _ => NCBI_TABLES
+                .iter()
+                .find(|(table_id, _, _)| *table_id == id)
+                .map(|(_, amino_acids, starts)| {
+                    Self::Table(CodonTable::from_ncbi(amino_acids, starts))
+                }),
+        }
+    }
+
+    /// Creates a genetic code from an explicit codon to amino acid mapping.
+    ///
+    /// All 64 codons must be given exactly once using the bases `A`, `C`, `G`, and `T` (or `U`).
+    /// Amino acids are single-letter codes, and `*` marks a stop codon. The only start codon is
+    /// `ATG`; use [`Self::with_start_codons`] to change it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::{Sequence, sequence::GeneticCode};
+    ///
+    /// let mut table = Vec::new();
+    ///
+    /// for a in *b"TCAG" {
+    ///     for b in *b"TCAG" {
+    ///         for c in *b"TCAG" {
+    ///             table.push(([a, b, c], b'G'));
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// table[14].1 = b'*'; // TGA
+    ///
+    /// let genetic_code = GeneticCode::from_table(&table)?;
+    /// assert!(genetic_code.is_stop_codon(b"TGA"));
+    ///
+    /// let sequence = Sequence::from(b"ATGTGA".to_vec());
+    /// assert_eq!(sequence.translate(0, genetic_code)?, b"G*");
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_table(table: &[([u8; 3], u8)]) -> Result<Self, TableError> {
+        let mut amino_acids: [Option<u8>; CODON_COUNT] = [None; CODON_COUNT];
+
+        for (codon, amino_acid) in table {
+            let i = codon_index(codon)?;
+
+            if !amino_acid.is_ascii_uppercase() && *amino_acid != STOP {
+                return Err(TableError::InvalidAminoAcid(*amino_acid));
+            }
+
+            if amino_acids[i].replace(*amino_acid).is_some() {
+                return Err(TableError::DuplicateCodon(*codon));
+            }
+        }
+
+        let mut table = CodonTable {
+            amino_acids: [0; CODON_COUNT],
+            start_codons: 0,
+        };
+
+        for (i, amino_acid) in amino_acids.into_iter().enumerate() {
+            table.amino_acids[i] = amino_acid.ok_or(TableError::MissingCodon(codon(i)))?;
+        }
+
+        table.start_codons = 1 << codon_index(b"ATG")?;
+
+        Ok(Self::from_codon_table(table))
+    }
+
+    /// Returns this genetic code with the given start codons.
+    ///
+    /// This replaces all start codons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::sequence::GeneticCode;
+    ///
+    /// let genetic_code = GeneticCode::Standard.with_start_codons(&[*b"ATG", *b"GTG"])?;
+    /// assert!(genetic_code.is_start_codon(b"GTG"));
+    /// assert!(!genetic_code.is_start_codon(b"TTG"));
+    /// # Ok::<_, noodles_fasta::record::sequence::translate::TableError>(())
+    /// ```
+    pub fn with_start_codons(self, start_codons: &[[u8; 3]]) -> Result<Self, TableError> {
+        let mut table = self.table();
+        table.start_codons = 0;
+
+        for codon in start_codons {
+            table.start_codons |= 1 << codon_index(codon)?;
+        }
+
+        Ok(Self::from_codon_table(table))
+    }
+
+    /// Returns whether the given codon is a start codon.
+    ///
+    /// A codon with ambiguous bases is a start codon if all its possible codons are start codons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::sequence::GeneticCode;
+    /// assert!(GeneticCode::Standard.is_start_codon(b"ATG"));
+    /// assert!(GeneticCode::Standard.is_start_codon(b"YTG"));
+    /// assert!(!GeneticCode::Standard.is_start_codon(b"GTG"));
+    /// ```
+    pub fn is_start_codon(&self, codon: &[u8]) -> bool {
+        let start_codons = self.table().start_codons;
+        all_codons(codon, |i| start_codons & (1 << i) != 0)
+    }
+
+    /// Returns whether the given codon is a stop codon.
+    ///
+    /// A codon with ambiguous bases is a stop codon if all its possible codons are stop codons.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::record::sequence::GeneticCode;
+    /// assert!(GeneticCode::Standard.is_stop_codon(b"TGA"));
+    /// assert!(GeneticCode::Standard.is_stop_codon(b"TAR"));
+    /// assert!(!GeneticCode::VertebrateMitochondrial.is_stop_codon(b"TGA"));
+    /// ```
+    pub fn is_stop_codon(&self, codon: &[u8]) -> bool {
+        let amino_acids = self.amino_acids();
+        all_codons(codon, |i| amino_acids[i] == STOP)
+    }
+
+    // Amino acids indexed by codon, where each base is ordered TCAG, e.g., TTT = 0, TTC = 1, ...,
+    // GGG = 63.
+    pub(super) fn amino_acids(&self) -> &[u8; 64] {
+        match self {
+            Self::Standard => STANDARD_AMINO_ACIDS,
+            Self::VertebrateMitochondrial => VERTEBRATE_MITOCHONDRIAL_AMINO_ACIDS,
+            Self::Table(table) => &table.amino_acids,
+        }
+    }
+
+    fn from_codon_table(table: CodonTable) -> Self {
+        [Self::Standard, Self::VertebrateMitochondrial]
+            .into_iter()
+            .find(|genetic_code| genetic_code.table() == table)
+            .unwrap_or(Self::Table(table))
+    }
+
+    fn table(&self) -> CodonTable {
+        match self {
+            Self::Standard => CodonTable::from_ncbi(STANDARD_AMINO_ACIDS, STANDARD_STARTS),
+            Self::VertebrateMitochondrial => CodonTable::from_ncbi(
+                VERTEBRATE_MITOCHONDRIAL_AMINO_ACIDS,
+                VERTEBRATE_MITOCHONDRIAL_STARTS,
+            ),
+            Self::Table(table) => *table,
+        }
+    }
+}
+
+/// A codon table.
+///
+/// This is created by [`GeneticCode::from_ncbi`], [`GeneticCode::from_table`], or
+/// [`GeneticCode::with_start_codons`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct CodonTable {
+    amino_acids: [u8; CODON_COUNT],
+    // Bit `i` is set if codon `i` is a start codon.
+    start_codons: u64,
+}
+
+impl CodonTable {
+    fn from_ncbi(amino_acids: &[u8; CODON_COUNT], starts: &[u8; CODON_COUNT]) -> Self {
+        const START: u8 = b'M';
+
+        let start_codons = starts
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| **s == START)
+            .fold(0, |mask, (i, _)| mask | (1 << i));
+
+        Self {
+            amino_acids: *amino_acids,
+            start_codons,
+        }
+    }
+}
+
+/// An error returned when a codon table is invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TableError {
+    /// A codon is invalid.
+    InvalidCodon([u8; 3]),
+    /// An amino acid is invalid.
+    InvalidAminoAcid(u8),
+    /// A codon is duplicated.
+    DuplicateCodon([u8; 3]),
+    /// A codon is missing.
+    MissingCodon([u8; 3]),
+}
+
+impl error::Error for TableError {}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCodon(codon) => write!(f, "invalid codon: {:?}", codon.as_bstr()),
+            Self::InvalidAminoAcid(b) => {
+                let bytes = [*b];
+                write!(f, "invalid amino acid: {:?}", bytes.as_bstr())
+            }
+            Self::DuplicateCodon(codon) => write!(f, "duplicate codon: {:?}", codon.as_bstr()),
+            Self::MissingCodon(codon) => write!(f, "missing codon: {:?}", codon.as_bstr()),
+        }
+    }
+}
+
+fn codon_index(codon: &[u8; 3]) -> Result<usize, TableError> {
+    let mut indices = codon_indices(codon).map_err(|_| TableError::InvalidCodon(*codon))?;
+
+    match (indices.next(), indices.next()) {
+        (Some(i), None) => Ok(i),
+        _ => Err(TableError::InvalidCodon(*codon)),
+    }
+}
+
+fn codon(i: usize) -> [u8; 3] {
+    const BASES: [u8; 4] = *b"TCAG";
+    [BASES[i / 16], BASES[(i / 4) % 4], BASES[i % 4]]
+}
+
+fn all_codons<F>(codon: &[u8], f: F) -> bool
+where
+    F: Fn(usize) -> bool,
+{
+    let Ok(codon) = <&[u8; 3]>::try_from(codon) else {
+        return false;
+    };
+
+    if codon.iter().any(|&b| base_mask(b).is_err()) {
+        return false;
+    }
+
+    codon_indices(codon)
+        .map(|mut indices| indices.all(f))
+        .unwrap_or_default()
+}
+
+// NCBI "The Genetic Codes". Each table lists the amino acids and start codons (`M`) of each codon
+// in TCAG order.
+const STANDARD_AMINO_ACIDS: &[u8; CODON_COUNT] =
+    b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG";
+const STANDARD_STARTS: &[u8; CODON_COUNT] =
+    b"---M------**--*----M---------------M----------------------------";
+
+const VERTEBRATE_MITOCHONDRIAL_AMINO_ACIDS: &[u8; CODON_COUNT] =
+    b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSS**VVVVAAAADDEEGGGG";
+const VERTEBRATE_MITOCHONDRIAL_STARTS: &[u8; CODON_COUNT] =
+    b"----------**--------------------MMMM----------**---M------------";
+
+type NcbiTable = (u8, &'static [u8; CODON_COUNT], &'static [u8; CODON_COUNT]);
+
+const NCBI_TABLES: &[NcbiTable] = &[
+    // The yeast mitochondrial code.
+    (
+        3,
+        b"FFLLSSSSYY**CCWWTTTTPPPPHHQQRRRRIIMMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"----------**----------------------MM----------------------------",
+    ),
+    // The mold, protozoan, and coelenterate mitochondrial code and the mycoplasma/spiroplasma
+    // code.
+    (
+        4,
+        b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"--MM------**-------M------------MMMM---------------M------------",
+    ),
+    // The invertebrate mitochondrial code.
+    (
+        5,
+        b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSSSVVVVAAAADDEEGGGG",
+        b"---M------**--------------------MMMM---------------M------------",
+    ),
+    // The ciliate, dasycladacean, and hexamita nuclear code.
+    (
+        6,
+        b"FFLLSSSSYYQQCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"--------------*--------------------M----------------------------",
+    ),
+    // The echinoderm and flatworm mitochondrial code.
+    (
+        9,
+        b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        b"----------**-----------------------M---------------M------------",
+    ),
+    // The euplotid nuclear code.
+    (
+        10,
+        b"FFLLSSSSYY**CCCWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"----------**-----------------------M----------------------------",
+    ),
+    // The bacterial, archaeal, and plant plastid code.
+    (
+        11,
+        b"FFLLSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"---M------**--*----M------------MMMM---------------M------------",
+    ),
+    // The alternative yeast nuclear code.
+    (
+        12,
+        b"FFLLSSSSYY**CC*WLLLSPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"----------**--*----M---------------M----------------------------",
+    ),
+    // The ascidian mitochondrial code.
+    (
+        13,
+        b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNKKSSGGVVVVAAAADDEEGGGG",
+        b"---M------**----------------------MM---------------M------------",
+    ),
+    // The alternative flatworm mitochondrial code.
+    (
+        14,
+        b"FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        b"-----------*-----------------------M----------------------------",
+    ),
+    // The chlorophycean mitochondrial code.
+    (
+        16,
+        b"FFLLSSSSYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"----------*---*--------------------M----------------------------",
+    ),
+    // The trematode mitochondrial code.
+    (
+        21,
+        b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIMMTTTTNNNKSSSSVVVVAAAADDEEGGGG",
+        b"----------**-----------------------M---------------M------------",
+    ),
+    // The Scenedesmus obliquus mitochondrial code.
+    (
+        22,
+        b"FFLLSS*SYY*LCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"------*---*---*--------------------M----------------------------",
+    ),
+    // The Thraustochytrium mitochondrial code.
+    (
+        23,
+        b"FF*LSSSSYY**CC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"--*-------**--*-----------------M--M---------------M------------",
+    ),
+    // The Rhabdopleuridae mitochondrial code.
+    (
+        24,
+        b"FFLLSSSSYY**CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        b"---M------**-------M---------------M---------------M------------",
+    ),
+    // The candidate division SR1 and Gracilibacteria code.
+    (
+        25,
+        b"FFLLSSSSYY**CCGWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"---M------**-----------------------M---------------M------------",
+    ),
+    // The Pachysolen tannophilus nuclear code.
+    (
+        26,
+        b"FFLLSSSSYY**CC*WLLLAPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"----------**--*----M---------------M----------------------------",
+    ),
+    // The Mesodinium nuclear code.
+    (
+        29,
+        b"FFLLSSSSYYYYCC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"--------------*--------------------M----------------------------",
+    ),
+    // The Peritrich nuclear code.
+    (
+        30,
+        b"FFLLSSSSYYEECC*WLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSRRVVVVAAAADDEEGGGG",
+        b"--------------*--------------------M----------------------------",
+    ),
+    // The Cephalodiscidae mitochondrial code.
+    (
+        33,
+        b"FFLLSSSSYYY*CCWWLLLLPPPPHHQQRRRRIIIMTTTTNNKKSSSKVVVVAAAADDEEGGGG",
+        b"---M-------*-------M---------------M---------------M------------",
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_table(amino_acids: &[u8; CODON_COUNT]) -> Vec<([u8; 3], u8)> {
+        amino_acids
+            .iter()
+            .enumerate()
+            .map(|(i, &amino_acid)| (codon(i), amino_acid))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_ncbi() {
+        assert_eq!(GeneticCode::from_ncbi(1), Some(GeneticCode::Standard));
+        assert_eq!(
+            GeneticCode::from_ncbi(2),
+            Some(GeneticCode::VertebrateMitochondrial)
+        );
+        assert!(GeneticCode::from_ncbi(0).is_none());
+        assert!(GeneticCode::from_ncbi(27).is_none());
+
+        for (id, amino_acids, starts) in NCBI_TABLES {
+            let genetic_code = GeneticCode::from_ncbi(*id).unwrap();
+            assert_eq!(genetic_code.amino_acids(), *amino_acids);
+
+            // Stop codons in the starts line match the amino acids.
+            for i in 0..CODON_COUNT {
+                assert_eq!(
+                    starts[i] == STOP,
+                    amino_acids[i] == STOP,
+                    "table {id}, codon {i}"
+                );
+            }
+        }
+
+        let bacterial = GeneticCode::from_ncbi(11).unwrap();
+        assert_eq!(bacterial.amino_acids(), GeneticCode::Standard.amino_acids());
+
+        for codon in [b"TTG", b"CTG", b"ATT", b"ATC", b"ATA", b"ATG", b"GTG"] {
+            assert!(bacterial.is_start_codon(codon));
+        }
+
+        assert!(!bacterial.is_start_codon(b"TTT"));
+    }
+
+    #[test]
+    fn test_from_table() {
+        let table = build_table(STANDARD_AMINO_ACIDS);
+        let genetic_code = GeneticCode::from_table(&table).unwrap();
+        assert_eq!(genetic_code.amino_acids(), STANDARD_AMINO_ACIDS);
+        assert!(genetic_code.is_start_codon(b"ATG"));
+        assert!(!genetic_code.is_start_codon(b"TTG"));
+
+        let mut table = build_table(STANDARD_AMINO_ACIDS);
+        table.pop();
+        assert_eq!(
+            GeneticCode::from_table(&table),
+            Err(TableError::MissingCodon(*b"GGG"))
+        );
+
+        let mut table = build_table(STANDARD_AMINO_ACIDS);
+        table[63].0 = *b"TTT";
+        assert_eq!(
+            GeneticCode::from_table(&table),
+            Err(TableError::DuplicateCodon(*b"TTT"))
+        );
+
+        let mut table = build_table(STANDARD_AMINO_ACIDS);
+        table[0].0 = *b"TTN";
+        assert_eq!(
+            GeneticCode::from_table(&table),
+            Err(TableError::InvalidCodon(*b"TTN"))
+        );
+
+        let mut table = build_table(STANDARD_AMINO_ACIDS);
+        table[0].1 = b'f';
+        assert_eq!(
+            GeneticCode::from_table(&table),
+            Err(TableError::InvalidAminoAcid(b'f'))
+        );
+    }
+
+    #[test]
+    fn test_with_start_codons() {
+        let genetic_code = GeneticCode::VertebrateMitochondrial
+            .with_start_codons(&[*b"ATG"])
+            .unwrap();
+
+        assert_eq!(
+            genetic_code.amino_acids(),
+            VERTEBRATE_MITOCHONDRIAL_AMINO_ACIDS
+        );
+        assert!(genetic_code.is_start_codon(b"ATG"));
+        assert!(!genetic_code.is_start_codon(b"ATA"));
+
+        assert_eq!(
+            GeneticCode::Standard.with_start_codons(&[*b"ANG"]),
+            Err(TableError::InvalidCodon(*b"ANG"))
+        );
+
+        // A table that is the same as a named genetic code is normalized to it.
+        let standard_start_codons = [*b"TTG", *b"CTG", *b"ATG"];
+
+        assert_eq!(
+            GeneticCode::Standard.with_start_codons(&standard_start_codons),
+            Ok(GeneticCode::Standard)
+        );
+
+        let genetic_code = GeneticCode::from_table(&build_table(STANDARD_AMINO_ACIDS))
+            .and_then(|genetic_code| genetic_code.with_start_codons(&standard_start_codons));
+        assert_eq!(genetic_code, Ok(GeneticCode::Standard));
+    }
+
+    #[test]
+    fn test_is_start_codon() {
+        assert!(GeneticCode::Standard.is_start_codon(b"TTG"));
+        assert!(GeneticCode::Standard.is_start_codon(b"atg"));
+        assert!(!GeneticCode::Standard.is_start_codon(b"NTG"));
+        assert!(!GeneticCode::Standard.is_start_codon(b"AT"));
+        assert!(!GeneticCode::Standard.is_start_codon(b"AT-"));
+
+        assert!(GeneticCode::VertebrateMitochondrial.is_start_codon(b"ATN"));
+    }
+
+    #[test]
+    fn test_is_stop_codon() {
+        assert!(GeneticCode::Standard.is_stop_codon(b"TAA"));
+        assert!(GeneticCode::Standard.is_stop_codon(b"TRA"));
+        assert!(!GeneticCode::Standard.is_stop_codon(b"TAN"));
+
+        assert!(GeneticCode::VertebrateMitochondrial.is_stop_codon(b"AGR"));
+    }
+}
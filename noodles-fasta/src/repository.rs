@@ -8,18 +8,113 @@ pub use self::adapter::Adapter;
 use std::{
     collections::HashMap,
     fmt, io,
-    sync::{Arc, RwLock},
+    sync::{
+        Arc, Mutex, RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
 };
 
 use super::record::Sequence;
 
+struct Entry {
+    sequence: Sequence,
+    // The logical time of the last use.
+    last_used: AtomicU64,
+}
+
+struct Cache {
+    entries: HashMap<Vec<u8>, Entry>,
+    capacity: Option<usize>,
+    size: usize,
+    // A logical clock that is advanced on each use. Recording uses with an atomic allows hits to
+    // only take a shared reference to the cache.
+    clock: AtomicU64,
+}
+
+impl Cache {
+    fn new(capacity: Option<usize>) -> Self {
+        Self {
+            entries: HashMap::new(),
+            capacity,
+            size: 0,
+            clock: AtomicU64::new(0),
+        }
+    }
+
+    fn get(&self, name: &[u8]) -> Option<Sequence> {
+        let entry = self.entries.get(name)?;
+        entry.last_used.store(self.tick(), Ordering::Relaxed);
+        Some(entry.sequence.clone())
+    }
+
+    fn tick(&self) -> u64 {
+        self.clock.fetch_add(1, Ordering::Relaxed)
+    }
+
+    fn insert(&mut self, name: &[u8], sequence: Sequence) {
+        let entry = Entry {
+            sequence,
+            last_used: AtomicU64::new(self.tick()),
+        };
+
+        let Some(capacity) = self.capacity else {
+            self.entries.entry(name.into()).or_insert(entry);
+            return;
+        };
+
+        let len = entry.sequence.len();
+
+        if self.entries.contains_key(name) || len > capacity {
+            return;
+        }
+
+        // Eviction is O(n) in the number of cached sequences, but it only happens on a miss,
+        // which is dominated by loading the sequence from the adapter.
+        while self.size + len > capacity {
+            let Some(key) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used.load(Ordering::Relaxed))
+                .map(|(key, _)| key.clone())
+            else {
+                break;
+            };
+
+            if let Some(evicted) = self.entries.remove(&key) {
+                self.size -= evicted.sequence.len();
+            }
+        }
+
+        self.size += len;
+        self.entries.insert(name.into(), entry);
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.size = 0;
+    }
+}
+
 struct AdapterCache {
     adapter: Box<dyn Adapter>,
-    cache: HashMap<Vec<u8>, Sequence>,
+    cache: Cache,
+    // Names of sequences resolved by MD5 checksum.
+    md5_names: HashMap<[u8; 16], Vec<u8>>,
+    // Names that were resolved by MD5 checksum to a sequence with a different name.
+    aliases: HashMap<Vec<u8>, Vec<u8>>,
 }
 
 /// A caching sequence repository.
-pub struct Repository(Arc<RwLock<AdapterCache>>);
+///
+/// Cloning a repository is cheap: clones share the same adapter and cache.
+///
+/// For concurrent use, e.g., one reader per thread over the same reference, see
+/// [`Self::with_local_cache_capacity`], which gives each handle a private cache in front of the
+/// shared one to avoid lock contention.
+pub struct Repository {
+    shared: Arc<RwLock<AdapterCache>>,
+    local: Option<Box<Mutex<Cache>>>,
+}
 
 impl Repository {
     /// Creates a sequence repository.
@@ -27,63 +122,264 @@ impl Repository {
     where
         A: Adapter + 'static,
     {
-        Self(Arc::new(RwLock::new(AdapterCache {
-            adapter: Box::new(adapter),
-            cache: HashMap::new(),
-        })))
+        Self::with_adapter_cache(adapter, None)
+    }
+
+    /// Creates a sequence repository with a bounded cache.
+    ///
+    /// Once the total length of the cached sequences would exceed `capacity` bytes, the least
+    /// recently used sequences are evicted. Sequences larger than the capacity are never cached
+    /// and are read from the adapter on each request.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    ///
+    /// let records = vec![
+    ///     fasta::Record::new(Definition::new("sq0", None), Sequence::from(b"ACGT".to_vec())),
+    ///     fasta::Record::new(Definition::new("sq1", None), Sequence::from(b"TCGA".to_vec())),
+    /// ];
+    ///
+    /// let repository = fasta::Repository::with_cache_capacity(records, 4);
+    ///
+    /// repository.get(b"sq0").transpose()?;
+    /// repository.get(b"sq1").transpose()?;
+    /// assert_eq!(repository.len(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn with_cache_capacity<A>(adapter: A, capacity: usize) -> Self
+    where
+        A: Adapter + 'static,
+    {
+        Self::with_adapter_cache(adapter, Some(capacity))
+    }
+
+    fn with_adapter_cache<A>(adapter: A, capacity: Option<usize>) -> Self
+    where
+        A: Adapter + 'static,
+    {
+        Self {
+            shared: Arc::new(RwLock::new(AdapterCache {
+                adapter: Box::new(adapter),
+                cache: Cache::new(capacity),
+                md5_names: HashMap::new(),
+                aliases: HashMap::new(),
+            })),
+            local: None,
+        }
+    }
+
+    /// Returns a handle to this repository with a private, bounded cache.
+    ///
+    /// The handle shares the adapter and cache of this repository but first looks up sequences in
+    /// its own cache of at most `capacity` bytes, evicting the least recently used sequences.
+    /// Hits on the local cache do not take the shared lock, so giving each thread its own handle
+    /// avoids contention while the sequences themselves are still only stored once.
+    ///
+    /// Clones of the returned handle each start with an empty local cache of the same capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    ///
+    /// let records = vec![
+    ///     fasta::Record::new(Definition::new("sq0", None), Sequence::from(b"ACGT".to_vec())),
+    /// ];
+    ///
+    /// let repository = fasta::Repository::new(records);
+    ///
+    /// thread::scope(|scope| {
+    ///     for _ in 0..2 {
+    ///         let repository = repository.with_local_cache_capacity(1 << 20);
+    ///         scope.spawn(move || repository.get(b"sq0").transpose());
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(repository.len(), 1);
+    /// ```
+    pub fn with_local_cache_capacity(&self, capacity: usize) -> Self {
+        Self {
+            shared: self.shared.clone(),
+            local: Some(Box::new(Mutex::new(Cache::new(Some(capacity))))),
+        }
     }
 
     /// Returns the sequence of the given name.
     pub fn get(&self, name: &[u8]) -> Option<io::Result<Sequence>> {
-        {
-            let lock = self.0.read().unwrap();
-
-            if let Some(sequence) = lock.cache.get(name) {
-                return Some(Ok(sequence.clone()));
+        if let Some(local) = &self.local {
+            if let Some(sequence) = local.lock().unwrap().get(name) {
+                return Some(Ok(sequence));
             }
         }
 
-        let mut lock = self.0.write().unwrap();
+        let result = self.get_shared(name);
+
+        if let (Some(local), Some(Ok(sequence))) = (&self.local, &result) {
+            local.lock().unwrap().insert(name, sequence.clone());
+        }
+
+        result
+    }
+
+    fn get_shared(&self, name: &[u8]) -> Option<io::Result<Sequence>> {
+        if let Some(sequence) = self.shared.read().unwrap().cache.get(name) {
+            return Some(Ok(sequence));
+        }
+
+        let mut lock = self.shared.write().unwrap();
+
+        // Another handle may have loaded the sequence while waiting for the write lock.
+        if let Some(sequence) = lock.cache.get(name) {
+            return Some(Ok(sequence));
+        }
 
         let record = match lock.adapter.get(name)? {
             Ok(record) => record,
             Err(e) => return Some(Err(e)),
         };
 
-        lock.cache
-            .entry(name.into())
-            .or_insert_with(|| record.sequence().clone());
+        lock.cache.insert(name, record.sequence().clone());
+
+        Some(Ok(record.sequence().clone()))
+    }
+
+    /// Returns the sequence with the given MD5 checksum.
+    ///
+    /// This is resolved by the adapter (see [`Adapter::get_by_md5`]) the first time a checksum is
+    /// requested. The sequence is then cached by its name, so subsequent lookups by either
+    /// checksum or name share the same cache entry.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta as fasta;
+    /// let repository = fasta::Repository::default();
+    /// assert!(repository.get_by_md5(&[0; 16]).is_none());
+    /// ```
+    pub fn get_by_md5(&self, md5: &[u8; 16]) -> Option<io::Result<Sequence>> {
+        let name = self.shared.read().unwrap().md5_names.get(md5).cloned();
+
+        if let Some(name) = name {
+            return self.get(&name);
+        }
+
+        let record = {
+            let mut lock = self.shared.write().unwrap();
+
+            let record = match lock.adapter.get_by_md5(md5)? {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            lock.md5_names.insert(*md5, record.name().to_vec());
+            lock.cache.insert(record.name(), record.sequence().clone());
+
+            record
+        };
+
+        if let Some(local) = &self.local {
+            local
+                .lock()
+                .unwrap()
+                .insert(record.name(), record.sequence().clone());
+        }
 
         Some(Ok(record.sequence().clone()))
     }
 
-    /// Returns the number of cached sequences.
+    /// Returns the sequence with the given name or, failing that, MD5 checksum.
+    ///
+    /// If the sequence is resolved by checksum (see [`Self::get_by_md5`]), the name is remembered
+    /// as an alias of the resolved sequence, so subsequent lookups by this method do not query the
+    /// adapter for the name again.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fasta::{self as fasta, record::{Definition, Sequence}};
+    ///
+    /// let sequence = Sequence::from(b"ACGT".to_vec());
+    /// let records = vec![fasta::Record::new(Definition::new("chr0", None), sequence.clone())];
+    /// let repository = fasta::Repository::new(records);
+    ///
+    /// let md5 = fasta::record::sequence::normalized_md5(b"ACGT");
+    /// assert_eq!(repository.get_by_name_or_md5(b"sq0", &md5).transpose()?, Some(sequence));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn get_by_name_or_md5(&self, name: &[u8], md5: &[u8; 16]) -> Option<io::Result<Sequence>> {
+        let alias = self.shared.read().unwrap().aliases.get(name).cloned();
+
+        if let Some(alias) = alias {
+            return self.get(&alias);
+        }
+
+        if let Some(result) = self.get(name) {
+            return Some(result);
+        }
+
+        let result = self.get_by_md5(md5)?;
+
+        if result.is_ok() {
+            let mut lock = self.shared.write().unwrap();
+
+            if let Some(alias) = lock.md5_names.get(md5).cloned() {
+                lock.aliases.insert(name.into(), alias);
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Returns the number of sequences in the shared cache.
     pub fn len(&self) -> usize {
-        self.0.read().unwrap().cache.len()
+        self.shared.read().unwrap().cache.entries.len()
     }
 
-    /// Returns whether any sequences are cached.
+    /// Returns whether any sequences are in the shared cache.
     pub fn is_empty(&self) -> bool {
-        self.0.read().unwrap().cache.is_empty()
+        self.shared.read().unwrap().cache.entries.is_empty()
     }
 
-    /// Clears the sequence cache.
+    /// Clears the shared sequence cache and the local cache of this handle, if any.
     pub fn clear(&self) {
-        self.0.write().unwrap().cache.clear();
+        if let Some(local) = &self.local {
+            local.lock().unwrap().clear();
+        }
+
+        let mut lock = self.shared.write().unwrap();
+        lock.cache.clear();
+        lock.md5_names.clear();
+        lock.aliases.clear();
     }
 }
 
 impl Clone for Repository {
     fn clone(&self) -> Self {
-        Self(self.0.clone())
+        Self {
+            shared: self.shared.clone(),
+            local: self
+                .local
+                .as_ref()
+                .map(|local| Box::new(Mutex::new(Cache::new(local.lock().unwrap().capacity)))),
+        }
     }
 }
 
 impl fmt::Debug for Repository {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Repository")
-            .field("cache", &self.0.read().unwrap().cache)
-            .finish()
+        let lock = self.shared.read().unwrap();
+
+        let cache: HashMap<_, _> = lock
+            .cache
+            .entries
+            .iter()
+            .map(|(name, entry)| (name, &entry.sequence))
+            .collect();
+
+        f.debug_struct("Repository").field("cache", &cache).finish()
     }
 }
 
@@ -117,4 +413,197 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_by_md5() -> io::Result<()> {
+        const MD5: [u8; 16] = [
+            0xf1, 0xf8, 0xf4, 0xbf, 0x41, 0x3b, 0x16, 0xad, 0x13, 0x57, 0x22, 0xaa, 0x45, 0x91,
+            0x04, 0x3e,
+        ];
+
+        struct Md5Adapter(Vec<Record>);
+
+        impl Adapter for Md5Adapter {
+            fn get(&mut self, name: &[u8]) -> Option<io::Result<Record>> {
+                self.0.get(name)
+            }
+
+            fn get_by_md5(&mut self, md5: &[u8; 16]) -> Option<io::Result<Record>> {
+                if md5 == &MD5 {
+                    self.0.first().cloned().map(Ok)
+                } else {
+                    None
+                }
+            }
+        }
+
+        let sq0 = Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGT".to_vec()),
+        );
+        let repository = Repository::new(Md5Adapter(vec![sq0.clone()]));
+
+        assert_eq!(
+            repository.get_by_md5(&MD5).transpose()?,
+            Some(sq0.sequence().clone())
+        );
+        assert_eq!(repository.get_by_md5(&[0; 16]).transpose()?, None);
+        assert_eq!(repository.len(), 1);
+
+        repository.clear();
+
+        assert_eq!(
+            repository.get_by_md5(&MD5).transpose()?,
+            Some(sq0.sequence().clone())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_by_name_or_md5() -> io::Result<()> {
+        use std::sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        };
+
+        struct CountingAdapter {
+            records: Vec<Record>,
+            get_count: Arc<AtomicUsize>,
+        }
+
+        impl Adapter for CountingAdapter {
+            fn get(&mut self, name: &[u8]) -> Option<io::Result<Record>> {
+                self.get_count.fetch_add(1, Ordering::Relaxed);
+                self.records.get(name)
+            }
+
+            fn get_by_md5(&mut self, md5: &[u8; 16]) -> Option<io::Result<Record>> {
+                self.records.get_by_md5(md5)
+            }
+        }
+
+        let sequence = Sequence::from(b"ACGT".to_vec());
+        let md5 = crate::record::sequence::normalized_md5(b"ACGT");
+
+        let get_count = Arc::new(AtomicUsize::new(0));
+        let repository = Repository::new(CountingAdapter {
+            records: vec![Record::new(Definition::new("chr0", None), sequence.clone())],
+            get_count: get_count.clone(),
+        });
+
+        for _ in 0..3 {
+            assert_eq!(
+                repository.get_by_name_or_md5(b"sq0", &md5).transpose()?,
+                Some(sequence.clone())
+            );
+        }
+
+        // `sq0` misses once and is then an alias of `chr0`, which is cached.
+        assert_eq!(get_count.load(Ordering::Relaxed), 1);
+        assert_eq!(repository.len(), 1);
+
+        assert!(repository.get_by_name_or_md5(b"sq1", &[0; 16]).is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_cache_capacity() -> io::Result<()> {
+        let records: Vec<_> = [("sq0", b"ACGT"), ("sq1", b"TCGA"), ("sq2", b"NNNN")]
+            .into_iter()
+            .map(|(name, sequence)| {
+                Record::new(
+                    Definition::new(name, None),
+                    Sequence::from(sequence.to_vec()),
+                )
+            })
+            .collect();
+
+        let repository = Repository::with_cache_capacity(records, 8);
+
+        repository.get(b"sq0").transpose()?;
+        repository.get(b"sq1").transpose()?;
+        assert_eq!(repository.len(), 2);
+
+        // sq0 becomes the most recently used, so sq1 is evicted next.
+        repository.get(b"sq0").transpose()?;
+        repository.get(b"sq2").transpose()?;
+
+        {
+            let lock = repository.shared.read().unwrap();
+            assert_eq!(lock.cache.size, 8);
+            assert!(lock.cache.entries.contains_key(&b"sq0"[..]));
+            assert!(!lock.cache.entries.contains_key(&b"sq1"[..]));
+            assert!(lock.cache.entries.contains_key(&b"sq2"[..]));
+        }
+
+        assert_eq!(
+            repository.get(b"sq1").transpose()?,
+            Some(Sequence::from(b"TCGA".to_vec()))
+        );
+
+        repository.clear();
+        assert!(repository.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_with_local_cache_capacity() -> io::Result<()> {
+        let records: Vec<_> = [("sq0", b"ACGT"), ("sq1", b"TCGA")]
+            .into_iter()
+            .map(|(name, sequence)| {
+                Record::new(
+                    Definition::new(name, None),
+                    Sequence::from(sequence.to_vec()),
+                )
+            })
+            .collect();
+
+        let repository = Repository::new(records);
+        let handle = repository.with_local_cache_capacity(4);
+
+        assert_eq!(
+            handle.get(b"sq0").transpose()?,
+            Some(Sequence::from(b"ACGT".to_vec()))
+        );
+        assert_eq!(handle.get(b"sq2").transpose()?, None);
+
+        handle.get(b"sq1").transpose()?;
+        assert_eq!(repository.len(), 2);
+
+        {
+            let local = handle.local.as_ref().unwrap().lock().unwrap();
+            assert!(!local.entries.contains_key(&b"sq0"[..]));
+            assert!(local.entries.contains_key(&b"sq1"[..]));
+        }
+
+        let clone = handle.clone();
+        assert!(
+            clone
+                .local
+                .as_ref()
+                .unwrap()
+                .lock()
+                .unwrap()
+                .entries
+                .is_empty()
+        );
+
+        handle.clear();
+        assert!(repository.is_empty());
+        assert!(
+            handle
+                .local
+                .as_ref()
+                .unwrap()
+                .lock()
+                .unwrap()
+                .entries
+                .is_empty()
+        );
+
+        Ok(())
+    }
 }
@@ -6,4 +6,16 @@ use crate::Record;
 pub trait Adapter: Send + Sync {
     /// Returns the record with the given name.
     fn get(&mut self, name: &[u8]) -> Option<io::Result<Record>>;
+
+    /// Returns the record whose sequence has the given MD5 checksum.
+    ///
+    /// The checksum is of the normalized sequence, i.e., uppercase without whitespace, as used by
+    /// SAM header reference sequence `M5` fields and refget. The name of a returned record must
+    /// also be resolvable by [`Self::get`].
+    ///
+    /// By default, this is unsupported and returns `None`.
+    fn get_by_md5(&mut self, md5: &[u8; 16]) -> Option<io::Result<Record>> {
+        let _ = md5;
+        None
+    }
 }
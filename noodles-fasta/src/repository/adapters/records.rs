@@ -1,6 +1,6 @@
 use std::io;
 
-use crate::{Record, repository::Adapter};
+use crate::{Record, record::sequence::normalized_md5, repository::Adapter};
 
 impl Adapter for Vec<Record> {
     fn get(&mut self, name: &[u8]) -> Option<io::Result<Record>> {
@@ -9,4 +9,36 @@ impl Adapter for Vec<Record> {
             .cloned()
             .map(Ok)
     }
+
+    fn get_by_md5(&mut self, md5: &[u8; 16]) -> Option<io::Result<Record>> {
+        self.iter()
+            .find(|record| &normalized_md5(record.sequence().as_ref()) == md5)
+            .cloned()
+            .map(Ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::{Definition, Sequence};
+
+    #[test]
+    fn test_get_by_md5() -> io::Result<()> {
+        let sq0 = Record::new(
+            Definition::new("sq0", None),
+            Sequence::from(b"ACGT".to_vec()),
+        );
+        let sq1 = Record::new(
+            Definition::new("sq1", None),
+            Sequence::from(b"ttga".to_vec()),
+        );
+        let mut adapter = vec![sq0, sq1.clone()];
+
+        let md5 = normalized_md5(b"TTGA");
+        assert_eq!(adapter.get_by_md5(&md5).transpose()?, Some(sq1));
+        assert!(adapter.get_by_md5(&[0; 16]).is_none());
+
+        Ok(())
+    }
 }
@@ -7,3 +7,46 @@ pub use self::record::Record;
 
 /// A FASTQ index.
 pub type Index = Vec<Record>;
+
+/// Returns the index record with the given name.
+///
+/// This is an O(n) search of the index records. If there are duplicate names, the first record
+/// with the name is returned.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::fai;
+///
+/// let index = vec![
+///     fai::Record::new("r0", 4, 4, 4, 5, 11),
+///     fai::Record::new("r1", 5, 25, 5, 6, 33),
+/// ];
+///
+/// assert_eq!(fai::get(&index, b"r1"), Some(&fai::Record::new("r1", 5, 25, 5, 6, 33)));
+/// assert!(fai::get(&index, b"r2").is_none());
+/// ```
+pub fn get<'a>(index: &'a [Record], name: &[u8]) -> Option<&'a Record> {
+    index.iter().find(|record| record.name().as_bytes() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get() {
+        let index = vec![
+            Record::new("r0", 4, 4, 4, 5, 11),
+            Record::new("r1", 5, 25, 5, 6, 33),
+            Record::new("r0", 6, 48, 6, 7, 57),
+        ];
+
+        assert_eq!(get(&index, b"r0"), Some(&Record::new("r0", 4, 4, 4, 5, 11)));
+        assert_eq!(
+            get(&index, b"r1"),
+            Some(&Record::new("r1", 5, 25, 5, 6, 33))
+        );
+        assert!(get(&index, b"r2").is_none());
+    }
+}
@@ -1,7 +1,8 @@
 //! FASTQ I/O.
 
 mod indexer;
+mod paired_writer;
 pub mod reader;
 pub mod writer;
 
-pub use self::{indexer::Indexer, reader::Reader, writer::Writer};
+pub use self::{indexer::Indexer, paired_writer::PairedWriter, reader::Reader, writer::Writer};
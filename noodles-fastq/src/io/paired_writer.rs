@@ -0,0 +1,153 @@
+use std::io::{self, Write};
+
+use super::Writer;
+use crate::Record;
+
+/// A FASTQ writer that splits read pairs into two streams.
+///
+/// The first mate of each pair is written to the first writer, and the second mate, to the
+/// second writer.
+pub struct PairedWriter<W> {
+    writer_1: Writer<W>,
+    writer_2: Writer<W>,
+}
+
+impl<W> PairedWriter<W> {
+    /// Returns references to the underlying writers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let writer = fastq::io::PairedWriter::new(io::sink(), io::sink());
+    /// let (_inner_1, _inner_2) = writer.get_ref();
+    /// ```
+    pub fn get_ref(&self) -> (&W, &W) {
+        (self.writer_1.get_ref(), self.writer_2.get_ref())
+    }
+
+    /// Returns mutable references to the underlying writers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let mut writer = fastq::io::PairedWriter::new(io::sink(), io::sink());
+    /// let (_inner_1, _inner_2) = writer.get_mut();
+    /// ```
+    pub fn get_mut(&mut self) -> (&mut W, &mut W) {
+        (self.writer_1.get_mut(), self.writer_2.get_mut())
+    }
+
+    /// Unwraps and returns the underlying writers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    /// let writer = fastq::io::PairedWriter::new(io::sink(), io::sink());
+    /// let (_inner_1, _inner_2) = writer.into_inner();
+    /// ```
+    pub fn into_inner(self) -> (W, W) {
+        (self.writer_1.into_inner(), self.writer_2.into_inner())
+    }
+}
+
+impl<W> PairedWriter<W>
+where
+    W: Write,
+{
+    /// Creates a paired FASTQ writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// let writer = fastq::io::PairedWriter::new(Vec::new(), Vec::new());
+    /// ```
+    pub fn new(inner_1: W, inner_2: W) -> Self {
+        Self::from((Writer::new(inner_1), Writer::new(inner_2)))
+    }
+
+    /// Writes a read pair.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    ///
+    /// let mut writer = fastq::io::PairedWriter::new(Vec::new(), Vec::new());
+    ///
+    /// let r1 = fastq::Record::new(Definition::new("r0", ""), "ATCG", "NDLS");
+    /// let r2 = fastq::Record::new(Definition::new("r0", ""), "CGAT", "SLDN");
+    /// writer.write_pair(&r1, &r2)?;
+    ///
+    /// let (dst_1, dst_2) = writer.get_ref();
+    /// assert_eq!(dst_1, b"@r0\nATCG\n+\nNDLS\n");
+    /// assert_eq!(dst_2, b"@r0\nCGAT\n+\nSLDN\n");
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn write_pair(&mut self, r1: &Record, r2: &Record) -> io::Result<()> {
+        self.writer_1.write_record(r1)?;
+        self.writer_2.write_record(r2)
+    }
+}
+
+impl<W> From<(Writer<W>, Writer<W>)> for PairedWriter<W> {
+    fn from((writer_1, writer_2): (Writer<W>, Writer<W>)) -> Self {
+        Self { writer_1, writer_2 }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{io::Reader, record::Definition};
+
+    #[test]
+    fn test_write_pair() -> io::Result<()> {
+        let pairs = [
+            (
+                Record::new(Definition::new("r0", "1:N:0"), "ATCG", "NDLS"),
+                Record::new(Definition::new("r0", "2:N:0"), "CGAT", "SLDN"),
+            ),
+            (
+                Record::new(Definition::new("r1", ""), "GGCC", "!!!!"),
+                Record::new(Definition::new("r1", ""), "AATT", "~~~~"),
+            ),
+        ];
+
+        let mut writer = PairedWriter::new(Vec::new(), Vec::new());
+
+        for (r1, r2) in &pairs {
+            writer.write_pair(r1, r2)?;
+        }
+
+        let (dst_1, dst_2) = writer.into_inner();
+
+        assert_eq!(
+            dst_1,
+            b"@r0 1:N:0\nATCG\n+\nNDLS\n@r1\nGGCC\n+\n!!!!\n".to_vec()
+        );
+        assert_eq!(
+            dst_2,
+            b"@r0 2:N:0\nCGAT\n+\nSLDN\n@r1\nAATT\n+\n~~~~\n".to_vec()
+        );
+
+        let mut reader_1 = Reader::new(&dst_1[..]);
+        let mut reader_2 = Reader::new(&dst_2[..]);
+        let actual: Vec<_> = reader_1
+            .records()
+            .zip(reader_2.records())
+            .map(|(r1, r2)| r1.and_then(|r1| r2.map(|r2| (r1, r2))))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual, pairs);
+
+        Ok(())
+    }
+}
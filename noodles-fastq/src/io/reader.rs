@@ -1,18 +1,25 @@
 //! FASTQ reader.
 
+mod builder;
 pub(crate) mod record;
+mod record_counter;
 mod records;
 
-pub use self::records::Records;
+pub use self::{
+    builder::Builder,
+    records::{Records, SampleFraction},
+};
 
 use std::io::{self, BufRead};
 
-use self::record::read_record;
+use self::{record::read_record, record_counter::RecordCounter};
 use crate::Record;
 
 /// A FASTQ reader.
 pub struct Reader<R> {
     inner: R,
+    validate: bool,
+    record_counter: RecordCounter,
 }
 
 impl<R> Reader<R> {
@@ -57,6 +64,56 @@ impl<R> Reader<R> {
     pub fn into_inner(self) -> R {
         self.inner
     }
+
+    /// Returns the number of records read.
+    ///
+    /// This counts the records successfully read using [`Self::read_record`], including those
+    /// read using record iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let mut reader = fastq::io::Reader::new(&data[..]);
+    /// assert_eq!(reader.records_read(), 0);
+    ///
+    /// let mut record = fastq::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// assert_eq!(reader.records_read(), 1);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records_read(&self) -> u64 {
+        self.record_counter.records_read()
+    }
+
+    /// Returns whether the last record read reached EOF.
+    ///
+    /// This is only set after a read returns 0 bytes, i.e., it is `false` after reading the last
+    /// record and before attempting to read another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0\nATCG\n+\nNDLS\n";
+    /// let mut reader = fastq::io::Reader::new(&data[..]);
+    ///
+    /// let mut record = fastq::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// assert!(!reader.is_eof());
+    ///
+    /// reader.read_record(&mut record)?;
+    /// assert!(reader.is_eof());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn is_eof(&self) -> bool {
+        self.record_counter.is_eof()
+    }
 }
 
 impl<R> Reader<R>
@@ -73,7 +130,7 @@ where
     /// let reader = fastq::io::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Self { inner }
+        Builder::default().build_from_reader(inner)
     }
 
     /// Reads a FASTQ record.
@@ -86,6 +143,9 @@ where
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
     /// stream reached EOF.
     ///
+    /// If the reader was built with validation enabled (see [`Builder::set_validation`]), a
+    /// record that fails validation returns an [`io::ErrorKind::InvalidData`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -104,7 +164,15 @@ where
     /// Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        let n = read_record(&mut self.inner, record)?;
+
+        if n > 0 && self.validate {
+            record
+                .validate()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        }
+
+        Ok(self.record_counter.track(n))
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -169,4 +237,26 @@ dcba
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_record_with_validation() -> io::Result<()> {
+        let data = b"@r0\nAGCT\n+\nNDLS\n@r1\nAGXT\n+\nNDLS\n";
+        let mut record = Record::default();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_record(&mut record)?;
+        reader.read_record(&mut record)?;
+
+        let mut reader = Builder::default()
+            .set_validation(true)
+            .build_from_reader(&data[..]);
+        reader.read_record(&mut record)?;
+
+        assert!(matches!(
+            reader.read_record(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
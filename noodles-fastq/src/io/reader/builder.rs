@@ -0,0 +1,79 @@
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::Path,
+};
+
+use super::{Reader, record_counter::RecordCounter};
+
+/// A FASTQ reader builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    validate: bool,
+}
+
+impl Builder {
+    /// Sets whether each record is validated after it is read.
+    ///
+    /// If enabled, a record that fails [`crate::Record::validate`] is returned as an
+    /// [`io::ErrorKind::InvalidData`] error. By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::io::reader::Builder;
+    /// let builder = Builder::default().set_validation(true);
+    /// ```
+    pub fn set_validation(mut self, validate: bool) -> Self {
+        self.validate = validate;
+        self
+    }
+
+    /// Builds a FASTQ reader from a path.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_fastq::io::reader::Builder;
+    /// let reader = Builder::default().build_from_path("sample.fq")?;
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn build_from_path<P>(self, src: P) -> io::Result<Reader<BufReader<File>>>
+    where
+        P: AsRef<Path>,
+    {
+        let reader = File::open(src).map(BufReader::new)?;
+        Ok(self.build_from_reader(reader))
+    }
+
+    /// Builds a FASTQ reader from a reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::io::reader::Builder;
+    /// let reader = Builder::default().build_from_reader(io::empty());
+    /// ```
+    pub fn build_from_reader<R>(self, reader: R) -> Reader<R>
+    where
+        R: BufRead,
+    {
+        Reader {
+            inner: reader,
+            validate: self.validate,
+            record_counter: RecordCounter::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let builder = Builder::default();
+        assert!(!builder.validate);
+    }
+}
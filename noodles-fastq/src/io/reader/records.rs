@@ -1,9 +1,16 @@
 use std::io::{self, BufRead};
 
+use noodles_core::sample::{self, FractionSampler};
+
 use crate::Record;
 
 use super::Reader;
 
+/// An iterator over a deterministic fraction of records of a FASTQ reader.
+///
+/// This is created by calling [`Records::sample_fraction`].
+pub type SampleFraction<'a, R> = sample::SampleFraction<Records<'a, R>, fn(&Record) -> &[u8]>;
+
 /// An iterator over records of a FASTQ reader.
 ///
 /// This is created by calling [`Reader::records`].
@@ -22,6 +29,32 @@ where
             buf: Record::default(),
         }
     }
+
+    /// Returns an iterator over a deterministic fraction of the records.
+    ///
+    /// Inclusion is decided by hashing the read name with the given seed, so records with the
+    /// same name, e.g., paired mates, are either all kept or all dropped. A trailing `/1` or `/2`
+    /// is ignored. On large inputs, about `fraction` of the records are kept. See
+    /// [`FractionSampler`].
+    ///
+    /// This returns an error if `fraction` is not in [0, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    ///
+    /// let data = b"@r0\nACGT\n+\nNDLS\n@r1\nTCGA\n+\nSLDN\n";
+    /// let mut reader = fastq::io::Reader::new(&data[..]);
+    ///
+    /// let records: Vec<_> = reader.records().sample_fraction(1.0, 0)?.collect::<Result<_, _>>()?;
+    /// assert_eq!(records.len(), 2);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn sample_fraction(self, fraction: f64, seed: u64) -> io::Result<SampleFraction<'a, R>> {
+        let sampler = FractionSampler::new(fraction, seed)?;
+        Ok(SampleFraction::new(self, sampler, name))
+    }
 }
 
 impl<R> Iterator for Records<'_, R>
@@ -40,3 +73,7 @@ where
         }
     }
 }
+
+fn name(record: &Record) -> &[u8] {
+    record.name()
+}
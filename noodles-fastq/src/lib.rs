@@ -43,6 +43,7 @@ pub mod r#async;
 pub mod fai;
 pub mod fs;
 pub mod io;
+pub mod quality_histogram;
 pub mod record;
 
-pub use self::record::Record;
+pub use self::{quality_histogram::QualityHistogram, record::Record};
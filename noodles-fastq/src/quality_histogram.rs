@@ -0,0 +1,243 @@
+//! Per-position quality score histogram.
+
+use std::io;
+
+use noodles_core::phred::{self, MAX_SCORE};
+
+use crate::Record;
+
+const SCORE_COUNT: usize = MAX_SCORE as usize + 1;
+
+/// A per-position quality score histogram.
+///
+/// This accumulates, for each read position (cycle), the number of times each Phred quality
+/// score is seen. Quality scores are decoded as Phred+33. The position axis grows as longer reads
+/// are added.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq::{self as fastq, record::Definition, QualityHistogram};
+///
+/// let mut histogram = QualityHistogram::default();
+/// histogram.push(&fastq::Record::new(Definition::new("r0", ""), "ACGT", "IIII"))?;
+/// histogram.push(&fastq::Record::new(Definition::new("r1", ""), "AC", "++"))?;
+///
+/// assert_eq!(histogram.len(), 4);
+///
+/// let summary = histogram.summary(0).unwrap();
+/// assert_eq!(summary.mean(), 25.0);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct QualityHistogram {
+    counts: Vec<[u64; SCORE_COUNT]>,
+}
+
+impl QualityHistogram {
+    /// Adds the quality scores of a record.
+    ///
+    /// This returns an error and leaves the histogram unchanged if any quality score is not a
+    /// printable Phred+33 value (`!`..=`~`).
+    pub fn push(&mut self, record: &Record) -> io::Result<()> {
+        self.push_quality_scores(record.quality_scores())
+    }
+
+    /// Adds raw Phred+33 quality scores.
+    ///
+    /// This returns an error and leaves the histogram unchanged if any quality score is not a
+    /// printable Phred+33 value (`!`..=`~`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::QualityHistogram;
+    ///
+    /// let mut histogram = QualityHistogram::default();
+    /// histogram.push_quality_scores(b"II")?;
+    /// assert_eq!(histogram.counts(0).map(|counts| counts[40]), Some(1));
+    ///
+    /// assert!(histogram.push_quality_scores(b"I\n").is_err());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn push_quality_scores(&mut self, quality_scores: &[u8]) -> io::Result<()> {
+        for &b in quality_scores {
+            phred::decode(b)?;
+        }
+
+        if quality_scores.len() > self.counts.len() {
+            self.counts.resize(quality_scores.len(), [0; SCORE_COUNT]);
+        }
+
+        for (counts, &b) in self.counts.iter_mut().zip(quality_scores) {
+            counts[usize::from(phred::decode_unchecked(b))] += 1;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of positions.
+    ///
+    /// This is the length of the longest read added.
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns whether no quality scores were added.
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Returns the counts of each score at the given 0-based position.
+    ///
+    /// The returned slice is indexed by Phred quality score.
+    pub fn counts(&self, position: usize) -> Option<&[u64]> {
+        self.counts.get(position).map(|counts| &counts[..])
+    }
+
+    /// Returns summary statistics of the scores at the given 0-based position.
+    pub fn summary(&self, position: usize) -> Option<Summary> {
+        self.counts
+            .get(position)
+            .and_then(|counts| Summary::new(counts))
+    }
+
+    /// Returns summary statistics for each position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::QualityHistogram;
+    ///
+    /// let mut histogram = QualityHistogram::default();
+    /// histogram.push_quality_scores(b"5+")?;
+    ///
+    /// let medians: Vec<_> = histogram.summaries().map(|summary| summary.median()).collect();
+    /// assert_eq!(medians, [20, 10]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn summaries(&self) -> impl Iterator<Item = Summary> + '_ {
+        // Every position has at least one count.
+        self.counts.iter().filter_map(|counts| Summary::new(counts))
+    }
+}
+
+/// Summary statistics of the quality scores at a position.
+///
+/// Quartiles use the nearest-rank method.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Summary {
+    count: u64,
+    mean: f64,
+    lower_quartile: u8,
+    median: u8,
+    upper_quartile: u8,
+}
+
+impl Summary {
+    fn new(counts: &[u64]) -> Option<Self> {
+        let count: u64 = counts.iter().sum();
+
+        if count == 0 {
+            return None;
+        }
+
+        let sum: u64 = counts
+            .iter()
+            .zip(0..)
+            .map(|(&n, score): (&u64, u64)| n * score)
+            .sum();
+
+        Some(Self {
+            count,
+            mean: sum as f64 / count as f64,
+            lower_quartile: nearest_rank(counts, count, 1, 4),
+            median: nearest_rank(counts, count, 1, 2),
+            upper_quartile: nearest_rank(counts, count, 3, 4),
+        })
+    }
+
+    /// Returns the number of scores.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the mean score.
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// Returns the lower quartile (25th percentile) score.
+    pub fn lower_quartile(&self) -> u8 {
+        self.lower_quartile
+    }
+
+    /// Returns the median score.
+    pub fn median(&self) -> u8 {
+        self.median
+    }
+
+    /// Returns the upper quartile (75th percentile) score.
+    pub fn upper_quartile(&self) -> u8 {
+        self.upper_quartile
+    }
+}
+
+// Returns the smallest score whose cumulative count reaches ⌈count * n / d⌉.
+fn nearest_rank(counts: &[u64], count: u64, n: u64, d: u64) -> u8 {
+    let rank = (count * n).div_ceil(d).max(1);
+    let mut cumulative_count = 0;
+
+    for (score, &c) in (0..).zip(counts) {
+        cumulative_count += c;
+
+        if cumulative_count >= rank {
+            return score;
+        }
+    }
+
+    MAX_SCORE
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_quality_scores() -> io::Result<()> {
+        let mut histogram = QualityHistogram::default();
+
+        histogram.push_quality_scores(b"!+")?;
+        histogram.push_quality_scores(b"!+5")?;
+        assert_eq!(histogram.len(), 3);
+        assert_eq!(histogram.counts(0).map(|counts| counts[0]), Some(2));
+        assert_eq!(histogram.counts(1).map(|counts| counts[10]), Some(2));
+        assert_eq!(histogram.counts(2).map(|counts| counts[20]), Some(1));
+
+        assert!(histogram.push_quality_scores(b"!!!! ").is_err());
+        assert_eq!(histogram.len(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_summary() -> io::Result<()> {
+        let mut histogram = QualityHistogram::default();
+
+        // Scores at position 0: 10, 20, 30, 40
+        for quality_scores in [b"+", b"5", b"?", b"I"] {
+            histogram.push_quality_scores(quality_scores)?;
+        }
+
+        let summary = histogram.summary(0).unwrap();
+        assert_eq!(summary.count(), 4);
+        assert_eq!(summary.mean(), 25.0);
+        assert_eq!(summary.lower_quartile(), 10);
+        assert_eq!(summary.median(), 20);
+        assert_eq!(summary.upper_quartile(), 30);
+
+        assert!(histogram.summary(1).is_none());
+
+        Ok(())
+    }
+}
@@ -1,12 +1,14 @@
 //! FASTQ record.
 
 mod definition;
+mod validation_error;
 
-use std::fmt;
+use std::{fmt, io};
 
 use bstr::{BStr, BString};
+use noodles_core::{BaseComposition, phred};
 
-pub use self::definition::Definition;
+pub use self::{definition::Definition, validation_error::ValidationError};
 
 /// A FASTQ record.
 #[derive(Clone, Default, Eq, PartialEq)]
@@ -130,6 +132,21 @@ impl Record {
         &mut self.sequence
     }
 
+    /// Returns the base composition of the sequence, ignoring case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCN", "NDLS");
+    /// let composition = record.base_composition();
+    /// assert_eq!(composition.n(), 1);
+    /// assert_eq!(composition.gc_fraction(), Some(2.0 / 3.0));
+    /// ```
+    pub fn base_composition(&self) -> BaseComposition {
+        BaseComposition::new(&self.sequence)
+    }
+
     /// Returns the quality scores of the record.
     ///
     /// The encoding of these scores are considered to be unknown; and it is up to the caller to
@@ -163,6 +180,160 @@ impl Record {
         &mut self.quality_scores
     }
 
+    /// Returns an iterator over the decoded Phred quality scores of the record.
+    ///
+    /// The quality scores are decoded as Phred+33 values without being checked, so the decoded
+    /// value of a byte outside of `!`..=`~` is meaningless. Use [`Self::checked_phred_scores`] to
+    /// reject out-of-range bytes instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "NDLS");
+    /// assert_eq!(record.phred_scores().collect::<Vec<_>>(), [45, 35, 43, 50]);
+    /// ```
+    pub fn phred_scores(&self) -> impl Iterator<Item = u8> + '_ {
+        self.quality_scores
+            .iter()
+            .map(|&b| phred::decode_unchecked(b))
+    }
+
+    /// Returns an iterator over the decoded Phred quality scores of the record, checking each
+    /// raw score.
+    ///
+    /// The quality scores are decoded as Phred+33 values. This yields an error for any byte
+    /// outside of `!`..=`~`, i.e., a score outside of 0..=93.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "NDLS");
+    /// assert_eq!(
+    ///     record.checked_phred_scores().collect::<io::Result<Vec<_>>>()?,
+    ///     [45, 35, 43, 50]
+    /// );
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "ND S");
+    /// assert!(record.checked_phred_scores().collect::<io::Result<Vec<_>>>().is_err());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn checked_phred_scores(&self) -> impl Iterator<Item = io::Result<u8>> + '_ {
+        self.quality_scores.iter().map(|&b| phred::decode(b))
+    }
+
+    /// Returns the mean quality score of the record.
+    ///
+    /// The quality scores are decoded as Phred+33 values. This returns `None` if there are no
+    /// quality scores and an error if any quality score is not a printable Phred+33 value
+    /// (`!`..=`~`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "+5?I");
+    /// assert_eq!(record.mean_quality()?, Some(25.0));
+    ///
+    /// let record = fastq::Record::default();
+    /// assert!(record.mean_quality()?.is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn mean_quality(&self) -> io::Result<Option<f64>> {
+        if self.quality_scores.is_empty() {
+            return Ok(None);
+        }
+
+        let mut sum = 0;
+
+        for result in self.checked_phred_scores() {
+            sum += u64::from(result?);
+        }
+
+        Ok(Some(sum as f64 / self.quality_scores.len() as f64))
+    }
+
+    /// Returns the median quality score of the record.
+    ///
+    /// The quality scores are decoded as Phred+33 values. When there is an even number of scores,
+    /// the median is the mean of the two middle scores. This returns `None` if there are no
+    /// quality scores and an error if any quality score is not a printable Phred+33 value
+    /// (`!`..=`~`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::Definition};
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "+5?I");
+    /// assert_eq!(record.median_quality()?, Some(25.0));
+    ///
+    /// let record = fastq::Record::default();
+    /// assert!(record.median_quality()?.is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn median_quality(&self) -> io::Result<Option<f64>> {
+        let scores: Vec<_> = self.checked_phred_scores().collect::<io::Result<_>>()?;
+        Ok(phred::median(scores))
+    }
+
+    /// Validates the internal consistency of the record.
+    ///
+    /// This checks that the name is not empty, the sequence and quality scores have the same
+    /// length, the sequence only contains IUPAC nucleotide symbols (case-insensitive, including
+    /// `.`, `-`, and `=`), and the quality scores are printable Phred+33 values (`!`..=`~`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq::{self as fastq, record::{Definition, ValidationError}};
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "NDLS");
+    /// assert!(record.validate().is_ok());
+    ///
+    /// let record = fastq::Record::new(Definition::new("r0", ""), "AGCT", "NDL");
+    /// assert_eq!(
+    ///     record.validate(),
+    ///     Err(ValidationError::LengthMismatch { sequence_len: 4, quality_scores_len: 3 })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), ValidationError> {
+        if self.name().is_empty() {
+            return Err(ValidationError::EmptyName);
+        }
+
+        if self.sequence.len() != self.quality_scores.len() {
+            return Err(ValidationError::LengthMismatch {
+                sequence_len: self.sequence.len(),
+                quality_scores_len: self.quality_scores.len(),
+            });
+        }
+
+        if let Some((position, &base)) = self
+            .sequence
+            .iter()
+            .enumerate()
+            .find(|(_, b)| !is_valid_base(**b))
+        {
+            return Err(ValidationError::InvalidBase { position, base });
+        }
+
+        if let Some((position, &score)) = self
+            .quality_scores
+            .iter()
+            .enumerate()
+            .find(|(_, s)| !(b'!'..=b'~').contains(*s))
+        {
+            return Err(ValidationError::InvalidQualityScore { position, score });
+        }
+
+        Ok(())
+    }
+
     // Truncates all field buffers to 0.
     pub(crate) fn clear(&mut self) {
         self.definition.clear();
@@ -189,6 +360,30 @@ impl fmt::Debug for Record {
     }
 }
 
+fn is_valid_base(b: u8) -> bool {
+    matches!(
+        b.to_ascii_uppercase(),
+        b'A' | b'C'
+            | b'G'
+            | b'T'
+            | b'U'
+            | b'R'
+            | b'Y'
+            | b'S'
+            | b'W'
+            | b'K'
+            | b'M'
+            | b'B'
+            | b'D'
+            | b'H'
+            | b'V'
+            | b'N'
+            | b'.'
+            | b'-'
+            | b'='
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -203,4 +398,104 @@ mod tests {
         assert!(record.sequence().is_empty());
         assert!(record.quality_scores().is_empty());
     }
+
+    #[test]
+    fn test_validate() {
+        let record = Record::new(Definition::new("r0", ""), "ACGTNacgtn", "!!!!!~~~~~");
+        assert!(record.validate().is_ok());
+
+        let record = Record::new(Definition::new("", ""), "AGCT", "NDLS");
+        assert_eq!(record.validate(), Err(ValidationError::EmptyName));
+
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "NDLSN");
+        assert_eq!(
+            record.validate(),
+            Err(ValidationError::LengthMismatch {
+                sequence_len: 4,
+                quality_scores_len: 5
+            })
+        );
+
+        let record = Record::new(Definition::new("r0", ""), "AGXT", "NDLS");
+        assert_eq!(
+            record.validate(),
+            Err(ValidationError::InvalidBase {
+                position: 2,
+                base: b'X'
+            })
+        );
+
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "ND S");
+        assert_eq!(
+            record.validate(),
+            Err(ValidationError::InvalidQualityScore {
+                position: 2,
+                score: b' '
+            })
+        );
+    }
+
+    #[test]
+    fn test_phred_scores() {
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "!+I~");
+        assert_eq!(record.phred_scores().collect::<Vec<_>>(), [0, 10, 40, 93]);
+    }
+
+    #[test]
+    fn test_checked_phred_scores() -> io::Result<()> {
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "!+I~");
+        assert_eq!(
+            record
+                .checked_phred_scores()
+                .collect::<io::Result<Vec<_>>>()?,
+            [0, 10, 40, 93]
+        );
+
+        for quality_scores in ["!+I ", "!+I\x7f"] {
+            let record = Record::new(Definition::new("r0", ""), "AGCT", quality_scores);
+            assert!(matches!(
+                record.checked_phred_scores().collect::<io::Result<Vec<_>>>(),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mean_quality() -> io::Result<()> {
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "!!+I");
+        assert_eq!(record.mean_quality()?, Some(12.5));
+
+        let record = Record::new(Definition::new("r0", ""), "", "");
+        assert!(record.mean_quality()?.is_none());
+
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "!! I");
+        assert!(matches!(
+            record.mean_quality(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_median_quality() -> io::Result<()> {
+        let record = Record::new(Definition::new("r0", ""), "AGC", "I!5");
+        assert_eq!(record.median_quality()?, Some(20.0));
+
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "I!+5");
+        assert_eq!(record.median_quality()?, Some(15.0));
+
+        let record = Record::new(Definition::new("r0", ""), "", "");
+        assert!(record.median_quality()?.is_none());
+
+        let record = Record::new(Definition::new("r0", ""), "AGCT", "I! 5");
+        assert!(matches!(
+            record.median_quality(),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
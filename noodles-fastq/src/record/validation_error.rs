@@ -0,0 +1,57 @@
+use std::{error, fmt};
+
+/// An error returned when a FASTQ record fails validation.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// The name is empty.
+    EmptyName,
+    /// The sequence and quality scores lengths differ.
+    LengthMismatch {
+        /// The sequence length.
+        sequence_len: usize,
+        /// The quality scores length.
+        quality_scores_len: usize,
+    },
+    /// A base is not an IUPAC nucleotide symbol.
+    InvalidBase {
+        /// The 0-based position of the base in the sequence.
+        position: usize,
+        /// The raw base.
+        base: u8,
+    },
+    /// A quality score is not in the printable Phred+33 range (`!`..=`~`).
+    InvalidQualityScore {
+        /// The 0-based position of the score in the quality scores.
+        position: usize,
+        /// The raw score.
+        score: u8,
+    },
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EmptyName => f.write_str("empty name"),
+            Self::LengthMismatch {
+                sequence_len,
+                quality_scores_len,
+            } => write!(
+                f,
+                "sequence length ({sequence_len}) does not match quality scores length ({quality_scores_len})"
+            ),
+            Self::InvalidBase { position, base } => {
+                write!(
+                    f,
+                    "invalid base at position {position}: {:?}",
+                    char::from(*base)
+                )
+            }
+            Self::InvalidQualityScore { position, score } => write!(
+                f,
+                "invalid quality score at position {position}: 0x{score:02x}"
+            ),
+        }
+    }
+}
@@ -1,8 +1,8 @@
 //! Feature record.
 
 pub mod attributes;
-mod phase;
-mod strand;
+pub mod phase;
+pub mod strand;
 
 use std::io;
 
@@ -1,5 +1,7 @@
 //! GFF record phase.
 
+use std::{error, fmt, str::FromStr};
+
 /// A GFF record phase.
 ///
 /// The phase is used for CDS (coding sequence) features to describe where the next codon begins
@@ -13,3 +15,85 @@ pub enum Phase {
     /// The codon begins at the third nucleotide (`2`).
     Two,
 }
+
+impl AsRef<str> for Phase {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Zero => "0",
+            Self::One => "1",
+            Self::Two => "2",
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a GFF record phase fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid {
+        /// The actual input.
+        actual: String,
+    },
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::Invalid { actual } => {
+                write!(f, "invalid phase: expected {{0, 1, 2}}, got {actual}")
+            }
+        }
+    }
+}
+
+impl FromStr for Phase {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "0" => Ok(Self::Zero),
+            "1" => Ok(Self::One),
+            "2" => Ok(Self::Two),
+            _ => Err(ParseError::Invalid { actual: s.into() }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Phase::Zero.to_string(), "0");
+        assert_eq!(Phase::One.to_string(), "1");
+        assert_eq!(Phase::Two.to_string(), "2");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("0".parse(), Ok(Phase::Zero));
+        assert_eq!("1".parse(), Ok(Phase::One));
+        assert_eq!("2".parse(), Ok(Phase::Two));
+
+        assert_eq!("".parse::<Phase>(), Err(ParseError::Empty));
+        assert_eq!(
+            "3".parse::<Phase>(),
+            Err(ParseError::Invalid {
+                actual: String::from("3")
+            })
+        );
+    }
+}
@@ -1,3 +1,7 @@
+//! Feature record strand.
+
+use std::{error, fmt, str::FromStr};
+
 /// A feature record strand.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
 pub enum Strand {
@@ -12,6 +16,63 @@ pub enum Strand {
     Unknown,
 }
 
+impl AsRef<str> for Strand {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::None => ".",
+            Self::Forward => "+",
+            Self::Reverse => "-",
+            Self::Unknown => "?",
+        }
+    }
+}
+
+impl fmt::Display for Strand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a feature record strand fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid {
+        /// The actual input.
+        actual: String,
+    },
+}
+
+impl error::Error for ParseError {}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty input"),
+            Self::Invalid { actual } => {
+                write!(f, "invalid strand: expected {{., +, -, ?}}, got {actual}")
+            }
+        }
+    }
+}
+
+impl FromStr for Strand {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(ParseError::Empty),
+            "." => Ok(Self::None),
+            "+" => Ok(Self::Forward),
+            "-" => Ok(Self::Reverse),
+            "?" => Ok(Self::Unknown),
+            _ => Err(ParseError::Invalid { actual: s.into() }),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -20,4 +81,28 @@ mod tests {
     fn test_default() {
         assert_eq!(Strand::default(), Strand::None);
     }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(Strand::None.to_string(), ".");
+        assert_eq!(Strand::Forward.to_string(), "+");
+        assert_eq!(Strand::Reverse.to_string(), "-");
+        assert_eq!(Strand::Unknown.to_string(), "?");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(".".parse(), Ok(Strand::None));
+        assert_eq!("+".parse(), Ok(Strand::Forward));
+        assert_eq!("-".parse(), Ok(Strand::Reverse));
+        assert_eq!("?".parse(), Ok(Strand::Unknown));
+
+        assert_eq!("".parse::<Strand>(), Err(ParseError::Empty));
+        assert_eq!(
+            "*".parse::<Strand>(),
+            Err(ParseError::Invalid {
+                actual: String::from("*")
+            })
+        );
+    }
 }
@@ -53,3 +53,58 @@ impl RecordBuf {
         Ok(builder.build())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{
+        feature::{
+            record::{Phase, Strand},
+            record_buf::attributes::field::Tag,
+        },
+        io::Writer,
+    };
+
+    #[test]
+    fn test_try_from_feature_record() -> Result<(), Box<dyn std::error::Error>> {
+        const SRC: &[u8] = b"sq0\tNOODLES\tgene\t8\t13\t0.5\t+\t0\tID=g0;Note=a,b";
+
+        let record = crate::Record::try_new(SRC)?;
+        let actual = RecordBuf::try_from_feature_record(&record)?;
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_source("NOODLES")
+            .set_type("gene")
+            .set_start(Position::try_from(8)?)
+            .set_end(Position::try_from(13)?)
+            .set_score(0.5)
+            .set_strand(Strand::Forward)
+            .set_phase(Phase::Zero)
+            .set_attributes(
+                [
+                    (Tag::from("ID"), Value::from("g0")),
+                    (
+                        Tag::from("Note"),
+                        Value::from(vec![bstr::BString::from("a"), bstr::BString::from("b")]),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        assert_eq!(actual, expected);
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_record(&actual)?;
+
+        let mut expected = SRC.to_vec();
+        expected.push(b'\n');
+        assert_eq!(writer.get_ref(), &expected);
+
+        Ok(())
+    }
+}
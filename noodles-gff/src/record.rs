@@ -3,7 +3,7 @@
 pub mod attributes;
 pub(crate) mod fields;
 
-use std::{fmt, io};
+use std::{fmt, io, str};
 
 use bstr::{BStr, ByteSlice};
 use noodles_core::Position;
@@ -133,25 +133,25 @@ fn parse_score(src: &[u8]) -> Option<io::Result<f32>> {
 }
 
 fn parse_strand(src: &[u8]) -> io::Result<Strand> {
-    match src {
-        b"." => Ok(Strand::None),
-        b"+" => Ok(Strand::Forward),
-        b"-" => Ok(Strand::Reverse),
-        b"?" => Ok(Strand::Unknown),
-        _ => Err(io::Error::new(io::ErrorKind::InvalidData, "invalid strand")),
-    }
+    str::from_utf8(src)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .and_then(|s| {
+            s.parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        })
 }
 
 fn parse_phase(src: &[u8]) -> Option<io::Result<Phase>> {
     match src {
         MISSING => None,
-        b"0" => Some(Ok(Phase::Zero)),
-        b"1" => Some(Ok(Phase::One)),
-        b"2" => Some(Ok(Phase::Two)),
-        _ => Some(Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "invalid phase",
-        ))),
+        _ => Some(
+            str::from_utf8(src)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                .and_then(|s| {
+                    s.parse()
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                }),
+        ),
     }
 }
 
@@ -225,6 +225,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_try_new_with_gencode_attributes() -> io::Result<()> {
+        let attributes = Attributes::try_new(
+            br#"gene_id "ENSG0"; transcript_id "ENST0"; tag "basic"; tag "CCDS"; note "nd\"ls\"";"#,
+        )?;
+
+        let actual: Vec<_> = attributes.iter().collect::<io::Result<_>>()?;
+
+        let expected = [
+            (
+                BStr::new("gene_id"),
+                &Value::String(Cow::from(BStr::new("ENSG0"))),
+            ),
+            (
+                BStr::new("transcript_id"),
+                &Value::String(Cow::from(BStr::new("ENST0"))),
+            ),
+            (
+                BStr::new("tag"),
+                &Value::Array(vec![
+                    Cow::from(BStr::new("basic")),
+                    Cow::from(BStr::new("CCDS")),
+                ]),
+            ),
+            (
+                BStr::new("note"),
+                &Value::String(Cow::from(BStr::new(r#"nd"ls""#))),
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_escape_decode() -> io::Result<()> {
         assert_eq!(escape_decode(b"")?, Cow::from(BStr::new("")));
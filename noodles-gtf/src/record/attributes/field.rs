@@ -8,6 +8,7 @@ pub use self::value::Value;
 
 const SEPARATOR: u8 = b' ';
 const DOUBLE_QUOTES: u8 = b'"';
+const BACKSLASH: u8 = b'\\';
 const TERMINATOR: u8 = b';';
 
 pub(super) fn parse_field<'a>(src: &mut &'a [u8]) -> io::Result<(&'a BStr, &'a BStr)> {
@@ -37,7 +38,20 @@ fn parse_value<'a>(src: &mut &'a [u8]) -> io::Result<&'a BStr> {
 }
 
 fn parse_string<'a>(src: &mut &'a [u8]) -> io::Result<&'a BStr> {
-    let Some(i) = src.iter().position(|c| *c == DOUBLE_QUOTES) else {
+    let mut is_escaped = false;
+
+    // Escaped double quotes (`\"`) are part of the string. They are decoded with the value.
+    let Some(i) = src.iter().position(|&c| {
+        if is_escaped {
+            is_escaped = false;
+            false
+        } else if c == BACKSLASH {
+            is_escaped = true;
+            false
+        } else {
+            c == DOUBLE_QUOTES
+        }
+    }) else {
         return Err(io::Error::from(io::ErrorKind::InvalidData));
     };
 
@@ -86,6 +100,8 @@ mod tests {
         t(b"id 0;", (b"id", b"0"))?;
         t(br#"id "0" ; "#, (b"id", b"0"))?;
         t(br#"id "0;1";"#, (b"id", b"0;1"))?;
+        t(br#"name "nd\"ls\"";"#, (b"name", br#"nd\"ls\""#))?;
+        t(br#"name "nd\\";"#, (b"name", br"nd\\"))?;
 
         assert!(matches!(
             parse_field(&mut &b""[..]),
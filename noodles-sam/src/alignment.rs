@@ -1,6 +1,9 @@
 //! Alignment record.
 
+pub mod assert_sorted;
+pub mod depth;
 pub mod io;
+pub mod pileup;
 pub mod record;
 pub mod record_buf;
 
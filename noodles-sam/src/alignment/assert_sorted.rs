@@ -0,0 +1,361 @@
+//! Alignment record sort order validation.
+
+use std::{fmt, io};
+
+use bstr::{BStr, BString};
+
+use super::Record;
+use crate::{
+    Header,
+    header::record::value::map::header::{sort_order, tag::SORT_ORDER},
+};
+
+/// An alignment record sort order.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SortOrder {
+    /// Records are sorted by reference sequence ID and then alignment start.
+    ///
+    /// Records without a reference sequence ID are ordered last.
+    Coordinate,
+    /// Records are sorted lexicographically by name.
+    QueryName,
+}
+
+impl SortOrder {
+    /// Returns the sort order set in the header, if any.
+    ///
+    /// This reads the sort order (`SO`) field of the header (`@HD`) record. `None` is returned if
+    /// the field is missing or is neither `coordinate` nor `queryname`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::assert_sorted::SortOrder,
+    ///     header::record::value::{
+    ///         map::{self, header::{sort_order, tag}},
+    ///         Map,
+    ///     },
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .set_header(
+    ///         Map::<map::Header>::builder()
+    ///             .insert(tag::SORT_ORDER, sort_order::COORDINATE)
+    ///             .build()?,
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(SortOrder::from_header(&header), Some(SortOrder::Coordinate));
+    /// assert!(SortOrder::from_header(&sam::Header::default()).is_none());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn from_header(header: &Header) -> Option<Self> {
+        let value = header
+            .header()
+            .and_then(|hdr| hdr.other_fields().get(&SORT_ORDER))?;
+
+        if value == sort_order::COORDINATE {
+            Some(Self::Coordinate)
+        } else if value == sort_order::QUERY_NAME {
+            Some(Self::QueryName)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for SortOrder {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Coordinate => f.write_str("coordinate"),
+            Self::QueryName => f.write_str("queryname"),
+        }
+    }
+}
+
+/// An iterator adaptor that validates the sort order of alignment records.
+///
+/// Records are passed through unchanged. The first record that is out of order relative to the
+/// previous record results in an error naming the offending record.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_sam::alignment::{
+///     RecordBuf,
+///     assert_sorted::{AssertSorted, SortOrder},
+/// };
+///
+/// let header = Default::default();
+///
+/// let records = [
+///     Ok(RecordBuf::builder().set_name("r1").build()),
+///     Ok(RecordBuf::builder().set_name("r0").build()),
+/// ];
+///
+/// let mut iter = AssertSorted::new(&header, records.into_iter(), SortOrder::QueryName);
+/// assert!(iter.next().transpose()?.is_some());
+/// assert!(iter.next().transpose().is_err());
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct AssertSorted<'h, I> {
+    header: &'h Header,
+    records: I,
+    sort_order: SortOrder,
+    last_key: Option<Key>,
+}
+
+impl<'h, I, R> AssertSorted<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    /// Creates an iterator adaptor that validates the records are in the given sort order.
+    pub fn new(header: &'h Header, records: I, sort_order: SortOrder) -> Self {
+        Self {
+            header,
+            records,
+            sort_order,
+            last_key: None,
+        }
+    }
+
+    fn key(&self, record: &R) -> io::Result<Key> {
+        match self.sort_order {
+            SortOrder::Coordinate => {
+                let reference_sequence_id = record
+                    .reference_sequence_id(self.header)
+                    .transpose()?
+                    .unwrap_or(usize::MAX);
+
+                let alignment_start = record
+                    .alignment_start()
+                    .transpose()?
+                    .map(usize::from)
+                    .unwrap_or_default();
+
+                Ok(Key::Coordinate(reference_sequence_id, alignment_start))
+            }
+            SortOrder::QueryName => {
+                let name = record.name().map(BString::from).unwrap_or_default();
+                Ok(Key::QueryName(name))
+            }
+        }
+    }
+}
+
+impl<I, R> Iterator for AssertSorted<'_, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = match self.records.next()? {
+            Ok(record) => record,
+            Err(e) => return Some(Err(e)),
+        };
+
+        let key = match self.key(&record) {
+            Ok(key) => key,
+            Err(e) => return Some(Err(e)),
+        };
+
+        if let Some(last_key) = self.last_key.as_ref() {
+            if key < *last_key {
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "record out of {} order: {}",
+                        self.sort_order,
+                        DisplayName(record.name())
+                    ),
+                )));
+            }
+        }
+
+        self.last_key = Some(key);
+
+        Some(Ok(record))
+    }
+}
+
+#[derive(Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Key {
+    Coordinate(usize, usize),
+    QueryName(BString),
+}
+
+struct DisplayName<'a>(Option<&'a BStr>);
+
+impl fmt::Display for DisplayName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(name) => write!(f, "{name}"),
+            None => f.write_str("*"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{
+        alignment::{RecordBuf, record::Flags},
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    #[test]
+    fn test_next_with_coordinate_sort_order() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r2")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::MIN)
+                .build(),
+            RecordBuf::builder().set_name("r3").build(),
+        ];
+
+        let names: Vec<_> =
+            AssertSorted::new(&header, records.into_iter().map(Ok), SortOrder::Coordinate)
+                .map(|result| result.map(|record| record.name().map(|name| name.to_string())))
+                .collect::<io::Result<_>>()?;
+
+        assert_eq!(
+            names,
+            [
+                Some(String::from("r0")),
+                Some(String::from("r1")),
+                Some(String::from("r2")),
+                Some(String::from("r3")),
+            ]
+        );
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::try_from(3)?)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(5)?)
+                .build(),
+        ];
+
+        let mut iter =
+            AssertSorted::new(&header, records.into_iter().map(Ok), SortOrder::Coordinate);
+
+        assert!(iter.next().transpose()?.is_some());
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData && e.to_string().contains("r1")
+        ));
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(5)?)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .build(),
+        ];
+
+        let mut iter =
+            AssertSorted::new(&header, records.into_iter().map(Ok), SortOrder::Coordinate);
+
+        assert!(iter.next().transpose()?.is_some());
+        assert!(matches!(iter.next(), Some(Err(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_query_name_sort_order() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::try_from(3)?)
+                .build(),
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(5)?)
+                .build(),
+            RecordBuf::builder().set_name("r1").build(),
+        ];
+
+        let iter = AssertSorted::new(&header, records.into_iter().map(Ok), SortOrder::QueryName);
+        assert_eq!(iter.collect::<io::Result<Vec<_>>>()?.len(), 3);
+
+        let records = [
+            RecordBuf::builder().set_name("r1").build(),
+            RecordBuf::builder().set_name("r0").build(),
+        ];
+
+        let mut iter =
+            AssertSorted::new(&header, records.into_iter().map(Ok), SortOrder::QueryName);
+
+        assert!(iter.next().transpose()?.is_some());
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.to_string() == "record out of queryname order: r0"
+        ));
+
+        Ok(())
+    }
+}
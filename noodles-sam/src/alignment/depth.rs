@@ -0,0 +1,343 @@
+//! Alignment depth of coverage.
+
+use std::io;
+
+use noodles_core::Region;
+
+use super::{Record, record::cigar::op::Kind};
+use crate::Header;
+
+/// Depth of coverage options.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    min_mapping_quality: u8,
+    count_reference_skips: bool,
+}
+
+impl Options {
+    /// Sets the minimum mapping quality of a record to be counted.
+    ///
+    /// By default, this is 0, i.e., all records are counted. If the minimum is greater than 0,
+    /// records with a missing mapping quality (255) are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::depth::Options;
+    /// let options = Options::default().set_min_mapping_quality(20);
+    /// ```
+    pub fn set_min_mapping_quality(mut self, min_mapping_quality: u8) -> Self {
+        self.min_mapping_quality = min_mapping_quality;
+        self
+    }
+
+    /// Sets whether reference skips (`N`) count toward depth.
+    ///
+    /// By default, reference skips are not counted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::depth::Options;
+    /// let options = Options::default().set_count_reference_skips(true);
+    /// ```
+    pub fn set_count_reference_skips(mut self, count_reference_skips: bool) -> Self {
+        self.count_reference_skips = count_reference_skips;
+        self
+    }
+}
+
+/// Computes the depth of coverage for each position in a region.
+///
+/// The returned depths are indexed from the start of the region. An unbounded region start is the
+/// start of the reference sequence, and an unbounded region end is the end of the reference
+/// sequence, as given by its length in the header. A region end past the end of the reference
+/// sequence is clamped to it.
+///
+/// A position is covered by a record if it is aligned to a match (`M`, `=`, `X`) or deletion
+/// (`D`) and, optionally, a reference skip (`N`). Unmapped records, records on other reference
+/// sequences, and records below the minimum mapping quality are not counted. No other filtering
+/// is done, e.g., on flags. Records do not need to be sorted.
+///
+/// This is a lighter alternative to [`super::pileup::Pileup`] when only the depth is needed. It
+/// works with any [`Record`], e.g., BAM records.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         depth::{self, Options},
+///         record::{cigar::{op::Kind, Op}, Flags},
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(std::num::NonZeroUsize::try_from(8)?),
+///     )
+///     .build();
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(2)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .build();
+///
+/// let region = "sq0".parse()?;
+/// let records = [Ok(record)].into_iter();
+/// let depths = depth::depth(&header, records, &region, &Options::default())?;
+///
+/// assert_eq!(depths, [0, 1, 1, 1, 1, 0, 0, 0]);
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn depth<I, R>(
+    header: &Header,
+    records: I,
+    region: &Region,
+    options: &Options,
+) -> io::Result<Vec<u32>>
+where
+    I: IntoIterator<Item = io::Result<R>>,
+    R: Record,
+{
+    let (reference_sequence_id, reference_sequence) = header
+        .reference_sequences()
+        .get_full(region.name())
+        .map(|(i, _, reference_sequence)| (i, reference_sequence))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid reference sequence name",
+            )
+        })?;
+
+    let reference_sequence_len = usize::from(reference_sequence.length());
+
+    let interval = region.interval();
+    let start = interval.start().map(usize::from).unwrap_or(1);
+    let end = interval
+        .end()
+        .map(usize::from)
+        .unwrap_or(reference_sequence_len)
+        .min(reference_sequence_len);
+
+    let mut depths = vec![0; (end + 1).saturating_sub(start)];
+
+    if depths.is_empty() {
+        return Ok(depths);
+    }
+
+    for result in records {
+        let record = result?;
+
+        if !is_counted(header, &record, reference_sequence_id, options)? {
+            continue;
+        }
+
+        let Some(alignment_start) = record.alignment_start().transpose()? else {
+            continue;
+        };
+
+        let mut reference_position = usize::from(alignment_start);
+
+        for result in record.cigar().iter() {
+            let op = result?;
+            let kind = op.kind();
+
+            if !kind.consumes_reference() {
+                continue;
+            }
+
+            let op_start = reference_position;
+            reference_position += op.len();
+
+            let is_covered = match kind {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch | Kind::Deletion => true,
+                Kind::Skip => options.count_reference_skips,
+                _ => false,
+            };
+
+            if !is_covered {
+                continue;
+            }
+
+            let covered_start = op_start.max(start);
+            let covered_end = (reference_position - 1).min(end);
+
+            for position in covered_start..=covered_end {
+                let depth = &mut depths[position - start];
+                *depth = depth.saturating_add(1);
+            }
+        }
+    }
+
+    Ok(depths)
+}
+
+fn is_counted<R>(
+    header: &Header,
+    record: &R,
+    reference_sequence_id: usize,
+    options: &Options,
+) -> io::Result<bool>
+where
+    R: Record,
+{
+    if record.flags()?.is_unmapped() {
+        return Ok(false);
+    }
+
+    if record.reference_sequence_id(header).transpose()? != Some(reference_sequence_id) {
+        return Ok(false);
+    }
+
+    if options.min_mapping_quality > 0 {
+        let Some(mapping_quality) = record.mapping_quality().transpose()? else {
+            return Ok(false);
+        };
+
+        if u8::from(mapping_quality) < options.min_mapping_quality {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{
+        alignment::{
+            RecordBuf,
+            record::{Flags, MappingQuality, cigar::Op},
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    #[test]
+    fn test_depth() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(12)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(12)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar(
+                    [
+                        Op::new(Kind::SoftClip, 2),
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::Insertion, 1),
+                        Op::new(Kind::Deletion, 1),
+                        Op::new(Kind::Skip, 2),
+                        Op::new(Kind::Match, 2),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_mapping_quality(MappingQuality::new(60).unwrap())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar([Op::new(Kind::SequenceMatch, 3)].into_iter().collect())
+                .set_mapping_quality(MappingQuality::new(8).unwrap())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(7)?)
+                .set_cigar([Op::new(Kind::SequenceMismatch, 8)].into_iter().collect())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_mapping_quality(MappingQuality::new(60).unwrap())
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_mapping_quality(MappingQuality::new(60).unwrap())
+                .build(),
+        ];
+
+        let region = "sq0".parse()?;
+        let actual = depth(
+            &header,
+            records.iter().cloned().map(Ok),
+            &region,
+            &Options::default(),
+        )?;
+        assert_eq!(actual, [0, 1, 2, 2, 1, 0, 2, 2, 1, 1, 1, 1]);
+
+        let options = Options::default().set_count_reference_skips(true);
+        let actual = depth(&header, records.iter().cloned().map(Ok), &region, &options)?;
+        assert_eq!(actual, [0, 1, 2, 2, 2, 1, 2, 2, 1, 1, 1, 1]);
+
+        let options = Options::default().set_min_mapping_quality(10);
+        let actual = depth(&header, records.iter().cloned().map(Ok), &region, &options)?;
+        assert_eq!(actual, [0, 1, 1, 1, 0, 0, 1, 1, 0, 0, 0, 0]);
+
+        let region = "sq0:3-8".parse()?;
+        let actual = depth(
+            &header,
+            records.iter().cloned().map(Ok),
+            &region,
+            &Options::default(),
+        )?;
+        assert_eq!(actual, [2, 2, 1, 0, 2, 2]);
+
+        let region = "sq1:2-3".parse()?;
+        let actual = depth(
+            &header,
+            records.iter().cloned().map(Ok),
+            &region,
+            &Options::default(),
+        )?;
+        assert_eq!(actual, [1, 1]);
+
+        // The region end is clamped to the end of the reference sequence.
+        let region = "sq0:10-1000000000".parse()?;
+        let actual = depth(
+            &header,
+            records.iter().cloned().map(Ok),
+            &region,
+            &Options::default(),
+        )?;
+        assert_eq!(actual, [1, 1, 1]);
+
+        let region = "sq2".parse()?;
+        assert!(matches!(
+            depth(&header, records.iter().cloned().map(Ok), &region, &Options::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,595 @@
+//! Alignment pileup.
+
+pub mod column;
+
+pub use self::column::Column;
+
+use std::{collections::VecDeque, io};
+
+use bstr::BString;
+use noodles_core::{Position, Region, region::Interval};
+
+use self::column::Entry;
+use super::{
+    Record,
+    record::{
+        Flags,
+        cigar::{Op, op::Kind},
+    },
+};
+use crate::Header;
+
+/// An iterator over pileup columns of a region.
+///
+/// This takes a stream of records sorted by reference sequence and alignment start (i.e.,
+/// coordinate-sorted) and yields a column for each position in the region that has at least one
+/// aligned record. Each column holds the base and quality score of every record aligned to the
+/// position, walking the CIGAR of each record to handle insertions, deletions, and reference skips
+/// (`N`).
+///
+/// Unmapped records are skipped. No other filtering is done, e.g., on flags or mapping quality.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         pileup::Pileup,
+///         record::{cigar::{op::Kind, Op}, Flags},
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(std::num::NonZeroUsize::try_from(8)?),
+///     )
+///     .build();
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+///     .set_sequence(b"AC".to_vec().into())
+///     .build();
+///
+/// let region = "sq0".parse()?;
+/// let records = [Ok(record)].into_iter();
+/// let mut pileup = Pileup::new(&header, records, &region)?;
+///
+/// let column = pileup.next().transpose()?.unwrap();
+/// assert_eq!(column.position(), Position::MIN);
+/// assert_eq!(column.depth(), 1);
+/// assert_eq!(column.entries()[0].base(), Some(b'A'));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub struct Pileup<'h, I> {
+    header: &'h Header,
+    records: I,
+    reference_sequence_id: usize,
+    interval: Interval,
+    pending: Option<Alignment>,
+    active: VecDeque<Alignment>,
+    position: Option<Position>,
+    last_start: Option<(usize, Position)>,
+    is_eof: bool,
+}
+
+impl<'h, I, R> Pileup<'h, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    /// Creates a pileup iterator over the given region.
+    ///
+    /// This returns an error if the region's reference sequence name is not in the header.
+    pub fn new(header: &'h Header, records: I, region: &Region) -> io::Result<Self> {
+        let reference_sequence_id = header
+            .reference_sequences()
+            .get_index_of(region.name())
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid reference sequence name",
+                )
+            })?;
+
+        Ok(Self {
+            header,
+            records,
+            reference_sequence_id,
+            interval: region.interval(),
+            pending: None,
+            active: VecDeque::new(),
+            position: None,
+            last_start: None,
+            is_eof: false,
+        })
+    }
+
+    fn next_alignment(&mut self) -> io::Result<Option<Alignment>> {
+        if let Some(alignment) = self.pending.take() {
+            return Ok(Some(alignment));
+        }
+
+        while !self.is_eof {
+            let Some(record) = self.records.next().transpose()? else {
+                self.is_eof = true;
+                break;
+            };
+
+            let flags = record.flags()?;
+
+            if flags.is_unmapped() {
+                continue;
+            }
+
+            let (Some(reference_sequence_id), Some(start)) = (
+                record.reference_sequence_id(self.header).transpose()?,
+                record.alignment_start().transpose()?,
+            ) else {
+                continue;
+            };
+
+            if let Some(last_start) = self.last_start {
+                if (reference_sequence_id, start) < last_start {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "records are not coordinate-sorted",
+                    ));
+                }
+            }
+
+            self.last_start = Some((reference_sequence_id, start));
+
+            if reference_sequence_id < self.reference_sequence_id {
+                continue;
+            } else if reference_sequence_id > self.reference_sequence_id
+                || self.interval.end().is_some_and(|end| start > end)
+            {
+                self.is_eof = true;
+                break;
+            }
+
+            let alignment = Alignment::try_from_record(&record, flags, start)?;
+
+            if self
+                .interval
+                .start()
+                .is_some_and(|region_start| alignment.end < region_start)
+            {
+                continue;
+            }
+
+            return Ok(Some(alignment));
+        }
+
+        Ok(None)
+    }
+
+    fn read_column(&mut self) -> io::Result<Option<Column>> {
+        loop {
+            if self.active.is_empty() {
+                let Some(alignment) = self.next_alignment()? else {
+                    return Ok(None);
+                };
+
+                let position = match self.interval.start() {
+                    Some(start) => alignment.start.max(start),
+                    None => alignment.start,
+                };
+
+                self.position = Some(self.position.map_or(position, |p| p.max(position)));
+                self.active.push_back(alignment);
+            }
+
+            let Some(position) = self.position else {
+                return Ok(None);
+            };
+
+            if self.interval.end().is_some_and(|end| position > end) {
+                self.active.clear();
+                return Ok(None);
+            }
+
+            while let Some(alignment) = self.next_alignment()? {
+                if alignment.start > position {
+                    self.pending = Some(alignment);
+                    break;
+                }
+
+                self.active.push_back(alignment);
+            }
+
+            self.active.retain(|alignment| alignment.end >= position);
+
+            let entries: Vec<_> = self
+                .active
+                .iter_mut()
+                .filter_map(|alignment| alignment.entry_at(position))
+                .collect();
+
+            self.position = usize::from(position).checked_add(1).and_then(Position::new);
+
+            self.active.retain(|alignment| alignment.end > position);
+
+            if !entries.is_empty() {
+                return Ok(Some(Column::new(position, entries)));
+            }
+        }
+    }
+}
+
+impl<I, R> Iterator for Pileup<'_, I>
+where
+    I: Iterator<Item = io::Result<R>>,
+    R: Record,
+{
+    type Item = io::Result<Column>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.read_column().transpose()
+    }
+}
+
+struct Alignment {
+    name: Option<BString>,
+    flags: Flags,
+    start: Position,
+    end: Position,
+    ops: Vec<Op>,
+    sequence: Vec<u8>,
+    quality_scores: Vec<u8>,
+
+    // The CIGAR cursor.
+    op_index: usize,
+    op_offset: usize,
+    reference_position: usize,
+    read_position: usize,
+}
+
+impl Alignment {
+    fn try_from_record<R>(record: &R, flags: Flags, start: Position) -> io::Result<Self>
+    where
+        R: Record,
+    {
+        let ops = record.cigar().iter().collect::<io::Result<Vec<_>>>()?;
+
+        let span: usize = ops
+            .iter()
+            .filter(|op| op.kind().consumes_reference())
+            .map(|op| op.len())
+            .sum();
+
+        let end = usize::from(start)
+            .checked_add(span.max(1) - 1)
+            .and_then(Position::new)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid alignment end"))?;
+
+        let sequence = record.sequence().iter().collect();
+
+        let quality_scores = record
+            .quality_scores()
+            .iter()
+            .collect::<io::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            name: record.name().map(BString::from),
+            flags,
+            start,
+            end,
+            ops,
+            sequence,
+            quality_scores,
+            op_index: 0,
+            op_offset: 0,
+            reference_position: usize::from(start),
+            read_position: 0,
+        })
+    }
+
+    /// Advances the cursor to the given position and returns its entry.
+    ///
+    /// Positions must be given in ascending order. This returns `None` if the position is not
+    /// aligned, i.e., it is in a reference skip or outside the alignment.
+    fn entry_at(&mut self, position: Position) -> Option<Entry> {
+        let position = usize::from(position);
+
+        while let Some(op) = self.ops.get(self.op_index).copied() {
+            let kind = op.kind();
+
+            if !kind.consumes_reference() {
+                if kind.consumes_read() {
+                    self.read_position += op.len();
+                }
+
+                self.op_index += 1;
+                continue;
+            }
+
+            let remaining = op.len() - self.op_offset;
+
+            if self.reference_position + remaining <= position {
+                self.reference_position += remaining;
+
+                if kind.consumes_read() {
+                    self.read_position += remaining;
+                }
+
+                self.op_index += 1;
+                self.op_offset = 0;
+
+                continue;
+            }
+
+            let offset = position - self.reference_position;
+            self.reference_position += offset;
+            self.op_offset += offset;
+
+            if kind.consumes_read() {
+                self.read_position += offset;
+            }
+
+            let is_last = self.op_offset + 1 == op.len();
+
+            let (base, quality_score, is_deletion) = match kind {
+                Kind::Match | Kind::SequenceMatch | Kind::SequenceMismatch => (
+                    self.sequence.get(self.read_position).copied(),
+                    self.quality_scores.get(self.read_position).copied(),
+                    false,
+                ),
+                Kind::Deletion => (None, None, true),
+                _ => return None,
+            };
+
+            let insertion = if is_last {
+                self.next_insertion(kind.consumes_read())
+            } else {
+                Vec::new()
+            };
+
+            return Some(Entry {
+                name: self.name.clone(),
+                flags: self.flags,
+                base,
+                quality_score,
+                is_deletion,
+                insertion,
+            });
+        }
+
+        None
+    }
+
+    fn next_insertion(&self, consumes_read: bool) -> Vec<u8> {
+        let mut start = self.read_position;
+
+        if consumes_read {
+            start += 1;
+        }
+
+        for op in &self.ops[self.op_index + 1..] {
+            match op.kind() {
+                Kind::Pad => {}
+                Kind::Insertion => {
+                    let end = start + op.len();
+                    return self.sequence.get(start..end).unwrap_or_default().to_vec();
+                }
+                _ => break,
+            }
+        }
+
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::{
+        alignment::{
+            RecordBuf,
+            record_buf::{QualityScores, Sequence},
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(16)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(16)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar(
+                    [
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::Insertion, 1),
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::Deletion, 1),
+                        Op::new(Kind::Match, 2),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_sequence(Sequence::from(b"ACGTAGT"))
+                .set_quality_scores(QualityScores::from(vec![0, 1, 2, 3, 4, 5, 6]))
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar(
+                    [
+                        Op::new(Kind::SoftClip, 1),
+                        Op::new(Kind::Match, 2),
+                        Op::new(Kind::Skip, 2),
+                        Op::new(Kind::Match, 2),
+                    ]
+                    .into_iter()
+                    .collect(),
+                )
+                .set_sequence(Sequence::from(b"NCGAC"))
+                .set_quality_scores(QualityScores::from(vec![0, 1, 2, 3, 4]))
+                .build(),
+            RecordBuf::builder()
+                .set_name("r2")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(1)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+                .set_sequence(Sequence::from(b"GG"))
+                .set_quality_scores(QualityScores::from(vec![0, 1]))
+                .build(),
+        ];
+
+        let region = "sq0".parse()?;
+        let columns: Vec<_> = Pileup::new(&header, records.into_iter().map(Ok), &region)?
+            .collect::<io::Result<_>>()?;
+
+        let positions: Vec<_> = columns.iter().map(|c| usize::from(c.position())).collect();
+        assert_eq!(positions, [2, 3, 4, 5, 6, 7, 8]);
+
+        let depths: Vec<_> = columns.iter().map(|c| c.depth()).collect();
+        assert_eq!(depths, [1, 2, 2, 1, 1, 2, 2]);
+
+        // r0 at 3: the last base of the first match, followed by the insertion.
+        let entry = &columns[1].entries()[0];
+        assert_eq!(entry.name(), Some(b"r0".as_ref().into()));
+        assert_eq!(entry.base(), Some(b'C'));
+        assert_eq!(entry.quality_score(), Some(1));
+        assert_eq!(entry.insertion(), b"G");
+
+        // r1 at 3: the first base after the soft clip.
+        let entry = &columns[1].entries()[1];
+        assert_eq!(entry.base(), Some(b'C'));
+        assert_eq!(entry.quality_score(), Some(1));
+        assert!(entry.insertion().is_empty());
+
+        // r0 at 6: a deletion.
+        let entry = &columns[4].entries()[0];
+        assert!(entry.is_deletion());
+        assert_eq!(entry.base(), None);
+        assert_eq!(entry.quality_score(), None);
+
+        // r0 and r1 at 7.
+        let bases: Vec<_> = columns[5].entries().iter().map(|e| e.base()).collect();
+        assert_eq!(bases, [Some(b'G'), Some(b'A')]);
+
+        let mut pileup = Pileup::new(
+            &header,
+            std::iter::empty::<io::Result<RecordBuf>>(),
+            &region,
+        )?;
+        assert!(pileup.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_interval() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(16)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGT"))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(6)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGT"))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(12)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGT"))
+                .build(),
+        ];
+
+        let region = "sq0:3-7".parse()?;
+        let columns: Vec<_> = Pileup::new(&header, records.into_iter().map(Ok), &region)?
+            .collect::<io::Result<_>>()?;
+
+        let positions: Vec<_> = columns.iter().map(|c| usize::from(c.position())).collect();
+        assert_eq!(positions, [3, 4, 6, 7]);
+
+        let bases: Vec<_> = columns
+            .iter()
+            .map(|c| c.entries()[0].base().unwrap())
+            .collect();
+        assert_eq!(bases, b"GTAC");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_unsorted_records() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(16)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(5)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGT"))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGT"))
+                .build(),
+        ];
+
+        let region = "sq0".parse()?;
+        let result: io::Result<Vec<_>> =
+            Pileup::new(&header, records.into_iter().map(Ok), &region)?.collect();
+
+        assert!(matches!(
+            result,
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
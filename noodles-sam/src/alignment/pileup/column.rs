@@ -0,0 +1,90 @@
+//! Alignment pileup column.
+
+use bstr::{BStr, BString};
+use noodles_core::Position;
+
+use crate::alignment::record::Flags;
+
+/// An alignment pileup column.
+///
+/// A column holds the records that align to a single reference sequence position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Column {
+    position: Position,
+    entries: Vec<Entry>,
+}
+
+impl Column {
+    pub(super) fn new(position: Position, entries: Vec<Entry>) -> Self {
+        Self { position, entries }
+    }
+
+    /// Returns the reference sequence position.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the entries of the records that align to this position.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// Returns the depth at this position.
+    ///
+    /// This is the number of records that align to this position, including those with a deletion
+    /// at this position. Records with a reference skip (`N`) at this position are not counted.
+    pub fn depth(&self) -> usize {
+        self.entries.len()
+    }
+}
+
+/// An alignment pileup column entry.
+///
+/// An entry is the part of a single record that aligns to a column position.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub(super) name: Option<BString>,
+    pub(super) flags: Flags,
+    pub(super) base: Option<u8>,
+    pub(super) quality_score: Option<u8>,
+    pub(super) is_deletion: bool,
+    pub(super) insertion: Vec<u8>,
+}
+
+impl Entry {
+    /// Returns the name of the record.
+    pub fn name(&self) -> Option<&BStr> {
+        self.name.as_ref().map(|name| name.as_ref())
+    }
+
+    /// Returns the flags of the record.
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Returns the read base at this position.
+    ///
+    /// This is `None` if the record has a deletion at this position or has no sequence.
+    pub fn base(&self) -> Option<u8> {
+        self.base
+    }
+
+    /// Returns the quality score of the read base at this position.
+    ///
+    /// This is `None` if the record has a deletion at this position or has no quality scores.
+    pub fn quality_score(&self) -> Option<u8> {
+        self.quality_score
+    }
+
+    /// Returns whether the record has a deletion at this position.
+    pub fn is_deletion(&self) -> bool {
+        self.is_deletion
+    }
+
+    /// Returns the bases inserted directly after this position.
+    ///
+    /// This is empty if there is no insertion.
+    pub fn insertion(&self) -> &[u8] {
+        &self.insertion
+    }
+}
@@ -2,7 +2,7 @@
 
 pub mod cigar;
 pub mod data;
-mod flags;
+pub mod flags;
 pub mod mapping_quality;
 mod quality_scores;
 mod sequence;
@@ -115,10 +115,7 @@ pub trait Record {
         };
 
         match self.alignment_span() {
-            Some(Ok(span)) => {
-                let end = usize::from(start) + span - 1;
-                Position::new(end).map(Ok)
-            }
+            Some(Ok(span)) => start.add_length(span).map(Ok),
             Some(Err(e)) => Some(Err(e)),
             None => Some(Ok(start)),
         }
@@ -4,7 +4,9 @@ pub mod field;
 
 use std::io;
 
-use self::field::{Tag, Value};
+use bstr::BStr;
+
+use self::field::{Tag, Value, value::Array};
 
 /// Alignment record data.
 pub trait Data {
@@ -16,6 +18,116 @@ pub trait Data {
 
     /// Returns an iterator over fields.
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<(Tag, Value<'_>)>> + '_>;
+
+    /// Returns the value for the given tag as a 32-bit integer.
+    ///
+    /// Any integer type is widened to an `i32`. This returns an error if the value is not an
+    /// integer or is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{data::field::Tag, Data as _},
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let data: Data = [(Tag::EDIT_DISTANCE, Value::UInt8(1))].into_iter().collect();
+    /// assert_eq!(data.get_i32(&Tag::EDIT_DISTANCE).transpose()?, Some(1));
+    /// assert!(data.get_i32(&Tag::ALIGNMENT_SCORE).is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    fn get_i32(&self, tag: &Tag) -> Option<io::Result<i32>> {
+        self.get(tag).map(|result| {
+            result.and_then(|value| {
+                let n = value.as_int().ok_or_else(|| type_mismatch_error(tag))?;
+                i32::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+        })
+    }
+
+    /// Returns the value for the given tag as a string.
+    ///
+    /// This returns an error if the value is not a string (`Z`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{data::field::Tag, Data as _},
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let data: Data = [(Tag::READ_GROUP, Value::from("rg0"))].into_iter().collect();
+    /// assert_eq!(data.get_str(&Tag::READ_GROUP).transpose()?, Some("rg0".into()));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    fn get_str(&self, tag: &Tag) -> Option<io::Result<&BStr>> {
+        self.get(tag).map(|result| {
+            result.and_then(|value| match value {
+                Value::String(s) => Ok(s),
+                _ => Err(type_mismatch_error(tag)),
+            })
+        })
+    }
+
+    /// Returns the value for the given tag as a character.
+    ///
+    /// This returns an error if the value is not a character (`A`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{data::field::Tag, Data as _},
+    ///     record_buf::{data::field::Value, Data},
+    /// };
+    ///
+    /// let data: Data = [(Tag::TRANSCRIPT_STRAND, Value::Character(b'+'))]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(data.get_char(&Tag::TRANSCRIPT_STRAND).transpose()?, Some(b'+'));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    fn get_char(&self, tag: &Tag) -> Option<io::Result<u8>> {
+        self.get(tag).map(|result| {
+            result.and_then(|value| match value {
+                Value::Character(b) => Ok(b),
+                _ => Err(type_mismatch_error(tag)),
+            })
+        })
+    }
+
+    /// Returns the value for the given tag as a list of 32-bit integers.
+    ///
+    /// Any integer array subtype is widened to `i32`s. This returns an error if the value is not
+    /// an integer array or any value is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::{
+    ///     record::{data::field::Tag, Data as _},
+    ///     record_buf::{data::field::{value::Array, Value}, Data},
+    /// };
+    ///
+    /// let tag = Tag::new(b'X', b'Z');
+    /// let data: Data = [(tag, Value::Array(Array::UInt8(vec![8, 13])))]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert_eq!(data.get_array_i32(&tag).transpose()?, Some(vec![8, 13]));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    fn get_array_i32(&self, tag: &Tag) -> Option<io::Result<Vec<i32>>> {
+        self.get(tag).map(|result| {
+            result.and_then(|value| match value {
+                Value::Array(array) => array_to_i32s(tag, array),
+                _ => Err(type_mismatch_error(tag)),
+            })
+        })
+    }
 }
 
 impl Data for Box<dyn Data + '_> {
@@ -31,3 +143,114 @@ impl Data for Box<dyn Data + '_> {
         (**self).iter()
     }
 }
+
+fn array_to_i32s(tag: &Tag, array: Array<'_>) -> io::Result<Vec<i32>> {
+    fn widen<N>(n: N) -> io::Result<i32>
+    where
+        i32: TryFrom<N>,
+        <i32 as TryFrom<N>>::Error: std::error::Error + Send + Sync + 'static,
+    {
+        i32::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    match array {
+        Array::Int8(values) => values.iter().map(|r| r.and_then(widen)).collect(),
+        Array::UInt8(values) => values.iter().map(|r| r.and_then(widen)).collect(),
+        Array::Int16(values) => values.iter().map(|r| r.and_then(widen)).collect(),
+        Array::UInt16(values) => values.iter().map(|r| r.and_then(widen)).collect(),
+        Array::Int32(values) => values.iter().collect(),
+        Array::UInt32(values) => values.iter().map(|r| r.and_then(widen)).collect(),
+        Array::Float(_) => Err(type_mismatch_error(tag)),
+    }
+}
+
+fn type_mismatch_error(tag: &Tag) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("type mismatch for data field {tag:?}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::record_buf::{
+        Data as DataBuf,
+        data::field::{Value as ValueBuf, value::Array as ArrayBuf},
+    };
+
+    #[test]
+    fn test_get_i32() -> io::Result<()> {
+        let data: DataBuf = [
+            (Tag::EDIT_DISTANCE, ValueBuf::Int8(-1)),
+            (Tag::ALIGNMENT_SCORE, ValueBuf::UInt16(300)),
+            (Tag::ALIGNMENT_HIT_COUNT, ValueBuf::UInt32(u32::MAX)),
+            (Tag::READ_GROUP, ValueBuf::from("rg0")),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(data.get_i32(&Tag::EDIT_DISTANCE).transpose()?, Some(-1));
+        assert_eq!(data.get_i32(&Tag::ALIGNMENT_SCORE).transpose()?, Some(300));
+        assert!(data.get_i32(&Tag::HIT_INDEX).is_none());
+
+        assert!(matches!(
+            data.get_i32(&Tag::ALIGNMENT_HIT_COUNT),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            data.get_i32(&Tag::READ_GROUP),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_str_and_get_char() -> io::Result<()> {
+        let data: DataBuf = [
+            (Tag::READ_GROUP, ValueBuf::from("rg0")),
+            (Tag::TRANSCRIPT_STRAND, ValueBuf::Character(b'-')),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(
+            data.get_str(&Tag::READ_GROUP).transpose()?,
+            Some(BStr::new("rg0"))
+        );
+        assert!(data.get_str(&Tag::TRANSCRIPT_STRAND).unwrap().is_err());
+
+        assert_eq!(
+            data.get_char(&Tag::TRANSCRIPT_STRAND).transpose()?,
+            Some(b'-')
+        );
+        assert!(data.get_char(&Tag::READ_GROUP).unwrap().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_array_i32() -> io::Result<()> {
+        let (x0, x1, x2) = (
+            Tag::new(b'X', b'0'),
+            Tag::new(b'X', b'1'),
+            Tag::new(b'X', b'2'),
+        );
+
+        let data: DataBuf = [
+            (x0, ValueBuf::Array(ArrayBuf::Int16(vec![-2, 5]))),
+            (x1, ValueBuf::Array(ArrayBuf::UInt32(vec![u32::MAX]))),
+            (x2, ValueBuf::Array(ArrayBuf::Float(vec![0.0]))),
+        ]
+        .into_iter()
+        .collect();
+
+        assert_eq!(data.get_array_i32(&x0).transpose()?, Some(vec![-2, 5]));
+        assert!(data.get_array_i32(&x1).unwrap().is_err());
+        assert!(data.get_array_i32(&x2).unwrap().is_err());
+
+        Ok(())
+    }
+}
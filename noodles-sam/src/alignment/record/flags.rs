@@ -1,3 +1,9 @@
+//! Alignment record flags.
+
+mod builder;
+
+pub use self::builder::Builder;
+
 bitflags::bitflags! {
     /// Alignment record flags.
     #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -30,6 +36,25 @@ bitflags::bitflags! {
 }
 
 impl Flags {
+    /// Returns a builder to create flags from each flag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    ///
+    /// let flags = Flags::builder()
+    ///     .segmented()
+    ///     .first_segment()
+    ///     .mate_unmapped()
+    ///     .build();
+    ///
+    /// assert_eq!(flags, Flags::SEGMENTED | Flags::FIRST_SEGMENT | Flags::MATE_UNMAPPED);
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
     /// Returns whether the `SEGMENTED` flag is set.
     ///
     /// # Examples
@@ -238,6 +263,22 @@ mod tests {
         assert!(Flags::SUPPLEMENTARY.is_supplementary());
     }
 
+    #[test]
+    fn test_bits() {
+        assert_eq!(Flags::SEGMENTED.bits(), 0x01);
+        assert_eq!(Flags::PROPERLY_SEGMENTED.bits(), 0x02);
+        assert_eq!(Flags::UNMAPPED.bits(), 0x04);
+        assert_eq!(Flags::MATE_UNMAPPED.bits(), 0x08);
+        assert_eq!(Flags::REVERSE_COMPLEMENTED.bits(), 0x10);
+        assert_eq!(Flags::MATE_REVERSE_COMPLEMENTED.bits(), 0x20);
+        assert_eq!(Flags::FIRST_SEGMENT.bits(), 0x40);
+        assert_eq!(Flags::LAST_SEGMENT.bits(), 0x80);
+        assert_eq!(Flags::SECONDARY.bits(), 0x0100);
+        assert_eq!(Flags::QC_FAIL.bits(), 0x0200);
+        assert_eq!(Flags::DUPLICATE.bits(), 0x0400);
+        assert_eq!(Flags::SUPPLEMENTARY.bits(), 0x0800);
+    }
+
     #[test]
     fn test_from_u16_for_flags() {
         assert_eq!(Flags::from(0x04), Flags::UNMAPPED);
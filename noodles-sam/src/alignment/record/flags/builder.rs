@@ -0,0 +1,221 @@
+use super::Flags;
+
+/// An alignment record flags builder.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Builder(Flags);
+
+impl Builder {
+    /// Sets the segmented flag (`0x01`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().segmented().build();
+    /// assert_eq!(flags, Flags::SEGMENTED);
+    /// ```
+    pub fn segmented(mut self) -> Self {
+        self.0.insert(Flags::SEGMENTED);
+        self
+    }
+
+    /// Sets the properly segmented flag (`0x02`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().properly_segmented().build();
+    /// assert_eq!(flags, Flags::PROPERLY_SEGMENTED);
+    /// ```
+    pub fn properly_segmented(mut self) -> Self {
+        self.0.insert(Flags::PROPERLY_SEGMENTED);
+        self
+    }
+
+    /// Sets the unmapped flag (`0x04`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().unmapped().build();
+    /// assert_eq!(flags, Flags::UNMAPPED);
+    /// ```
+    pub fn unmapped(mut self) -> Self {
+        self.0.insert(Flags::UNMAPPED);
+        self
+    }
+
+    /// Sets the mate unmapped flag (`0x08`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().mate_unmapped().build();
+    /// assert_eq!(flags, Flags::MATE_UNMAPPED);
+    /// ```
+    pub fn mate_unmapped(mut self) -> Self {
+        self.0.insert(Flags::MATE_UNMAPPED);
+        self
+    }
+
+    /// Sets the reverse complemented flag (`0x10`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().reverse_complemented().build();
+    /// assert_eq!(flags, Flags::REVERSE_COMPLEMENTED);
+    /// ```
+    pub fn reverse_complemented(mut self) -> Self {
+        self.0.insert(Flags::REVERSE_COMPLEMENTED);
+        self
+    }
+
+    /// Sets the mate reverse complemented flag (`0x20`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().mate_reverse_complemented().build();
+    /// assert_eq!(flags, Flags::MATE_REVERSE_COMPLEMENTED);
+    /// ```
+    pub fn mate_reverse_complemented(mut self) -> Self {
+        self.0.insert(Flags::MATE_REVERSE_COMPLEMENTED);
+        self
+    }
+
+    /// Sets the first segment flag (`0x40`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().first_segment().build();
+    /// assert_eq!(flags, Flags::FIRST_SEGMENT);
+    /// ```
+    pub fn first_segment(mut self) -> Self {
+        self.0.insert(Flags::FIRST_SEGMENT);
+        self
+    }
+
+    /// Sets the last segment flag (`0x80`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().last_segment().build();
+    /// assert_eq!(flags, Flags::LAST_SEGMENT);
+    /// ```
+    pub fn last_segment(mut self) -> Self {
+        self.0.insert(Flags::LAST_SEGMENT);
+        self
+    }
+
+    /// Sets the secondary flag (`0x0100`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().secondary().build();
+    /// assert_eq!(flags, Flags::SECONDARY);
+    /// ```
+    pub fn secondary(mut self) -> Self {
+        self.0.insert(Flags::SECONDARY);
+        self
+    }
+
+    /// Sets the QC fail flag (`0x0200`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().qc_fail().build();
+    /// assert_eq!(flags, Flags::QC_FAIL);
+    /// ```
+    pub fn qc_fail(mut self) -> Self {
+        self.0.insert(Flags::QC_FAIL);
+        self
+    }
+
+    /// Sets the duplicate flag (`0x0400`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().duplicate().build();
+    /// assert_eq!(flags, Flags::DUPLICATE);
+    /// ```
+    pub fn duplicate(mut self) -> Self {
+        self.0.insert(Flags::DUPLICATE);
+        self
+    }
+
+    /// Sets the supplementary flag (`0x0800`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().supplementary().build();
+    /// assert_eq!(flags, Flags::SUPPLEMENTARY);
+    /// ```
+    pub fn supplementary(mut self) -> Self {
+        self.0.insert(Flags::SUPPLEMENTARY);
+        self
+    }
+
+    /// Builds the alignment record flags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// let flags = Flags::builder().build();
+    /// assert!(flags.is_empty());
+    /// ```
+    pub fn build(self) -> Flags {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build() {
+        let flags = Flags::builder()
+            .segmented()
+            .first_segment()
+            .mate_unmapped()
+            .build();
+
+        assert_eq!(u16::from(flags), 0x49);
+
+        let flags = Builder::default()
+            .segmented()
+            .properly_segmented()
+            .unmapped()
+            .mate_unmapped()
+            .reverse_complemented()
+            .mate_reverse_complemented()
+            .first_segment()
+            .last_segment()
+            .secondary()
+            .qc_fail()
+            .duplicate()
+            .supplementary()
+            .build();
+
+        assert_eq!(flags, Flags::all());
+    }
+}
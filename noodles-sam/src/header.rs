@@ -81,7 +81,10 @@ pub use self::{
     record::Record,
 };
 
-use std::str::{self, FromStr};
+use std::{
+    io,
+    str::{self, FromStr},
+};
 
 use bstr::BString;
 use indexmap::IndexMap;
@@ -271,6 +274,60 @@ impl Header {
         &mut self.read_groups
     }
 
+    /// Returns the index of the read group of the given record.
+    ///
+    /// The read group is looked up using the record's read group (`RG`) data field. This returns
+    /// `None` if the record has no read group. It returns an error if the read group is not a
+    /// string or is not in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     alignment::{record::data::field::Tag, record_buf::data::field::Value, RecordBuf},
+    ///     header::record::value::{map::ReadGroup, Map},
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_read_group("rg0", Map::<ReadGroup>::default())
+    ///     .add_read_group("rg1", Map::<ReadGroup>::default())
+    ///     .build();
+    ///
+    /// let record = RecordBuf::default();
+    /// assert!(header.read_group_index_of_record(&record).is_none());
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_data([(Tag::READ_GROUP, Value::from("rg1"))].into_iter().collect())
+    ///     .build();
+    /// assert_eq!(header.read_group_index_of_record(&record).transpose()?, Some(1));
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_data([(Tag::READ_GROUP, Value::from("rg2"))].into_iter().collect())
+    ///     .build();
+    /// assert!(header.read_group_index_of_record(&record).unwrap().is_err());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn read_group_index_of_record<R>(&self, record: &R) -> Option<io::Result<usize>>
+    where
+        R: crate::alignment::Record + ?Sized,
+    {
+        use crate::alignment::record::data::field::Tag;
+
+        let data = record.data();
+
+        data.get_str(&Tag::READ_GROUP).map(|result| {
+            result.and_then(|name| {
+                self.read_groups.get_index_of(name).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("read group not in header: {name}"),
+                    )
+                })
+            })
+        })
+    }
+
     /// Returns the SAM header programs.
     ///
     /// # Examples
@@ -312,6 +369,55 @@ impl Header {
         &mut self.programs
     }
 
+    /// Adds a program with the given name (`PN`), version (`VN`), and command line (`CL`).
+    ///
+    /// This is typically used by a tool to record itself as the latest program to process the
+    /// data. The program is added using [`Programs::add`], i.e., it is attached to the end of each
+    /// program chain, and `id` is used as an ID prefix.
+    ///
+    /// # Errors
+    ///
+    /// See [`Programs::add`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, header::record::value::map::program::tag};
+    ///
+    /// let mut header = sam::Header::default();
+    /// header.add_program("pg0", "noodles", "0.1.0", "noodles view")?;
+    /// header.add_program("pg1", "noodles", "0.1.0", "noodles sort")?;
+    ///
+    /// let program = header.programs().as_ref().get(&b"pg1"[..]);
+    /// let previous_program_id = program.and_then(|map| map.other_fields().get(&tag::PREVIOUS_PROGRAM_ID));
+    /// assert_eq!(previous_program_id.map(|id| id.as_ref()), Some(&b"pg0"[..]));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add_program<I, N, V, C>(
+        &mut self,
+        id: I,
+        name: N,
+        version: V,
+        command_line: C,
+    ) -> io::Result<()>
+    where
+        I: Into<BString>,
+        N: Into<BString>,
+        V: Into<BString>,
+        C: Into<BString>,
+    {
+        use self::record::value::map::program::tag;
+
+        let mut map = Map::<map::Program>::default();
+        let other_fields = map.other_fields_mut();
+
+        other_fields.insert(tag::NAME, name.into());
+        other_fields.insert(tag::VERSION, version.into());
+        other_fields.insert(tag::COMMAND_LINE, command_line.into());
+
+        self.programs.add(id, map)
+    }
+
     /// Returns the SAM header comments.
     ///
     /// # Examples
@@ -434,3 +540,50 @@ impl FromStr for Header {
         parser::parse(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_program() -> io::Result<()> {
+        use self::record::value::map::program::tag;
+
+        let mut header = Header::default();
+        header.add_program("pg0", "noodles", "0.1.0", "noodles view")?;
+        header.add_program("pg1", "samtools", "1.21", "samtools sort")?;
+
+        let programs = header.programs().as_ref();
+        assert_eq!(programs.len(), 2);
+
+        let pg0 = &programs[&b"pg0"[..]];
+        assert!(!pg0.other_fields().contains_key(&tag::PREVIOUS_PROGRAM_ID));
+
+        let pg1 = &programs[&b"pg1"[..]];
+        assert_eq!(
+            pg1.other_fields().get(&tag::PREVIOUS_PROGRAM_ID),
+            Some(&BString::from("pg0"))
+        );
+        assert_eq!(
+            pg1.other_fields().get(&tag::NAME),
+            Some(&BString::from("samtools"))
+        );
+        assert_eq!(
+            pg1.other_fields().get(&tag::VERSION),
+            Some(&BString::from("1.21"))
+        );
+        assert_eq!(
+            pg1.other_fields().get(&tag::COMMAND_LINE),
+            Some(&BString::from("samtools sort"))
+        );
+
+        header.add_program("pg0", "noodles", "0.1.0", "noodles index")?;
+        let pg0_pg1 = &header.programs().as_ref()[&b"pg0-pg1"[..]];
+        assert_eq!(
+            pg0_pg1.other_fields().get(&tag::PREVIOUS_PROGRAM_ID),
+            Some(&BString::from("pg1"))
+        );
+
+        Ok(())
+    }
+}
@@ -79,6 +79,8 @@ impl Parser {
 
     /// Parses and adds a raw record to the header.
     ///
+    /// A trailing carriage return (`\r`), e.g., from a CRLF line ending, is ignored.
+    ///
     /// # Examples
     ///
     /// ```
@@ -88,6 +90,8 @@ impl Parser {
     /// # Ok::<_, sam::header::ParseError>(())
     /// ```
     pub fn parse_partial(&mut self, src: &[u8]) -> Result<(), ParseError> {
+        let src = strip_carriage_return(src);
+
         if self.is_empty() {
             if let Some(version) = extract_version(src) {
                 self.ctx = Context::from(version);
@@ -150,6 +154,11 @@ impl Parser {
     }
 }
 
+fn strip_carriage_return(src: &[u8]) -> &[u8] {
+    const CARRIAGE_RETURN: u8 = b'\r';
+    src.strip_suffix(&[CARRIAGE_RETURN]).unwrap_or(src)
+}
+
 fn extract_version(src: &[u8]) -> Option<Version> {
     use self::record::value::map::header::parse_version;
 
@@ -275,6 +284,25 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_partial_with_crlf_line_endings() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        let mut parser = Parser::default();
+        parser.parse_partial(b"@HD\tVN:1.6\r")?;
+        parser.parse_partial(b"@SQ\tSN:sq0\tLN:8\r")?;
+        let actual = parser.finish();
+
+        let expected = parse("@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n")?;
+
+        assert_eq!(actual, expected);
+
+        let reference_sequence = &actual.reference_sequences()[&b"sq0"[..]];
+        assert_eq!(reference_sequence.length(), NonZeroUsize::try_from(8)?);
+
+        Ok(())
+    }
+
     #[test]
     fn test_parse_with_empty_input() -> Result<(), ParseError> {
         let header = parse("")?;
@@ -147,4 +147,17 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_header_with_crlf_line_endings() -> io::Result<()> {
+        let mut reader = &b"@HD\tVN:1.6\r\n@SQ\tSN:sq0\tLN:8\r\n"[..];
+        let actual = read_header(&mut reader)?;
+
+        let mut reader = &b"@HD\tVN:1.6\n@SQ\tSN:sq0\tLN:8\n"[..];
+        let expected = read_header(&mut reader)?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
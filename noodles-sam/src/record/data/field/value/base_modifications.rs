@@ -5,6 +5,9 @@ mod parser;
 
 pub use self::group::Group;
 
+use std::io;
+
+use self::group::Modification;
 use crate::alignment::record_buf::Sequence;
 
 /// Base modifications.
@@ -54,6 +57,109 @@ impl BaseModifications {
     ) -> Result<Self, parser::ParseError> {
         parser::parse(s, is_reverse_complemented, sequence)
     }
+
+    /// Returns an iterator over base modification calls.
+    ///
+    /// Each call is a group, a 0-based position in the sequence, and a modification. Calls are
+    /// yielded in the order of the base modification probabilities (`ML`): by group, then by
+    /// position, then by modification. A group with multiple modifications (e.g., `C+mh`) yields a
+    /// call per modification at each position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     alignment::record_buf::Sequence,
+    ///     record::data::field::value::{
+    ///         base_modifications::group::modification, BaseModifications,
+    ///     },
+    /// };
+    ///
+    /// let sequence = Sequence::from(b"CACCCGATGACCGGCT");
+    /// let base_modifications = BaseModifications::parse("C+mh,1;", false, &sequence)?;
+    ///
+    /// let calls: Vec<_> = base_modifications
+    ///     .calls()
+    ///     .map(|(_, position, modification)| (position, modification))
+    ///     .collect();
+    ///
+    /// assert_eq!(calls, [
+    ///     (2, modification::FIVE_METHYLCYTOSINE),
+    ///     (2, modification::FIVE_HYDROXYMETHYLCYTOSINE),
+    /// ]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn calls(&self) -> impl Iterator<Item = (&Group, usize, Modification)> + '_ {
+        self.0.iter().flat_map(|group| {
+            group.positions().iter().flat_map(move |&position| {
+                group
+                    .modifications()
+                    .iter()
+                    .map(move |&modification| (group, position, modification))
+            })
+        })
+    }
+
+    /// Returns an iterator over base modification calls with their probabilities.
+    ///
+    /// `probabilities` are the raw base modification probabilities (`ML`), where a value _N_
+    /// represents a probability in the range [_N_/256, (_N_ + 1)/256). This returns an error if
+    /// the number of probabilities does not match the number of [calls](Self::calls).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{
+    ///     alignment::record_buf::Sequence,
+    ///     record::data::field::value::{
+    ///         base_modifications::group::{modification, Strand, UnmodifiedBase},
+    ///         BaseModifications,
+    ///     },
+    /// };
+    ///
+    /// let sequence = Sequence::from(b"CACCCGATGACCGGCT");
+    /// let base_modifications = BaseModifications::parse("C+m,1,3;G-o,2;", false, &sequence)?;
+    ///
+    /// let calls: Vec<_> = base_modifications
+    ///     .calls_with_probabilities(&[204, 3, 128])?
+    ///     .map(|(group, position, modification, probability)| {
+    ///         (group.unmodified_base(), group.strand(), position, modification, probability)
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(calls, [
+    ///     (UnmodifiedBase::C, Strand::Forward, 2, modification::FIVE_METHYLCYTOSINE, 204),
+    ///     (UnmodifiedBase::C, Strand::Forward, 11, modification::FIVE_METHYLCYTOSINE, 3),
+    ///     (UnmodifiedBase::G, Strand::Reverse, 12, modification::EIGHT_OXOGUANINE, 128),
+    /// ]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn calls_with_probabilities<'a>(
+        &'a self,
+        probabilities: &'a [u8],
+    ) -> io::Result<impl Iterator<Item = (&'a Group, usize, Modification, u8)> + 'a> {
+        let call_count = self
+            .0
+            .iter()
+            .map(|group| group.positions().len() * group.modifications().len())
+            .sum::<usize>();
+
+        if probabilities.len() != call_count {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "base modification probability count mismatch: expected {call_count}, got {}",
+                    probabilities.len()
+                ),
+            ));
+        }
+
+        Ok(self.calls().zip(probabilities.iter().copied()).map(
+            |((group, position, modification), probability)| {
+                (group, position, modification, probability)
+            },
+        ))
+    }
 }
 
 impl AsRef<[Group]> for BaseModifications {
@@ -79,3 +185,55 @@ impl From<BaseModifications> for Vec<Group> {
         base_modifications.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::record::data::field::value::base_modifications::group::{
+        UnmodifiedBase, modification,
+    };
+
+    #[test]
+    fn test_calls_with_probabilities() -> Result<(), Box<dyn std::error::Error>> {
+        // original sequence: AGCCGGTCATCGGGTG
+        let sequence = Sequence::from(b"CACCCGATGACCGGCT");
+        let base_modifications = BaseModifications::parse("C+mh,0,2;A+a,1;", true, &sequence)?;
+
+        let actual: Vec<_> = base_modifications
+            .calls_with_probabilities(&[1, 2, 3, 4, 5])?
+            .map(|(group, position, modification, probability)| {
+                (group.unmodified_base(), position, modification, probability)
+            })
+            .collect();
+
+        assert_eq!(
+            actual,
+            [
+                (UnmodifiedBase::C, 13, modification::FIVE_METHYLCYTOSINE, 1),
+                (
+                    UnmodifiedBase::C,
+                    13,
+                    modification::FIVE_HYDROXYMETHYLCYTOSINE,
+                    2
+                ),
+                (UnmodifiedBase::C, 5, modification::FIVE_METHYLCYTOSINE, 3),
+                (
+                    UnmodifiedBase::C,
+                    5,
+                    modification::FIVE_HYDROXYMETHYLCYTOSINE,
+                    4
+                ),
+                (UnmodifiedBase::A, 7, modification::SIX_METHYLADENINE, 5),
+            ]
+        );
+
+        for probabilities in [&[1, 2, 3, 4][..], &[1, 2, 3, 4, 5, 6]] {
+            assert!(matches!(
+                base_modifications.calls_with_probabilities(probabilities),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        Ok(())
+    }
+}
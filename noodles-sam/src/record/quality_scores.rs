@@ -1,5 +1,7 @@
 use std::io;
 
+use noodles_core::phred;
+
 /// SAM record quality scores.
 #[derive(Debug, Eq, PartialEq)]
 pub struct QualityScores<'a>(&'a [u8]);
@@ -46,6 +48,48 @@ impl<'a> QualityScores<'a> {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    /// Returns an iterator over the decoded Phred quality scores.
+    ///
+    /// The raw scores are decoded as Phred+33 values without being checked, so the decoded value
+    /// of a byte outside of `!`..=`~` is meaningless. Use [`Self::checked_phred_scores`] to
+    /// reject out-of-range bytes instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::record::QualityScores;
+    /// let quality_scores = QualityScores::new(b"NDLS");
+    /// assert_eq!(quality_scores.phred_scores().collect::<Vec<_>>(), [45, 35, 43, 50]);
+    /// ```
+    pub fn phred_scores(&self) -> impl Iterator<Item = u8> + use<'a> {
+        self.0.iter().map(|&b| phred::decode_unchecked(b))
+    }
+
+    /// Returns an iterator over the decoded Phred quality scores, checking each raw score.
+    ///
+    /// The raw scores are decoded as Phred+33 values. This yields an error for any byte outside
+    /// of `!`..=`~`, i.e., a score outside of 0..=93.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_sam::record::QualityScores;
+    ///
+    /// let quality_scores = QualityScores::new(b"NDLS");
+    /// assert_eq!(
+    ///     quality_scores.checked_phred_scores().collect::<io::Result<Vec<_>>>()?,
+    ///     [45, 35, 43, 50]
+    /// );
+    ///
+    /// let quality_scores = QualityScores::new(b"ND S");
+    /// assert!(quality_scores.checked_phred_scores().collect::<io::Result<Vec<_>>>().is_err());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn checked_phred_scores(&self) -> impl Iterator<Item = io::Result<u8>> + use<'a> {
+        self.0.iter().map(|&b| phred::decode(b))
+    }
 }
 
 impl crate::alignment::record::QualityScores for QualityScores<'_> {
@@ -88,6 +132,9 @@ mod tests {
             [45, 35, 43, 50]
         );
 
+        let quality_scores = QualityScores::new(b"\x7f");
+        assert_eq!(quality_scores.iter().collect::<io::Result<Vec<_>>>()?, [94]);
+
         let quality_scores = QualityScores::new(&[0x00]);
 
         assert!(matches!(
@@ -97,4 +144,34 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_phred_scores() {
+        let quality_scores = QualityScores::new(b"!+I~");
+        assert_eq!(
+            quality_scores.phred_scores().collect::<Vec<_>>(),
+            [0, 10, 40, 93]
+        );
+    }
+
+    #[test]
+    fn test_checked_phred_scores() -> io::Result<()> {
+        let quality_scores = QualityScores::new(b"!+I~");
+        assert_eq!(
+            quality_scores
+                .checked_phred_scores()
+                .collect::<io::Result<Vec<_>>>()?,
+            [0, 10, 40, 93]
+        );
+
+        for src in [&b"!+I "[..], &b"!+I\x7f"[..]] {
+            let quality_scores = QualityScores::new(src);
+            assert!(matches!(
+                quality_scores.checked_phred_scores().collect::<io::Result<Vec<_>>>(),
+                Err(e) if e.kind() == io::ErrorKind::InvalidData
+            ));
+        }
+
+        Ok(())
+    }
 }
@@ -63,4 +63,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_index_reference_sequence_names() -> io::Result<()> {
+        use bstr::BString;
+        use noodles_csi::binning_index::index::{Header, ReferenceSequence};
+
+        let reference_sequence_names = [BString::from("sq0"), BString::from("sq1")]
+            .into_iter()
+            .collect();
+
+        let header = Header::builder()
+            .set_reference_sequence_names(reference_sequence_names)
+            .build();
+
+        let reference_sequences = vec![
+            ReferenceSequence::new(Default::default(), Vec::new(), None),
+            ReferenceSequence::new(Default::default(), Vec::new(), None),
+        ];
+
+        let expected = Index::builder()
+            .set_header(header)
+            .set_reference_sequences(reference_sequences)
+            .build();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_index(&expected)?;
+        let src = writer.into_inner().finish()?;
+
+        let mut reader = crate::io::Reader::new(&src[..]);
+        let actual = reader.read_index()?;
+
+        let names = actual
+            .reference_sequence_names()
+            .expect("missing reference sequence names");
+
+        assert_eq!(names.len(), 2);
+        assert!(names.contains(&b"sq0"[..]));
+        assert!(names.contains(&b"sq1"[..]));
+
+        Ok(())
+    }
 }
@@ -14,16 +14,9 @@ fn main() -> io::Result<()> {
 
     let mut reader = variant::io::reader::Builder::default().build_from_path(src)?;
 
-    let header = reader.read_header()?;
-
     let mut writer = variant::io::writer::Builder::default().build_from_path(dst)?;
 
-    writer.write_header(&header)?;
-
-    for result in reader.records(&header) {
-        let record = result?;
-        writer.write_record(&header, record.as_ref())?;
-    }
+    variant::convert(&mut reader, &mut writer)?;
 
     Ok(())
 }
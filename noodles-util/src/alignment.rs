@@ -3,8 +3,11 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+pub mod consensus;
+pub mod fastq;
 pub mod io;
 pub mod iter;
+pub mod liftover;
 mod record;
 
 pub use self::record::Record;
@@ -0,0 +1,411 @@
+//! Alignment consensus calling.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::{collections::BTreeMap, io};
+
+use noodles_core::{Position, Region};
+use noodles_fasta as fasta;
+use noodles_sam::{
+    Header,
+    alignment::{
+        Record,
+        pileup::{Pileup, column::Entry},
+    },
+};
+
+const MISSING_BASE: u8 = b'N';
+
+/// A majority consensus caller.
+///
+/// The caller piles up coordinate-sorted records over a region and, for each position, emits the
+/// most common base. Deletions count as votes, and when a deletion wins, no base is emitted for the
+/// position. Positions with insufficient coverage or a tie for the most common base are called as
+/// `N`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::{
+///     self as sam,
+///     alignment::{
+///         record::{cigar::{op::Kind, Op}, Flags},
+///         RecordBuf,
+///     },
+///     header::record::value::{map::ReferenceSequence, Map},
+/// };
+/// use noodles_util::alignment::consensus::Caller;
+///
+/// let header = sam::Header::builder()
+///     .add_reference_sequence(
+///         "sq0",
+///         Map::<ReferenceSequence>::new(std::num::NonZeroUsize::try_from(4)?),
+///     )
+///     .build();
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::MIN)
+///     .set_cigar([Op::new(Kind::Match, 2)].into_iter().collect())
+///     .set_sequence(b"AC".to_vec().into())
+///     .build();
+///
+/// let caller = Caller::default();
+/// let region = "sq0".parse()?;
+/// let record = caller.call(&header, [Ok(record)].into_iter(), &region)?;
+///
+/// assert_eq!(record.name(), b"sq0");
+/// assert_eq!(record.sequence().as_ref(), b"ACNN");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Caller {
+    min_depth: usize,
+    min_base_quality: u8,
+    include_insertions: bool,
+}
+
+impl Caller {
+    /// Returns a builder to create a consensus caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::consensus::Caller;
+    /// let builder = Caller::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Calls the consensus sequence of a region.
+    ///
+    /// The records must be coordinate-sorted. The returned record is named after the region's
+    /// reference sequence name, and its sequence spans the region. If the region has no end, it
+    /// extends to the end of the reference sequence.
+    ///
+    /// This returns an error if the region's reference sequence name is not in the header or the
+    /// records are not coordinate-sorted.
+    pub fn call<I, R>(
+        &self,
+        header: &Header,
+        records: I,
+        region: &Region,
+    ) -> io::Result<fasta::Record>
+    where
+        I: Iterator<Item = io::Result<R>>,
+        R: Record,
+    {
+        let (start, end) = resolve_interval(header, region)?;
+        let region = Region::new(region.name(), start..=end);
+
+        let mut sequence = Vec::new();
+        let mut next_position = usize::from(start);
+
+        for result in Pileup::new(header, records, &region)? {
+            let column = result?;
+            let position = usize::from(column.position());
+
+            sequence.resize(sequence.len() + (position - next_position), MISSING_BASE);
+            self.call_column(column.entries(), &mut sequence);
+
+            next_position = position + 1;
+        }
+
+        let end = usize::from(end);
+
+        if next_position <= end {
+            sequence.resize(sequence.len() + (end - next_position + 1), MISSING_BASE);
+        }
+
+        Ok(fasta::Record::new(
+            fasta::record::Definition::new(region.name(), None),
+            fasta::record::Sequence::from(sequence),
+        ))
+    }
+
+    fn call_column(&self, entries: &[Entry], dst: &mut Vec<u8>) {
+        let entries: Vec<_> = entries
+            .iter()
+            .filter(|entry| self.is_qualifying_entry(entry))
+            .collect();
+
+        if entries.is_empty() || entries.len() < self.min_depth {
+            dst.push(MISSING_BASE);
+            return;
+        }
+
+        // `None` is a deletion.
+        let votes = entries
+            .iter()
+            .map(|entry| entry.base().map(|b| b.to_ascii_uppercase()));
+
+        match majority(votes) {
+            Some(Some(base)) => dst.push(base),
+            Some(None) => {}
+            None => dst.push(MISSING_BASE),
+        }
+
+        if self.include_insertions {
+            let votes = entries.iter().map(|entry| entry.insertion());
+
+            if let Some(insertion) = majority(votes) {
+                dst.extend(insertion.iter().map(|b| b.to_ascii_uppercase()));
+            }
+        }
+    }
+
+    fn is_qualifying_entry(&self, entry: &Entry) -> bool {
+        if entry.is_deletion() {
+            return true;
+        }
+
+        if entry.base().is_none() {
+            return false;
+        }
+
+        match entry.quality_score() {
+            Some(score) => score >= self.min_base_quality,
+            None => self.min_base_quality == 0,
+        }
+    }
+}
+
+impl Default for Caller {
+    fn default() -> Self {
+        Self {
+            min_depth: 1,
+            min_base_quality: 0,
+            include_insertions: false,
+        }
+    }
+}
+
+fn resolve_interval(header: &Header, region: &Region) -> io::Result<(Position, Position)> {
+    let interval = region.interval();
+    let start = interval.start().unwrap_or(Position::MIN);
+
+    let end = match interval.end() {
+        Some(end) => end,
+        None => header
+            .reference_sequences()
+            .get(region.name())
+            .and_then(|reference_sequence| Position::new(reference_sequence.length().get()))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "invalid reference sequence name",
+                )
+            })?,
+    };
+
+    Ok((start, end))
+}
+
+// Returns the most common value or `None` if there is a tie.
+fn majority<T, I>(values: I) -> Option<T>
+where
+    I: Iterator<Item = T>,
+    T: Ord,
+{
+    let mut counts = BTreeMap::new();
+
+    for value in values {
+        *counts.entry(value).or_insert(0) += 1;
+    }
+
+    let mut winner = None;
+    let mut max_count = 0;
+    let mut is_tie = false;
+
+    for (value, count) in counts {
+        if count > max_count {
+            winner = Some(value);
+            max_count = count;
+            is_tie = false;
+        } else if count == max_count {
+            is_tie = true;
+        }
+    }
+
+    if is_tie { None } else { winner }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_sam::{
+        self as sam,
+        alignment::{
+            RecordBuf,
+            record::{
+                Flags,
+                cigar::{Op, op::Kind},
+            },
+            record_buf::{QualityScores, Sequence},
+        },
+        header::record::value::{Map, map::ReferenceSequence},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_call() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let records = vec![
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGT"))
+                .set_quality_scores(QualityScores::from(vec![30; 4]))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(2)?)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                .set_sequence(Sequence::from(b"ACGA"))
+                .set_quality_scores(QualityScores::from(vec![30; 4]))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar([Op::new(Kind::Match, 3)].into_iter().collect())
+                .set_sequence(Sequence::from(b"cga"))
+                .set_quality_scores(QualityScores::from(vec![30; 3]))
+                .build(),
+            RecordBuf::builder()
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(3)?)
+                .set_cigar([Op::new(Kind::Match, 3)].into_iter().collect())
+                .set_sequence(Sequence::from(b"TGA"))
+                .set_quality_scores(QualityScores::from(vec![5; 3]))
+                .build(),
+        ];
+
+        let region = "sq0".parse()?;
+
+        let caller = Caller::default();
+        let record = caller.call(&header, records.clone().into_iter().map(Ok), &region)?;
+        assert_eq!(record.name(), b"sq0");
+        assert_eq!(record.sequence().as_ref(), b"NACGANNN");
+
+        let caller = Caller::builder()
+            .set_min_depth(3)
+            .set_min_base_quality(20)
+            .build();
+        let record = caller.call(&header, records.clone().into_iter().map(Ok), &region)?;
+        assert_eq!(record.sequence().as_ref(), b"NNCGANNN");
+
+        let region = "sq0:3-5".parse()?;
+        let record = Caller::default().call(&header, records.into_iter().map(Ok), &region)?;
+        assert_eq!(record.sequence().as_ref(), b"CGA");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_call_with_indels() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+        let region = "sq0:1-4".parse()?;
+
+        let insertion_record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(
+                [
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Insertion, 2),
+                    Op::new(Kind::Match, 2),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(Sequence::from(b"ACttGT"))
+            .set_quality_scores(QualityScores::from(vec![30; 6]))
+            .build();
+        let match_record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(Sequence::from(b"ACGT"))
+            .set_quality_scores(QualityScores::from(vec![30; 4]))
+            .build();
+
+        let records = vec![
+            insertion_record.clone(),
+            insertion_record.clone(),
+            insertion_record,
+            match_record.clone(),
+            match_record.clone(),
+        ];
+
+        let record =
+            Caller::default().call(&header, records.clone().into_iter().map(Ok), &region)?;
+        assert_eq!(record.sequence().as_ref(), b"ACGT");
+
+        let caller = Caller::builder().set_include_insertions(true).build();
+        let record = caller.call(&header, records.into_iter().map(Ok), &region)?;
+        assert_eq!(record.sequence().as_ref(), b"ACTTGT");
+
+        let deletion_record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar(
+                [
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Deletion, 1),
+                    Op::new(Kind::Match, 1),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_sequence(Sequence::from(b"ACT"))
+            .set_quality_scores(QualityScores::from(vec![30; 3]))
+            .build();
+
+        let records = vec![
+            deletion_record.clone(),
+            deletion_record.clone(),
+            deletion_record,
+            match_record.clone(),
+            match_record,
+        ];
+
+        let record = Caller::default().call(&header, records.into_iter().map(Ok), &region)?;
+        assert_eq!(record.sequence().as_ref(), b"ACT");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_majority() {
+        assert_eq!(majority([b'A', b'C', b'A'].into_iter()), Some(b'A'));
+        assert_eq!(majority([b'A', b'C'].into_iter()), None);
+        assert_eq!(majority([None, Some(b'A'), None].into_iter()), Some(None));
+        assert_eq!(majority(std::iter::empty::<u8>()), None);
+    }
+}
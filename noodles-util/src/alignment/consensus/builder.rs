@@ -0,0 +1,70 @@
+use super::Caller;
+
+/// A consensus caller builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    caller: Caller,
+}
+
+impl Builder {
+    /// Sets the minimum depth.
+    ///
+    /// Positions covered by fewer than this number of qualifying bases and deletions are called
+    /// as `N`. By default, this is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::consensus::Caller;
+    /// let caller = Caller::builder().set_min_depth(10).build();
+    /// ```
+    pub fn set_min_depth(mut self, min_depth: usize) -> Self {
+        self.caller.min_depth = min_depth;
+        self
+    }
+
+    /// Sets the minimum base quality.
+    ///
+    /// Bases with a quality score less than this value are ignored. Bases without quality scores
+    /// are only counted when the minimum base quality is 0. By default, this is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::consensus::Caller;
+    /// let caller = Caller::builder().set_min_base_quality(20).build();
+    /// ```
+    pub fn set_min_base_quality(mut self, min_base_quality: u8) -> Self {
+        self.caller.min_base_quality = min_base_quality;
+        self
+    }
+
+    /// Sets whether to include insertions in the consensus sequence.
+    ///
+    /// When enabled, an insertion after a position is included if it is the most common insertion
+    /// sequence at that position, where no insertion also counts as an option. By default,
+    /// insertions are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::consensus::Caller;
+    /// let caller = Caller::builder().set_include_insertions(true).build();
+    /// ```
+    pub fn set_include_insertions(mut self, include_insertions: bool) -> Self {
+        self.caller.include_insertions = include_insertions;
+        self
+    }
+
+    /// Builds a consensus caller.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::consensus::Caller;
+    /// let caller = Caller::builder().build();
+    /// ```
+    pub fn build(self) -> Caller {
+        self.caller
+    }
+}
@@ -0,0 +1,264 @@
+//! FASTQ to unmapped alignment record conversion.
+
+mod builder;
+
+pub use self::builder::Builder;
+
+use std::io;
+
+use bstr::{BStr, BString, ByteSlice};
+use noodles_core::phred;
+use noodles_fastq as fastq;
+use noodles_sam::alignment::{
+    RecordBuf,
+    record::{Flags, data::field::Tag},
+    record_buf::{Data, QualityScores, Sequence, data::field::Value},
+};
+
+/// A FASTQ record to unmapped alignment record converter.
+///
+/// This wraps FASTQ records as unmapped alignment records, e.g., to write an unmapped BAM
+/// (uBAM). The read name, sequence, and quality scores are preserved, and the quality scores are
+/// decoded from Phred+33.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_fastq as fastq;
+/// use noodles_sam::alignment::record::Flags;
+/// use noodles_util::alignment::fastq::Converter;
+///
+/// let record = fastq::Record::new(fastq::record::Definition::new("r0", ""), "ACGT", "NDLS");
+///
+/// let converter = Converter::default();
+/// let record = converter.convert(&record)?;
+///
+/// assert_eq!(record.name().map(|name| name.as_ref()), Some(&b"r0"[..]));
+/// assert_eq!(record.flags(), Flags::UNMAPPED);
+/// assert_eq!(record.sequence().as_ref(), b"ACGT");
+/// assert_eq!(record.quality_scores().as_ref(), [45, 35, 43, 50]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Converter {
+    read_group_id: Option<BString>,
+    barcode: Option<BString>,
+}
+
+impl Converter {
+    /// Returns a builder to create a converter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::fastq::Converter;
+    /// let builder = Converter::builder();
+    /// ```
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    /// Converts a FASTQ record to an unmapped alignment record.
+    ///
+    /// The record flags are set to `UNMAPPED`. This returns an error if a quality score is not a
+    /// valid Phred+33 value.
+    pub fn convert(&self, record: &fastq::Record) -> io::Result<RecordBuf> {
+        self.build_record(record.name(), record, Flags::UNMAPPED)
+    }
+
+    /// Converts a pair of FASTQ records to unmapped alignment records.
+    ///
+    /// The first record is the first segment, and the second record is the last segment. Both
+    /// records' flags are set to `SEGMENTED | UNMAPPED | MATE_UNMAPPED` with `FIRST_SEGMENT` or
+    /// `LAST_SEGMENT`, respectively.
+    ///
+    /// A mate suffix (`/1` or `/2`) is removed from the read names. This returns an error if the
+    /// read names do not match or a quality score is not a valid Phred+33 value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_fastq as fastq;
+    /// use noodles_sam::alignment::record::Flags;
+    /// use noodles_util::alignment::fastq::Converter;
+    ///
+    /// let r1 = fastq::Record::new(fastq::record::Definition::new("r0/1", ""), "ACGT", "NDLS");
+    /// let r2 = fastq::Record::new(fastq::record::Definition::new("r0/2", ""), "TGCA", "SLDN");
+    ///
+    /// let converter = Converter::default();
+    /// let (r1, r2) = converter.convert_pair(&r1, &r2)?;
+    ///
+    /// assert_eq!(r1.name().map(|name| name.as_ref()), Some(&b"r0"[..]));
+    /// assert_eq!(
+    ///     r1.flags(),
+    ///     Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED | Flags::FIRST_SEGMENT
+    /// );
+    ///
+    /// assert_eq!(r2.name().map(|name| name.as_ref()), Some(&b"r0"[..]));
+    /// assert_eq!(
+    ///     r2.flags(),
+    ///     Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED | Flags::LAST_SEGMENT
+    /// );
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn convert_pair(
+        &self,
+        r1: &fastq::Record,
+        r2: &fastq::Record,
+    ) -> io::Result<(RecordBuf, RecordBuf)> {
+        const PAIRED_FLAGS: Flags = Flags::SEGMENTED
+            .union(Flags::UNMAPPED)
+            .union(Flags::MATE_UNMAPPED);
+
+        let r1_name = trim_mate_suffix(r1.name());
+        let r2_name = trim_mate_suffix(r2.name());
+
+        if r1_name != r2_name {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("read name mismatch: {r1_name} != {r2_name}"),
+            ));
+        }
+
+        let r1 = self.build_record(r1_name, r1, PAIRED_FLAGS | Flags::FIRST_SEGMENT)?;
+        let r2 = self.build_record(r2_name, r2, PAIRED_FLAGS | Flags::LAST_SEGMENT)?;
+
+        Ok((r1, r2))
+    }
+
+    fn build_record(
+        &self,
+        name: &BStr,
+        record: &fastq::Record,
+        flags: Flags,
+    ) -> io::Result<RecordBuf> {
+        let mut builder = RecordBuf::builder()
+            .set_flags(flags)
+            .set_sequence(Sequence::from(record.sequence().to_vec()))
+            .set_quality_scores(decode_quality_scores(record.quality_scores())?)
+            .set_data(self.build_data());
+
+        if !name.is_empty() {
+            builder = builder.set_name(name);
+        }
+
+        Ok(builder.build())
+    }
+
+    fn build_data(&self) -> Data {
+        let mut data = Data::default();
+
+        if let Some(read_group_id) = &self.read_group_id {
+            data.insert(Tag::READ_GROUP, Value::String(read_group_id.clone()));
+        }
+
+        if let Some(barcode) = &self.barcode {
+            data.insert(Tag::SAMPLE_BARCODE_SEQUENCE, Value::String(barcode.clone()));
+        }
+
+        data
+    }
+}
+
+fn trim_mate_suffix(name: &BStr) -> &BStr {
+    name.strip_suffix(b"/1")
+        .or_else(|| name.strip_suffix(b"/2"))
+        .map(|s| s.as_bstr())
+        .unwrap_or(name)
+}
+
+fn decode_quality_scores(src: &[u8]) -> io::Result<QualityScores> {
+    src.iter()
+        .map(|&b| phred::decode(b))
+        .collect::<io::Result<Vec<_>>>()
+        .map(QualityScores::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use fastq::record::Definition;
+
+    use super::*;
+
+    #[test]
+    fn test_convert() -> io::Result<()> {
+        let converter = Converter::builder()
+            .set_read_group_id("rg0")
+            .set_barcode("ACGT")
+            .build();
+
+        let fastq_record = fastq::Record::new(Definition::new("r0/1", ""), "ACGT", "NDLS");
+        let record = converter.convert(&fastq_record)?;
+
+        let expected = RecordBuf::builder()
+            .set_name("r0/1")
+            .set_flags(Flags::UNMAPPED)
+            .set_sequence(Sequence::from(b"ACGT".to_vec()))
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .set_data(
+                [
+                    (Tag::READ_GROUP, Value::from("rg0")),
+                    (Tag::SAMPLE_BARCODE_SEQUENCE, Value::from("ACGT")),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        assert_eq!(record, expected);
+
+        let fastq_record = fastq::Record::new(Definition::new("r0", ""), "ACGT", "NDL\x1f");
+        assert!(matches!(
+            converter.convert(&fastq_record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_convert_pair() -> io::Result<()> {
+        let converter = Converter::builder().set_read_group_id("rg0").build();
+
+        let (r1, r2) = converter.convert_pair(
+            &fastq::Record::new(Definition::new("r0/1", ""), "ACGT", "NDLS"),
+            &fastq::Record::new(Definition::new("r0/2", ""), "ACGT", "NDLS"),
+        )?;
+
+        assert_eq!(r1.name(), Some(b"r0".as_bstr()));
+        assert_eq!(
+            r1.flags(),
+            Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED | Flags::FIRST_SEGMENT
+        );
+        assert_eq!(r1.data().get(&Tag::READ_GROUP), Some(&Value::from("rg0")));
+
+        assert_eq!(r2.name(), Some(b"r0".as_bstr()));
+        assert_eq!(
+            r2.flags(),
+            Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED | Flags::LAST_SEGMENT
+        );
+        assert_eq!(r2.data().get(&Tag::READ_GROUP), Some(&Value::from("rg0")));
+
+        let (r1, r2) = converter.convert_pair(
+            &fastq::Record::new(Definition::new("r0", ""), "ACGT", "NDLS"),
+            &fastq::Record::new(Definition::new("r0", ""), "ACGT", "NDLS"),
+        )?;
+        assert_eq!(r1.name(), Some(b"r0".as_bstr()));
+        assert_eq!(r2.name(), Some(b"r0".as_bstr()));
+
+        assert!(matches!(
+            converter.convert_pair(&fastq::Record::new(Definition::new("r0/1", ""), "ACGT", "NDLS"), &fastq::Record::new(Definition::new("r1/2", ""), "ACGT", "NDLS")),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trim_mate_suffix() {
+        assert_eq!(trim_mate_suffix(b"r0/1".as_bstr()), "r0");
+        assert_eq!(trim_mate_suffix(b"r0/2".as_bstr()), "r0");
+        assert_eq!(trim_mate_suffix(b"r0/3".as_bstr()), "r0/3");
+        assert_eq!(trim_mate_suffix(b"r0".as_bstr()), "r0");
+    }
+}
@@ -0,0 +1,60 @@
+use bstr::BString;
+
+use super::Converter;
+
+/// A FASTQ record converter builder.
+#[derive(Debug, Default)]
+pub struct Builder {
+    converter: Converter,
+}
+
+impl Builder {
+    /// Sets the read group ID.
+    ///
+    /// When set, converted records have a read group (`RG`) data field with this value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::fastq::Converter;
+    /// let converter = Converter::builder().set_read_group_id("rg0").build();
+    /// ```
+    pub fn set_read_group_id<I>(mut self, read_group_id: I) -> Self
+    where
+        I: Into<BString>,
+    {
+        self.converter.read_group_id = Some(read_group_id.into());
+        self
+    }
+
+    /// Sets the sample barcode sequence.
+    ///
+    /// When set, converted records have a sample barcode sequence (`BC`) data field with this
+    /// value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::fastq::Converter;
+    /// let converter = Converter::builder().set_barcode("ACGT").build();
+    /// ```
+    pub fn set_barcode<B>(mut self, barcode: B) -> Self
+    where
+        B: Into<BString>,
+    {
+        self.converter.barcode = Some(barcode.into());
+        self
+    }
+
+    /// Builds a FASTQ record converter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_util::alignment::fastq::Converter;
+    /// let converter = Converter::builder().build();
+    /// ```
+    pub fn build(self) -> Converter {
+        self.converter
+    }
+}
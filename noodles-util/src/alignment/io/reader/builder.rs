@@ -220,6 +220,26 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_detect_compression_method() -> io::Result<()> {
+        let mut src = &[0x1f, 0x8b][..];
+        assert_eq!(
+            detect_compression_method(&mut src)?,
+            Some(CompressionMethod::Bgzf)
+        );
+
+        let mut src = &b"@HD\tVN:1.6\n"[..];
+        assert!(detect_compression_method(&mut src)?.is_none());
+
+        let mut src = &b"CRAM"[..];
+        assert!(detect_compression_method(&mut src)?.is_none());
+
+        let mut src = &[][..];
+        assert!(detect_compression_method(&mut src)?.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_detect_format() -> io::Result<()> {
         use std::io::Write;
@@ -265,4 +285,94 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_build_from_reader() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                RecordBuf,
+                io::Write,
+                record::{
+                    Flags,
+                    cigar::{Op, op::Kind},
+                },
+            },
+            header::record::value::{Map, map::ReferenceSequence},
+        };
+
+        const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(8) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"ACGTACGT".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_sequence(b"ACGT".to_vec().into())
+            .set_quality_scores(vec![20; 4].into())
+            .build();
+
+        let mut writer = sam::io::Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        let sam_src = writer.into_inner();
+
+        let mut writer = sam::io::Writer::new(bgzf::io::Writer::new(Vec::new()));
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        let bgzf_sam_src = writer.into_inner().finish()?;
+
+        let mut writer = bam::io::Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        let bam_src = writer.into_inner().finish()?;
+
+        let mut writer = cram::io::writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_writer(Vec::new());
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+        let cram_src = writer.into_inner();
+
+        for src in [sam_src, bgzf_sam_src, bam_src, cram_src] {
+            let mut reader = Builder::default()
+                .set_reference_sequence_repository(repository.clone())
+                .build_from_reader(io::Cursor::new(src))?;
+
+            let actual_header = reader.read_header()?;
+            assert!(
+                actual_header
+                    .reference_sequences()
+                    .keys()
+                    .eq(header.reference_sequences().keys())
+            );
+
+            let records: Vec<_> = reader
+                .records(&actual_header)
+                .map(|result| {
+                    result.and_then(|r| RecordBuf::try_from_alignment_record(&actual_header, &r))
+                })
+                .collect::<io::Result<_>>()?;
+
+            assert_eq!(records, vec![record.clone()]);
+        }
+
+        Ok(())
+    }
 }
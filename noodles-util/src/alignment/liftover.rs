@@ -0,0 +1,340 @@
+//! Alignment record coordinate liftover.
+
+use std::io;
+
+use bstr::BString;
+use noodles_core::Position;
+use noodles_fasta::record::sequence::complement::complement;
+use noodles_sam::alignment::{
+    RecordBuf,
+    record::{Cigar as _, Flags, data::field::Tag},
+    record_buf::data::field::Value,
+};
+
+/// Lifts over the coordinates of an alignment record.
+///
+/// `f` maps a reference sequence ID and position to a position in the target assembly. It
+/// returns the target reference sequence ID, position, and whether the segment of the chain it
+/// lies in is inverted, or `None` if the position does not map.
+///
+/// The reference sequence ID and alignment start of a mapped record are lifted from the start
+/// and end of its alignment. Both must map to the same reference sequence with the same
+/// orientation, otherwise, the record does not map, and this returns `None`. Unmapped records that
+/// are placed only have their position lifted. Unplaced records are returned unchanged.
+///
+/// When the record is inverted, its reverse complemented flag is toggled; the sequence is reverse
+/// complemented; and the quality scores and CIGAR operations are reversed.
+///
+/// The mate reference sequence ID and alignment start are lifted similarly. An inverted mate
+/// toggles the mate reverse complemented flag and reverses the mate CIGAR (`MC`) data field, if
+/// present. The mate CIGAR is also used to find the mate alignment end, without which the lifted
+/// mate start of an inverted mate is approximated by the lift of its original start. If the mate
+/// position does not map, the mate reference sequence ID and alignment start are cleared.
+///
+/// The template length is kept, negated when the record is inverted, only if the record and its
+/// mate are lifted to the same reference sequence with the same orientation. Otherwise, it is set
+/// to 0.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_sam::alignment::{
+///     record::{cigar::{op::Kind, Op}, Flags},
+///     RecordBuf,
+/// };
+/// use noodles_util::alignment::liftover::lift_over;
+///
+/// let record = RecordBuf::builder()
+///     .set_flags(Flags::empty())
+///     .set_reference_sequence_id(0)
+///     .set_alignment_start(Position::try_from(8)?)
+///     .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+///     .set_sequence(b"ACGT".to_vec().into())
+///     .build();
+///
+/// // Shifts positions on reference sequence 0 by 100 bases to reference sequence 1.
+/// let f = |id, position: Position| {
+///     (id == 0).then(|| (1, position.checked_add(100).unwrap(), false))
+/// };
+///
+/// let record = lift_over(record, f)?.expect("record did not map");
+/// assert_eq!(record.reference_sequence_id(), Some(1));
+/// assert_eq!(record.alignment_start(), Position::new(108));
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn lift_over<F>(mut record: RecordBuf, mut f: F) -> io::Result<Option<RecordBuf>>
+where
+    F: FnMut(usize, Position) -> Option<(usize, Position, bool)>,
+{
+    let mut is_inverted = false;
+
+    if let (Some(id), Some(start)) = (record.reference_sequence_id(), record.alignment_start()) {
+        let lifted = if record.flags().is_unmapped() {
+            f(id, start).map(|(id, start, _)| (id, start, false))
+        } else {
+            let end = record.alignment_end().unwrap_or(start);
+            lift_interval(&mut f, id, start, end)
+        };
+
+        let Some((id, start, inverted)) = lifted else {
+            return Ok(None);
+        };
+
+        *record.reference_sequence_id_mut() = Some(id);
+        *record.alignment_start_mut() = Some(start);
+
+        if inverted {
+            invert(&mut record);
+            is_inverted = true;
+        }
+    }
+
+    if let (Some(id), Some(start)) = (
+        record.mate_reference_sequence_id(),
+        record.mate_alignment_start(),
+    ) {
+        let mate_end = mate_alignment_end(&record, start)?;
+
+        match lift_interval(&mut f, id, start, mate_end.unwrap_or(start)) {
+            Some((mate_id, mate_start, mate_is_inverted)) => {
+                *record.mate_reference_sequence_id_mut() = Some(mate_id);
+                *record.mate_alignment_start_mut() = Some(mate_start);
+
+                if mate_is_inverted {
+                    invert_mate(&mut record);
+                }
+
+                let is_same_frame = record.reference_sequence_id() == Some(mate_id)
+                    && is_inverted == mate_is_inverted;
+
+                if !is_same_frame {
+                    *record.template_length_mut() = 0;
+                } else if is_inverted {
+                    *record.template_length_mut() = -record.template_length();
+                }
+            }
+            None => {
+                *record.mate_reference_sequence_id_mut() = None;
+                *record.mate_alignment_start_mut() = None;
+                *record.template_length_mut() = 0;
+            }
+        }
+    }
+
+    Ok(Some(record))
+}
+
+// Lifts an interval, returning the start of the lifted interval.
+fn lift_interval<F>(
+    f: &mut F,
+    id: usize,
+    start: Position,
+    end: Position,
+) -> Option<(usize, Position, bool)>
+where
+    F: FnMut(usize, Position) -> Option<(usize, Position, bool)>,
+{
+    let (start_id, lifted_start, start_is_inverted) = f(id, start)?;
+    let (end_id, lifted_end, end_is_inverted) = f(id, end)?;
+
+    if start_id != end_id || start_is_inverted != end_is_inverted {
+        return None;
+    }
+
+    if start_is_inverted {
+        Some((start_id, lifted_end, true))
+    } else {
+        Some((start_id, lifted_start, false))
+    }
+}
+
+fn mate_alignment_end(record: &RecordBuf, mate_start: Position) -> io::Result<Option<Position>> {
+    let Some(Value::String(mate_cigar)) = record.data().get(&Tag::MATE_CIGAR) else {
+        return Ok(None);
+    };
+
+    let span = noodles_sam::record::Cigar::new(mate_cigar).alignment_span()?;
+
+    Ok(match span {
+        0 => Some(mate_start),
+        n => mate_start.checked_add(n - 1),
+    })
+}
+
+fn invert(record: &mut RecordBuf) {
+    record.flags_mut().toggle(Flags::REVERSE_COMPLEMENTED);
+
+    let sequence = record.sequence_mut().as_mut();
+    sequence.reverse();
+
+    for base in sequence.iter_mut() {
+        // Bases without a complement, e.g., `=`, are kept as is.
+        *base = complement(*base).unwrap_or(*base);
+    }
+
+    record.quality_scores_mut().as_mut().reverse();
+    record.cigar_mut().as_mut().reverse();
+}
+
+fn invert_mate(record: &mut RecordBuf) {
+    record.flags_mut().toggle(Flags::MATE_REVERSE_COMPLEMENTED);
+
+    if let Some(Value::String(mate_cigar)) = record.data_mut().get_mut(&Tag::MATE_CIGAR) {
+        *mate_cigar = reverse_cigar(mate_cigar);
+    }
+}
+
+// Reverses the operations of a raw CIGAR string, e.g., `8M2I` => `2I8M`.
+fn reverse_cigar(src: &[u8]) -> BString {
+    let mut ops: Vec<_> = src.split_inclusive(|b| !b.is_ascii_digit()).collect();
+
+    ops.reverse();
+
+    ops.concat().into()
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::record::cigar::{Op, op::Kind};
+
+    use super::*;
+
+    // Maps reference sequence 0 to reference sequence 1. Positions 1-100 are shifted by 100
+    // bases, and positions 101-200 are inverted to 1-100.
+    fn f(id: usize, position: Position) -> Option<(usize, Position, bool)> {
+        if id != 0 {
+            return None;
+        }
+
+        let n = usize::from(position);
+
+        match n {
+            1..=100 => Position::new(n + 100).map(|p| (1, p, false)),
+            101..=200 => Position::new(201 - n).map(|p| (1, p, true)),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_lift_over() -> Result<(), Box<dyn std::error::Error>> {
+        let record = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(21)?)
+            .set_template_length(17)
+            .build();
+
+        let actual = lift_over(record, f)?;
+
+        let expected = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED)
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::try_from(108)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_mate_reference_sequence_id(1)
+            .set_mate_alignment_start(Position::try_from(121)?)
+            .set_template_length(17)
+            .build();
+
+        assert_eq!(actual, Some(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lift_over_with_inversion() -> Result<(), Box<dyn std::error::Error>> {
+        let record = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(101)?)
+            .set_cigar(
+                [Op::new(Kind::SoftClip, 1), Op::new(Kind::Match, 4)]
+                    .into_iter()
+                    .collect(),
+            )
+            .set_sequence(b"nACGG".to_vec().into())
+            .set_quality_scores(vec![1, 2, 3, 4, 5].into())
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(111)?)
+            .set_template_length(20)
+            .set_data(
+                [(Tag::MATE_CIGAR, Value::from("8M2S"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let actual = lift_over(record, f)?;
+
+        let expected = RecordBuf::builder()
+            .set_flags(
+                Flags::SEGMENTED | Flags::REVERSE_COMPLEMENTED | Flags::MATE_REVERSE_COMPLEMENTED,
+            )
+            .set_reference_sequence_id(1)
+            .set_alignment_start(Position::try_from(97)?)
+            .set_cigar(
+                [Op::new(Kind::Match, 4), Op::new(Kind::SoftClip, 1)]
+                    .into_iter()
+                    .collect(),
+            )
+            .set_sequence(b"CCGTn".to_vec().into())
+            .set_quality_scores(vec![5, 4, 3, 2, 1].into())
+            .set_mate_reference_sequence_id(1)
+            .set_mate_alignment_start(Position::try_from(83)?)
+            .set_template_length(-20)
+            .set_data(
+                [(Tag::MATE_CIGAR, Value::from("2S8M"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert_eq!(actual, Some(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_lift_over_with_unmapped_positions() -> Result<(), Box<dyn std::error::Error>> {
+        let record = RecordBuf::builder()
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(98)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .build();
+
+        // The alignment spans the inversion breakpoint.
+        assert!(lift_over(record, f)?.is_none());
+
+        let record = RecordBuf::builder()
+            .set_flags(Flags::SEGMENTED)
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::try_from(8)?)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+            .set_mate_reference_sequence_id(0)
+            .set_mate_alignment_start(Position::try_from(233)?)
+            .set_template_length(229)
+            .build();
+
+        let record = lift_over(record, f)?.expect("record did not map");
+        assert_eq!(record.alignment_start(), Position::new(108));
+        assert!(record.mate_reference_sequence_id().is_none());
+        assert!(record.mate_alignment_start().is_none());
+        assert_eq!(record.template_length(), 0);
+
+        let record = RecordBuf::default();
+        assert_eq!(lift_over(record.clone(), f)?, Some(record));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reverse_cigar() {
+        assert_eq!(reverse_cigar(b"8M2I1D"), "1D2I8M");
+        assert_eq!(reverse_cigar(b""), "");
+    }
+}
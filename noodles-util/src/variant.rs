@@ -3,7 +3,8 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+mod convert;
 pub mod io;
 mod record;
 
-pub use self::record::Record;
+pub use self::{convert::convert, record::Record};
@@ -81,6 +81,8 @@ impl Builder {
     /// By default, the format and compression method will be autodetected. This can be overridden
     /// by using [`Self::set_format`] and [`Self::set_compression_method`].
     ///
+    /// Plain gzip (i.e., non-BGZF) compression is not supported.
+    ///
     /// # Examples
     ///
     /// ```
@@ -138,6 +140,12 @@ impl Builder {
                     Box::new(bgzf::r#async::io::Reader::new(reader));
                 Reader::Bcf(bcf::r#async::io::Reader::from(decoder))
             }
+            (_, Some(CompressionMethod::Gzip)) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "gzip compression is not supported",
+                ));
+            }
         };
 
         Ok(reader)
@@ -88,7 +88,7 @@ impl Builder {
     /// Builds a variant writer from a writer.
     ///
     /// If the format is not set, a default format is used. If the compression method is not set, a
-    /// default one is determined by the format.
+    /// default one is determined by the format. Gzip compression is written as BGZF.
     ///
     /// # Examples
     ///
@@ -120,7 +120,8 @@ impl Builder {
                 let inner: Box<dyn AsyncWrite + Unpin> = Box::new(writer);
                 Writer::Vcf(vcf::r#async::io::Writer::new(inner))
             }
-            (Format::Vcf, Some(CompressionMethod::Bgzf)) => {
+            // BGZF is a gzip-compatible format.
+            (Format::Vcf, Some(CompressionMethod::Bgzf | CompressionMethod::Gzip)) => {
                 let encoder: Box<dyn AsyncWrite + Unpin> =
                     Box::new(bgzf::r#async::io::Writer::new(writer));
                 Writer::Vcf(vcf::r#async::io::Writer::new(encoder))
@@ -129,7 +130,7 @@ impl Builder {
                 let inner: Box<dyn AsyncWrite + Unpin> = Box::new(writer);
                 Writer::Bcf(bcf::r#async::io::Writer::from(inner))
             }
-            (Format::Bcf, Some(CompressionMethod::Bgzf)) => {
+            (Format::Bcf, Some(CompressionMethod::Bgzf | CompressionMethod::Gzip)) => {
                 let encoder: Box<dyn AsyncWrite + Unpin> =
                     Box::new(bgzf::r#async::io::Writer::new(writer));
                 Writer::Bcf(bcf::r#async::io::Writer::from(encoder))
@@ -0,0 +1,85 @@
+use std::io;
+
+use noodles_vcf::variant::io::{Read, Write};
+
+/// Converts a variant stream to another variant format.
+///
+/// This reads the header from the source reader, writes it to the destination writer, and then
+/// streams each record through. The reader can be any variant reader, e.g.,
+/// [`super::io::Reader`], [`super::io::IndexedReader`], or a format-specific reader. Likewise, the
+/// writer can be a [`super::io::Writer`], where the output format is set using
+/// [`super::io::Format`], or a format-specific writer.
+///
+/// Writers are not finalized, i.e., callers are responsible for finishing the output stream, if
+/// necessary.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf as bcf;
+/// use noodles_util::variant::{self, io::reader::Builder};
+///
+/// let src = b"##fileformat=VCFv4.5\n##contig=<ID=sq0>
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// sq0\t1\t.\tA\t.\t.\t.\t.
+/// ";
+///
+/// let mut reader = Builder::default().build_from_reader(&src[..])?;
+/// let mut writer = bcf::io::Writer::new(Vec::new());
+///
+/// variant::convert(&mut reader, &mut writer)?;
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn convert<S, R, W>(reader: &mut R, writer: &mut W) -> io::Result<()>
+where
+    R: Read<S>,
+    W: Write,
+{
+    let header = reader.read_variant_header()?;
+    writer.write_variant_header(&header)?;
+
+    for result in reader.variant_records(&header) {
+        let record = result?;
+        writer.write_variant_record(&header, record.as_ref())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_bcf as bcf;
+    use noodles_vcf as vcf;
+
+    use super::*;
+    use crate::variant::io::reader::Builder;
+
+    #[test]
+    fn test_convert() -> io::Result<()> {
+        const SRC: &[u8] = b"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##FILTER=<ID=PASS,Description=\"All filters passed\">
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##contig=<ID=sq0,length=8>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\tC\t13\tq10\tDP=5\tGT\t0|1
+sq0\t3\tid0\tG\t.\t.\tPASS\t.\tGT\t0/0
+";
+
+        // VCF to BCF
+        let mut reader = Builder::default().build_from_reader(SRC)?;
+        let mut writer = bcf::io::Writer::new(Vec::new());
+        convert(&mut reader, &mut writer)?;
+        let bcf_src = writer.into_inner().finish()?;
+
+        // BCF to VCF
+        let mut reader = Builder::default().build_from_reader(io::Cursor::new(bcf_src))?;
+        let mut writer = vcf::io::Writer::new(Vec::new());
+        convert(&mut reader, &mut writer)?;
+
+        assert_eq!(writer.get_ref(), SRC);
+
+        Ok(())
+    }
+}
@@ -1,6 +1,12 @@
 /// A variant compression method.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum CompressionMethod {
     /// BGZF compression.
     Bgzf,
+    /// gzip compression.
+    ///
+    /// This is a plain gzip stream that is not BGZF-framed. It can only be read sequentially,
+    /// i.e., it does not support indexed queries.
+    Gzip,
 }
@@ -85,3 +85,19 @@ where
         Ok(records)
     }
 }
+
+impl<R> vcf::variant::io::Read<R> for IndexedReader<R>
+where
+    R: BufRead,
+{
+    fn read_variant_header(&mut self) -> io::Result<vcf::Header> {
+        self.read_header()
+    }
+
+    fn variant_records<'r, 'h: 'r>(
+        &'r mut self,
+        _: &'h vcf::Header,
+    ) -> Box<dyn Iterator<Item = io::Result<Box<dyn Record>>> + 'r> {
+        Box::new(self.records())
+    }
+}
@@ -127,7 +127,7 @@ impl Builder {
 
                 builder.build_from_path(src).map(IndexedReader::Bcf)
             }
-            (_, None) => Err(io::Error::new(
+            (_, None | Some(CompressionMethod::Gzip)) => Err(io::Error::new(
                 io::ErrorKind::InvalidData,
                 "source not bgzip-compressed",
             )),
@@ -195,7 +195,7 @@ impl Builder {
 
                 builder.build_from_reader(reader).map(IndexedReader::Bcf)
             }
-            (_, None) => Err(io::Error::new(
+            (_, None | Some(CompressionMethod::Gzip)) => Err(io::Error::new(
                 io::ErrorKind::InvalidData,
                 "source not bgzip-compressed",
             )),
@@ -126,3 +126,19 @@ where
         }
     }
 }
+
+impl<R> vcf::variant::io::Read<R> for Reader<R>
+where
+    R: BufRead,
+{
+    fn read_variant_header(&mut self) -> io::Result<vcf::Header> {
+        self.read_header()
+    }
+
+    fn variant_records<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h vcf::Header,
+    ) -> Box<dyn Iterator<Item = io::Result<Box<dyn vcf::variant::Record>>> + 'r> {
+        Box::new(self.records(header))
+    }
+}
@@ -4,6 +4,7 @@ use std::{
     path::Path,
 };
 
+use flate2::bufread::MultiGzDecoder;
 use noodles_bcf as bcf;
 use noodles_bgzf as bgzf;
 use noodles_vcf as vcf;
@@ -11,6 +12,12 @@ use noodles_vcf as vcf;
 use super::Reader;
 use crate::variant::io::{CompressionMethod, Format};
 
+const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+const FLG_POSITION: usize = 3;
+const FEXTRA: u8 = 0x04;
+const XLEN_POSITION: usize = 10;
+const BCF_MAGIC_NUMBER: [u8; 3] = *b"BCF";
+
 /// A variant reader builder.
 #[derive(Default)]
 pub struct Builder {
@@ -90,18 +97,24 @@ impl Builder {
     {
         use super::Inner;
 
-        let mut reader = BufReader::new(reader);
+        let mut reader = reader;
+        let mut buf = read_gzip_header(&mut reader)?;
 
         let compression_method = match self.compression_method {
             Some(compression_method) => compression_method,
-            None => detect_compression_method(&mut reader)?,
+            None => detect_compression_method(&mut &buf[..])?,
         };
 
         let format = match self.format {
             Some(format) => format,
-            None => detect_format(&mut reader, compression_method)?,
+            None if compression_method.is_some() => {
+                detect_compressed_format(&mut reader, &mut buf)?
+            }
+            None => detect_format(&mut &buf[..], None)?,
         };
 
+        let reader = BufReader::new(io::Cursor::new(buf).chain(reader));
+
         let inner = match (format, compression_method) {
             (Format::Vcf, None) => {
                 let inner: Box<dyn BufRead> = Box::new(reader);
@@ -111,6 +124,10 @@ impl Builder {
                 let inner: Box<dyn BufRead> = Box::new(bgzf::io::Reader::new(reader));
                 Inner::Vcf(vcf::io::Reader::new(inner))
             }
+            (Format::Vcf, Some(CompressionMethod::Gzip)) => {
+                let inner: Box<dyn BufRead> = Box::new(BufReader::new(MultiGzDecoder::new(reader)));
+                Inner::Vcf(vcf::io::Reader::new(inner))
+            }
             (Format::Bcf, None) => {
                 let inner: Box<dyn BufRead> = Box::new(reader);
                 Inner::Bcf(bcf::io::Reader::from(inner))
@@ -119,29 +136,157 @@ impl Builder {
                 let inner: Box<dyn BufRead> = Box::new(bgzf::io::Reader::new(reader));
                 Inner::Bcf(bcf::io::Reader::from(inner))
             }
+            (Format::Bcf, Some(CompressionMethod::Gzip)) => {
+                let inner: Box<dyn BufRead> = Box::new(BufReader::new(MultiGzDecoder::new(reader)));
+                Inner::Bcf(bcf::io::Reader::from(inner))
+            }
         };
 
         Ok(Reader { inner })
     }
 }
 
+// Reads the start of the stream up to the end of the gzip member extra field, if any.
+//
+// A single read may be too short to include the whole gzip member header, so the fixed header (10
+// bytes) and XLEN (2 bytes) are read first, followed by the extra field (XLEN bytes) when FEXTRA
+// is set. Only the bytes needed for detection are read.
+fn read_gzip_header<R>(reader: &mut R) -> io::Result<Vec<u8>>
+where
+    R: Read,
+{
+    const FIXED_HEADER_LEN: u64 = XLEN_POSITION as u64 + 2;
+
+    let mut buf = Vec::new();
+    reader
+        .by_ref()
+        .take(FIXED_HEADER_LEN)
+        .read_to_end(&mut buf)?;
+
+    let has_extra_field = buf.get(FLG_POSITION).is_some_and(|flg| flg & FEXTRA != 0);
+
+    if buf.starts_with(&GZIP_MAGIC_NUMBER) && has_extra_field {
+        if let Some(&[lo, hi]) = buf.get(XLEN_POSITION..XLEN_POSITION + 2) {
+            let xlen = u16::from_le_bytes([lo, hi]);
+            reader
+                .by_ref()
+                .take(u64::from(xlen))
+                .read_to_end(&mut buf)?;
+        }
+    }
+
+    Ok(buf)
+}
+
+// Detects the format of a compressed stream.
+//
+// Only as much of the stream as needed to decompress the format magic number is read. The bytes
+// read are appended to `buf`, which holds the start of the stream.
+fn detect_compressed_format<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<Format>
+where
+    R: Read,
+{
+    use flate2::read::MultiGzDecoder;
+
+    let mut rest = Vec::new();
+
+    let mut magic_number = [0; BCF_MAGIC_NUMBER.len()];
+
+    {
+        let tee = TeeReader {
+            inner: reader,
+            buf: &mut rest,
+        };
+
+        let mut decoder = MultiGzDecoder::new(buf.as_slice().chain(tee));
+        decoder.read_exact(&mut magic_number)?;
+    }
+
+    buf.extend(rest);
+
+    if magic_number == BCF_MAGIC_NUMBER {
+        Ok(Format::Bcf)
+    } else {
+        Ok(Format::Vcf)
+    }
+}
+
+// A reader that copies the bytes read from the inner reader to a buffer.
+struct TeeReader<'a, R> {
+    inner: R,
+    buf: &'a mut Vec<u8>,
+}
+
+impl<R> Read for TeeReader<'_, R>
+where
+    R: Read,
+{
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.buf.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+}
+
 pub(crate) fn detect_compression_method<R>(reader: &mut R) -> io::Result<Option<CompressionMethod>>
 where
     R: BufRead,
 {
-    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
-
     let src = reader.fill_buf()?;
 
     if let Some(buf) = src.get(..GZIP_MAGIC_NUMBER.len()) {
         if buf == GZIP_MAGIC_NUMBER {
-            return Ok(Some(CompressionMethod::Bgzf));
+            // An incomplete header is assumed to be BGZF.
+            if is_bgzf(src).unwrap_or(true) {
+                return Ok(Some(CompressionMethod::Bgzf));
+            } else {
+                return Ok(Some(CompressionMethod::Gzip));
+            }
         }
     }
 
     Ok(None)
 }
 
+// Returns whether the gzip member header has a BGZF block size (`BC`) extra subfield.
+//
+// This returns `None` if the header is incomplete.
+fn is_bgzf(src: &[u8]) -> Option<bool> {
+    const BGZF_SI1: u8 = b'B';
+    const BGZF_SI2: u8 = b'C';
+
+    let flg = src.get(FLG_POSITION)?;
+
+    if flg & FEXTRA == 0 {
+        return Some(false);
+    }
+
+    let xlen = src
+        .get(XLEN_POSITION..XLEN_POSITION + 2)
+        .map(|buf| usize::from(u16::from_le_bytes([buf[0], buf[1]])))?;
+
+    let start = XLEN_POSITION + 2;
+    let mut extra = src.get(start..start + xlen)?;
+
+    while let Some((header, rest)) = extra.split_first_chunk::<4>() {
+        let [si1, si2, slen_lo, slen_hi] = *header;
+
+        if si1 == BGZF_SI1 && si2 == BGZF_SI2 {
+            return Some(true);
+        }
+
+        let slen = usize::from(u16::from_le_bytes([slen_lo, slen_hi]));
+
+        let Some(next) = rest.get(slen..) else {
+            return Some(false);
+        };
+
+        extra = next;
+    }
+
+    Some(false)
+}
+
 pub(crate) fn detect_format<R>(
     reader: &mut R,
     compression_method: Option<CompressionMethod>,
@@ -149,21 +294,15 @@ pub(crate) fn detect_format<R>(
 where
     R: BufRead,
 {
-    use flate2::bufread::MultiGzDecoder;
-
-    const BCF_MAGIC_NUMBER: [u8; 3] = *b"BCF";
-
     let src = reader.fill_buf()?;
 
-    if let Some(compression_method) = compression_method {
-        if compression_method == CompressionMethod::Bgzf {
-            let mut decoder = MultiGzDecoder::new(src);
-            let mut buf = [0; BCF_MAGIC_NUMBER.len()];
-            decoder.read_exact(&mut buf)?;
+    if compression_method.is_some() {
+        let mut decoder = MultiGzDecoder::new(src);
+        let mut buf = [0; BCF_MAGIC_NUMBER.len()];
+        decoder.read_exact(&mut buf)?;
 
-            if buf == BCF_MAGIC_NUMBER {
-                return Ok(Format::Bcf);
-            }
+        if buf == BCF_MAGIC_NUMBER {
+            return Ok(Format::Bcf);
         }
     } else if let Some(buf) = src.get(..BCF_MAGIC_NUMBER.len()) {
         if buf == BCF_MAGIC_NUMBER {
@@ -180,6 +319,18 @@ mod tests {
 
     #[test]
     fn test_detect_compression_method() -> io::Result<()> {
+        let src = bgzf::io::Writer::new(Vec::new()).finish()?;
+        assert_eq!(
+            detect_compression_method(&mut &src[..])?,
+            Some(CompressionMethod::Bgzf)
+        );
+
+        let src = flate2::write::GzEncoder::new(Vec::new(), Default::default()).finish()?;
+        assert_eq!(
+            detect_compression_method(&mut &src[..])?,
+            Some(CompressionMethod::Gzip)
+        );
+
         let mut src = &[0x1f, 0x8b][..];
         assert_eq!(
             detect_compression_method(&mut src)?,
@@ -228,6 +379,19 @@ mod tests {
         let src = writer.into_inner().finish()?;
         t(&src, Some(CompressionMethod::Bgzf), Format::Bcf);
 
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(&raw_header)?;
+        let src = encoder.finish()?;
+        t(&src, Some(CompressionMethod::Gzip), Format::Vcf);
+
+        let mut writer = bcf::io::Writer::from(flate2::write::GzEncoder::new(
+            Vec::new(),
+            Default::default(),
+        ));
+        writer.write_header(&header)?;
+        let src = writer.into_inner().finish()?;
+        t(&src, Some(CompressionMethod::Gzip), Format::Bcf);
+
         // An incomplete gzip stream.
         #[rustfmt::skip]
         let src = [
@@ -248,4 +412,124 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_is_bgzf() {
+        // FLG = 0
+        assert_eq!(is_bgzf(&[0x1f, 0x8b, 0x08, 0x00]), Some(false));
+
+        // FLG = FEXTRA, XLEN = 4, SI1 = 'A', SI2 = 'B', SLEN = 0
+        assert_eq!(
+            is_bgzf(&[
+                0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x04, 0x00, b'A', b'B',
+                0x00, 0x00,
+            ]),
+            Some(false)
+        );
+
+        // FLG = FEXTRA, XLEN = 10, [SI1 = 'A', SI2 = 'B', SLEN = 0], [SI1 = 'B', SI2 = 'C', SLEN = 2]
+        assert_eq!(
+            is_bgzf(&[
+                0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x0a, 0x00, b'A', b'B',
+                0x00, 0x00, b'B', b'C', 0x02, 0x00, 0x00, 0x40,
+            ]),
+            Some(true)
+        );
+
+        // FLG = FEXTRA, incomplete XLEN
+        assert!(
+            is_bgzf(&[
+                0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06
+            ])
+            .is_none()
+        );
+
+        // Incomplete FLG
+        assert!(is_bgzf(&[0x1f, 0x8b]).is_none());
+    }
+
+    #[test]
+    fn test_build_from_reader_with_short_reads() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        // A reader that returns at most one byte per read.
+        struct ByteReader<R>(R);
+
+        impl<R: Read> Read for ByteReader<R> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let len = buf.len().min(1);
+                self.0.read(&mut buf[..len])
+            }
+        }
+
+        const DATA: &[u8] = b"##fileformat=VCFv4.5
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        let bgzf_src = writer.finish()?;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(DATA)?;
+        let gzip_src = encoder.finish()?;
+
+        for src in [bgzf_src, gzip_src] {
+            let mut reader =
+                Builder::default().build_from_reader(ByteReader(io::Cursor::new(src)))?;
+            let header = reader.read_header()?;
+            assert_eq!(reader.records(&header).count(), 1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reader_reads_only_detection_bytes() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use std::io::Write;
+
+        // A reader that fails on read, e.g., a pipe with no more available data.
+        struct ErrReader;
+
+        impl Read for ErrReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::WouldBlock))
+            }
+        }
+
+        const DATA: &[u8] = b"##fileformat=VCFv4.5\n";
+
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        writer.flush()?;
+        let bgzf_src = writer.get_ref().clone();
+
+        for src in [DATA.to_vec(), bgzf_src] {
+            Builder::default().build_from_reader(io::Cursor::new(src).chain(ErrReader))?;
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reader_with_gzip_compression() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(
+            b"##fileformat=VCFv4.5
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+",
+        )?;
+        let src = encoder.finish()?;
+
+        let mut reader = Builder::default().build_from_reader(io::Cursor::new(src))?;
+        let header = reader.read_header()?;
+        assert_eq!(reader.records(&header).count(), 1);
+
+        Ok(())
+    }
 }
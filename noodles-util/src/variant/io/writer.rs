@@ -65,3 +65,17 @@ impl Writer {
         self.inner.write_variant_record(header, record)
     }
 }
+
+impl vcf::variant::io::Write for Writer {
+    fn write_variant_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        self.write_header(header)
+    }
+
+    fn write_variant_record(
+        &mut self,
+        header: &vcf::Header,
+        record: &dyn vcf::variant::Record,
+    ) -> io::Result<()> {
+        self.write_record(header, record)
+    }
+}
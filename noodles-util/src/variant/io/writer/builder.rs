@@ -78,7 +78,7 @@ impl Builder {
     /// Builds a variant writer from a writer.
     ///
     /// If the format is not set, a default format is used. If the compression method is not set, a
-    /// default one is determined by the format.
+    /// default one is determined by the format. Gzip compression is written as BGZF.
     ///
     /// # Examples
     ///
@@ -103,11 +103,14 @@ impl Builder {
 
         let inner: Box<dyn vcf::variant::io::Write> = match (format, compression_method) {
             (Format::Vcf, None) => Box::new(vcf::io::Writer::new(writer)),
-            (Format::Vcf, Some(CompressionMethod::Bgzf)) => {
+            // BGZF is a gzip-compatible format.
+            (Format::Vcf, Some(CompressionMethod::Bgzf | CompressionMethod::Gzip)) => {
                 Box::new(vcf::io::Writer::new(bgzf::io::Writer::new(writer)))
             }
             (Format::Bcf, None) => Box::new(bcf::io::Writer::from(writer)),
-            (Format::Bcf, Some(CompressionMethod::Bgzf)) => Box::new(bcf::io::Writer::new(writer)),
+            (Format::Bcf, Some(CompressionMethod::Bgzf | CompressionMethod::Gzip)) => {
+                Box::new(bcf::io::Writer::new(writer))
+            }
         };
 
         Writer { inner }
@@ -2,12 +2,12 @@ use std::vec;
 
 use futures::{Stream, stream};
 use noodles_bgzf as bgzf;
-use noodles_core::region::Interval;
+use noodles_core::region::{Interval, QueryMode};
 use noodles_csi::binning_index::index::reference_sequence::bin::Chunk;
 use tokio::io::{self, AsyncRead, AsyncSeek};
 
 use super::Reader;
-use crate::{Header, Record, io::reader::query::intersects};
+use crate::{Header, Record, io::reader::query::matches};
 
 enum State {
     Seek,
@@ -72,11 +72,12 @@ where
                             ctx.state = State::Seek;
                         }
 
-                        if intersects(
+                        if matches(
                             ctx.header,
                             &record,
                             &ctx.reference_sequence_name,
                             ctx.interval,
+                            QueryMode::Overlap,
                         )? {
                             return Ok(Some((record, ctx)));
                         }
@@ -0,0 +1,83 @@
+//! Insertion-ordered collection utilities.
+
+use indexmap::{IndexMap, IndexSet};
+
+/// Renames a map key in place.
+///
+/// The entry keeps its position. An existing entry with the new key is removed. This returns
+/// whether an entry with the old key was renamed.
+pub(crate) fn rename_key<V>(map: &mut IndexMap<String, V>, old: &str, new: &str) -> bool {
+    if !map.contains_key(old) {
+        return false;
+    } else if old == new {
+        return true;
+    }
+
+    map.shift_remove(new);
+
+    if let Some((i, _, value)) = map.shift_remove_full(old) {
+        map.shift_insert(i, new.into(), value);
+    }
+
+    true
+}
+
+/// Renames a set value in place.
+///
+/// The value keeps its position. An existing new value is removed. This returns whether the old
+/// value was renamed.
+pub(crate) fn rename_value(set: &mut IndexSet<String>, old: &str, new: &str) -> bool {
+    if !set.contains(old) {
+        return false;
+    } else if old == new {
+        return true;
+    }
+
+    set.shift_remove(new);
+
+    if let Some((i, _)) = set.shift_remove_full(old) {
+        set.shift_insert(i, new.into());
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rename_key() {
+        let mut map: IndexMap<_, _> = [("a", 0), ("b", 1), ("c", 2)]
+            .into_iter()
+            .map(|(k, v)| (String::from(k), v))
+            .collect();
+
+        assert!(rename_key(&mut map, "b", "d"));
+        assert_eq!(map.get_index(1), Some((&String::from("d"), &1)));
+
+        assert!(rename_key(&mut map, "c", "a"));
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get_index(1), Some((&String::from("a"), &2)));
+
+        assert!(rename_key(&mut map, "a", "a"));
+        assert!(!rename_key(&mut map, "x", "y"));
+        assert_eq!(map.len(), 2);
+    }
+
+    #[test]
+    fn test_rename_value() {
+        let mut set: IndexSet<_> = ["a", "b", "c"].into_iter().map(String::from).collect();
+
+        assert!(rename_value(&mut set, "b", "d"));
+        assert_eq!(set.get_index_of("d"), Some(1));
+
+        assert!(rename_value(&mut set, "c", "a"));
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.get_index_of("a"), Some(1));
+
+        assert!(rename_value(&mut set, "a", "a"));
+        assert!(!rename_value(&mut set, "x", "y"));
+        assert_eq!(set.len(), 2);
+    }
+}
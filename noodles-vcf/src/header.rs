@@ -11,7 +11,7 @@ pub use self::{
     string_maps::StringMaps,
 };
 
-use std::{hash::Hash, str::FromStr};
+use std::{collections::HashMap, error, fmt, hash::Hash, str::FromStr};
 
 use indexmap::{IndexMap, IndexSet};
 
@@ -42,7 +42,7 @@ pub type SampleNames = IndexSet<String>;
 pub type OtherRecords = IndexMap<record::key::Other, record::value::Collection>;
 
 /// A VCF header.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct Header {
     file_format: FileFormat,
     infos: Infos,
@@ -53,6 +53,8 @@ pub struct Header {
     sample_names: SampleNames,
     other_records: OtherRecords,
     string_maps: StringMaps,
+    // The keys of the records in the order they were read, excluding the file format.
+    record_order: Vec<record::Key>,
 }
 
 impl Header {
@@ -250,6 +252,100 @@ impl Header {
         &mut self.formats
     }
 
+    /// Removes an information record (`INFO`).
+    ///
+    /// This keeps the order of the remaining records. Use [`RecordBuf::remove_info_field`] to
+    /// remove the field from records.
+    ///
+    /// [`RecordBuf::remove_info_field`]: crate::variant::RecordBuf::remove_info_field
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Info, Map}};
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_info("AF", Map::<Info>::from("AF"))
+    ///     .build();
+    ///
+    /// assert!(header.remove_info_field("AF").is_some());
+    /// assert!(header.infos().is_empty());
+    /// ```
+    pub fn remove_info_field(&mut self, id: &str) -> Option<Map<Info>> {
+        self.infos.shift_remove(id)
+    }
+
+    /// Renames an information record (`INFO`).
+    ///
+    /// The record keeps its position. If a record with the new ID already exists, it is replaced.
+    /// This returns whether a record with the old ID was renamed. Use
+    /// [`RecordBuf::rename_info_field`] to rename the field in records.
+    ///
+    /// [`RecordBuf::rename_info_field`]: crate::variant::RecordBuf::rename_info_field
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Info, Map}};
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_info("AF", Map::<Info>::from("AF"))
+    ///     .build();
+    ///
+    /// assert!(header.rename_info_field("AF", "gnomAD_AF"));
+    /// assert!(header.infos().contains_key("gnomAD_AF"));
+    /// ```
+    pub fn rename_info_field(&mut self, old: &str, new: &str) -> bool {
+        crate::collections::rename_key(&mut self.infos, old, new)
+    }
+
+    /// Removes a genotype format record (`FORMAT`).
+    ///
+    /// This keeps the order of the remaining records. Use [`RecordBuf::remove_format_field`] to
+    /// remove the field from records.
+    ///
+    /// [`RecordBuf::remove_format_field`]: crate::variant::RecordBuf::remove_format_field
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Format, Map}};
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_format("AD", Map::<Format>::from("AD"))
+    ///     .build();
+    ///
+    /// assert!(header.remove_format_field("AD").is_some());
+    /// assert!(header.formats().is_empty());
+    /// ```
+    pub fn remove_format_field(&mut self, id: &str) -> Option<Map<Format>> {
+        self.formats.shift_remove(id)
+    }
+
+    /// Renames a genotype format record (`FORMAT`).
+    ///
+    /// The record keeps its position. If a record with the new ID already exists, it is replaced.
+    /// This returns whether a record with the old ID was renamed. Use
+    /// [`RecordBuf::rename_format_field`] to rename the field in records.
+    ///
+    /// [`RecordBuf::rename_format_field`]: crate::variant::RecordBuf::rename_format_field
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Format, Map}};
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_format("AD", Map::<Format>::from("AD"))
+    ///     .build();
+    ///
+    /// assert!(header.rename_format_field("AD", "XD"));
+    /// assert!(header.formats().contains_key("XD"));
+    /// ```
+    pub fn rename_format_field(&mut self, old: &str, new: &str) -> bool {
+        crate::collections::rename_key(&mut self.formats, old, new)
+    }
+
     /// Returns a map of symbolic alternate alleles (`ALT`).
     ///
     /// # Examples
@@ -338,6 +434,56 @@ impl Header {
         &mut self.contigs
     }
 
+    /// Reorders the contig records to match the given list of names.
+    ///
+    /// This is typically used to order contigs as they are in a reference sequence index (e.g.,
+    /// FASTA index), which some tools require. Contigs not in the list are moved after the listed
+    /// contigs, keeping their relative order.
+    ///
+    /// If any name is not a contig in the header, an error is returned, and the header is
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Contig, Map}};
+    ///
+    /// let mut header = vcf::Header::builder()
+    ///     .add_contig("sq0", Map::<Contig>::new())
+    ///     .add_contig("sq1", Map::<Contig>::new())
+    ///     .add_contig("sq2", Map::<Contig>::new())
+    ///     .build();
+    ///
+    /// header.sort_contigs_by(&["sq2", "sq0"])?;
+    ///
+    /// let names: Vec<_> = header.contigs().keys().map(|name| name.as_str()).collect();
+    /// assert_eq!(names, ["sq2", "sq0", "sq1"]);
+    ///
+    /// assert!(header.sort_contigs_by(&["sq3"]).is_err());
+    /// # Ok::<_, vcf::header::SortContigsError>(())
+    /// ```
+    pub fn sort_contigs_by<S>(&mut self, names: &[S]) -> Result<(), SortContigsError>
+    where
+        S: AsRef<str>,
+    {
+        let mut ranks = HashMap::with_capacity(names.len());
+
+        for (i, name) in names.iter().enumerate() {
+            let name = name.as_ref();
+
+            if !self.contigs.contains_key(name) {
+                return Err(SortContigsError::MissingContig(name.into()));
+            }
+
+            ranks.entry(name).or_insert(i);
+        }
+
+        self.contigs
+            .sort_by_cached_key(|name, _| ranks.get(name.as_str()).copied().unwrap_or(usize::MAX));
+
+        Ok(())
+    }
+
     /// Returns a list of sample names that come after the FORMAT column in the header record.
     ///
     /// # Examples
@@ -499,6 +645,69 @@ impl Header {
         collection.add(value)
     }
 
+    /// Returns the key and index of each record, in the order they are written, excluding the
+    /// file format.
+    ///
+    /// The index is the position of the record in the collection of its key. Records are listed in
+    /// the order they were read, if known. Any remaining records, e.g., those added after parsing,
+    /// follow, grouped by key.
+    ///
+    /// Both the writer and string maps use this, so string IDs are assigned in the order that
+    /// records are written.
+    pub(crate) fn record_indices(&self) -> Vec<(record::Key, usize)> {
+        use self::record::key;
+
+        let mut counts: HashMap<&record::Key, usize> = HashMap::new();
+        let mut indices = Vec::new();
+
+        for k in &self.record_order {
+            let count = counts.entry(k).or_default();
+
+            if *count < self.record_count(k) {
+                indices.push((k.clone(), *count));
+                *count += 1;
+            }
+        }
+
+        let keys = [
+            key::INFO,
+            key::FILTER,
+            key::FORMAT,
+            key::ALTERNATIVE_ALLELE,
+            key::CONTIG,
+        ]
+        .into_iter()
+        .chain(self.other_records.keys().cloned().map(record::Key::Other));
+
+        for k in keys {
+            let start = counts.get(&k).copied().unwrap_or_default();
+
+            for i in start..self.record_count(&k) {
+                indices.push((k.clone(), i));
+            }
+        }
+
+        indices
+    }
+
+    fn record_count(&self, key: &record::Key) -> usize {
+        use self::record::key::Standard;
+
+        match key {
+            record::Key::Standard(Standard::FileFormat) => 0,
+            record::Key::Standard(Standard::Info) => self.infos.len(),
+            record::Key::Standard(Standard::Filter) => self.filters.len(),
+            record::Key::Standard(Standard::Format) => self.formats.len(),
+            record::Key::Standard(Standard::AlternativeAllele) => self.alternative_alleles.len(),
+            record::Key::Standard(Standard::Contig) => self.contigs.len(),
+            record::Key::Other(k) => self
+                .other_records
+                .get(k)
+                .map(|collection| collection.len())
+                .unwrap_or_default(),
+        }
+    }
+
     #[doc(hidden)]
     pub fn string_maps(&self) -> &StringMaps {
         &self.string_maps
@@ -510,12 +719,46 @@ impl Header {
     }
 }
 
+/// An error returned when the contig records of a VCF header fail to be sorted.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SortContigsError {
+    /// A contig is missing from the header.
+    MissingContig(String),
+}
+
+impl error::Error for SortContigsError {}
+
+impl fmt::Display for SortContigsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingContig(name) => write!(f, "missing contig: {name}"),
+        }
+    }
+}
+
 impl Default for Header {
     fn default() -> Self {
         Builder::default().build()
     }
 }
 
+// The record order only affects how the header is written and is not part of its value.
+impl PartialEq for Header {
+    fn eq(&self, other: &Self) -> bool {
+        self.file_format == other.file_format
+            && self.infos == other.infos
+            && self.filters == other.filters
+            && self.formats == other.formats
+            && self.alternative_alleles == other.alternative_alleles
+            && self.contigs == other.contigs
+            && self.sample_names == other.sample_names
+            && self.other_records == other.other_records
+            && self.string_maps == other.string_maps
+    }
+}
+
+impl Eq for Header {}
+
 impl FromStr for Header {
     type Err = ParseError;
 
@@ -534,6 +777,27 @@ mod tests {
         assert_eq!(header.file_format(), FileFormat::default());
     }
 
+    #[test]
+    fn test_eq_with_different_record_order() -> Result<(), ParseError> {
+        let a: Header = "##fileformat=VCFv4.5
+##contig=<ID=sq0>
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        let b: Header = "##fileformat=VCFv4.5
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        assert_eq!(a, b);
+
+        Ok(())
+    }
+
     #[test]
     fn test_insert_with_duplicate_keys() -> Result<(), Box<dyn std::error::Error>> {
         let key: record::key::Other = "noodles".parse()?;
@@ -555,4 +819,37 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_sort_contigs_by() -> Result<(), Box<dyn std::error::Error>> {
+        let mut header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_contig("sq2", Map::<Contig>::new())
+            .build();
+
+        header.sort_contigs_by(&["sq2", "sq0", "sq1"])?;
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        let expected = b"##fileformat=VCFv4.5
+##contig=<ID=sq2>
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        assert_eq!(writer.get_ref(), expected);
+
+        assert_eq!(
+            header.sort_contigs_by(&["sq1", "sq3"]),
+            Err(SortContigsError::MissingContig(String::from("sq3")))
+        );
+
+        let names: Vec<_> = header.contigs().keys().collect();
+        assert_eq!(names, ["sq2", "sq0", "sq1"]);
+
+        Ok(())
+    }
 }
@@ -297,6 +297,7 @@ impl Builder {
             sample_names: self.sample_names,
             other_records: self.other_records,
             string_maps: StringMaps::default(),
+            record_order: Vec::new(),
         }
     }
 }
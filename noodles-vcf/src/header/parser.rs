@@ -42,6 +42,7 @@ pub struct Parser {
     contigs: Contigs,
     sample_names: SampleNames,
     other_records: OtherRecords,
+    record_order: Vec<super::record::Key>,
 }
 
 impl Parser {
@@ -62,11 +63,15 @@ impl Parser {
     }
 
     /// Parses and adds a raw record to the header.
+    ///
+    /// A trailing carriage return (`\r`), e.g., from a CRLF line ending, is ignored.
     pub fn parse_partial(&mut self, src: &[u8]) -> Result<Entry<'_>, ParseError> {
         if self.state == State::Done {
             return Err(ParseError::ExpectedEof);
         }
 
+        let src = strip_carriage_return(src);
+
         if self.state == State::Empty {
             let file_format = match parse_file_format(src) {
                 Ok(f) => match self.file_format_option {
@@ -90,6 +95,10 @@ impl Parser {
 
         let record = parse_record(src, self.file_format).map_err(ParseError::InvalidRecord)?;
 
+        if let Some(key) = record_key(&record) {
+            self.record_order.push(key);
+        }
+
         match record {
             Record::FileFormat(_) => Err(ParseError::UnexpectedFileFormat),
             Record::Info(id, info) => try_insert_info(&mut self.infos, id, info),
@@ -118,11 +127,26 @@ impl Parser {
                 sample_names: self.sample_names,
                 other_records: self.other_records,
                 string_maps: StringMaps::default(),
+                record_order: self.record_order,
             }),
         }
     }
 }
 
+fn record_key(record: &Record) -> Option<super::record::Key> {
+    use super::record::key;
+
+    match record {
+        Record::FileFormat(_) => None,
+        Record::Info(..) => Some(key::INFO),
+        Record::Filter(..) => Some(key::FILTER),
+        Record::Format(..) => Some(key::FORMAT),
+        Record::AlternativeAllele(..) => Some(key::ALTERNATIVE_ALLELE),
+        Record::Contig(..) => Some(key::CONTIG),
+        Record::Other(key, _) => Some(super::record::Key::Other(key.clone())),
+    }
+}
+
 /// An error returned when a raw VCF header fails to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
@@ -205,6 +229,11 @@ impl std::fmt::Display for ParseError {
     }
 }
 
+fn strip_carriage_return(src: &[u8]) -> &[u8] {
+    const CARRIAGE_RETURN: u8 = b'\r';
+    src.strip_suffix(&[CARRIAGE_RETURN]).unwrap_or(src)
+}
+
 fn parse_file_format(src: &[u8]) -> Result<FileFormat, ParseError> {
     let record = parse_record(src, FileFormat::default()).map_err(ParseError::InvalidRecord)?;
 
@@ -476,6 +505,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_partial_with_crlf_line_endings() -> Result<(), ParseError> {
+        fn parse_lines(lines: &[&[u8]]) -> Result<Header, ParseError> {
+            let mut parser = Parser::default();
+
+            for line in lines {
+                parser.parse_partial(line)?;
+            }
+
+            parser.finish()
+        }
+
+        let actual = parse_lines(&[
+            b"##fileformat=VCFv4.3\r",
+            b"##contig=<ID=sq0,length=8>\r",
+            b"#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\r",
+        ])?;
+
+        let expected = parse_lines(&[
+            b"##fileformat=VCFv4.3",
+            b"##contig=<ID=sq0,length=8>",
+            b"#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0",
+        ])?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(actual.contigs()["sq0"].length(), Some(8));
+        assert!(actual.sample_names().contains("sample0"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_from_str_without_file_format() {
         let s = r#"##ALT=<ID=DEL,Description="Deletion">
@@ -10,6 +10,7 @@ use crate::{
     header::{
         FileFormat, ParseError, Record,
         parser::{Entry, parse_record},
+        record::{Key, key::Standard},
     },
 };
 
@@ -210,20 +211,32 @@ impl TryFrom<&Header> for StringMaps {
     fn try_from(header: &Header) -> Result<Self, Self::Error> {
         let mut string_maps = StringMaps::default();
 
-        for (id, contig) in header.contigs() {
-            insert(string_maps.contigs_mut(), id.as_ref(), contig.idx())?;
-        }
-
-        for (id, info) in header.infos() {
-            insert(string_maps.strings_mut(), id.as_ref(), info.idx())?;
-        }
-
-        for (id, filter) in header.filters() {
-            insert(string_maps.strings_mut(), id, filter.idx())?;
-        }
-
-        for (id, format) in header.formats() {
-            insert(string_maps.strings_mut(), id.as_ref(), format.idx())?;
+        // Strings are assigned in the order the records are written, which is the order a reader
+        // assigns them when the header has no IDX fields.
+        for (key, i) in header.record_indices() {
+            match key {
+                Key::Standard(Standard::Contig) => {
+                    if let Some((id, contig)) = header.contigs().get_index(i) {
+                        insert(string_maps.contigs_mut(), id.as_ref(), contig.idx())?;
+                    }
+                }
+                Key::Standard(Standard::Info) => {
+                    if let Some((id, info)) = header.infos().get_index(i) {
+                        insert(string_maps.strings_mut(), id.as_ref(), info.idx())?;
+                    }
+                }
+                Key::Standard(Standard::Filter) => {
+                    if let Some((id, filter)) = header.filters().get_index(i) {
+                        insert(string_maps.strings_mut(), id, filter.idx())?;
+                    }
+                }
+                Key::Standard(Standard::Format) => {
+                    if let Some((id, format)) = header.formats().get_index(i) {
+                        insert(string_maps.strings_mut(), id.as_ref(), format.idx())?;
+                    }
+                }
+                _ => {}
+            }
         }
 
         Ok(string_maps)
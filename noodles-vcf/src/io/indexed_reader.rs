@@ -4,15 +4,21 @@ mod builder;
 
 pub use self::builder::Builder;
 
-use std::io::{self, BufRead, Read};
+use std::{
+    borrow::Cow,
+    io::{self, BufRead, Read},
+};
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{
+    Region,
+    region::{Interval, QueryMode},
+};
 use noodles_csi::BinningIndex;
 
 use super::{
     Reader,
-    reader::{Query, RecordBufs},
+    reader::{Query, QueryRegions, RecordBufs},
 };
 use crate::{Header, Record, variant::RecordBuf};
 
@@ -20,6 +26,7 @@ use crate::{Header, Record, variant::RecordBuf};
 pub struct IndexedReader<R> {
     inner: Reader<R>,
     index: Box<dyn BinningIndex>,
+    resolve_contig_aliases: bool,
 }
 
 impl<R> IndexedReader<R> {
@@ -88,7 +95,61 @@ where
         header: &'h Header,
         region: &Region,
     ) -> io::Result<Query<'r, 'h, R>> {
-        self.inner.query(header, &self.index, region)
+        let region = self.resolve_region(region);
+        self.inner.query(header, &self.index, &region)
+    }
+
+    /// Returns an iterator over records that match the given region using the given query mode.
+    pub fn query_with_mode<'r, 'h>(
+        &'r mut self,
+        header: &'h Header,
+        region: &Region,
+        query_mode: QueryMode,
+    ) -> io::Result<Query<'r, 'h, R>> {
+        let region = self.resolve_region(region);
+        self.inner
+            .query_with_mode(header, &self.index, &region, query_mode)
+    }
+
+    /// Returns an iterator over records that intersect the given interval of the reference
+    /// sequence with the given ID.
+    ///
+    /// See [`Reader::query_by_reference_id`].
+    pub fn query_by_reference_id<'r, 'h>(
+        &'r mut self,
+        header: &'h Header,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> io::Result<Query<'r, 'h, R>> {
+        self.inner
+            .query_by_reference_id(header, &self.index, reference_sequence_id, interval)
+    }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Each matching record is returned once, even if it intersects more than one region. See
+    /// [`Reader::query_regions`].
+    pub fn query_regions<'r, 'h>(
+        &'r mut self,
+        header: &'h Header,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>> {
+        let regions: Vec<_> = regions
+            .iter()
+            .map(|region| self.resolve_region(region).into_owned())
+            .collect();
+
+        self.inner.query_regions(header, &self.index, &regions)
+    }
+
+    fn resolve_region<'a>(&self, region: &'a Region) -> Cow<'a, Region> {
+        match self.index.header() {
+            Some(header) if self.resolve_contig_aliases => {
+                let reference_sequence_names = header.reference_sequence_names();
+                region.resolve_chr_alias(|name| reference_sequence_names.contains(name))
+            }
+            _ => Cow::Borrowed(region),
+        }
     }
 }
 
@@ -104,6 +165,42 @@ where
         Self {
             inner: Reader::new(bgzf::io::Reader::new(inner)),
             index: Box::new(index),
+            resolve_contig_aliases: false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use noodles_csi::binning_index::index::Header as IndexHeader;
+    use noodles_tabix as tabix;
+
+    use super::*;
+
+    #[test]
+    fn test_resolve_region() -> Result<(), Box<dyn std::error::Error>> {
+        let index_header = IndexHeader::builder()
+            .set_reference_sequence_names([b"chr1".into()].into_iter().collect())
+            .build();
+
+        let region = Region::new("1", Position::try_from(2)?..=Position::try_from(5)?);
+
+        let reader = Builder::default()
+            .set_index(
+                tabix::Index::builder()
+                    .set_header(index_header.clone())
+                    .build(),
+            )
+            .build_from_reader(io::empty())?;
+        assert_eq!(reader.resolve_region(&region).as_ref(), &region);
+
+        let reader = Builder::default()
+            .set_index(tabix::Index::builder().set_header(index_header).build())
+            .set_resolve_contig_aliases(true)
+            .build_from_reader(io::empty())?;
+        assert_eq!(reader.resolve_region(&region).name(), b"chr1".as_slice());
+
+        Ok(())
+    }
+}
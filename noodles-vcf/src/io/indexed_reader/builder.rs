@@ -10,11 +10,13 @@ use noodles_csi::{self as csi, BinningIndex};
 use noodles_tabix as tabix;
 
 use super::IndexedReader;
+use crate::io::Reader;
 
 /// An indexed VCF reader builder.
 #[derive(Default)]
 pub struct Builder {
     index: Option<Box<dyn BinningIndex>>,
+    resolve_contig_aliases: bool,
 }
 
 impl Builder {
@@ -37,6 +39,23 @@ impl Builder {
         self
     }
 
+    /// Sets whether to resolve contig aliases when querying.
+    ///
+    /// When enabled, a query region whose reference sequence name is not in the index is retried
+    /// with a `chr` prefix added to or removed from the name, e.g., `1` resolves to `chr1`. By
+    /// default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::indexed_reader::Builder;
+    /// let builder = Builder::default().set_resolve_contig_aliases(true);
+    /// ```
+    pub fn set_resolve_contig_aliases(mut self, resolve_contig_aliases: bool) -> Self {
+        self.resolve_contig_aliases = resolve_contig_aliases;
+        self
+    }
+
     /// Builds an indexed VCF reader from a path.
     ///
     /// # Examples
@@ -59,7 +78,11 @@ impl Builder {
 
         let file = File::open(src)?;
 
-        Ok(IndexedReader::new(file, index))
+        Ok(IndexedReader {
+            inner: Reader::new(bgzf::io::Reader::new(file)),
+            index,
+            resolve_contig_aliases: self.resolve_contig_aliases,
+        })
     }
 
     /// Builds an indexed VCF reader from a reader.
@@ -85,7 +108,11 @@ impl Builder {
             .index
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing index"))?;
 
-        Ok(IndexedReader::new(reader, index))
+        Ok(IndexedReader {
+            inner: Reader::new(bgzf::io::Reader::new(reader)),
+            index,
+            resolve_contig_aliases: self.resolve_contig_aliases,
+        })
     }
 }
 
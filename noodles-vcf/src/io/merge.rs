@@ -0,0 +1,350 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    io::{self, BufRead},
+};
+
+use noodles_core::Position;
+
+use super::Reader;
+use crate::{Header, Record};
+
+type Key = (usize, Option<Position>);
+
+/// A k-way merge of coordinate-sorted VCF readers.
+///
+/// Records are yielded in coordinate order, i.e., by the order of the reference sequence
+/// (`contig`) in the header and then by position. Records with equal coordinates are yielded in
+/// the order of the input readers.
+///
+/// Each reader is expected to be positioned at the start of its records, i.e., after its header
+/// is read, and to be coordinate-sorted. All reference sequences must be defined in the given
+/// header, which is typically the merged header of the inputs. An error is returned if a record's
+/// reference sequence is undefined or if a reader is not coordinate-sorted.
+///
+/// A reader's next record is read after its current record is popped. If that fails, the current
+/// record is still returned, and the error is returned by the following call. The merge ends after
+/// an error.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf as vcf;
+///
+/// let header = b"##fileformat=VCFv4.5\n##contig=<ID=sq0>\n##contig=<ID=sq1>
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// ";
+///
+/// let data = [
+///     [&header[..], b"sq0\t8\t.\tA\t.\t.\tPASS\t.\nsq1\t5\t.\tC\t.\t.\tPASS\t.\n"].concat(),
+///     [&header[..], b"sq0\t13\t.\tG\t.\t.\tPASS\t.\n"].concat(),
+/// ];
+///
+/// let mut readers = Vec::new();
+/// let mut headers = Vec::new();
+///
+/// for src in &data {
+///     let mut reader = vcf::io::Reader::new(&src[..]);
+///     headers.push(reader.read_header()?);
+///     readers.push(reader);
+/// }
+///
+/// let merge = vcf::io::Merge::new(readers, &headers[0])?;
+///
+/// let reference_bases: Vec<_> = merge
+///     .map(|result| result.map(|record| record.reference_bases().to_string()))
+///     .collect::<std::io::Result<_>>()?;
+///
+/// assert_eq!(reference_bases, ["A", "G", "C"]);
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub struct Merge<'h, R> {
+    readers: Vec<Reader<R>>,
+    header: &'h Header,
+    heap: BinaryHeap<Reverse<Entry>>,
+    pending_error: Option<io::Error>,
+    is_done: bool,
+}
+
+impl<'h, R> Merge<'h, R>
+where
+    R: BufRead,
+{
+    /// Creates a k-way merge of coordinate-sorted VCF readers.
+    ///
+    /// This reads the first record of each reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let header = vcf::Header::default();
+    /// let readers = vec![vcf::io::Reader::new(io::empty())];
+    /// let merge = vcf::io::Merge::new(readers, &header)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(mut readers: Vec<Reader<R>>, header: &'h Header) -> io::Result<Self> {
+        let mut heap = BinaryHeap::with_capacity(readers.len());
+
+        for (i, reader) in readers.iter_mut().enumerate() {
+            if let Some(entry) = read_entry(reader, header, i)? {
+                heap.push(Reverse(entry));
+            }
+        }
+
+        Ok(Self {
+            readers,
+            header,
+            heap,
+            pending_error: None,
+            is_done: false,
+        })
+    }
+
+    /// Returns the readers.
+    pub fn into_inner(self) -> Vec<Reader<R>> {
+        self.readers
+    }
+
+    fn next_record(&mut self) -> io::Result<Option<Record>> {
+        if self.is_done {
+            return Ok(None);
+        }
+
+        if let Some(e) = self.pending_error.take() {
+            self.is_done = true;
+            return Err(e);
+        }
+
+        let Some(Reverse(entry)) = self.heap.pop() else {
+            self.is_done = true;
+            return Ok(None);
+        };
+
+        let i = entry.reader_index;
+
+        match read_entry(&mut self.readers[i], self.header, i) {
+            Ok(Some(next_entry)) => {
+                if next_entry.key < entry.key {
+                    self.pending_error = Some(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("input {i} is not coordinate-sorted"),
+                    ));
+                } else {
+                    self.heap.push(Reverse(next_entry));
+                }
+            }
+            Ok(None) => {}
+            Err(e) => self.pending_error = Some(e),
+        }
+
+        Ok(Some(entry.record))
+    }
+}
+
+impl<R> Iterator for Merge<'_, R>
+where
+    R: BufRead,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_record().transpose()
+    }
+}
+
+struct Entry {
+    key: Key,
+    reader_index: usize,
+    record: Record,
+}
+
+impl Entry {
+    fn sort_key(&self) -> (Key, usize) {
+        (self.key, self.reader_index)
+    }
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.sort_key() == other.sort_key()
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key())
+    }
+}
+
+fn read_entry<R>(
+    reader: &mut Reader<R>,
+    header: &Header,
+    reader_index: usize,
+) -> io::Result<Option<Entry>>
+where
+    R: BufRead,
+{
+    let mut record = Record::default();
+
+    if reader.read_record(&mut record)? == 0 {
+        return Ok(None);
+    }
+
+    let key = sort_key(header, &record)?;
+
+    Ok(Some(Entry {
+        key,
+        reader_index,
+        record,
+    }))
+}
+
+fn sort_key(header: &Header, record: &Record) -> io::Result<Key> {
+    let name = record.reference_sequence_name();
+
+    let reference_sequence_index = header.contigs().get_index_of(name).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("undefined reference sequence: {name}"),
+        )
+    })?;
+
+    let position = record.variant_start().transpose()?;
+
+    Ok((reference_sequence_index, position))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEADER: &[u8] = b"##fileformat=VCFv4.5
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+##contig=<ID=sq2>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+    fn build_merge<'h>(
+        header: &'h Header,
+        records: &[&[u8]],
+    ) -> io::Result<Merge<'h, io::Cursor<Vec<u8>>>> {
+        let readers = records
+            .iter()
+            .map(|src| {
+                let mut reader = Reader::new(io::Cursor::new([HEADER, src].concat()));
+                reader.read_header()?;
+                Ok(reader)
+            })
+            .collect::<io::Result<_>>()?;
+
+        Merge::new(readers, header)
+    }
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Reader::new(HEADER).read_header()?;
+
+        let merge = build_merge(
+            &header,
+            &[
+                b"sq0\t1\ta0\tA\t.\t.\tPASS\t.
+sq0\t5\ta1\tA\t.\t.\tPASS\t.
+sq2\t3\ta2\tA\t.\t.\tPASS\t.
+",
+                b"",
+                b"sq0\t5\tb0\tA\t.\t.\tPASS\t.
+sq1\t8\tb1\tA\t.\t.\tPASS\t.
+sq2\t1\tb2\tA\t.\t.\tPASS\t.
+",
+            ],
+        )?;
+
+        let actual: Vec<_> = merge
+            .map(|result| result.map(|record| String::from(record.ids().as_ref())))
+            .collect::<io::Result<_>>()?;
+
+        let expected = ["a0", "a1", "b0", "b1", "b2", "a2"];
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_unsorted_input() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Reader::new(HEADER).read_header()?;
+
+        let mut merge = build_merge(
+            &header,
+            &[b"sq1\t1\ta0\tA\t.\t.\tPASS\t.
+sq0\t5\ta1\tA\t.\t.\tPASS\t.
+"],
+        )?;
+
+        assert!(matches!(
+            merge.next(),
+            Some(Ok(record)) if record.ids().as_ref() == "a0"
+        ));
+
+        assert!(matches!(
+            merge.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(merge.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next_with_read_error() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Reader::new(HEADER).read_header()?;
+
+        let mut merge = build_merge(
+            &header,
+            &[
+                b"sq0\t1\ta0\tA\t.\t.\tPASS\t.
+sq3\t5\ta1\tA\t.\t.\tPASS\t.
+",
+                b"sq0\t8\tb0\tA\t.\t.\tPASS\t.\n",
+            ],
+        )?;
+
+        assert!(matches!(
+            merge.next(),
+            Some(Ok(record)) if record.ids().as_ref() == "a0"
+        ));
+
+        assert!(matches!(
+            merge.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(merge.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_new_with_undefined_reference_sequence() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Reader::new(HEADER).read_header()?;
+
+        assert!(matches!(
+            build_merge(&header, &[b"sq3\t1\t.\tA\t.\t.\tPASS\t.\n"]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+}
@@ -2,14 +2,20 @@
 
 mod builder;
 pub mod header;
+mod malformed_record_policy;
 pub(crate) mod query;
+mod query_regions;
 pub(crate) mod record;
 pub mod record_buf;
 mod record_bufs;
+mod record_counter;
 
 use self::record::read_record;
-pub(crate) use self::record_buf::parse_record_buf;
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs};
+pub(crate) use self::record_buf::{parse_record_buf, validate_value_counts};
+pub use self::{
+    builder::Builder, malformed_record_policy::MalformedRecordPolicy, query::Query,
+    query_regions::QueryRegions, record_bufs::RecordBufs,
+};
 
 use std::{
     io::{self, BufRead},
@@ -17,10 +23,13 @@ use std::{
 };
 
 use noodles_bgzf as bgzf;
-use noodles_core::Region;
+use noodles_core::{
+    Region,
+    region::{Interval, QueryMode},
+};
 use noodles_csi::BinningIndex;
 
-use self::header::read_header;
+use self::{header::read_header, record_counter::RecordCounter};
 use crate::{Header, Record, variant::RecordBuf};
 
 /// A VCF reader.
@@ -50,6 +59,10 @@ use crate::{Header, Record, variant::RecordBuf};
 pub struct Reader<R> {
     inner: R,
     buf: String,
+    strict: bool,
+    malformed_record_policy: MalformedRecordPolicy,
+    record_counter: RecordCounter,
+    skipped_record_count: u64,
 }
 
 impl<R> Reader<R> {
@@ -94,6 +107,110 @@ impl<R> Reader<R> {
     pub fn into_inner(self) -> R {
         self.inner
     }
+
+    /// Returns the number of records read.
+    ///
+    /// This counts the records successfully read using [`Self::read_record`] or
+    /// [`Self::read_record_buf`], including those read using record iterators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.5
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::io::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    /// assert_eq!(reader.records_read(), 0);
+    ///
+    /// let mut record = vcf::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// assert_eq!(reader.records_read(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn records_read(&self) -> u64 {
+        self.record_counter.records_read()
+    }
+
+    /// Returns whether the last record read reached EOF.
+    ///
+    /// This is only set after a read returns 0 bytes, i.e., it is `false` after reading the last
+    /// record and before attempting to read another.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let data = b"##fileformat=VCFv4.5
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::io::Reader::new(&data[..]);
+    /// reader.read_header()?;
+    ///
+    /// let mut record = vcf::Record::default();
+    /// reader.read_record(&mut record)?;
+    /// assert!(!reader.is_eof());
+    ///
+    /// reader.read_record(&mut record)?;
+    /// assert!(reader.is_eof());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn is_eof(&self) -> bool {
+        self.record_counter.is_eof()
+    }
+
+    /// Returns the number of malformed records skipped.
+    ///
+    /// This is only nonzero if the reader was built with [`MalformedRecordPolicy::Skip`] (see
+    /// [`Builder::set_malformed_record_policy`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, io::reader::MalformedRecordPolicy};
+    ///
+    /// let data = b"##fileformat=VCFv4.5
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\tndls\t.\tA\t.\t.\tPASS\t.
+    /// sq0\t8\t.\tA\t.\t.\tPASS\t.
+    /// ";
+    ///
+    /// let mut reader = vcf::io::reader::Builder::default()
+    ///     .set_malformed_record_policy(MalformedRecordPolicy::Skip)
+    ///     .build_from_reader(&data[..])?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let mut record = vcf::variant::RecordBuf::default();
+    /// reader.read_record_buf(&header, &mut record)?;
+    ///
+    /// assert_eq!(record.variant_start(), noodles_core::Position::new(8));
+    /// assert_eq!(reader.skipped_record_count(), 1);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn skipped_record_count(&self) -> u64 {
+        self.skipped_record_count
+    }
+
+    // `e` must be a parse or validation error of a line that was fully read. Errors from the
+    // underlying reader are never skipped.
+    fn handle_malformed_record(&mut self, e: io::Error) -> io::Result<()> {
+        if self.malformed_record_policy == MalformedRecordPolicy::Skip
+            && e.kind() == io::ErrorKind::InvalidData
+        {
+            self.skipped_record_count += 1;
+            Ok(())
+        } else {
+            Err(e)
+        }
+    }
 }
 
 impl<R> Reader<R>
@@ -118,6 +235,10 @@ where
         Self {
             inner,
             buf: String::new(),
+            strict: false,
+            malformed_record_policy: MalformedRecordPolicy::default(),
+            record_counter: RecordCounter::default(),
+            skipped_record_count: 0,
         }
     }
 
@@ -195,6 +316,14 @@ where
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
     /// stream reached EOF.
     ///
+    /// If the reader was built with [`MalformedRecordPolicy::Skip`], malformed records are skipped
+    /// (see [`Self::skipped_record_count`]), and the number of bytes read is that of the returned
+    /// record.
+    ///
+    /// If the reader was built in strict mode (see [`Builder::set_strict`]), a record with an info
+    /// or sample field with `Number=A`, `Number=R`, or `Number=G` whose value count does not match
+    /// the record's alleles returns an [`io::ErrorKind::InvalidData`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -217,15 +346,29 @@ where
         header: &Header,
         record: &mut RecordBuf,
     ) -> io::Result<usize> {
-        self.buf.clear();
+        loop {
+            self.buf.clear();
 
-        match read_line(&mut self.inner, &mut self.buf)? {
-            0 => Ok(0),
-            n => {
-                parse_record_buf(&self.buf, header, record)
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            let n = read_line(&mut self.inner, &mut self.buf)?;
 
-                Ok(n)
+            if n == 0 {
+                return Ok(self.record_counter.track(n));
+            }
+
+            let result = parse_record_buf(&self.buf, header, record)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                .and_then(|_| {
+                    if self.strict {
+                        validate_value_counts(header, record)
+                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                    }
+
+                    Ok(())
+                });
+
+            match result {
+                Ok(()) => return Ok(self.record_counter.track(n)),
+                Err(e) => self.handle_malformed_record(e)?,
             }
         }
     }
@@ -267,6 +410,10 @@ where
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
     /// stream reached EOF.
     ///
+    /// If the reader was built with [`MalformedRecordPolicy::Skip`], malformed records are skipped
+    /// (see [`Self::skipped_record_count`]), and the number of bytes read is that of the returned
+    /// record.
+    ///
     /// # Examples
     ///
     /// ```
@@ -285,7 +432,27 @@ where
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        let n = match self.malformed_record_policy {
+            MalformedRecordPolicy::Fail => read_record(&mut self.inner, record)?,
+            // The line is read first so that a malformed record is consumed entirely. Its
+            // terminator is kept so that missing fields are detected.
+            MalformedRecordPolicy::Skip => loop {
+                self.buf.clear();
+
+                let n = self.inner.read_line(&mut self.buf)?;
+
+                if n == 0 {
+                    break n;
+                }
+
+                match read_record(&mut self.buf.as_bytes(), record) {
+                    Ok(_) => break n,
+                    Err(e) => self.handle_malformed_record(e)?,
+                }
+            },
+        };
+
+        Ok(self.record_counter.track(n))
     }
 
     /// Returns an iterator over records.
@@ -322,10 +489,54 @@ where
     }
 }
 
+impl<R> Reader<R>
+where
+    R: bgzf::io::Read,
+{
+    /// Returns the current virtual position of the underlying BGZF reader.
+    ///
+    /// When called directly after reading a record, this is the start of the next record.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let reader = vcf::io::Reader::new(bgzf::io::Reader::new(io::empty()));
+    /// assert_eq!(reader.virtual_position(), bgzf::VirtualPosition::default());
+    /// ```
+    pub fn virtual_position(&self) -> bgzf::VirtualPosition {
+        self.inner.virtual_position()
+    }
+}
+
 impl<R> Reader<R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
 {
+    /// Seeks the underlying BGZF reader to the given virtual position.
+    ///
+    /// The position is expected to be the start of a record, e.g., one returned by
+    /// [`Self::virtual_position`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = vcf::io::Reader::new(bgzf::io::Reader::new(Cursor::new(Vec::new())));
+    /// let pos = reader.virtual_position();
+    /// reader.seek(pos)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn seek(&mut self, pos: bgzf::VirtualPosition) -> io::Result<bgzf::VirtualPosition> {
+        self.inner.seek_to_virtual_position(pos)
+    }
+
     /// Returns an iterator over records that intersects the given region.
     ///
     /// # Examples
@@ -359,6 +570,50 @@ where
         index: &I,
         region: &Region,
     ) -> io::Result<Query<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        self.query_with_mode(header, index, region, QueryMode::default())
+    }
+
+    /// Returns an iterator over records that match the given region using the given query mode.
+    ///
+    /// With [`QueryMode::Overlap`], this is the same as [`Self::query`]. With
+    /// [`QueryMode::Contained`], only records whose variant spans are fully contained in the
+    /// region are returned.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::region::QueryMode;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::io::Reader::new)
+    ///     .map(vcf::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = tabix::fs::read("sample.vcf.gz.tbi")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query_with_mode(&header, &index, &region, QueryMode::Contained)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_with_mode<'r, 'h, I>(
+        &'r mut self,
+        header: &'h Header,
+        index: &I,
+        region: &Region,
+        query_mode: QueryMode,
+    ) -> io::Result<Query<'r, 'h, R>>
     where
         I: BinningIndex,
     {
@@ -370,6 +625,134 @@ where
             chunks,
             reference_sequence_name,
             region.interval(),
+            query_mode,
+            header,
+        ))
+    }
+
+    /// Returns an iterator over records that intersect the given interval of the reference
+    /// sequence with the given ID.
+    ///
+    /// This is the same as [`Self::query`] but skips resolving a region name. The reference
+    /// sequence ID is the index of the reference sequence name in the index header.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_core::Position;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::io::Reader::new)
+    ///     .map(vcf::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = tabix::fs::read("sample.vcf.gz.tbi")?;
+    /// let interval = (Position::try_from(8)?..=Position::try_from(13)?).into();
+    /// let query = reader.query_by_reference_id(&header, &index, 0, interval)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_by_reference_id<'r, 'h, I>(
+        &'r mut self,
+        header: &'h Header,
+        index: &I,
+        reference_sequence_id: usize,
+        interval: Interval,
+    ) -> io::Result<Query<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        let reference_sequence_name = resolve_reference_sequence_id(index, reference_sequence_id)?;
+        let chunks = index.query(reference_sequence_id, interval)?;
+
+        Ok(Query::new(
+            self.get_mut(),
+            chunks,
+            reference_sequence_name,
+            interval,
+            QueryMode::default(),
+            header,
+        ))
+    }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Overlapping and adjacent regions are merged, and the index chunks of all regions are
+    /// resolved together, so each part of the file is read at most once. Records are returned in
+    /// file order, and a record that intersects more than one region is only returned once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::io::Reader::new)
+    ///     .map(vcf::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = tabix::fs::read("sample.vcf.gz.tbi")?;
+    /// let regions = ["sq0:8-13".parse()?, "sq1:21-34".parse()?];
+    /// let query = reader.query_regions(&header, &index, &regions)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, 'h, I>(
+        &'r mut self,
+        header: &'h Header,
+        index: &I,
+        regions: &[Region],
+    ) -> io::Result<QueryRegions<'r, 'h, R>>
+    where
+        I: BinningIndex,
+    {
+        use noodles_csi::binning_index::{merge_chunks, merge_intervals};
+
+        let intervals: Vec<_> = regions
+            .iter()
+            .map(|region| {
+                resolve_region(index, region)
+                    .map(|(reference_sequence_id, _)| (reference_sequence_id, region.interval()))
+            })
+            .collect::<io::Result<_>>()?;
+
+        let intervals = merge_intervals(&intervals);
+
+        let mut chunks = Vec::new();
+
+        for &(reference_sequence_id, interval) in &intervals {
+            chunks.extend(index.query(reference_sequence_id, interval)?);
+        }
+
+        let intervals = intervals
+            .into_iter()
+            .map(|(reference_sequence_id, interval)| {
+                resolve_reference_sequence_id(index, reference_sequence_id)
+                    .map(|reference_sequence_name| (reference_sequence_name, interval))
+            })
+            .collect::<io::Result<_>>()?;
+
+        Ok(QueryRegions::new(
+            self.get_mut(),
+            merge_chunks(&chunks),
+            intervals,
             header,
         ))
     }
@@ -445,6 +828,30 @@ where
     Ok((i, region.name().to_vec()))
 }
 
+fn resolve_reference_sequence_id<I>(index: &I, reference_sequence_id: usize) -> io::Result<Vec<u8>>
+where
+    I: BinningIndex,
+{
+    let header = index
+        .header()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "missing tabix header"))?;
+
+    let reference_sequence_names = header.reference_sequence_names();
+
+    reference_sequence_names
+        .get_index(reference_sequence_id)
+        .map(|name| name.to_vec())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "reference sequence ID out of range: {reference_sequence_id} (len = {})",
+                    reference_sequence_names.len()
+                ),
+            )
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -472,6 +879,153 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_buf_with_strict() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\tC,G\t.\tPASS\tAF=0.5
+";
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+        let mut record = RecordBuf::default();
+        assert!(reader.read_record_buf(&header, &mut record).is_ok());
+
+        let mut reader = Builder::default()
+            .set_strict(true)
+            .build_from_reader(DATA)?;
+        let header = reader.read_header()?;
+        let mut record = RecordBuf::default();
+        assert!(matches!(
+            reader.read_record_buf(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_skip_malformed_record_policy() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.5
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\tr0\tA\t.\t.\tPASS\t.
+sq0\t2\tr1\tA
+sq0\tndls\tr2\tA\t.\t.\tPASS\t.
+sq0\t3\tr3\tA\t.\t.\tPASS\t.
+";
+
+        let build = || {
+            Builder::default()
+                .set_malformed_record_policy(MalformedRecordPolicy::Skip)
+                .build_from_reader(DATA)
+        };
+
+        let mut reader = build()?;
+        let header = reader.read_header()?;
+
+        let ids: Vec<_> = reader
+            .record_bufs(&header)
+            .map(|result| result.map(|record| record.ids().as_ref().iter().cloned().collect()))
+            .collect::<io::Result<Vec<Vec<String>>>>()?;
+
+        assert_eq!(ids, [["r0"], ["r3"]]);
+        assert_eq!(reader.records_read(), 2);
+        assert_eq!(reader.skipped_record_count(), 2);
+
+        // Lazy records are not parsed, so only the record with missing fields is malformed.
+        let mut reader = build()?;
+        reader.read_header()?;
+
+        let ids: Vec<_> = reader
+            .records()
+            .map(|result| result.map(|record| String::from(record.ids().as_ref())))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(ids, ["r0", "r2", "r3"]);
+        assert_eq!(reader.skipped_record_count(), 1);
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+        let mut record = RecordBuf::default();
+        reader.read_record_buf(&header, &mut record)?;
+        assert!(matches!(
+            reader.read_record_buf(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_skip_malformed_record_policy_and_read_error() -> io::Result<()> {
+        use std::io::Read;
+
+        struct InvalidDataReader;
+
+        impl Read for InvalidDataReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::from(io::ErrorKind::InvalidData))
+            }
+        }
+
+        // The stream fails while reading the first record.
+        static DATA: &[u8] = b"##fileformat=VCFv4.5
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1";
+
+        let build = || {
+            Builder::default()
+                .set_malformed_record_policy(MalformedRecordPolicy::Skip)
+                .build_from_reader(DATA.chain(InvalidDataReader))
+        };
+
+        let mut reader = build()?;
+        let header = reader.read_header()?;
+        let mut record = RecordBuf::default();
+        assert!(matches!(
+            reader.read_record_buf(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert_eq!(reader.skipped_record_count(), 0);
+
+        let mut reader = build()?;
+        reader.read_header()?;
+        let mut record = Record::default();
+        assert!(matches!(
+            reader.read_record(&mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert_eq!(reader.skipped_record_count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolve_reference_sequence_id() -> io::Result<()> {
+        use noodles_csi::binning_index::index::Header as IndexHeader;
+
+        let index = noodles_tabix::Index::default();
+        assert!(matches!(
+            resolve_reference_sequence_id(&index, 0),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let header = IndexHeader::builder()
+            .set_reference_sequence_names(["sq0"].into_iter().map(Into::into).collect())
+            .build();
+        let index = noodles_tabix::Index::builder().set_header(header).build();
+
+        assert_eq!(resolve_reference_sequence_id(&index, 0)?, b"sq0");
+        assert!(matches!(
+            resolve_reference_sequence_id(&index, 1),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -496,4 +1050,37 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
 
         Ok(())
     }
+
+    #[test]
+    fn test_virtual_position_and_seek() -> io::Result<()> {
+        use std::io::{Cursor, Write};
+
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer
+            .write_all(b"##fileformat=VCFv4.3\n#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\n")?;
+        writer.flush()?;
+        writer.write_all(b"sq0\t1\t.\tA\t.\t.\tPASS\t.\n")?;
+        writer.flush()?;
+        writer.write_all(b"sq0\t2\t.\tC\t.\t.\tPASS\t.\n")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::io::Reader::new(Cursor::new(data)));
+        reader.read_header()?;
+
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+        assert_eq!(record.reference_bases(), "A");
+
+        let pos = reader.virtual_position();
+
+        reader.read_record(&mut record)?;
+        assert_eq!(record.reference_bases(), "C");
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        reader.seek(pos)?;
+        reader.read_record(&mut record)?;
+        assert_eq!(record.reference_bases(), "C");
+
+        Ok(())
+    }
 }
@@ -6,13 +6,16 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::Reader;
+use super::{MalformedRecordPolicy, Reader, record_counter::RecordCounter};
 use crate::io::CompressionMethod;
 
 /// A VCF reader builder.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    strict: bool,
+    malformed_record_policy: MalformedRecordPolicy,
+    check_eof: bool,
 }
 
 impl Builder {
@@ -29,6 +32,62 @@ impl Builder {
         self
     }
 
+    /// Sets whether record field value counts are validated.
+    ///
+    /// If enabled, a record with an info or sample field with `Number=A` or `Number=R` whose
+    /// value count does not match the number of alternate bases, or a sample field with
+    /// `Number=G` whose value count does not match the number of possible genotypes, is returned
+    /// as an [`io::ErrorKind::InvalidData`] error. By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::Builder;
+    /// let builder = Builder::default().set_strict(true);
+    /// ```
+    pub fn set_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets the malformed record policy.
+    ///
+    /// This determines whether a record that fails to be read or parsed, including failing
+    /// validation in strict mode, is returned as an error or skipped. By default, this is
+    /// [`MalformedRecordPolicy::Fail`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::{Builder, MalformedRecordPolicy};
+    /// let builder = Builder::default().set_malformed_record_policy(MalformedRecordPolicy::Skip);
+    /// ```
+    pub fn set_malformed_record_policy(
+        mut self,
+        malformed_record_policy: MalformedRecordPolicy,
+    ) -> Self {
+        self.malformed_record_policy = malformed_record_policy;
+        self
+    }
+
+    /// Sets whether to check for a BGZF EOF marker at the end of a bgzip-compressed stream.
+    ///
+    /// When enabled, reaching the end of a stream that does not end with a BGZF EOF marker block
+    /// returns an [`io::ErrorKind::UnexpectedEof`] error, e.g., when the file is truncated (see
+    /// [`bgzf::io::Reader::set_check_eof`]). This has no effect on uncompressed streams. By
+    /// default, this is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::Builder;
+    /// let builder = Builder::default().set_check_eof(false);
+    /// ```
+    pub fn set_check_eof(mut self, check_eof: bool) -> Self {
+        self.check_eof = check_eof;
+        self
+    }
+
     /// Builds a VCF reader from a path.
     ///
     /// By default, the compression method will be autodetected. This can be overridden by using
@@ -73,10 +132,32 @@ impl Builder {
         R: Read + 'r,
     {
         let inner: Box<dyn BufRead> = match self.compression_method {
-            Some(CompressionMethod::Bgzf) => Box::new(bgzf::io::Reader::new(reader)),
+            Some(CompressionMethod::Bgzf) => {
+                let mut inner = bgzf::io::Reader::new(reader);
+                inner.set_check_eof(self.check_eof);
+                Box::new(inner)
+            }
             Some(CompressionMethod::None) | None => Box::new(BufReader::new(reader)),
         };
 
-        Ok(Reader::new(inner))
+        Ok(Reader {
+            inner,
+            buf: String::new(),
+            strict: self.strict,
+            malformed_record_policy: self.malformed_record_policy,
+            record_counter: RecordCounter::default(),
+            skipped_record_count: 0,
+        })
+    }
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            compression_method: None,
+            strict: false,
+            malformed_record_policy: MalformedRecordPolicy::default(),
+            check_eof: true,
+        }
     }
 }
@@ -0,0 +1,15 @@
+/// A policy for handling malformed records.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MalformedRecordPolicy {
+    /// A malformed record is returned as an [`std::io::ErrorKind::InvalidData`] error.
+    #[default]
+    Fail,
+    /// A malformed record is skipped, and the next record is read.
+    ///
+    /// Only records that fail to parse or validate are skipped. Errors reading the underlying
+    /// stream, e.g., I/O or decompression errors, are always returned.
+    ///
+    /// The number of skipped records is available using
+    /// [`super::Reader::skipped_record_count`].
+    Skip,
+}
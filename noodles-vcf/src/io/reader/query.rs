@@ -1,7 +1,9 @@
+//! VCF reader query.
+
 use std::io;
 
 use noodles_bgzf as bgzf;
-use noodles_core::region::Interval;
+use noodles_core::region::{Interval, QueryMode};
 use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
 
 use crate::{Header, Record, variant::Record as _};
@@ -10,6 +12,7 @@ struct Reader<'r, R> {
     inner: super::Reader<csi::io::Query<'r, R>>,
     reference_sequence_name: Vec<u8>,
     interval: Interval,
+    query_mode: QueryMode,
 }
 
 impl<'r, R> Reader<'r, R>
@@ -21,11 +24,13 @@ where
         chunks: Vec<Chunk>,
         reference_sequence_name: Vec<u8>,
         interval: Interval,
+        query_mode: QueryMode,
     ) -> Self {
         Self {
             inner: super::Reader::new(csi::io::Query::new(reader, chunks)),
             reference_sequence_name,
             interval,
+            query_mode,
         }
     }
 
@@ -36,13 +41,14 @@ where
             header,
             &self.reference_sequence_name,
             self.interval,
+            self.query_mode,
         )
     }
 }
 
 /// An iterator over records of a VCF reader that intersects a given region.
 ///
-/// This is created by calling [`super::Reader::query`].
+/// This is created by calling [`super::Reader::query`] or [`super::Reader::query_with_mode`].
 pub struct Query<'r, 'h, R> {
     reader: Reader<'r, R>,
     header: &'h Header,
@@ -58,14 +64,96 @@ where
         chunks: Vec<Chunk>,
         reference_sequence_name: Vec<u8>,
         interval: Interval,
+        query_mode: QueryMode,
         header: &'h Header,
     ) -> Self {
         Self {
-            reader: Reader::new(reader, chunks, reference_sequence_name, interval),
+            reader: Reader::new(
+                reader,
+                chunks,
+                reference_sequence_name,
+                interval,
+                query_mode,
+            ),
             header,
             record: Record::default(),
         }
     }
+
+    /// Reads the next record that intersects the query region into the given buffer.
+    ///
+    /// Unlike iterating the query, this reuses the caller's record buffer and does not clone each
+    /// record.
+    ///
+    /// If successful, the number of bytes read is returned. If the number of bytes read is 0, no
+    /// more records intersect the region.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::io::Reader::new)
+    ///     .map(vcf::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = tabix::fs::read("sample.vcf.gz.tbi")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let mut query = reader.query(&header, &index, &region)?;
+    ///
+    /// let mut record = vcf::Record::default();
+    ///
+    /// while query.read_record(&mut record)? != 0 {
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        self.reader.read_record(self.header, record)
+    }
+}
+
+impl<R> Query<'_, '_, R> {
+    /// Returns whether the given record matches the query region using the query mode.
+    ///
+    /// This is the predicate used to filter records read by the query.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::fs::File;
+    /// use noodles_bgzf as bgzf;
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut reader = File::open("sample.vcf.gz")
+    ///     .map(bgzf::io::Reader::new)
+    ///     .map(vcf::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let index = tabix::fs::read("sample.vcf.gz.tbi")?;
+    /// let region = "sq0:8-13".parse()?;
+    /// let query = reader.query(&header, &index, &region)?;
+    ///
+    /// let record = vcf::Record::try_from(&b"sq0\t8\t.\tA\t.\t.\tPASS\t."[..])?;
+    /// assert!(query.matches(&record)?);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn matches(&self, record: &Record) -> io::Result<bool> {
+        matches(
+            self.header,
+            record,
+            &self.reader.reference_sequence_name,
+            self.reader.interval,
+            self.reader.query_mode,
+        )
+    }
 }
 
 impl<R> Iterator for Query<'_, '_, R>
@@ -83,11 +171,12 @@ where
     }
 }
 
-pub(crate) fn intersects(
+pub(crate) fn matches(
     header: &Header,
     record: &Record,
     reference_sequence_name: &[u8],
     region_interval: Interval,
+    query_mode: QueryMode,
 ) -> io::Result<bool> {
     if reference_sequence_name != record.reference_sequence_name().as_bytes() {
         return Ok(false);
@@ -103,7 +192,7 @@ pub(crate) fn intersects(
         let end = record.variant_end(header)?;
         let record_interval = Interval::from(start..=end);
 
-        Ok(record_interval.intersects(region_interval))
+        Ok(query_mode.matches(region_interval, record_interval))
     }
 }
 
@@ -117,6 +206,7 @@ fn next_record<R>(
     header: &Header,
     reference_sequence_name: &[u8],
     interval: Interval,
+    query_mode: QueryMode,
 ) -> io::Result<usize>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
@@ -125,10 +215,102 @@ where
         match reader.read_record(record)? {
             0 => return Ok(0),
             n => {
-                if intersects(header, record, reference_sequence_name, interval)? {
+                if matches(
+                    header,
+                    record,
+                    reference_sequence_name,
+                    interval,
+                    query_mode,
+                )? {
                     return Ok(n);
                 }
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use super::*;
+
+    #[test]
+    fn test_read_record() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(
+            b"##fileformat=VCFv4.5
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq0\t8\t.\tC\t.\t.\tPASS\t.
+sq0\t13\t.\tG\t.\t.\tPASS\t.
+sq0\t21\t.\tT\t.\t.\tPASS\t.
+",
+        )?;
+        let data = writer.finish()?;
+
+        let index = {
+            let mut reader = super::super::Reader::new(bgzf::io::Reader::new(&data[..]));
+            crate::fs::index::index_inner(&mut reader)?
+        };
+
+        let mut reader = super::super::Reader::new(bgzf::io::Reader::new(Cursor::new(data)));
+        let header = reader.read_header()?;
+
+        let region = "sq0:5-15".parse()?;
+        let mut query = reader.query(&header, &index, &region)?;
+
+        let mut record = Record::default();
+        let mut reference_bases = Vec::new();
+
+        while query.read_record(&mut record)? != 0 {
+            reference_bases.push(record.reference_bases().to_string());
+        }
+
+        assert_eq!(reference_bases, ["C", "G"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_with_contained_query_mode() -> Result<(), Box<dyn std::error::Error>> {
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(
+            b"##fileformat=VCFv4.5
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t3\t.\tACGT\t.\t.\tPASS\t.
+sq0\t8\t.\tC\t.\t.\tPASS\t.
+sq0\t12\t.\tGTCA\t.\t.\tPASS\t.
+",
+        )?;
+        let data = writer.finish()?;
+
+        let index = {
+            let mut reader = super::super::Reader::new(bgzf::io::Reader::new(&data[..]));
+            crate::fs::index::index_inner(&mut reader)?
+        };
+
+        let mut reader = super::super::Reader::new(bgzf::io::Reader::new(Cursor::new(data)));
+        let header = reader.read_header()?;
+
+        let region = "sq0:5-13".parse()?;
+
+        let query = reader.query_with_mode(&header, &index, &region, QueryMode::Overlap)?;
+        assert_eq!(query.count(), 3);
+
+        let mut query = reader.query_with_mode(&header, &index, &region, QueryMode::Contained)?;
+
+        let mut record = Record::default();
+        let mut reference_bases = Vec::new();
+
+        while query.read_record(&mut record)? != 0 {
+            reference_bases.push(record.reference_bases().to_string());
+        }
+
+        assert_eq!(reference_bases, ["C"]);
+
+        Ok(())
+    }
+}
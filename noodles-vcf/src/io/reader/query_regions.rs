@@ -0,0 +1,137 @@
+use std::io;
+
+use noodles_bgzf as bgzf;
+use noodles_core::region::{Interval, QueryMode};
+use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+
+use super::query::matches;
+use crate::{Header, Record};
+
+/// An iterator over records of a VCF reader that intersect any of a set of regions.
+///
+/// This is created by calling [`super::Reader::query_regions`].
+///
+/// Each matching record is returned once, even if it intersects more than one region.
+pub struct QueryRegions<'r, 'h, R> {
+    reader: super::Reader<csi::io::Query<'r, R>>,
+    header: &'h Header,
+    intervals: Vec<(Vec<u8>, Interval)>,
+    record: Record,
+}
+
+impl<'r, 'h, R> QueryRegions<'r, 'h, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    pub(super) fn new(
+        reader: &'r mut R,
+        chunks: Vec<Chunk>,
+        intervals: Vec<(Vec<u8>, Interval)>,
+        header: &'h Header,
+    ) -> Self {
+        Self {
+            reader: super::Reader::new(csi::io::Query::new(reader, chunks)),
+            header,
+            intervals,
+            record: Record::default(),
+        }
+    }
+
+    fn read_record(&mut self) -> io::Result<usize> {
+        loop {
+            match self.reader.read_record(&mut self.record)? {
+                0 => return Ok(0),
+                n => {
+                    if matches_any(self.header, &self.record, &self.intervals)? {
+                        return Ok(n);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<R> Iterator for QueryRegions<'_, '_, R>
+where
+    R: bgzf::io::BufRead + bgzf::io::Seek,
+{
+    type Item = io::Result<Record>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.read_record() {
+            Ok(0) => None,
+            Ok(_) => Some(Ok(self.record.clone())),
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+fn matches_any(
+    header: &Header,
+    record: &Record,
+    intervals: &[(Vec<u8>, Interval)],
+) -> io::Result<bool> {
+    for (reference_sequence_name, interval) in intervals {
+        if matches(
+            header,
+            record,
+            reference_sequence_name,
+            *interval,
+            QueryMode::Overlap,
+        )? {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::{Cursor, Write};
+
+        use crate::variant::record::Ids as _;
+
+        const DATA: &[u8] = b"##fileformat=VCFv4.5
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\tr0\tACGT\t.\t.\t.\t.
+sq0\t3\tr1\tACGT\t.\t.\t.\t.
+sq0\t8\tr2\tACGT\t.\t.\t.\t.
+sq1\t1\tr3\tACGT\t.\t.\t.\t.
+sq1\t8\tr4\tACGT\t.\t.\t.\t.
+";
+
+        let mut writer = bgzf::io::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        let src = writer.finish()?;
+
+        let mut reader = super::super::Reader::new(bgzf::io::Reader::new(&src[..]));
+        let index = crate::fs::index::index_inner(&mut reader)?;
+
+        let mut reader = super::super::Reader::new(bgzf::io::Reader::new(Cursor::new(src)));
+        let header = reader.read_header()?;
+
+        let regions = [
+            "sq1:1-2".parse()?,
+            "sq0:2-3".parse()?,
+            "sq0:4-5".parse()?,
+            "sq0:5-9".parse()?,
+            "sq0:4-4".parse()?,
+        ];
+
+        let ids: Vec<_> = reader
+            .query_regions(&header, &index, &regions)?
+            .map(|result| result.map(|record| record.ids().iter().collect::<Vec<_>>().join(",")))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(ids, ["r0", "r1", "r2", "r3"]);
+
+        Ok(())
+    }
+}
@@ -10,6 +10,10 @@ mod reference_bases;
 mod reference_sequence_name;
 mod samples;
 pub(crate) mod value;
+mod value_counts;
+
+pub use self::value_counts::ValidationError;
+pub(crate) use self::value_counts::validate_value_counts;
 
 use std::{error, fmt};
 
@@ -0,0 +1,263 @@
+use std::{error, fmt};
+
+use crate::{
+    Header,
+    header::record::value::map::{format, info},
+    variant::{
+        RecordBuf,
+        record::samples::keys::key,
+        record_buf::fields::{
+            format_number, genotype_count, info_array_value_counts, info_number,
+            sample_array_value_counts,
+        },
+    },
+};
+
+/// An error returned when a VCF record field has an unexpected number of values.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ValidationError {
+    /// An info field value count does not match the number of alleles.
+    InvalidInfoValueCount {
+        /// The field key.
+        key: String,
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        actual: usize,
+    },
+    /// A sample value count does not match the number of alleles or genotypes.
+    InvalidSampleValueCount {
+        /// The field key.
+        key: String,
+        /// The expected number of values.
+        expected: usize,
+        /// The actual number of values.
+        actual: usize,
+    },
+}
+
+impl error::Error for ValidationError {}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidInfoValueCount {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid info value count for {key}: expected {expected}, got {actual}"
+            ),
+            Self::InvalidSampleValueCount {
+                key,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "invalid sample value count for {key}: expected {expected}, got {actual}"
+            ),
+        }
+    }
+}
+
+/// Validates the value counts of info fields with `Number=A` or `Number=R` and sample fields with
+/// `Number=A`, `Number=R`, or `Number=G`.
+///
+/// The expected number of values is derived from the number of alternate bases in the record. For
+/// `Number=G`, it is the number of possible genotypes given the ploidy of the sample's genotype
+/// (`GT`). Samples without a genotype are not checked for `Number=G`.
+pub(crate) fn validate_value_counts(
+    header: &Header,
+    record: &RecordBuf,
+) -> Result<(), ValidationError> {
+    let alternate_base_count = record.alternate_bases().as_ref().len();
+
+    for (key, value) in record.info().keys().zip(record.info().values()) {
+        use crate::variant::record_buf::info::field::Value;
+
+        let Some(Value::Array(array)) = value else {
+            continue;
+        };
+
+        let Some(expected) = info_number(header, key)
+            .and_then(|number| expected_info_value_count(number, alternate_base_count))
+        else {
+            continue;
+        };
+
+        let (actual, _) = info_array_value_counts(array);
+
+        if actual != expected {
+            return Err(ValidationError::InvalidInfoValueCount {
+                key: key.clone(),
+                expected,
+                actual,
+            });
+        }
+    }
+
+    let samples = record.samples();
+
+    for sample in samples.values() {
+        use crate::variant::record_buf::samples::sample::Value;
+
+        let ploidy = match sample.get(key::GENOTYPE) {
+            Some(Some(Value::Genotype(genotype))) => Some(genotype.as_ref().len()),
+            _ => None,
+        };
+
+        for (key, value) in sample.keys().as_ref().iter().zip(sample.values()) {
+            let Some(Value::Array(array)) = value else {
+                continue;
+            };
+
+            let Some(expected) = format_number(header, key).and_then(|number| {
+                expected_format_value_count(number, alternate_base_count, ploidy)
+            }) else {
+                continue;
+            };
+
+            let (actual, _) = sample_array_value_counts(array);
+
+            if actual != expected {
+                return Err(ValidationError::InvalidSampleValueCount {
+                    key: key.clone(),
+                    expected,
+                    actual,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn expected_info_value_count(number: info::Number, alternate_base_count: usize) -> Option<usize> {
+    match number {
+        info::Number::AlternateBases => Some(alternate_base_count),
+        info::Number::ReferenceAlternateBases => Some(alternate_base_count + 1),
+        _ => None,
+    }
+}
+
+fn expected_format_value_count(
+    number: format::Number,
+    alternate_base_count: usize,
+    ploidy: Option<usize>,
+) -> Option<usize> {
+    match number {
+        format::Number::AlternateBases => Some(alternate_base_count),
+        format::Number::ReferenceAlternateBases => Some(alternate_base_count + 1),
+        format::Number::Samples => {
+            ploidy.and_then(|ploidy| genotype_count(ploidy, alternate_base_count + 1))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_value_counts() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::io::reader::parse_record_buf;
+
+        let header = Header::builder().add_sample_name("sample0").build();
+        let mut record = RecordBuf::default();
+
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tAF=0.25,0.5\tGT:AD\t1/2:0,3,5",
+            &header,
+            &mut record,
+        )?;
+        assert!(validate_value_counts(&header, &record).is_ok());
+
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tAF=0.25\tGT:AD\t1/2:0,3,5",
+            &header,
+            &mut record,
+        )?;
+        assert_eq!(
+            validate_value_counts(&header, &record),
+            Err(ValidationError::InvalidInfoValueCount {
+                key: String::from("AF"),
+                expected: 2,
+                actual: 1,
+            })
+        );
+
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\t.\tGT:AD\t1/2:0,3",
+            &header,
+            &mut record,
+        )?;
+        assert_eq!(
+            validate_value_counts(&header, &record),
+            Err(ValidationError::InvalidSampleValueCount {
+                key: String::from("AD"),
+                expected: 3,
+                actual: 2,
+            })
+        );
+
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tAF=.\tGT:AD\t1/2:.",
+            &header,
+            &mut record,
+        )?;
+        assert!(validate_value_counts(&header, &record).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_value_counts_with_genotype_value_counts()
+    -> Result<(), Box<dyn std::error::Error>> {
+        use crate::io::reader::parse_record_buf;
+
+        let header = Header::builder().add_sample_name("sample0").build();
+        let mut record = RecordBuf::default();
+
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT:PL\t0/1:0,3,5",
+            &header,
+            &mut record,
+        )?;
+        assert!(validate_value_counts(&header, &record).is_ok());
+
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\t.\tGT:PL\t1/2:0,3,5,7,9,11",
+            &header,
+            &mut record,
+        )?;
+        assert!(validate_value_counts(&header, &record).is_ok());
+
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT:PL\t1:0,3",
+            &header,
+            &mut record,
+        )?;
+        assert!(validate_value_counts(&header, &record).is_ok());
+
+        parse_record_buf("sq0\t1\t.\tA\tC\t.\tPASS\t.\tPL\t0,3", &header, &mut record)?;
+        assert!(validate_value_counts(&header, &record).is_ok());
+
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC\t.\tPASS\t.\tGT:PL\t0/1:0,3",
+            &header,
+            &mut record,
+        )?;
+        assert_eq!(
+            validate_value_counts(&header, &record),
+            Err(ValidationError::InvalidSampleValueCount {
+                key: String::from("PL"),
+                expected: 3,
+                actual: 2,
+            })
+        );
+
+        Ok(())
+    }
+}
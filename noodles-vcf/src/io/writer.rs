@@ -2,12 +2,13 @@
 
 mod builder;
 mod header;
+mod options;
 mod record;
 
 use std::io::{self, Write};
 
 pub use self::builder::Builder;
-use self::{header::write_header, record::write_record};
+use self::{header::write_header, options::Options, record::write_record};
 use crate::{Header, Record};
 
 /// A VCF writer.
@@ -55,6 +56,7 @@ use crate::{Header, Record};
 #[derive(Debug)]
 pub struct Writer<W> {
     inner: W,
+    options: Options,
 }
 
 impl<W> Writer<W>
@@ -70,7 +72,10 @@ where
     /// let writer = vcf::io::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            options: Options::default(),
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -148,7 +153,7 @@ where
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        write_record(&mut self.inner, header, record, &self.options)
     }
 }
 
@@ -165,7 +170,7 @@ where
         header: &Header,
         record: &dyn crate::variant::Record,
     ) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        write_record(&mut self.inner, header, record, &self.options)
     }
 }
 
@@ -232,4 +237,303 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_variant_record_with_info_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{Map, map::Info},
+            variant::{
+                record::info::field::key,
+                record_buf::{AlternateBases, info::field::Value},
+            },
+        };
+
+        let header = Header::builder()
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(key::TOTAL_DEPTH))
+            .add_info(
+                key::ALLELE_FREQUENCIES,
+                Map::<Info>::from(key::ALLELE_FREQUENCIES),
+            )
+            .add_info(key::IS_IN_DB_SNP, Map::<Info>::from(key::IS_IN_DB_SNP))
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("C"),
+                String::from("G"),
+            ]))
+            .set_info(
+                [
+                    (String::from(key::TOTAL_DEPTH), Some(Value::from(13))),
+                    (
+                        String::from(key::ALLELE_FREQUENCIES),
+                        Some(Value::from(vec![Some(0.5), Some(0.25)])),
+                    ),
+                    (String::from(key::IS_IN_DB_SNP), Some(Value::Flag)),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_variant_record(&header, &record)?;
+
+        let expected = b"sq0\t1\t.\tA\tC,G\t.\t.\tDP=13;AF=0.5,0.25;DB\n";
+        assert_eq!(writer.get_ref(), expected);
+
+        let mut reader = crate::io::Reader::new(&writer.get_ref()[..]);
+        let mut actual = RecordBuf::default();
+        reader.read_record_buf(&header, &mut actual)?;
+        assert_eq!(actual.info(), record.info());
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_variant_record(&header, &actual)?;
+        assert_eq!(writer.get_ref(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_with_tabs_and_newlines_in_values() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{
+                Map,
+                map::{
+                    Info,
+                    info::{Number, Type},
+                },
+            },
+            variant::record_buf::info::field::Value,
+        };
+
+        let header = Header::builder()
+            .add_info(
+                "NOTE",
+                Map::<Info>::new(Number::Count(1), Type::String, "A\tnote"),
+            )
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+
+        assert!(matches!(
+            writer.write_header(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        // Record values are percent-encoded.
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_info(
+                [(String::from("NOTE"), Some(Value::from("a\tb\nc")))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t.\t.\t.\tNOTE=a%09b%0Ac\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_variant_record_with_add_end_for_symbolic_alleles()
+    -> Result<(), Box<dyn std::error::Error>> {
+        use crate::variant::{
+            record::info::field::key,
+            record_buf::{AlternateBases, info::field::Value},
+        };
+
+        let header = Header::default();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("<DEL>")]))
+            .set_info(
+                [(
+                    String::from(key::SV_LENGTHS),
+                    Some(Value::from(vec![Some(5)])),
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"sq0\t8\t.\tA\t<DEL>\t.\t.\tSVLEN=5\n");
+
+        let mut writer = Writer::new(Vec::new());
+        writer.options.add_end_for_symbolic_alleles = true;
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(
+            writer.get_ref(),
+            b"sq0\t8\t.\tA\t<DEL>\t.\t.\tSVLEN=5;END=12\n"
+        );
+
+        // An existing END is not overridden.
+        let mut record = record;
+        record
+            .info_mut()
+            .insert(String::from(key::END_POSITION), Some(Value::from(13)));
+
+        let mut writer = Writer::new(Vec::new());
+        writer.options.add_end_for_symbolic_alleles = true;
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(
+            writer.get_ref(),
+            b"sq0\t8\t.\tA\t<DEL>\t.\t.\tSVLEN=5;END=13\n"
+        );
+
+        // Records without symbolic alleles are unchanged.
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("AC")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.options.add_end_for_symbolic_alleles = true;
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"sq0\t8\t.\tAC\tA\t.\t.\t.\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_variant_record_with_add_end_for_symbolic_alleles_in_vcf_4_3()
+    -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::FileFormat,
+            variant::{
+                record::info::field::key,
+                record_buf::{AlternateBases, info::field::Value},
+            },
+        };
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("<DEL>")]))
+            .set_info(
+                [(
+                    String::from(key::SV_LENGTHS),
+                    Some(Value::from(vec![Some(-1000)])),
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.options.add_end_for_symbolic_alleles = true;
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(
+            writer.get_ref(),
+            b"sq0\t8\t.\tA\t<DEL>\t.\t.\tSVLEN=-1000;END=1008\n"
+        );
+
+        // The span is unknown without SVLEN or LEN.
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("<DEL>")]))
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.options.add_end_for_symbolic_alleles = true;
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"sq0\t8\t.\tA\t<DEL>\t.\t.\t.\n");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_variant_record_with_undeclared_filter() -> Result<(), Box<dyn std::error::Error>>
+    {
+        let header = Header::default();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_filters([String::from("q10")].into_iter().collect())
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t.\t.\tq10\t.\n");
+
+        let mut writer = super::Builder::default()
+            .set_validate_filters(true)
+            .build_from_writer(Vec::new());
+
+        assert!(matches!(
+            writer.write_variant_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_variant_record_with_invalid_info_value() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use crate::{
+            header::record::value::{Map, map::Info},
+            variant::{
+                record::info::field::key,
+                record_buf::{Info as InfoBuf, info::field::Value},
+            },
+        };
+
+        let header = Header::builder()
+            .add_info(key::TOTAL_DEPTH, Map::<Info>::from(key::TOTAL_DEPTH))
+            .build();
+
+        let info: InfoBuf = [(
+            String::from(key::TOTAL_DEPTH),
+            Some(Value::from(vec![Some(8), Some(13)])),
+        )]
+        .into_iter()
+        .collect();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_info(info)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_variant_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t.\t.\t.\tDP=8,13\n");
+
+        let mut writer = super::Builder::default()
+            .set_validate_info_values(true)
+            .build_from_writer(Vec::new());
+
+        assert!(matches!(
+            writer.write_variant_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
 }
@@ -6,13 +6,14 @@ use std::{
 
 use noodles_bgzf as bgzf;
 
-use super::Writer;
+use super::{Options, Writer};
 use crate::io::CompressionMethod;
 
 /// A BAM writer builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    options: Options,
 }
 
 impl Builder {
@@ -29,6 +30,85 @@ impl Builder {
         self
     }
 
+    /// Sets whether to omit the leading phasing indicator of genotypes when it can be inferred.
+    ///
+    /// Starting with VCF 4.4, the phasing indicator of the first allele of a genotype is written
+    /// explicitly, e.g., a phased haploid genotype is written as `|0`. The leading indicator is
+    /// optional, however; when it is absent, readers infer it as phased if all other alleles are
+    /// phased and unphased otherwise.
+    ///
+    /// When enabled, the leading phasing indicator is omitted when it matches the inferred
+    /// phasing, e.g., `|0` is written as `0` and `|0|1` as `0|1`, but `/0|1` is kept as is. This
+    /// has no effect for files before VCF 4.4. By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_omit_leading_genotype_phasing(true);
+    /// ```
+    pub fn set_omit_leading_genotype_phasing(
+        mut self,
+        omit_leading_genotype_phasing: bool,
+    ) -> Self {
+        self.options.omit_leading_genotype_phasing = omit_leading_genotype_phasing;
+        self
+    }
+
+    /// Sets whether to validate record filters against the header.
+    ///
+    /// When enabled, writing a record fails with an [`io::ErrorKind::InvalidInput`] error if any
+    /// of its filters is neither `PASS` nor declared in the header (`FILTER`). By default, this is
+    /// disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_validate_filters(true);
+    /// ```
+    pub fn set_validate_filters(mut self, validate_filters: bool) -> Self {
+        self.options.validate_filters = validate_filters;
+        self
+    }
+
+    /// Sets whether to validate INFO field values against the header.
+    ///
+    /// When enabled, writing a record fails with an [`io::ErrorKind::InvalidInput`] error if the
+    /// type or number of values of an INFO field does not match its header definition. By
+    /// default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_validate_info_values(true);
+    /// ```
+    pub fn set_validate_info_values(mut self, validate_info_values: bool) -> Self {
+        self.options.validate_info_values = validate_info_values;
+        self
+    }
+
+    /// Sets whether to add an INFO END field to records with symbolic alternate alleles.
+    ///
+    /// When enabled, if a record has a symbolic alternate allele (e.g., `<DEL>` or `<DUP>`) but no
+    /// INFO END field, `END` is computed from the record's span and written as the last INFO
+    /// field. The span is the maximum of the number of reference bases, the absolute INFO `SVLEN`
+    /// values, and the FORMAT `LEN` values, for any file format. If a record has neither `SVLEN`
+    /// nor `LEN`, its span is unknown, and no `END` is added. An existing `END` is never
+    /// overridden. By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_add_end_for_symbolic_alleles(true);
+    /// ```
+    pub fn set_add_end_for_symbolic_alleles(mut self, add_end_for_symbolic_alleles: bool) -> Self {
+        self.options.add_end_for_symbolic_alleles = add_end_for_symbolic_alleles;
+        self
+    }
+
     /// Builds a VCF writer from a path.
     ///
     /// If the compression method is not set, it is detected from the path extension.
@@ -77,6 +157,8 @@ impl Builder {
             Some(CompressionMethod::None) | None => Box::new(BufWriter::new(writer)),
         };
 
-        Writer::new(inner)
+        let mut writer = Writer::new(inner);
+        writer.options = self.options;
+        writer
     }
 }
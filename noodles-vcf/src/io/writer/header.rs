@@ -4,9 +4,15 @@ use std::io::{self, Write};
 
 use self::record::{
     write_alternative_allele, write_contig, write_file_format, write_filter, write_format,
-    write_info, write_other,
+    write_info, write_other_entry,
+};
+use crate::{
+    Header,
+    header::{
+        SampleNames,
+        record::{Key, key::Standard},
+    },
 };
-use crate::{Header, header::SampleNames};
 
 pub(super) fn write_header<W>(writer: &mut W, header: &Header) -> io::Result<()>
 where
@@ -16,28 +22,40 @@ where
 
     write_file_format(writer, file_format)?;
 
-    for (id, info) in header.infos() {
-        write_info(writer, id, info)?;
-    }
-
-    for (id, filter) in header.filters() {
-        write_filter(writer, id, filter)?;
-    }
-
-    for (id, format) in header.formats() {
-        write_format(writer, id, format)?;
-    }
-
-    for (id, alternative_allele) in header.alternative_alleles() {
-        write_alternative_allele(writer, id, alternative_allele)?;
-    }
-
-    for (id, contig) in header.contigs() {
-        write_contig(writer, id, contig)?;
-    }
-
-    for (key, collection) in header.other_records() {
-        write_other(writer, file_format, key, collection)?;
+    for (key, i) in header.record_indices() {
+        match key {
+            Key::Standard(Standard::FileFormat) => {}
+            Key::Standard(Standard::Info) => {
+                if let Some((id, info)) = header.infos().get_index(i) {
+                    write_info(writer, id, info)?;
+                }
+            }
+            Key::Standard(Standard::Filter) => {
+                if let Some((id, filter)) = header.filters().get_index(i) {
+                    write_filter(writer, id, filter)?;
+                }
+            }
+            Key::Standard(Standard::Format) => {
+                if let Some((id, format)) = header.formats().get_index(i) {
+                    write_format(writer, id, format)?;
+                }
+            }
+            Key::Standard(Standard::AlternativeAllele) => {
+                if let Some((id, alternative_allele)) = header.alternative_alleles().get_index(i) {
+                    write_alternative_allele(writer, id, alternative_allele)?;
+                }
+            }
+            Key::Standard(Standard::Contig) => {
+                if let Some((id, contig)) = header.contigs().get_index(i) {
+                    write_contig(writer, id, contig)?;
+                }
+            }
+            Key::Other(key) => {
+                if let Some(collection) = header.other_records().get(&key) {
+                    write_other_entry(writer, file_format, &key, collection, i)?;
+                }
+            }
+        }
     }
 
     write_column_names(writer, header.sample_names())?;
@@ -137,4 +155,61 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_header_with_parsed_record_order() -> Result<(), Box<dyn std::error::Error>> {
+        let src = "##fileformat=VCFv4.3
+##fileDate=20200709
+##pipeline=noodles-a
+##contig=<ID=sq0,length=8>
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##pipeline=noodles-b
+##contig=<ID=sq1,length=13>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        let header: Header = src.parse()?;
+
+        let mut buf = Vec::new();
+        write_header(&mut buf, &header)?;
+        assert_eq!(buf, src.as_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_header_with_records_added_after_parsing() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use crate::header::record::{
+            Value,
+            value::{Map, map::Contig},
+        };
+
+        let mut header: Header = "##fileformat=VCFv4.3
+##pipeline=noodles-a
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+"
+        .parse()?;
+
+        header
+            .contigs_mut()
+            .insert(String::from("sq1"), Map::<Contig>::new());
+        header.insert("pipeline".parse()?, Value::from("noodles-b"))?;
+
+        let mut buf = Vec::new();
+        write_header(&mut buf, &header)?;
+
+        let expected = b"##fileformat=VCFv4.3
+##pipeline=noodles-a
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+##pipeline=noodles-b
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        assert_eq!(buf, expected);
+
+        Ok(())
+    }
 }
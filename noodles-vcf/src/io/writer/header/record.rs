@@ -99,11 +99,13 @@ where
     })
 }
 
-pub(super) fn write_other<W>(
+// Writes the `i`th value of the collection, if present.
+pub(super) fn write_other_entry<W>(
     writer: &mut W,
     file_format: FileFormat,
     key: &record::key::Other,
     collection: &Collection,
+    i: usize,
 ) -> io::Result<()>
 where
     W: Write,
@@ -112,12 +114,12 @@ where
 
     match collection {
         Collection::Unstructured(vs) => {
-            for v in vs {
+            if let Some(v) = vs.get(i) {
                 write_record(writer, key, |w| value::write_string(w, file_format, v))?;
             }
         }
         Collection::Structured(maps) => {
-            for (id, map) in maps {
+            if let Some((id, map)) = maps.get_index(i) {
                 write_record(writer, key, |w| {
                     value::write_other_map(w, map.id_tag(), id, |x| {
                         if key.as_ref() == META {
@@ -147,7 +149,7 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_write_other() -> Result<(), Box<dyn std::error::Error>> {
+    fn test_write_other_entry() -> Result<(), Box<dyn std::error::Error>> {
         let mut buf = Vec::new();
 
         let file_format = FileFormat::new(4, 4);
@@ -156,7 +158,9 @@ mod tests {
         buf.clear();
         let collection =
             Collection::Unstructured(vec![String::from("noodles"), String::from("vcf")]);
-        write_other(&mut buf, file_format, &key, &collection)?;
+        for i in 0..collection.len() {
+            write_other_entry(&mut buf, file_format, &key, &collection, i)?;
+        }
         assert_eq!(buf, b"##comment=noodles\n##comment=vcf\n");
 
         buf.clear();
@@ -168,7 +172,9 @@ mod tests {
             .into_iter()
             .collect(),
         );
-        write_other(&mut buf, file_format, &key, &collection)?;
+        for i in 0..collection.len() {
+            write_other_entry(&mut buf, file_format, &key, &collection, i)?;
+        }
         assert_eq!(buf, b"##comment=<ID=noodles>\n##comment=<ID=vcf>\n");
 
         Ok(())
@@ -2,8 +2,25 @@ pub(super) mod file_format;
 pub(super) mod map;
 mod string;
 
+use std::io;
+
 pub(super) use self::{
     file_format::write_file_format,
     map::{write_map, write_other_map},
     string::write_string,
 };
+
+// Header record values are line-delimited, and unlike record fields, have no percent-encoding to
+// represent control characters. A raw tab or newline would change how the line is read back.
+fn validate_value(s: &str) -> io::Result<()> {
+    if s.contains(['\t', '\n', '\r']) {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid header record value: {s:?}: tabs and newlines must be percent-encoded (%09, %0A, %0D)"
+            ),
+        ))
+    } else {
+        Ok(())
+    }
+}
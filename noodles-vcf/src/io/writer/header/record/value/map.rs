@@ -12,6 +12,7 @@ pub(crate) use self::{
     alternative_allele::write_alternative_allele, contig::write_contig, filter::write_filter,
     format::write_format, info::write_info, meta::write_meta, other::write_other,
 };
+use super::validate_value;
 use crate::{
     header::record::value::map::{self, OtherFields, tag},
     io::writer::header::record::write_separator,
@@ -126,6 +127,8 @@ where
 
     let s = value.as_ref();
 
+    validate_value(s)?;
+
     if requires_escapes(s) {
         write_escaped_string(writer, s)?;
     } else {
@@ -142,7 +145,9 @@ where
     W: Write,
     V: AsRef<str>,
 {
-    writer.write_all(value.as_ref().as_bytes())
+    let s = value.as_ref();
+    validate_value(s)?;
+    writer.write_all(s.as_bytes())
 }
 
 fn write_prefix<W>(writer: &mut W) -> io::Result<()>
@@ -216,6 +221,14 @@ mod tests {
         t(&mut buf, r#"noodles-"vcf""#, br#""noodles-\"vcf\"""#)?;
         t(&mut buf, r"noodles\vcf", br#""noodles\\vcf""#)?;
 
+        for s in ["noodles\tvcf", "noodles\nvcf", "noodles\r\nvcf"] {
+            buf.clear();
+            assert!(matches!(
+                write_string(&mut buf, s),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
         Ok(())
     }
 }
@@ -16,6 +16,8 @@ where
         s.chars().next().is_some_and(|c| c != LESS_THAN_SIGN)
     }
 
+    super::validate_value(s)?;
+
     if file_format < VCF_4_3 || is_valid(s) {
         writer.write_all(s.as_bytes())
     } else {
@@ -56,6 +58,12 @@ mod tests {
             Err(e) if e.kind() == io::ErrorKind::InvalidInput
         ));
 
+        buf.clear();
+        assert!(matches!(
+            write_string(&mut buf, VCF_4_2, "nd\nls"),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
         Ok(())
     }
 }
@@ -0,0 +1,7 @@
+#[derive(Clone, Debug, Default)]
+pub struct Options {
+    pub omit_leading_genotype_phasing: bool,
+    pub validate_filters: bool,
+    pub validate_info_values: bool,
+    pub add_end_for_symbolic_alleles: bool,
+}
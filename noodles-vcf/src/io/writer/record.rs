@@ -11,17 +11,25 @@ pub(super) mod value;
 
 use std::io::{self, Write};
 
+use noodles_core::Position;
+
 use self::{
     alternate_bases::write_alternate_bases, filters::write_filters, ids::write_ids,
     info::write_info, position::write_position, quality_score::write_quality_score,
     reference_bases::write_reference_bases, reference_sequence_name::write_reference_sequence_name,
     samples::write_samples,
 };
+use super::Options;
 use crate::{Header, variant::Record};
 
 const MISSING: &[u8] = b".";
 
-pub(super) fn write_record<W, R>(writer: &mut W, header: &Header, record: &R) -> io::Result<()>
+pub(super) fn write_record<W, R>(
+    writer: &mut W,
+    header: &Header,
+    record: &R,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
     R: Record + ?Sized,
@@ -50,16 +58,35 @@ where
     write_quality_score(writer, quality_score)?;
 
     writer.write_all(DELIMITER)?;
-    write_filters(writer, header, record.filters())?;
+    write_filters(writer, header, record.filters(), options.validate_filters)?;
 
     writer.write_all(DELIMITER)?;
-    write_info(writer, header, record.info())?;
+
+    let end = if options.add_end_for_symbolic_alleles {
+        symbolic_allele_end(header, record)?
+    } else {
+        None
+    };
+
+    write_info(
+        writer,
+        header,
+        record.alternate_bases().len(),
+        record.info(),
+        end,
+        options.validate_info_values,
+    )?;
 
     let samples = record.samples()?;
 
     if !samples.is_empty() {
         writer.write_all(DELIMITER)?;
-        write_samples(writer, header, samples)?;
+        write_samples(
+            writer,
+            header,
+            samples,
+            options.omit_leading_genotype_phasing,
+        )?;
     }
 
     writer.write_all(b"\n")?;
@@ -67,6 +94,66 @@ where
     Ok(())
 }
 
+// Returns the end position of a record with a symbolic alternate allele that is missing an INFO
+// END field.
+//
+// For VCF < 4.5, the end position is the variant start plus the maximum absolute INFO SVLEN
+// value, e.g., a deletion at POS=321682 with SVLEN=-205 ends at 321887. For VCF >= 4.5, it is the
+// resolved variant end (see [`Record::variant_end`]), which also considers FORMAT LEN values. If
+// the record has none of these values, the span is unknown, and no end position is returned.
+fn symbolic_allele_end<R>(header: &Header, record: &R) -> io::Result<Option<Position>>
+where
+    R: Record + ?Sized,
+{
+    use crate::{
+        header::FileFormat,
+        variant::record::{
+            AlternateBases, info::field::key, info_max_abs_sv_len, info_max_sv_len, samples_max_len,
+        },
+    };
+
+    const VCF_4_5: FileFormat = FileFormat::new(4, 5);
+
+    let mut has_symbolic_allele = false;
+
+    for result in record.alternate_bases().iter() {
+        if result?.starts_with('<') {
+            has_symbolic_allele = true;
+            break;
+        }
+    }
+
+    let info = record.info();
+
+    if !has_symbolic_allele || info.get(header, key::END_POSITION).is_some() {
+        return Ok(None);
+    }
+
+    if header.file_format() < VCF_4_5 {
+        let Some(len) = info_max_abs_sv_len(header, &info).transpose()?.flatten() else {
+            return Ok(None);
+        };
+
+        let start = record.variant_start().transpose()?.unwrap_or(Position::MIN);
+
+        start
+            .checked_add(len)
+            .map(Some)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "position overflow"))
+    } else {
+        let samples = record.samples()?;
+
+        let sv_len = info_max_sv_len(header, &info).transpose()?.flatten();
+        let len = samples_max_len(header, &samples).transpose()?.flatten();
+
+        if sv_len.is_none() && len.is_none() {
+            return Ok(None);
+        }
+
+        record.variant_end(header).map(Some)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use noodles_core::Position;
@@ -84,7 +171,7 @@ mod tests {
 
         let header = Header::default();
         let mut buf = Vec::new();
-        write_record(&mut buf, &header, &record)?;
+        write_record(&mut buf, &header, &record, &Options::default())?;
         assert_eq!(buf, b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
 
         Ok(())
@@ -3,7 +3,12 @@ use std::io::{self, Write};
 use super::MISSING;
 use crate::{Header, variant::record::Filters};
 
-pub(super) fn write_filters<W, F>(writer: &mut W, header: &Header, filters: F) -> io::Result<()>
+pub(super) fn write_filters<W, F>(
+    writer: &mut W,
+    header: &Header,
+    filters: F,
+    validate_filters: bool,
+) -> io::Result<()>
 where
     W: Write,
     F: Filters,
@@ -20,14 +25,21 @@ where
                 writer.write_all(DELIMITER)?;
             }
 
-            if is_valid(id) {
-                writer.write_all(id.as_bytes())?;
-            } else {
+            if !is_valid(id) {
                 return Err(io::Error::new(
                     io::ErrorKind::InvalidInput,
                     "invalid filter",
                 ));
             }
+
+            if validate_filters && !is_declared(header, id) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("undeclared filter: {id}"),
+                ));
+            }
+
+            writer.write_all(id.as_bytes())?;
         }
     }
 
@@ -43,6 +55,11 @@ fn is_valid(s: &str) -> bool {
     s.chars().all(is_valid_char)
 }
 
+fn is_declared(header: &Header, id: &str) -> bool {
+    const PASS: &str = "PASS";
+    id == PASS || header.filters().contains_key(id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -57,7 +74,7 @@ mod tests {
             expected: &[u8],
         ) -> io::Result<()> {
             buf.clear();
-            write_filters(buf, header, filters)?;
+            write_filters(buf, header, filters, false)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -79,13 +96,46 @@ mod tests {
         buf.clear();
         let filters = [String::from("q 10")].into_iter().collect();
         assert!(matches!(
-            write_filters(&mut buf, &header, &filters),
+            write_filters(&mut buf, &header, &filters, false),
             Err(e) if e.kind() == io::ErrorKind::InvalidInput
         ));
 
         Ok(())
     }
 
+    #[test]
+    fn test_write_filters_with_validation() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::record::value::{Map, map::Filter};
+
+        let mut buf = Vec::new();
+
+        let header = Header::builder()
+            .add_filter(
+                "s50",
+                Map::<Filter>::new("Less than 50% of samples have data"),
+            )
+            .build();
+
+        write_filters(&mut buf, &header, FiltersBuf::pass(), true)?;
+        assert_eq!(buf, b"PASS");
+
+        buf.clear();
+        let filters: FiltersBuf = [String::from("s50")].into_iter().collect();
+        write_filters(&mut buf, &header, &filters, true)?;
+        assert_eq!(buf, b"s50");
+
+        buf.clear();
+        let filters: FiltersBuf = [String::from("q10"), String::from("s50")]
+            .into_iter()
+            .collect();
+        assert!(matches!(
+            write_filters(&mut buf, &header, &filters, true),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput && e.to_string() == "undeclared filter: q10"
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_is_valid() {
         assert!(is_valid("PASS"));
@@ -2,18 +2,36 @@ mod field;
 
 use std::io::{self, Write};
 
+use noodles_core::Position;
+
 use self::field::write_field;
 use super::MISSING;
-use crate::{Header, variant::record::Info};
+use crate::{
+    Header,
+    header::record::value::map::info::{Number, Type},
+    variant::record::{
+        Info,
+        info::field::{Value, value::Array},
+    },
+};
 
-pub(super) fn write_info<W, I>(writer: &mut W, header: &Header, info: I) -> io::Result<()>
+pub(super) fn write_info<W, I>(
+    writer: &mut W,
+    header: &Header,
+    alternate_bases_len: usize,
+    info: I,
+    end: Option<Position>,
+    validate_values: bool,
+) -> io::Result<()>
 where
     W: Write,
     I: Info,
 {
+    use crate::variant::record::info::field::key;
+
     const DELIMITER: &[u8] = b";";
 
-    if info.is_empty() {
+    if info.is_empty() && end.is_none() {
         writer.write_all(MISSING)?;
     } else {
         for (i, result) in info.iter(header).enumerate() {
@@ -23,13 +41,99 @@ where
                 writer.write_all(DELIMITER)?;
             }
 
+            if validate_values {
+                if let Some(definition) = header.infos().get(key) {
+                    validate_value(
+                        definition.number(),
+                        definition.ty(),
+                        alternate_bases_len,
+                        value.as_ref(),
+                    )?;
+                }
+            }
+
             write_field(writer, key, value.as_ref())?;
         }
+
+        if let Some(position) = end {
+            if !info.is_empty() {
+                writer.write_all(DELIMITER)?;
+            }
+
+            let n = i32::try_from(usize::from(position))
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+            write_field(writer, key::END_POSITION, Some(&Value::Integer(n)))?;
+        }
     }
 
     Ok(())
 }
 
+fn validate_value(
+    number: Number,
+    ty: Type,
+    alternate_bases_len: usize,
+    value: Option<&Value>,
+) -> io::Result<()> {
+    let Some(value) = value else {
+        return Ok(());
+    };
+
+    let is_valid_type = match (ty, value) {
+        (Type::Flag, Value::Flag) => true,
+        (Type::Integer, Value::Integer(_) | Value::Array(Array::Integer(_))) => true,
+        // Integers are written the same way as floats with no fractional part.
+        (
+            Type::Float,
+            Value::Integer(_) | Value::Float(_) | Value::Array(Array::Integer(_) | Array::Float(_)),
+        ) => true,
+        (Type::Character, Value::Character(_) | Value::Array(Array::Character(_))) => true,
+        (
+            Type::String,
+            Value::Character(_)
+            | Value::String(_)
+            | Value::Array(Array::Character(_) | Array::String(_)),
+        ) => true,
+        _ => false,
+    };
+
+    if !is_valid_type {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "info field value type does not match header definition",
+        ));
+    }
+
+    if matches!(ty, Type::Flag) {
+        return Ok(());
+    }
+
+    let expected_len = match number {
+        Number::Count(n) => n,
+        Number::AlternateBases => alternate_bases_len,
+        Number::ReferenceAlternateBases => alternate_bases_len + 1,
+        Number::Samples | Number::Unknown => return Ok(()),
+    };
+
+    let actual_len = match value {
+        Value::Array(Array::Integer(values)) => values.len(),
+        Value::Array(Array::Float(values)) => values.len(),
+        Value::Array(Array::Character(values)) => values.len(),
+        Value::Array(Array::String(values)) => values.len(),
+        _ => 1,
+    };
+
+    if actual_len == expected_len {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "info field value count does not match header definition",
+        ))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -48,7 +152,7 @@ mod tests {
             expected: &[u8],
         ) -> io::Result<()> {
             buf.clear();
-            write_info(buf, header, info)?;
+            write_info(buf, header, 0, info, None, false)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -81,4 +185,81 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_info_with_header_definitions() -> io::Result<()> {
+        use crate::{
+            header::record::value::{Map, map::Info as InfoDefinition},
+            variant::{
+                record::info::field::key,
+                record_buf::{Info as InfoBuf, info::field::Value as ValueBuf},
+            },
+        };
+
+        let header = Header::builder()
+            .add_info(
+                key::TOTAL_DEPTH,
+                Map::<InfoDefinition>::from(key::TOTAL_DEPTH),
+            )
+            .add_info(
+                key::ALLELE_FREQUENCIES,
+                Map::<InfoDefinition>::from(key::ALLELE_FREQUENCIES),
+            )
+            .add_info(
+                key::IS_IN_DB_SNP,
+                Map::<InfoDefinition>::from(key::IS_IN_DB_SNP),
+            )
+            .build();
+
+        let mut buf = Vec::new();
+
+        let info: InfoBuf = [
+            (String::from(key::TOTAL_DEPTH), Some(ValueBuf::from(13))),
+            (
+                String::from(key::ALLELE_FREQUENCIES),
+                Some(ValueBuf::from(vec![Some(0.5), Some(0.25)])),
+            ),
+            (String::from(key::IS_IN_DB_SNP), Some(ValueBuf::Flag)),
+        ]
+        .into_iter()
+        .collect();
+
+        write_info(&mut buf, &header, 2, &info, None, true)?;
+        assert_eq!(buf, b"DP=13;AF=0.5,0.25;DB");
+
+        buf.clear();
+        assert!(matches!(
+            write_info(&mut buf, &header, 1, &info, None, true),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let info: InfoBuf = [(
+            String::from(key::TOTAL_DEPTH),
+            Some(ValueBuf::from(vec![Some(8), Some(13)])),
+        )]
+        .into_iter()
+        .collect();
+
+        buf.clear();
+        assert!(matches!(
+            write_info(&mut buf, &header, 0, &info, None, true),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let info: InfoBuf = [(String::from(key::IS_IN_DB_SNP), Some(ValueBuf::from(1)))]
+            .into_iter()
+            .collect();
+
+        buf.clear();
+        assert!(matches!(
+            write_info(&mut buf, &header, 0, &info, None, true),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        buf.clear();
+        write_info(&mut buf, &header, 0, &info, None, false)?;
+        assert_eq!(buf, b"DB=1");
+
+        Ok(())
+    }
 }
@@ -6,7 +6,12 @@ use std::io::{self, Write};
 use self::{keys::write_keys, sample::write_sample};
 use crate::{Header, variant::record::Samples};
 
-pub(super) fn write_samples<W, S>(writer: &mut W, header: &Header, samples: S) -> io::Result<()>
+pub(super) fn write_samples<W, S>(
+    writer: &mut W,
+    header: &Header,
+    samples: S,
+    omit_leading_genotype_phasing: bool,
+) -> io::Result<()>
 where
     W: Write,
     S: Samples,
@@ -17,7 +22,7 @@ where
 
     for sample in samples.iter() {
         writer.write_all(DELIMITER)?;
-        write_sample(writer, header, sample)?;
+        write_sample(writer, header, sample, omit_leading_genotype_phasing)?;
     }
 
     Ok(())
@@ -39,7 +44,7 @@ mod tests {
             expected: &[u8],
         ) -> io::Result<()> {
             buf.clear();
-            write_samples(buf, header, genotypes)?;
+            write_samples(buf, header, genotypes, false)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -5,7 +5,12 @@ use std::io::{self, Write};
 use self::value::write_value;
 use crate::{Header, io::writer::record::MISSING, variant::record::samples::Sample};
 
-pub(super) fn write_sample<W, S>(writer: &mut W, header: &Header, sample: S) -> io::Result<()>
+pub(super) fn write_sample<W, S>(
+    writer: &mut W,
+    header: &Header,
+    sample: S,
+    omit_leading_genotype_phasing: bool,
+) -> io::Result<()>
 where
     W: Write,
     S: Sample,
@@ -20,7 +25,7 @@ where
         }
 
         match value {
-            Some(v) => write_value(writer, header, &v)?,
+            Some(v) => write_value(writer, header, &v, omit_leading_genotype_phasing)?,
             None => writer.write_all(MISSING)?,
         }
     }
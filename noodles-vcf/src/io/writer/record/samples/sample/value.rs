@@ -12,7 +12,12 @@ use self::{
 };
 use crate::{Header, variant::record::samples::series::Value};
 
-pub(super) fn write_value<W>(writer: &mut W, header: &Header, value: &Value) -> io::Result<()>
+pub(super) fn write_value<W>(
+    writer: &mut W,
+    header: &Header,
+    value: &Value,
+    omit_leading_genotype_phasing: bool,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -21,7 +26,12 @@ where
         Value::Float(n) => write!(writer, "{n}"),
         Value::Character(c) => write_character(writer, *c),
         Value::String(s) => write_string(writer, s),
-        Value::Genotype(genotype) => write_genotype(writer, header, genotype.as_ref()),
+        Value::Genotype(genotype) => write_genotype(
+            writer,
+            header,
+            genotype.as_ref(),
+            omit_leading_genotype_phasing,
+        ),
         Value::Array(array) => write_array(writer, array),
     }
 }
@@ -40,7 +50,7 @@ mod tests {
             expected: &[u8],
         ) -> io::Result<()> {
             buf.clear();
-            write_value(buf, header, &Value::from(value))?;
+            write_value(buf, header, &Value::from(value), false)?;
             assert_eq!(buf, expected);
             Ok(())
         }
@@ -12,12 +12,15 @@ pub(super) fn write_genotype<W>(
     writer: &mut W,
     header: &Header,
     genotype: &dyn Genotype,
+    omit_leading_phasing: bool,
 ) -> io::Result<()>
 where
     W: Write,
 {
     if header.file_format() < VCF_4_4 {
         vcf_4_0_write_genotype(writer, genotype)
+    } else if omit_leading_phasing {
+        vcf_4_4_write_genotype_with_implicit_leading_phasing(writer, genotype)
     } else {
         vcf_4_4_write_genotype(writer, genotype)
     }
@@ -53,6 +56,41 @@ where
     Ok(())
 }
 
+// Omits the phasing indicator of the first allele when it is the same as the one a reader infers,
+// i.e., phased if all other alleles are phased and unphased otherwise.
+fn vcf_4_4_write_genotype_with_implicit_leading_phasing<W>(
+    writer: &mut W,
+    genotype: &dyn Genotype,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    let alleles: Vec<_> = genotype.iter().collect::<io::Result<_>>()?;
+
+    let Some(((first_position, first_phasing), rest)) = alleles.split_first() else {
+        return Ok(());
+    };
+
+    let implicit_phasing = if rest.iter().all(|(_, phasing)| *phasing == Phasing::Phased) {
+        Phasing::Phased
+    } else {
+        Phasing::Unphased
+    };
+
+    if *first_phasing != implicit_phasing {
+        write_phasing(writer, *first_phasing)?;
+    }
+
+    write_position(writer, *first_position)?;
+
+    for &(position, phasing) in rest {
+        write_phasing(writer, phasing)?;
+        write_position(writer, position)?;
+    }
+
+    Ok(())
+}
+
 fn write_phasing<W>(writer: &mut W, phasing: Phasing) -> io::Result<()>
 where
     W: Write,
@@ -82,7 +120,9 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::variant::record_buf::samples::sample::value::genotype::Allele;
+    use crate::variant::record_buf::samples::sample::value::{
+        Genotype as GenotypeBuf, genotype::Allele,
+    };
 
     #[test]
     fn test_vcf_4_0_write_genotype() -> Result<(), Box<dyn std::error::Error>> {
@@ -96,7 +136,7 @@ mod tests {
         let genotype = &[Allele::new(Some(0), Phasing::Phased)]
             .into_iter()
             .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"0");
 
         buf.clear();
@@ -106,7 +146,7 @@ mod tests {
         ]
         .into_iter()
         .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"0/1");
 
         buf.clear();
@@ -116,7 +156,7 @@ mod tests {
         ]
         .into_iter()
         .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"0|1");
 
         buf.clear();
@@ -127,7 +167,7 @@ mod tests {
         ]
         .into_iter()
         .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"0/1|2");
 
         buf.clear();
@@ -137,7 +177,7 @@ mod tests {
         ]
         .into_iter()
         .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"./.");
 
         Ok(())
@@ -155,7 +195,7 @@ mod tests {
         let genotype = &[Allele::new(Some(0), Phasing::Phased)]
             .into_iter()
             .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"|0");
 
         buf.clear();
@@ -165,7 +205,7 @@ mod tests {
         ]
         .into_iter()
         .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"/0/1");
 
         buf.clear();
@@ -175,7 +215,7 @@ mod tests {
         ]
         .into_iter()
         .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"|0|1");
 
         buf.clear();
@@ -186,7 +226,7 @@ mod tests {
         ]
         .into_iter()
         .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"/0/1|2");
 
         buf.clear();
@@ -196,7 +236,7 @@ mod tests {
         ]
         .into_iter()
         .collect();
-        write_genotype(&mut buf, &header, &genotype)?;
+        write_genotype(&mut buf, &header, &genotype, false)?;
         assert_eq!(buf, b"/./.");
 
         Ok(())
@@ -231,4 +271,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_vcf_4_4_write_genotype_with_implicit_leading_phasing()
+    -> Result<(), Box<dyn std::error::Error>> {
+        fn t(header: &Header, genotype: &str, expected: &[u8]) -> io::Result<()> {
+            let genotype: GenotypeBuf = genotype
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+            let mut buf = Vec::new();
+            write_genotype(&mut buf, header, &&genotype, true)?;
+            assert_eq!(buf, expected);
+
+            Ok(())
+        }
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 4))
+            .build();
+
+        // Haploid
+        t(&header, "0", b"0")?;
+        t(&header, "|0", b"0")?;
+        t(&header, "/0", b"/0")?;
+        t(&header, ".", b".")?;
+
+        t(&header, "0|1", b"0|1")?;
+        t(&header, "0/1", b"0/1")?;
+        t(&header, "/0|1", b"/0|1")?;
+        t(&header, "0/1|2", b"0/1|2")?;
+        t(&header, "|0/1|2", b"|0/1|2")?;
+        t(&header, "./.", b"./.")?;
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 3))
+            .build();
+
+        t(&header, "0|1", b"0|1")?;
+
+        Ok(())
+    }
 }
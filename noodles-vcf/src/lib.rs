@@ -20,6 +20,7 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+mod collections;
 pub mod fs;
 pub mod header;
 pub mod io;
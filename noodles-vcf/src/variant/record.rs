@@ -52,7 +52,10 @@ pub trait Record {
     fn variant_span(&self, header: &Header) -> io::Result<usize> {
         let start = self.variant_start().transpose()?.unwrap_or(Position::MIN);
         let end = self.variant_end(header)?;
-        Ok(usize::from(end) - usize::from(start) + 1)
+
+        start
+            .span_to(end)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid variant end"))
     }
 
     /// Resolves the variant end position.
@@ -101,7 +104,7 @@ pub trait Record {
         let start = self.variant_start().transpose()?.unwrap_or(Position::MIN);
 
         start
-            .checked_add(len - 1)
+            .add_length(len)
             .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "position overflow"))
     }
 }
@@ -146,12 +149,34 @@ where
     }
 }
 
-fn info_max_sv_len<I>(header: &Header, info: &I) -> Option<io::Result<Option<usize>>>
+pub(crate) fn info_max_sv_len<I>(header: &Header, info: &I) -> Option<io::Result<Option<usize>>>
+where
+    I: Info,
+{
+    info_max_sv_len_by(header, info, |n| usize::try_from(n).ok())
+}
+
+// Returns the maximum absolute INFO SVLEN value.
+//
+// Before VCF 4.5, SVLEN is negative for deletions.
+pub(crate) fn info_max_abs_sv_len<I>(header: &Header, info: &I) -> Option<io::Result<Option<usize>>>
 where
     I: Info,
+{
+    info_max_sv_len_by(header, info, |n| usize::try_from(n.unsigned_abs()).ok())
+}
+
+fn info_max_sv_len_by<I, F>(header: &Header, info: &I, f: F) -> Option<io::Result<Option<usize>>>
+where
+    I: Info,
+    F: Fn(i32) -> Option<usize>,
 {
     use self::info::field::{Value, key, value::Array};
 
+    fn invalid_sv_len() -> io::Error {
+        io::Error::new(io::ErrorKind::InvalidData, "invalid INFO SVLEN value")
+    }
+
     let value = match info.get(header, key::SV_LENGTHS).transpose() {
         Ok(value) => value??,
         Err(e) => return Some(Err(e)),
@@ -164,14 +189,8 @@ where
             for result in values.iter() {
                 match result {
                     Ok(Some(n)) => {
-                        let len = match usize::try_from(n) {
-                            Ok(len) => len,
-                            Err(_) => {
-                                return Some(Err(io::Error::new(
-                                    io::ErrorKind::InvalidData,
-                                    "invalid INFO SVLEN value",
-                                )));
-                            }
+                        let Some(len) = f(n) else {
+                            return Some(Err(invalid_sv_len()));
                         };
 
                         max_len = max_len.map(|n| n.max(len)).or(Some(len));
@@ -192,7 +211,7 @@ where
     Some(Ok(max_len))
 }
 
-fn samples_max_len<S>(header: &Header, samples: &S) -> Option<io::Result<Option<usize>>>
+pub(crate) fn samples_max_len<S>(header: &Header, samples: &S) -> Option<io::Result<Option<usize>>>
 where
     S: Samples,
 {
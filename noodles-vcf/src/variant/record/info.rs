@@ -16,6 +16,9 @@ pub trait Info {
     fn len(&self) -> usize;
 
     /// Returns the value of the given key.
+    ///
+    /// This returns `None` if the key is absent and `Some(Ok(None))` if its value is missing
+    /// (`.`). A present flag, i.e., a key with no value, is returned as [`Value::Flag`].
     fn get<'a, 'h: 'a>(
         &'a self,
         header: &'h Header,
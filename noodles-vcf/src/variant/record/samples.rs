@@ -6,7 +6,10 @@ pub mod series;
 
 use std::io;
 
-pub use self::{sample::Sample, series::Series};
+pub use self::{
+    sample::{AlleleObservations, Sample},
+    series::Series,
+};
 use crate::Header;
 
 #[allow(clippy::tabs_in_doc_comments)]
@@ -1,5 +1,8 @@
+mod allele_observations;
+
 use std::io;
 
+pub use self::allele_observations::AlleleObservations;
 use super::series::Value;
 use crate::Header;
 
@@ -24,6 +27,45 @@ pub trait Sample {
         &'a self,
         header: &'h Header,
     ) -> Box<dyn Iterator<Item = io::Result<(&'a str, Option<Value<'a>>)>> + 'a>;
+
+    /// Returns the genotype (`GT`), read depth (`DP`), and read depths (`AD`).
+    ///
+    /// Fields that are absent or missing are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::{
+    ///         record::samples::{keys::key, Sample},
+    ///         record_buf::{samples::sample::Value, Samples},
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let samples = Samples::new(
+    ///     [String::from(key::GENOTYPE), String::from(key::READ_DEPTHS)]
+    ///         .into_iter()
+    ///         .collect(),
+    ///     vec![vec![
+    ///         Some(Value::from("0/1")),
+    ///         Some(Value::from(vec![Some(5), Some(8)])),
+    ///     ]],
+    /// );
+    ///
+    /// let sample = samples.get_index(0).unwrap();
+    /// let allele_observations = sample.allele_observations(&header)?;
+    ///
+    /// assert_eq!(allele_observations.genotype(), Some(&"0/1".parse()?));
+    /// assert!(allele_observations.read_depth().is_none());
+    /// assert_eq!(allele_observations.read_depths(), Some(&[Some(5), Some(8)][..]));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    fn allele_observations(&self, header: &Header) -> io::Result<AlleleObservations> {
+        allele_observations::allele_observations(self, header)
+    }
 }
 
 impl Sample for Box<dyn Sample + '_> {
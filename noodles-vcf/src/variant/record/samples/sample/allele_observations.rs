@@ -0,0 +1,183 @@
+use std::io;
+
+use super::Sample;
+use crate::{
+    Header,
+    variant::{
+        record::samples::{
+            keys::key,
+            series::{Value, value::Array},
+        },
+        record_buf::samples::sample::value::Genotype,
+    },
+};
+
+/// The genotype, total read depth (`DP`), and per-allele read depths (`AD`) of a sample.
+///
+/// Each field is `None` if it is absent or missing in the sample.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AlleleObservations {
+    genotype: Option<Genotype>,
+    read_depth: Option<i32>,
+    read_depths: Option<Vec<Option<i32>>>,
+}
+
+impl AlleleObservations {
+    /// Returns the genotype (`GT`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record::samples::AlleleObservations;
+    /// let allele_observations = AlleleObservations::default();
+    /// assert!(allele_observations.genotype().is_none());
+    /// ```
+    pub fn genotype(&self) -> Option<&Genotype> {
+        self.genotype.as_ref()
+    }
+
+    /// Returns the total read depth (`DP`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record::samples::AlleleObservations;
+    /// let allele_observations = AlleleObservations::default();
+    /// assert!(allele_observations.read_depth().is_none());
+    /// ```
+    pub fn read_depth(&self) -> Option<i32> {
+        self.read_depth
+    }
+
+    /// Returns the read depths for each allele (`AD`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record::samples::AlleleObservations;
+    /// let allele_observations = AlleleObservations::default();
+    /// assert!(allele_observations.read_depths().is_none());
+    /// ```
+    pub fn read_depths(&self) -> Option<&[Option<i32>]> {
+        self.read_depths.as_deref()
+    }
+}
+
+pub(super) fn allele_observations<S>(sample: &S, header: &Header) -> io::Result<AlleleObservations>
+where
+    S: Sample + ?Sized,
+{
+    let genotype = match get(sample, header, key::GENOTYPE)? {
+        Some(Value::Genotype(genotype)) => Some(Genotype::try_from(genotype.as_ref())?),
+        Some(Value::String(s)) => Some(
+            s.parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+        ),
+        Some(_) => return Err(invalid_type_error(key::GENOTYPE)),
+        None => None,
+    };
+
+    let read_depth = match get(sample, header, key::READ_DEPTH)? {
+        Some(Value::Integer(n)) => Some(n),
+        Some(Value::Array(Array::Integer(values))) if values.len() == 1 => {
+            values.iter().next().transpose()?.flatten()
+        }
+        Some(_) => return Err(invalid_type_error(key::READ_DEPTH)),
+        None => None,
+    };
+
+    let read_depths = match get(sample, header, key::READ_DEPTHS)? {
+        Some(Value::Integer(n)) => Some(vec![Some(n)]),
+        Some(Value::Array(Array::Integer(values))) => {
+            Some(values.iter().collect::<io::Result<_>>()?)
+        }
+        Some(_) => return Err(invalid_type_error(key::READ_DEPTHS)),
+        None => None,
+    };
+
+    Ok(AlleleObservations {
+        genotype,
+        read_depth,
+        read_depths,
+    })
+}
+
+fn get<'a, S>(sample: &'a S, header: &'a Header, key: &str) -> io::Result<Option<Value<'a>>>
+where
+    S: Sample + ?Sized,
+{
+    sample
+        .get(header, key)
+        .transpose()
+        .map(|value| value.flatten())
+}
+
+fn invalid_type_error(key: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid {key} value type"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variant::record_buf::samples::{Keys, sample::Value as ValueBuf};
+
+    #[test]
+    fn test_allele_observations() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::variant::record_buf::Samples;
+
+        let header = Header::default();
+
+        let keys: Keys = [
+            String::from(key::GENOTYPE),
+            String::from(key::READ_DEPTH),
+            String::from(key::READ_DEPTHS),
+        ]
+        .into_iter()
+        .collect();
+
+        let samples = Samples::new(
+            keys,
+            vec![
+                vec![
+                    Some(ValueBuf::from("0/1")),
+                    Some(ValueBuf::from(13)),
+                    Some(ValueBuf::from(vec![Some(5), Some(8)])),
+                ],
+                vec![Some(ValueBuf::from("./.")), None, None],
+                vec![],
+            ],
+        );
+
+        let actual = allele_observations(&samples.get_index(0).unwrap(), &header)?;
+        assert_eq!(actual.genotype(), Some(&"0/1".parse()?));
+        assert_eq!(actual.read_depth(), Some(13));
+        assert_eq!(actual.read_depths(), Some(&[Some(5), Some(8)][..]));
+
+        let actual = allele_observations(&samples.get_index(1).unwrap(), &header)?;
+        assert_eq!(actual.genotype(), Some(&"./.".parse()?));
+        assert!(actual.read_depth().is_none());
+        assert!(actual.read_depths().is_none());
+
+        let actual = allele_observations(&samples.get_index(2).unwrap(), &header)?;
+        assert_eq!(actual, AlleleObservations::default());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_allele_observations_with_invalid_type() {
+        use crate::variant::record_buf::Samples;
+
+        let header = Header::default();
+        let keys: Keys = [String::from(key::READ_DEPTH)].into_iter().collect();
+        let samples = Samples::new(keys, vec![vec![Some(ValueBuf::from("13"))]]);
+
+        assert!(matches!(
+            allele_observations(&samples.get_index(0).unwrap(), &header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+    }
+}
@@ -2,23 +2,39 @@
 
 mod alternate_bases;
 pub mod builder;
+mod canonicalize;
 mod convert;
+pub(crate) mod fields;
 mod filters;
 pub mod ids;
 pub mod info;
+#[cfg(feature = "fasta")]
+mod normalize;
 pub mod samples;
+mod split_multiallelic;
 
 use std::io;
 
 use noodles_core::Position;
+#[cfg(feature = "fasta")]
+use noodles_fasta as fasta;
 
 pub use self::{
     alternate_bases::AlternateBases, builder::Builder, filters::Filters, ids::Ids, info::Info,
-    samples::Samples,
+    samples::Samples, split_multiallelic::OtherAllelePolicy,
 };
 use crate::Header;
 
 /// A variant record buffer.
+///
+/// # Equality
+///
+/// Records are compared field by field, as represented. IDs, filters, info fields, and sample
+/// keys are compared irrespective of order, but sample values are compared by position, i.e., in
+/// the order of the sample keys. Values are not normalized, e.g., a genotype stored as a string is
+/// not equal to the same genotype stored as a genotype value, and floating-point values use IEEE
+/// 754 equality, i.e., `NaN != NaN`. Use [`Self::canonicalize`] to compare records that may
+/// differ only in representation.
 #[derive(Clone, Debug, PartialEq)]
 pub struct RecordBuf {
     reference_sequence_name: String,
@@ -465,6 +481,277 @@ impl RecordBuf {
     pub fn samples_mut(&mut self) -> &mut Samples {
         &mut self.samples
     }
+
+    /// Removes an info field.
+    ///
+    /// This returns the removed value, if the field is present. Use
+    /// [`Header::remove_info_field`] to remove the field definition from the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::record_buf::info::field::Value};
+    ///
+    /// let mut record = vcf::variant::RecordBuf::builder()
+    ///     .set_info(
+    ///         [(String::from("AF"), Some(Value::from(vec![Some(0.5)])))]
+    ///             .into_iter()
+    ///             .collect(),
+    ///     )
+    ///     .build();
+    ///
+    /// assert!(record.remove_info_field("AF").is_some());
+    /// assert!(record.info().as_ref().is_empty());
+    /// ```
+    pub fn remove_info_field(&mut self, id: &str) -> Option<Option<info::field::Value>> {
+        self.info.as_mut().shift_remove(id)
+    }
+
+    /// Renames an info field.
+    ///
+    /// The field keeps its position. If a field with the new ID already exists, it is replaced.
+    /// This returns whether a field with the old ID was renamed. Use
+    /// [`Header::rename_info_field`] to rename the field definition in the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::record_buf::info::field::Value};
+    ///
+    /// let mut record = vcf::variant::RecordBuf::builder()
+    ///     .set_info(
+    ///         [(String::from("AF"), Some(Value::from(vec![Some(0.5)])))]
+    ///             .into_iter()
+    ///             .collect(),
+    ///     )
+    ///     .build();
+    ///
+    /// assert!(record.rename_info_field("AF", "gnomAD_AF"));
+    /// assert!(record.info().get("gnomAD_AF").is_some());
+    /// ```
+    pub fn rename_info_field(&mut self, old: &str, new: &str) -> bool {
+        crate::collections::rename_key(self.info.as_mut(), old, new)
+    }
+
+    /// Removes a genotype field from the format and every sample.
+    ///
+    /// This returns whether the field was present. Use [`Header::remove_format_field`] to remove
+    /// the field definition from the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::record_buf::{samples::sample::Value, Samples},
+    /// };
+    ///
+    /// let keys = [String::from("GT"), String::from("AD")].into_iter().collect();
+    /// let samples = Samples::new(
+    ///     keys,
+    ///     vec![vec![Some(Value::from("0|1")), Some(Value::from(vec![Some(5), Some(8)]))]],
+    /// );
+    ///
+    /// let mut record = vcf::variant::RecordBuf::builder()
+    ///     .set_samples(samples)
+    ///     .build();
+    ///
+    /// assert!(record.remove_format_field("AD"));
+    /// assert_eq!(record.format().as_ref().len(), 1);
+    /// ```
+    pub fn remove_format_field(&mut self, id: &str) -> bool {
+        let Some((i, _)) = self.samples.keys.as_mut().shift_remove_full(id) else {
+            return false;
+        };
+
+        for values in &mut self.samples.values {
+            if i < values.len() {
+                values.remove(i);
+            }
+        }
+
+        true
+    }
+
+    /// Renames a genotype field.
+    ///
+    /// The field keeps its position in the format. If a field with the new ID already exists, it
+    /// is removed from the format and every sample. This returns whether a field with the old ID
+    /// was renamed. Use [`Header::rename_format_field`] to rename the field definition in the
+    /// header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::record_buf::{samples::sample::Value, Samples},
+    /// };
+    ///
+    /// let keys = [String::from("GT"), String::from("AD")].into_iter().collect();
+    /// let samples = Samples::new(
+    ///     keys,
+    ///     vec![vec![Some(Value::from("0|1")), Some(Value::from(vec![Some(5), Some(8)]))]],
+    /// );
+    ///
+    /// let mut record = vcf::variant::RecordBuf::builder()
+    ///     .set_samples(samples)
+    ///     .build();
+    ///
+    /// assert!(record.rename_format_field("AD", "XD"));
+    /// assert_eq!(record.format().as_ref().get_index_of("XD"), Some(1));
+    /// ```
+    pub fn rename_format_field(&mut self, old: &str, new: &str) -> bool {
+        if !self.samples.keys.as_ref().contains(old) {
+            return false;
+        } else if old == new {
+            return true;
+        }
+
+        self.remove_format_field(new);
+
+        crate::collections::rename_value(self.samples.keys.as_mut(), old, new)
+    }
+
+    /// Returns a normalized copy of the record.
+    ///
+    /// This trims bases common to the ends of all alleles (parsimony) and left-aligns indels using
+    /// the reference sequence, adjusting the variant start. Alleles stay padded with a preceding
+    /// base, or at the start of the reference sequence, a following base.
+    ///
+    /// An existing INFO `END` field is updated to the position of the last normalized reference
+    /// base.
+    ///
+    /// Records with symbolic alleles, breakends, overlapping deletions (`*`), or no alternate
+    /// bases are returned unchanged. This returns an error if the reference sequence is missing
+    /// or the reference bases do not match it.
+    ///
+    /// This requires the `fasta` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_fasta as fasta;
+    /// use noodles_vcf::{self as vcf, variant::record_buf::AlternateBases};
+    ///
+    /// let repository = fasta::Repository::new(vec![fasta::Record::new(
+    ///     fasta::record::Definition::new("sq0", None),
+    ///     fasta::record::Sequence::from(b"ACAGCAGTT".to_vec()),
+    /// )]);
+    ///
+    /// let record = vcf::variant::RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_variant_start(Position::try_from(4)?)
+    ///     .set_reference_bases("GCAG")
+    ///     .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+    ///     .build();
+    ///
+    /// let normalized_record = record.normalize(&repository)?;
+    ///
+    /// assert_eq!(normalized_record.variant_start(), Some(Position::try_from(1)?));
+    /// assert_eq!(normalized_record.reference_bases(), "ACAG");
+    /// assert_eq!(normalized_record.alternate_bases().as_ref(), [String::from("A")]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    #[cfg(feature = "fasta")]
+    pub fn normalize(&self, reference_sequence_repository: &fasta::Repository) -> io::Result<Self> {
+        normalize::normalize(self, reference_sequence_repository)
+    }
+
+    /// Splits a multiallelic record into biallelic records, one for each alternate allele.
+    ///
+    /// Info and sample values with `Number=A`, `Number=R`, or `Number=G` are subset to the
+    /// reference allele and the kept alternate allele. Info values with `Number=G` are assumed
+    /// to be diploid, and sample values, of the ploidy of the sample genotype (`GT`). Genotype
+    /// alleles are recoded so the kept alternate allele is `1`, and other alternate alleles are
+    /// recoded using the given policy. All other fields are copied as is.
+    ///
+    /// A record with fewer than two alternate alleles is returned as is. This returns an error
+    /// if a value to subset has an unexpected number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::record_buf::{AlternateBases, OtherAllelePolicy},
+    /// };
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let record = vcf::variant::RecordBuf::builder()
+    ///     .set_reference_bases("A")
+    ///     .set_alternate_bases(AlternateBases::from(vec![String::from("C"), String::from("G")]))
+    ///     .build();
+    ///
+    /// let records = record.split_multiallelic(&header, OtherAllelePolicy::Reference)?;
+    ///
+    /// assert_eq!(records.len(), 2);
+    /// assert_eq!(records[0].alternate_bases().as_ref(), [String::from("C")]);
+    /// assert_eq!(records[1].alternate_bases().as_ref(), [String::from("G")]);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn split_multiallelic(
+        &self,
+        header: &Header,
+        other_allele_policy: OtherAllelePolicy,
+    ) -> io::Result<Vec<Self>> {
+        split_multiallelic::split_multiallelic(self, header, other_allele_policy)
+    }
+
+    /// Returns a canonical form of the record for comparison.
+    ///
+    /// Records from different sources can differ in representation but not content. This
+    /// removes such differences, so that canonical records can be compared using `==`.
+    ///
+    ///   * Filters and info fields are ordered as defined in the header, followed by undefined
+    ///     keys in lexicographical order.
+    ///   * Info values that are arrays of only missing values become missing.
+    ///   * Sample keys are ordered with the genotype (`GT`) first, followed by the order of the
+    ///     header and then undefined keys in lexicographical order. Sample values are reordered
+    ///     to match, and omitted trailing values are filled in as missing.
+    ///   * Sample values that are arrays of only missing values become missing. Genotypes stored
+    ///     as strings are parsed, and genotypes of only missing alleles become unphased.
+    ///   * Sample keys with values missing in all samples are removed.
+    ///
+    /// All other fields are copied as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, variant::record_buf::info::field::Value};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let a = vcf::variant::RecordBuf::builder()
+    ///     .set_info(
+    ///         [
+    ///             (String::from("NS"), Some(Value::from(2))),
+    ///             (String::from("AF"), Some(Value::from(vec![None::<f32>]))),
+    ///         ]
+    ///         .into_iter()
+    ///         .collect(),
+    ///     )
+    ///     .build();
+    ///
+    /// let b = vcf::variant::RecordBuf::builder()
+    ///     .set_info(
+    ///         [
+    ///             (String::from("AF"), None),
+    ///             (String::from("NS"), Some(Value::from(2))),
+    ///         ]
+    ///         .into_iter()
+    ///         .collect(),
+    ///     )
+    ///     .build();
+    ///
+    /// assert_ne!(a, b);
+    /// assert_eq!(a.canonicalize(&header), b.canonicalize(&header));
+    /// ```
+    pub fn canonicalize(&self, header: &Header) -> Self {
+        canonicalize::canonicalize(self, header)
+    }
 }
 
 impl Default for RecordBuf {
@@ -539,4 +826,97 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_remove_info_field() {
+        use crate::header::record::value::{Map, map::Info as InfoMap};
+
+        let mut header = Header::builder()
+            .add_info("NS", Map::<InfoMap>::from("NS"))
+            .add_info("AF", Map::<InfoMap>::from("AF"))
+            .build();
+
+        let mut record = RecordBuf::builder()
+            .set_info(
+                [
+                    (String::from("NS"), Some(info::field::Value::from(2))),
+                    (
+                        String::from("AF"),
+                        Some(info::field::Value::from(vec![Some(0.5)])),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        assert!(header.remove_info_field("AF").is_some());
+        assert!(record.remove_info_field("AF").is_some());
+
+        assert!(header.infos().keys().eq(["NS"]));
+        assert!(record.info().keys().eq(["NS"]));
+
+        assert!(header.remove_info_field("AF").is_none());
+        assert!(record.remove_info_field("AF").is_none());
+    }
+
+    #[test]
+    fn test_rename_info_field() {
+        let mut record = RecordBuf::builder()
+            .set_info(
+                [
+                    (String::from("AF"), None),
+                    (String::from("NS"), Some(info::field::Value::from(2))),
+                    (String::from("DP"), Some(info::field::Value::from(13))),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        assert!(record.rename_info_field("NS", "DP"));
+        assert!(record.info().keys().eq(["AF", "DP"]));
+        assert_eq!(
+            record.info().get("DP"),
+            Some(Some(&info::field::Value::from(2)))
+        );
+
+        assert!(!record.rename_info_field("NS", "XX"));
+    }
+
+    #[test]
+    fn test_remove_and_rename_format_field() {
+        use self::samples::sample::Value;
+
+        let mut record = RecordBuf::builder()
+            .set_samples(Samples::new(
+                [String::from("GT"), String::from("DP"), String::from("AD")]
+                    .into_iter()
+                    .collect(),
+                vec![
+                    vec![
+                        Some(Value::from("0|1")),
+                        Some(Value::from(13)),
+                        Some(Value::from(vec![Some(5), Some(8)])),
+                    ],
+                    vec![Some(Value::from("0/0")), Some(Value::from(8))],
+                ],
+            ))
+            .build();
+
+        assert!(record.remove_format_field("DP"));
+        assert!(!record.remove_format_field("DP"));
+
+        assert!(record.rename_format_field("GT", "AD"));
+
+        let expected = Samples::new(
+            [String::from("AD")].into_iter().collect(),
+            vec![
+                vec![Some(Value::from("0|1"))],
+                vec![Some(Value::from("0/0"))],
+            ],
+        );
+
+        assert_eq!(record.samples(), &expected);
+    }
 }
@@ -0,0 +1,269 @@
+use std::cmp::Ordering;
+
+use super::{
+    Filters, Info, RecordBuf, Samples,
+    fields::{info_array_value_counts, sample_array_value_counts},
+    info::field::Value as InfoValue,
+    samples::{
+        Keys,
+        sample::{Value, value::Genotype},
+    },
+};
+use crate::{Header, variant::record::samples::keys::key};
+
+pub(super) fn canonicalize(record: &RecordBuf, header: &Header) -> RecordBuf {
+    let mut canonical_record = record.clone();
+
+    canonicalize_filters(header, canonical_record.filters_mut());
+    canonicalize_info(header, canonical_record.info_mut());
+    *canonical_record.samples_mut() = canonicalize_samples(header, record.samples());
+
+    canonical_record
+}
+
+fn canonicalize_filters(header: &Header, filters: &mut Filters) {
+    let ids = header.filters();
+
+    filters
+        .as_mut()
+        .sort_by(|a, b| compare_keys(ids.get_index_of(a), a, ids.get_index_of(b), b));
+}
+
+fn canonicalize_info(header: &Header, info: &mut Info) {
+    let fields = info.as_mut();
+
+    for value in fields.values_mut() {
+        if matches!(value, Some(InfoValue::Array(array)) if is_missing(info_array_value_counts(array)))
+        {
+            *value = None;
+        }
+    }
+
+    let infos = header.infos();
+
+    fields.sort_by(|a, _, b, _| compare_keys(infos.get_index_of(a), a, infos.get_index_of(b), b));
+}
+
+fn canonicalize_samples(header: &Header, samples: &Samples) -> Samples {
+    let keys = samples.keys().as_ref();
+    let formats = header.formats();
+
+    let format_index = |key: &str| {
+        if key == key::GENOTYPE {
+            Some(0)
+        } else {
+            formats.get_index_of(key).map(|i| i + 1)
+        }
+    };
+
+    let mut indices: Vec<_> = (0..keys.len()).collect();
+
+    indices.sort_by(|&i, &j| {
+        let (a, b) = (&keys[i], &keys[j]);
+        compare_keys(format_index(a), a, format_index(b), b)
+    });
+
+    let values: Vec<Vec<_>> = samples
+        .values()
+        .map(|sample| {
+            indices
+                .iter()
+                .map(|&i| {
+                    sample
+                        .values()
+                        .get(i)
+                        .cloned()
+                        .flatten()
+                        .and_then(|value| canonicalize_sample_value(&keys[i], value))
+                })
+                .collect()
+        })
+        .collect();
+
+    // Keys with values missing in all samples are dropped.
+    let is_present: Vec<_> = (0..indices.len())
+        .map(|j| {
+            values
+                .iter()
+                .any(|sample_values| sample_values[j].is_some())
+        })
+        .collect();
+
+    let canonical_keys: Keys = indices
+        .iter()
+        .zip(&is_present)
+        .filter(|(_, is_present)| **is_present)
+        .map(|(&i, _)| keys[i].clone())
+        .collect();
+
+    let canonical_values = values
+        .into_iter()
+        .map(|sample_values| {
+            sample_values
+                .into_iter()
+                .zip(&is_present)
+                .filter(|(_, is_present)| **is_present)
+                .map(|(value, _)| value)
+                .collect()
+        })
+        .collect();
+
+    Samples::new(canonical_keys, canonical_values)
+}
+
+fn canonicalize_sample_value(key: &str, value: Value) -> Option<Value> {
+    match value {
+        Value::String(s) if key == key::GENOTYPE => match s.parse() {
+            Ok(genotype) => Some(Value::Genotype(canonicalize_genotype(genotype))),
+            Err(_) => Some(Value::String(s)),
+        },
+        Value::Genotype(genotype) => Some(Value::Genotype(canonicalize_genotype(genotype))),
+        Value::Array(array) if is_missing(sample_array_value_counts(&array)) => None,
+        value => Some(value),
+    }
+}
+
+// The phasing of a genotype with only missing alleles is not meaningful.
+fn canonicalize_genotype(genotype: Genotype) -> Genotype {
+    if genotype
+        .as_ref()
+        .iter()
+        .all(|allele| allele.position().is_none())
+    {
+        genotype.to_unphased()
+    } else {
+        genotype
+    }
+}
+
+fn is_missing((value_count, missing_count): (usize, usize)) -> bool {
+    missing_count == value_count
+}
+
+// Orders keys defined in the header by their header index, followed by undefined keys in
+// lexicographical order.
+fn compare_keys(a_index: Option<usize>, a: &str, b_index: Option<usize>, b: &str) -> Ordering {
+    match (a_index, b_index) {
+        (Some(i), Some(j)) => i.cmp(&j),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => a.cmp(b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::header::record::value::{
+        Map,
+        map::{Filter, Format, Info as InfoMap},
+    };
+
+    #[test]
+    fn test_canonicalize() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_filter("q10", Map::<Filter>::new("Quality below 10"))
+            .add_filter(
+                "s50",
+                Map::<Filter>::new("Less than 50% of samples have data"),
+            )
+            .add_info("NS", Map::<InfoMap>::from("NS"))
+            .add_info("AF", Map::<InfoMap>::from("AF"))
+            .add_format("DP", Map::<Format>::from("DP"))
+            .add_format("AD", Map::<Format>::from("AD"))
+            .add_format(key::GENOTYPE, Map::<Format>::from(key::GENOTYPE))
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_filters(["zz", "s50", "q10"].into_iter().map(String::from).collect())
+            .set_info(
+                [
+                    (String::from("AF"), Some(InfoValue::from(vec![None::<f32>]))),
+                    (String::from("XX"), Some(InfoValue::Flag)),
+                    (String::from("NS"), Some(InfoValue::from(2))),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(
+                [
+                    String::from("AD"),
+                    String::from("XX"),
+                    String::from(key::GENOTYPE),
+                    String::from("DP"),
+                ]
+                .into_iter()
+                .collect(),
+                vec![
+                    vec![
+                        Some(Value::from(vec![Some(5), Some(8)])),
+                        None,
+                        Some(Value::from("0|1")),
+                        Some(Value::from(13)),
+                    ],
+                    vec![
+                        Some(Value::from(vec![None::<i32>, None])),
+                        None,
+                        Some(Value::from(".|.")),
+                    ],
+                ],
+            ))
+            .build();
+
+        let expected = RecordBuf::builder()
+            .set_filters(["q10", "s50", "zz"].into_iter().map(String::from).collect())
+            .set_info(
+                [
+                    (String::from("NS"), Some(InfoValue::from(2))),
+                    (String::from("AF"), None),
+                    (String::from("XX"), Some(InfoValue::Flag)),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(
+                [
+                    String::from(key::GENOTYPE),
+                    String::from("DP"),
+                    String::from("AD"),
+                ]
+                .into_iter()
+                .collect(),
+                vec![
+                    vec![
+                        Some(Value::Genotype("0|1".parse()?)),
+                        Some(Value::from(13)),
+                        Some(Value::from(vec![Some(5), Some(8)])),
+                    ],
+                    vec![Some(Value::Genotype("./.".parse()?)), None, None],
+                ],
+            ))
+            .build();
+
+        let actual = canonicalize(&record, &header);
+
+        assert_eq!(actual, expected);
+        assert!(
+            actual
+                .filters()
+                .as_ref()
+                .iter()
+                .eq(expected.filters().as_ref())
+        );
+        assert!(actual.info().keys().eq(expected.info().keys()));
+        assert!(
+            actual
+                .samples()
+                .keys()
+                .as_ref()
+                .iter()
+                .eq(expected.samples().keys().as_ref())
+        );
+
+        assert_eq!(canonicalize(&actual, &header), actual);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    Header,
+    header::record::value::map::{format, info},
+    variant::record_buf::{
+        info::field::value::Array as InfoArray, samples::sample::value::Array as SampleArray,
+    },
+};
+
+// Returns the number of values of an info field from its header record or, if undeclared, its
+// reserved definition.
+pub(crate) fn info_number(header: &Header, key: &str) -> Option<info::Number> {
+    use crate::header::record::value::map::info::definition::definition;
+
+    header
+        .infos()
+        .get(key)
+        .map(|info| info.number())
+        .or_else(|| definition(header.file_format(), key).map(|(n, _, _)| n))
+}
+
+// Returns the number of values of a sample field from its header record or, if undeclared, its
+// reserved definition.
+pub(crate) fn format_number(header: &Header, key: &str) -> Option<format::Number> {
+    use crate::header::record::value::map::format::definition::definition;
+
+    header
+        .formats()
+        .get(key)
+        .map(|format| format.number())
+        .or_else(|| definition(header.file_format(), key).map(|(n, _, _)| n))
+}
+
+// Returns the number of values and the number of missing values in an info field array.
+pub(crate) fn info_array_value_counts(array: &InfoArray) -> (usize, usize) {
+    match array {
+        InfoArray::Integer(values) => value_counts(values),
+        InfoArray::Float(values) => value_counts(values),
+        InfoArray::Character(values) => value_counts(values),
+        InfoArray::String(values) => value_counts(values),
+    }
+}
+
+// Returns the number of values and the number of missing values in a sample field array.
+pub(crate) fn sample_array_value_counts(array: &SampleArray) -> (usize, usize) {
+    match array {
+        SampleArray::Integer(values) => value_counts(values),
+        SampleArray::Float(values) => value_counts(values),
+        SampleArray::Character(values) => value_counts(values),
+        SampleArray::String(values) => value_counts(values),
+    }
+}
+
+// Returns the number of possible unordered genotypes, i.e., C(ploidy + allele_count - 1,
+// allele_count - 1), or `None` if it overflows.
+pub(crate) fn genotype_count(ploidy: usize, allele_count: usize) -> Option<usize> {
+    let n = ploidy.checked_add(allele_count)?.checked_sub(1)?;
+    let k = allele_count.checked_sub(1)?;
+    binomial(n, k)
+}
+
+// Returns the binomial coefficient C(n, k), or `None` if it overflows.
+pub(crate) fn binomial(n: usize, k: usize) -> Option<usize> {
+    if k > n {
+        return Some(0);
+    }
+
+    let k = k.min(n - k);
+    (1..=k).try_fold(1usize, |c, i| c.checked_mul(n - k + i).map(|m| m / i))
+}
+
+fn value_counts<T>(values: &[Option<T>]) -> (usize, usize) {
+    let missing_count = values.iter().filter(|value| value.is_none()).count();
+    (values.len(), missing_count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_genotype_count() {
+        assert_eq!(genotype_count(2, 1), Some(1));
+        assert_eq!(genotype_count(2, 2), Some(3));
+        assert_eq!(genotype_count(2, 3), Some(6));
+        assert_eq!(genotype_count(1, 3), Some(3));
+        assert_eq!(genotype_count(3, 2), Some(4));
+        assert_eq!(genotype_count(0, 2), Some(1));
+        assert_eq!(genotype_count(2, 0), None);
+        assert_eq!(genotype_count(usize::MAX, 2), None);
+    }
+
+    #[test]
+    fn test_binomial() {
+        assert_eq!(binomial(4, 2), Some(6));
+        assert_eq!(binomial(4, 0), Some(1));
+        assert_eq!(binomial(2, 3), Some(0));
+        assert_eq!(binomial(128, 64), None);
+    }
+}
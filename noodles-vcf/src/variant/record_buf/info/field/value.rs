@@ -13,7 +13,10 @@ pub enum Value {
     Integer(i32),
     /// A single-precision floating-point.
     Float(f32),
-    /// A boolean.
+    /// A flag (`Number=0`, `Type=Flag`).
+    ///
+    /// A flag is present when its key appears in the INFO field without a value, e.g., `SOMATIC`.
+    /// An absent flag has no field.
     Flag,
     /// A character.
     Character(char),
@@ -0,0 +1,476 @@
+use std::io;
+
+use noodles_core::Position;
+use noodles_fasta as fasta;
+
+use super::{AlternateBases, RecordBuf};
+
+pub(super) fn normalize(
+    record: &RecordBuf,
+    reference_sequence_repository: &fasta::Repository,
+) -> io::Result<RecordBuf> {
+    let mut normalized_record = record.clone();
+
+    let Some(variant_start) = record.variant_start() else {
+        return Ok(normalized_record);
+    };
+
+    let reference_bases = record.reference_bases();
+    let alternate_bases = record.alternate_bases().as_ref();
+
+    if alternate_bases.is_empty()
+        || !is_sequence(reference_bases)
+        || !alternate_bases.iter().all(|allele| is_sequence(allele))
+    {
+        return Ok(normalized_record);
+    }
+
+    // A record with only equal alleles cannot be normalized and would otherwise be shifted to the
+    // start of the reference sequence.
+    if alternate_bases
+        .iter()
+        .all(|allele| allele.eq_ignore_ascii_case(reference_bases))
+    {
+        return Ok(normalized_record);
+    }
+
+    let name = record.reference_sequence_name();
+
+    let reference_sequence = reference_sequence_repository
+        .get(name.as_bytes())
+        .transpose()?
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("missing reference sequence: {name}"),
+            )
+        })?;
+
+    validate_reference_bases(&reference_sequence, variant_start, reference_bases)
+        .map_err(|e| io::Error::new(e.kind(), format!("{name}:{variant_start}: {e}")))?;
+
+    let mut alleles: Vec<Vec<u8>> = [reference_bases]
+        .into_iter()
+        .chain(alternate_bases.iter().map(|allele| allele.as_str()))
+        .map(|allele| allele.as_bytes().to_vec())
+        .collect();
+
+    let mut position = usize::from(variant_start);
+
+    loop {
+        let mut is_changed = false;
+
+        // At the start of the reference sequence, there is no preceding base to pad with, so the
+        // alleles are only trimmed while they remain nonempty.
+        if have_equal_last_bases(&alleles)
+            && (position > 1 || alleles.iter().all(|allele| allele.len() > 1))
+        {
+            for allele in &mut alleles {
+                allele.pop();
+            }
+
+            is_changed = true;
+        }
+
+        if alleles.iter().any(|allele| allele.is_empty()) {
+            position -= 1;
+
+            let base = get_reference_base(&reference_sequence, position)?;
+
+            for allele in &mut alleles {
+                allele.insert(0, base);
+            }
+
+            is_changed = true;
+        }
+
+        if !is_changed {
+            break;
+        }
+    }
+
+    while alleles.iter().all(|allele| allele.len() > 1) && have_equal_first_bases(&alleles) {
+        for allele in &mut alleles {
+            allele.remove(0);
+        }
+
+        position += 1;
+    }
+
+    let mut alleles = alleles.into_iter().map(|allele| {
+        String::from_utf8(allele).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    });
+
+    if let Some(reference_bases) = alleles.next() {
+        *normalized_record.reference_bases_mut() = reference_bases?;
+    }
+
+    *normalized_record.alternate_bases_mut() = alleles
+        .collect::<io::Result<Vec<_>>>()
+        .map(AlternateBases::from)?;
+
+    *normalized_record.variant_start_mut() = Position::new(position);
+
+    update_end(&mut normalized_record, position)?;
+
+    Ok(normalized_record)
+}
+
+// Sets an existing INFO END field to the position of the last normalized reference base.
+fn update_end(record: &mut RecordBuf, position: usize) -> io::Result<()> {
+    use crate::variant::{record::info::field::key, record_buf::info::field::Value};
+
+    let end = position + record.reference_bases().len() - 1;
+
+    if let Some(value) = record.info_mut().get_mut(key::END_POSITION) {
+        let n = i32::try_from(end).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        *value = Some(Value::from(n));
+    }
+
+    Ok(())
+}
+
+// Symbolic alleles, breakends, overlapping deletions (`*`), and missing alleles (`.`) are not
+// sequences.
+fn is_sequence(allele: &str) -> bool {
+    !allele.is_empty() && allele.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn validate_reference_bases(
+    reference_sequence: &fasta::record::Sequence,
+    variant_start: Position,
+    reference_bases: &str,
+) -> io::Result<()> {
+    for (i, base) in reference_bases.bytes().enumerate() {
+        let position = usize::from(variant_start) + i;
+        let reference_base = get_reference_base(reference_sequence, position)?;
+
+        if !base.eq_ignore_ascii_case(&reference_base) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "reference bases do not match the reference sequence",
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn get_reference_base(
+    reference_sequence: &fasta::record::Sequence,
+    position: usize,
+) -> io::Result<u8> {
+    Position::new(position)
+        .and_then(|position| reference_sequence.get(position))
+        .map(|base| base.to_ascii_uppercase())
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("position out of range of the reference sequence: {position}"),
+            )
+        })
+}
+
+fn have_equal_last_bases(alleles: &[Vec<u8>]) -> bool {
+    have_equal_bases(alleles.iter().map(|allele| allele.last()))
+}
+
+fn have_equal_first_bases(alleles: &[Vec<u8>]) -> bool {
+    have_equal_bases(alleles.iter().map(|allele| allele.first()))
+}
+
+fn have_equal_bases<'a, I>(mut bases: I) -> bool
+where
+    I: Iterator<Item = Option<&'a u8>>,
+{
+    let Some(Some(first)) = bases.next() else {
+        return false;
+    };
+
+    bases.all(|base| base.is_some_and(|b| b.eq_ignore_ascii_case(first)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_repository() -> fasta::Repository {
+        fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            // 1-based positions:       1234567890123
+            fasta::record::Sequence::from(b"ACAGCAGCAGTTT".to_vec()),
+        )])
+    }
+
+    #[test]
+    fn test_normalize() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+
+        // SNV
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(4)?)
+            .set_reference_bases("G")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, record);
+
+        // parsimony (trailing and leading bases)
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(3)?)
+            .set_reference_bases("AGC")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("ATC")]))
+            .build();
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(4)?)
+            .set_reference_bases("G")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, expected);
+
+        // deletion in a repeat
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(5)?)
+            .set_reference_bases("CAGC")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+            .build();
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("ACAG")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, expected);
+
+        // insertion in a repeat
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(10)?)
+            .set_reference_bases("G")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("GCAG")]))
+            .build();
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("ACAG")]))
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, expected);
+
+        // insertion at a homopolymer run at the end of the reference sequence
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(13)?)
+            .set_reference_bases("T")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("TT")]))
+            .build();
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(10)?)
+            .set_reference_bases("G")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("GT")]))
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, expected);
+
+        // multiallelic
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(7)?)
+            .set_reference_bases("GCAG")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("G"),
+                String::from("GCAGCAG"),
+                String::from("TCAG"),
+            ]))
+            .build();
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(4)?)
+            .set_reference_bases("GCAG")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("G"),
+                String::from("GCAGCAG"),
+                String::from("GCAT"),
+            ]))
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, expected);
+
+        // symbolic alleles
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("CAGC")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("C"),
+                String::from("<DEL>"),
+            ]))
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, record);
+
+        // missing alternate bases
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(4)?)
+            .set_reference_bases("G")
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_at_reference_sequence_start() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+
+        // deletion of the first base
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("AC")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, record);
+
+        // padded with a trailing base
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("ACA")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("AA")]))
+            .build();
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("AC")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_with_end() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::variant::{record::info::field::key, record_buf::info::field::Value};
+
+        let repository = build_repository();
+
+        // deletion in a repeat
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(5)?)
+            .set_reference_bases("CAGC")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+            .set_info(
+                [(String::from(key::END_POSITION), Some(Value::from(8)))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("ACAG")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("A")]))
+            .set_info(
+                [(String::from(key::END_POSITION), Some(Value::from(4)))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, expected);
+
+        // parsimony
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(3)?)
+            .set_reference_bases("AGC")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("ATC")]))
+            .set_info(
+                [(String::from(key::END_POSITION), Some(Value::from(5)))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        let expected = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(4)?)
+            .set_reference_bases("G")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .set_info(
+                [(String::from(key::END_POSITION), Some(Value::from(4)))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+
+        assert_eq!(normalize(&record, &repository)?, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_with_invalid_input() -> Result<(), Box<dyn std::error::Error>> {
+        let repository = build_repository();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(4)?)
+            .set_reference_bases("T")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("G")]))
+            .build();
+
+        assert!(matches!(
+            normalize(&record, &repository),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(13)?)
+            .set_reference_bases("TT")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .build();
+
+        assert!(matches!(
+            normalize(&record, &repository),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq1")
+            .set_variant_start(Position::try_from(4)?)
+            .set_reference_bases("G")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("T")]))
+            .build();
+
+        assert!(matches!(
+            normalize(&record, &repository),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+}
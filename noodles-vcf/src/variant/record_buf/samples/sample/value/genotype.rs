@@ -12,6 +12,55 @@ use std::{io, str::FromStr};
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Genotype(Vec<Allele>);
 
+impl Genotype {
+    /// Returns a copy of the genotype with all alleles unphased.
+    ///
+    /// The allele order is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "1|0".parse()?;
+    /// assert_eq!(genotype.to_unphased(), "1/0".parse()?);
+    /// # Ok::<_, noodles_vcf::variant::record_buf::samples::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn to_unphased(&self) -> Self {
+        self.0
+            .iter()
+            .map(|allele| Allele::new(allele.position(), Phasing::Unphased))
+            .collect()
+    }
+
+    /// Returns a copy of the genotype with all alleles unphased and sorted by position.
+    ///
+    /// This gives a canonical unphased form, e.g., both `1|0` and `0|1` become `0/1`. Missing
+    /// alleles (`.`) are ordered before all other alleles.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "1|0".parse()?;
+    /// assert_eq!(genotype.to_sorted_unphased(), "0/1".parse()?);
+    ///
+    /// let genotype: Genotype = "1|.".parse()?;
+    /// assert_eq!(genotype.to_sorted_unphased(), "./1".parse()?);
+    /// # Ok::<_, noodles_vcf::variant::record_buf::samples::sample::value::genotype::ParseError>(())
+    /// ```
+    pub fn to_sorted_unphased(&self) -> Self {
+        let mut positions: Vec<_> = self.0.iter().map(|allele| allele.position()).collect();
+        positions.sort_unstable();
+
+        positions
+            .into_iter()
+            .map(|position| Allele::new(position, Phasing::Unphased))
+            .collect()
+    }
+}
+
 impl AsRef<[Allele]> for Genotype {
     fn as_ref(&self) -> &[Allele] {
         &self.0
@@ -137,4 +186,38 @@ mod tests {
             Err(ParseError::InvalidAllele(_))
         ));
     }
+
+    #[test]
+    fn test_to_unphased() -> Result<(), ParseError> {
+        let genotype: Genotype = "1|0".parse()?;
+        assert_eq!(genotype.to_unphased(), "1/0".parse()?);
+
+        let genotype: Genotype = "0|1/2".parse()?;
+        assert_eq!(genotype.to_unphased(), "0/1/2".parse()?);
+
+        let genotype: Genotype = "1".parse()?;
+        assert_eq!(
+            genotype.to_unphased(),
+            Genotype(vec![Allele::new(Some(1), Phasing::Unphased)])
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_sorted_unphased() -> Result<(), ParseError> {
+        let expected: Genotype = "0/1".parse()?;
+        assert_eq!("1|0".parse::<Genotype>()?.to_sorted_unphased(), expected);
+        assert_eq!("0|1".parse::<Genotype>()?.to_sorted_unphased(), expected);
+        assert_eq!("1/0".parse::<Genotype>()?.to_sorted_unphased(), expected);
+
+        let expected: Genotype = "./1".parse()?;
+        assert_eq!("./1".parse::<Genotype>()?.to_sorted_unphased(), expected);
+        assert_eq!("1|.".parse::<Genotype>()?.to_sorted_unphased(), expected);
+
+        let genotype: Genotype = "2|.|0".parse()?;
+        assert_eq!(genotype.to_sorted_unphased(), "./0/2".parse()?);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,358 @@
+use std::io;
+
+use super::{
+    AlternateBases, RecordBuf,
+    fields::{binomial, format_number, genotype_count, info_number},
+    info::field::{Value as InfoValue, value::Array as InfoArray},
+    samples::sample::{
+        Value,
+        value::{Array, Genotype},
+    },
+};
+use crate::{
+    Header,
+    header::record::value::map::{format, info},
+    variant::record::samples::keys::key,
+};
+
+// The ploidy assumed for info fields with `Number=G`.
+const INFO_PLOIDY: usize = 2;
+
+/// How genotype alleles other than the reference and kept alternate allele are recoded when
+/// splitting a multiallelic record.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum OtherAllelePolicy {
+    /// Other alleles become the reference allele (`0`).
+    #[default]
+    Reference,
+    /// Other alleles become missing (`.`).
+    Missing,
+}
+
+pub(super) fn split_multiallelic(
+    record: &RecordBuf,
+    header: &Header,
+    other_allele_policy: OtherAllelePolicy,
+) -> io::Result<Vec<RecordBuf>> {
+    let alternate_bases = record.alternate_bases().as_ref();
+
+    if alternate_bases.len() < 2 {
+        return Ok(vec![record.clone()]);
+    }
+
+    let allele_count = alternate_bases.len() + 1;
+
+    (1..allele_count)
+        .map(|kept_allele| {
+            split_allele(
+                record,
+                header,
+                allele_count,
+                kept_allele,
+                other_allele_policy,
+            )
+        })
+        .collect()
+}
+
+fn split_allele(
+    record: &RecordBuf,
+    header: &Header,
+    allele_count: usize,
+    kept_allele: usize,
+    other_allele_policy: OtherAllelePolicy,
+) -> io::Result<RecordBuf> {
+    let mut split_record = record.clone();
+
+    let alternate_base = record.alternate_bases().as_ref()[kept_allele - 1].clone();
+    *split_record.alternate_bases_mut() = AlternateBases::from(vec![alternate_base]);
+
+    for (key, value) in split_record.info_mut().as_mut() {
+        let Some(value) = value else {
+            continue;
+        };
+
+        let Some(selection) = info_number(header, key).and_then(|number| match number {
+            info::Number::AlternateBases => Some(Ok((vec![kept_allele - 1], allele_count - 1))),
+            info::Number::ReferenceAlternateBases => Some(Ok((vec![0, kept_allele], allele_count))),
+            info::Number::Samples => {
+                Some(genotype_selection(INFO_PLOIDY, allele_count, kept_allele))
+            }
+            _ => None,
+        }) else {
+            continue;
+        };
+
+        let (indices, expected_len) = selection?;
+        *value = select_info_values(key, value, &indices, expected_len)?;
+    }
+
+    let samples = split_record.samples_mut();
+    let keys = samples.keys.clone();
+
+    for sample_values in &mut samples.values {
+        let ploidy = keys
+            .as_ref()
+            .iter()
+            .zip(sample_values.iter())
+            .find_map(|(key, value)| match value {
+                Some(Value::Genotype(genotype)) if key == key::GENOTYPE => {
+                    Some(genotype.as_ref().len())
+                }
+                _ => None,
+            })
+            .unwrap_or(INFO_PLOIDY);
+
+        for (key, value) in keys.as_ref().iter().zip(sample_values.iter_mut()) {
+            let Some(value) = value else {
+                continue;
+            };
+
+            if let Value::Genotype(genotype) = value {
+                *genotype = recode_genotype(genotype, kept_allele, other_allele_policy);
+                continue;
+            }
+
+            let Some(selection) = format_number(header, key).and_then(|number| match number {
+                format::Number::AlternateBases => {
+                    Some(Ok((vec![kept_allele - 1], allele_count - 1)))
+                }
+                format::Number::ReferenceAlternateBases => {
+                    Some(Ok((vec![0, kept_allele], allele_count)))
+                }
+                format::Number::Samples => {
+                    Some(genotype_selection(ploidy, allele_count, kept_allele))
+                }
+                _ => None,
+            }) else {
+                continue;
+            };
+
+            let (indices, expected_len) = selection?;
+            *value = select_sample_values(key, value, &indices, expected_len)?;
+        }
+    }
+
+    Ok(split_record)
+}
+
+fn recode_genotype(
+    genotype: &Genotype,
+    kept_allele: usize,
+    other_allele_policy: OtherAllelePolicy,
+) -> Genotype {
+    let mut recoded_genotype = genotype.clone();
+
+    for allele in recoded_genotype.as_mut() {
+        let position = allele.position_mut();
+
+        *position = match *position {
+            Some(0) => Some(0),
+            Some(i) if i == kept_allele => Some(1),
+            Some(_) => match other_allele_policy {
+                OtherAllelePolicy::Reference => Some(0),
+                OtherAllelePolicy::Missing => None,
+            },
+            None => None,
+        };
+    }
+
+    recoded_genotype
+}
+
+// Returns the indices of the genotypes that only contain the reference allele and the kept allele
+// for the given ploidy, in order, and the number of genotypes for the given ploidy and allele count.
+fn genotype_selection(
+    ploidy: usize,
+    allele_count: usize,
+    kept_allele: usize,
+) -> io::Result<(Vec<usize>, usize)> {
+    genotype_indices(ploidy, kept_allele)
+        .zip(genotype_count(ploidy, allele_count))
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "genotype count overflow"))
+}
+
+// § 1.6.2 "Genotype fields" (2024-06-28): the index of the genotype with sorted alleles
+// a_1 <= ... <= a_p is sum_{i = 1..=p} C(a_i + i - 1, i).
+fn genotype_indices(ploidy: usize, kept_allele: usize) -> Option<Vec<usize>> {
+    (0..=ploidy)
+        .map(|kept_allele_count| {
+            let reference_allele_count = ploidy - kept_allele_count;
+
+            (reference_allele_count + 1..=ploidy).try_fold(0usize, |sum, i| {
+                let n = kept_allele.checked_add(i - 1)?;
+                sum.checked_add(binomial(n, i)?)
+            })
+        })
+        .collect()
+}
+
+fn select_info_values(
+    key: &str,
+    value: &InfoValue,
+    indices: &[usize],
+    expected_len: usize,
+) -> io::Result<InfoValue> {
+    let InfoValue::Array(array) = value else {
+        return Err(invalid_value_count_error(key, expected_len, 1));
+    };
+
+    let array = match array {
+        InfoArray::Integer(values) => {
+            InfoArray::Integer(select(key, values, indices, expected_len)?)
+        }
+        InfoArray::Float(values) => InfoArray::Float(select(key, values, indices, expected_len)?),
+        InfoArray::Character(values) => {
+            InfoArray::Character(select(key, values, indices, expected_len)?)
+        }
+        InfoArray::String(values) => InfoArray::String(select(key, values, indices, expected_len)?),
+    };
+
+    Ok(InfoValue::Array(array))
+}
+
+fn select_sample_values(
+    key: &str,
+    value: &Value,
+    indices: &[usize],
+    expected_len: usize,
+) -> io::Result<Value> {
+    let Value::Array(array) = value else {
+        return Err(invalid_value_count_error(key, expected_len, 1));
+    };
+
+    let array = match array {
+        Array::Integer(values) => Array::Integer(select(key, values, indices, expected_len)?),
+        Array::Float(values) => Array::Float(select(key, values, indices, expected_len)?),
+        Array::Character(values) => Array::Character(select(key, values, indices, expected_len)?),
+        Array::String(values) => Array::String(select(key, values, indices, expected_len)?),
+    };
+
+    Ok(Value::Array(array))
+}
+
+fn select<T>(
+    key: &str,
+    values: &[Option<T>],
+    indices: &[usize],
+    expected_len: usize,
+) -> io::Result<Vec<Option<T>>>
+where
+    T: Clone,
+{
+    if values.len() != expected_len {
+        return Err(invalid_value_count_error(key, expected_len, values.len()));
+    }
+
+    Ok(indices.iter().map(|&i| values[i].clone()).collect())
+}
+
+fn invalid_value_count_error(key: &str, expected: usize, actual: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("invalid value count for {key}: expected {expected}, got {actual}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_multiallelic() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::io::reader::parse_record_buf;
+
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .add_sample_name("sample2")
+            .build();
+
+        let mut record = RecordBuf::default();
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC,G\t.\tPASS\tAF=0.25,0.5;DP=13\tGT:AD:PL\t1/2:0,3,5:90,60,50,40,0,30\t0|2:8,0,5:10,20,30,0,40,50\t0:8,.,.:0,5,7",
+            &header,
+            &mut record,
+        )?;
+
+        let records = split_multiallelic(&record, &header, OtherAllelePolicy::Reference)?;
+
+        let mut expected_c = RecordBuf::default();
+        parse_record_buf(
+            "sq0\t1\t.\tA\tC\t.\tPASS\tAF=0.25;DP=13\tGT:AD:PL\t1/0:0,3:90,60,50\t0|0:8,0:10,20,30\t0:8,.:0,5",
+            &header,
+            &mut expected_c,
+        )?;
+
+        let mut expected_g = RecordBuf::default();
+        parse_record_buf(
+            "sq0\t1\t.\tA\tG\t.\tPASS\tAF=0.5;DP=13\tGT:AD:PL\t0/1:0,5:90,40,30\t0|1:8,5:10,0,50\t0:8,.:0,7",
+            &header,
+            &mut expected_g,
+        )?;
+
+        assert_eq!(records, [expected_c, expected_g]);
+
+        let records = split_multiallelic(&record, &header, OtherAllelePolicy::Missing)?;
+        let genotypes: Vec<_> = records[0]
+            .samples()
+            .values()
+            .map(|sample| sample.get(key::GENOTYPE).flatten().cloned())
+            .collect();
+
+        assert_eq!(
+            genotypes,
+            [
+                Some(Value::Genotype("1/.".parse()?)),
+                Some(Value::Genotype("0|.".parse()?)),
+                Some(Value::Genotype("0".parse()?)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_multiallelic_with_biallelic_record() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::io::reader::parse_record_buf;
+
+        let header = Header::default();
+        let mut record = RecordBuf::default();
+        parse_record_buf("sq0\t1\t.\tA\tC\t.\tPASS\tAF=0.25", &header, &mut record)?;
+
+        assert_eq!(
+            split_multiallelic(&record, &header, OtherAllelePolicy::Reference)?,
+            [record]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_multiallelic_with_invalid_value_count() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use crate::io::reader::parse_record_buf;
+
+        let header = Header::default();
+        let mut record = RecordBuf::default();
+        parse_record_buf("sq0\t1\t.\tA\tC,G\t.\tPASS\tAF=0.25", &header, &mut record)?;
+
+        assert!(matches!(
+            split_multiallelic(&record, &header, OtherAllelePolicy::Reference),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_genotype_indices() {
+        // diploid: 0/0, 0/1, 1/1, 0/2, 1/2, 2/2
+        assert_eq!(genotype_indices(2, 1), Some(vec![0, 1, 2]));
+        assert_eq!(genotype_indices(2, 2), Some(vec![0, 3, 5]));
+        // haploid
+        assert_eq!(genotype_indices(1, 2), Some(vec![0, 2]));
+        // triploid: 000, 001, 011, 111, 002, 012, 112, 022, 122, 222
+        assert_eq!(genotype_indices(3, 2), Some(vec![0, 4, 7, 9]));
+    }
+}